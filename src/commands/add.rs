@@ -1,22 +1,221 @@
 use std::fs;
 use std::path::Path;
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
+
+use crate::rule::find_config;
 
 const VERSION: &str = include_str!("../../.version");
 
 const TEMPLATE: &str = r#"# yaml-language-server: $schema=https://raw.githubusercontent.com/suzuki-hoge/rec_lint/refs/tags/v{version}/schema/rec_lint.schema.json
 "#;
 
-pub fn run(dir: &Path) -> Result<Vec<String>> {
+/// A built-in .rec_lint.yaml template (`add --template`)
+pub struct Template {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Content appended to the schema header
+    pub content: &'static str,
+}
+
+pub const TEMPLATES: &[Template] = &[
+    Template { name: "rust-lib", description: "Rust library (rustdoc, unit tests)", content: RUST_LIB },
+    Template { name: "kotlin-service", description: "Kotlin service (KDoc, Kotest)", content: KOTLIN_SERVICE },
+    Template { name: "php-app", description: "PHP application (PHPDoc, PHPUnit)", content: PHP_APP },
+    Template { name: "java-app", description: "Java application (JUnit, imports)", content: JAVA_APP },
+];
+
+const RUST_LIB: &str = r##"
+rule:
+  - require_rust_doc:
+      label: pub な宣言の rustdoc は必須
+      option:
+        struct: exported
+        enum: exported
+        trait: exported
+        fn: exported
+      message: rustdoc を記述すること
+      match:
+        - pattern: file_ends_with
+          keywords: [ .rs ]
+
+  - require_rust_unit_test:
+      label: ユニットテストは必須
+      option:
+        require: exists
+        style: inline_module
+      message: ユニットテストを作成すること
+      match:
+        - pattern: file_ends_with
+          keywords: [ .rs ]
+
+  - no_skipped_tests:
+      label: スキップしたテストの禁止
+      option:
+        lang: rust
+      message: "#[ignore] を外してテストを実行すること"
+      match:
+        - pattern: file_ends_with
+          keywords: [ .rs ]
+
+  - forbidden_texts:
+      label: dbg! の禁止
+      texts: [ "dbg!(" ]
+      message: デバッグ残りは削除すること
+      match:
+        - pattern: file_ends_with
+          keywords: [ .rs ]
+"##;
+
+const KOTLIN_SERVICE: &str = r##"
+rule:
+  - require_kotlin_doc:
+      label: public な宣言の KDoc は必須
+      option:
+        class: public
+        interface: public
+        function: public
+      message: KDoc を記述すること
+      match:
+        - pattern: file_ends_with
+          keywords: [ .kt ]
+        - pattern: path_contains
+          keywords: [ src/main/kotlin ]
+
+  - require_kotest_test:
+      label: Kotest のテストファイルは必須
+      option:
+        test_directory: src/test/kotlin
+        require: exists
+        test_file_suffix: Test
+      message: テストファイルを作成すること
+      match:
+        - pattern: file_ends_with
+          keywords: [ .kt ]
+        - pattern: path_contains
+          keywords: [ src/main/kotlin ]
+
+  - require_japanese_kotest_test_name:
+      label: Kotest のテスト名は日本語
+      message: テスト名を日本語で記述すること
+      match:
+        - pattern: file_ends_with
+          keywords: [ Test.kt ]
+
+  - no_skipped_tests:
+      label: スキップしたテストの禁止
+      option:
+        lang: kotlin
+      message: "@Disabled を外してテストを実行すること"
+      match:
+        - pattern: file_ends_with
+          keywords: [ Test.kt ]
+"##;
+
+const PHP_APP: &str = r##"
+rule:
+  - require_php_doc:
+      label: public な宣言の PHPDoc は必須
+      option:
+        class: public
+        function: public
+      message: PHPDoc を記述すること
+      match:
+        - pattern: file_ends_with
+          keywords: [ .php ]
+        - pattern: path_contains
+          keywords: [ src/ ]
+
+  - require_phpunit_test:
+      label: PHPUnit のテストファイルは必須
+      option:
+        test_directory: tests
+        require: exists
+        test_file_suffix: Test
+      message: テストファイルを作成すること
+      match:
+        - pattern: file_ends_with
+          keywords: [ .php ]
+        - pattern: path_contains
+          keywords: [ src/ ]
+
+  - require_japanese_phpunit_test_name:
+      label: PHPUnit のテスト名は日本語
+      message: テスト名を日本語で記述すること
+      match:
+        - pattern: file_ends_with
+          keywords: [ Test.php ]
+
+  - forbidden_texts:
+      label: var_dump の禁止
+      texts: [ var_dump ]
+      message: デバッグ残りは削除し、必要な出力は Logger を使うこと
+      match:
+        - pattern: file_ends_with
+          keywords: [ .php ]
+"##;
+
+const JAVA_APP: &str = r##"
+rule:
+  - require_japanese_junit_test_name:
+      label: JUnit のテスト名は日本語
+      message: テスト名を日本語で記述すること
+      match:
+        - pattern: file_ends_with
+          keywords: [ Test.java ]
+
+  - no_skipped_tests:
+      label: スキップしたテストの禁止
+      option:
+        lang: java
+      message: "@Disabled を外してテストを実行すること"
+      match:
+        - pattern: file_ends_with
+          keywords: [ Test.java ]
+
+  - forbidden_imports:
+      label: 旧日付 API の禁止
+      imports: [ java.util.Date, java.util.Calendar ]
+      message: java.time を使うこと
+      match:
+        - pattern: file_ends_with
+          keywords: [ .java ]
+
+  - forbidden_texts:
+      label: 標準出力の禁止
+      texts: [ System.out.print, printStackTrace() ]
+      message: デバッグ残りは削除し、必要な出力は Logger を使うこと
+      match:
+        - pattern: file_ends_with
+          keywords: [ .java ]
+"##;
+
+/// Content of .rec_lint.yaml for `template` (header only when None)
+pub fn content(template: Option<&str>) -> Result<String> {
+    let mut content = TEMPLATE.replace("{version}", VERSION.trim());
+    if let Some(name) = template {
+        let template = TEMPLATES
+            .iter()
+            .find(|t| t.name == name)
+            .ok_or_else(|| anyhow!("Unknown template: {name} (see --list-templates)"))?;
+        content.push_str(template.content);
+    }
+    Ok(content)
+}
+
+pub fn run(dir: &Path, template: Option<&str>) -> Result<Vec<String>> {
     let file_path = dir.join(".rec_lint.yaml");
 
-    if file_path.exists() {
-        bail!("File already exists: {}", file_path.display());
+    if let Some(existing) = find_config(dir) {
+        bail!("File already exists: {}", existing.display());
     }
 
-    let content = TEMPLATE.replace("{version}", VERSION.trim());
-    fs::write(&file_path, content)?;
+    fs::write(&file_path, content(template)?)?;
 
     Ok(vec![format!("Created: {}", file_path.display())])
 }
+
+/// Names and descriptions of the built-in templates
+pub fn list_templates() -> Vec<String> {
+    TEMPLATES.iter().map(|t| format!("{:<16}{}", t.name, t.description)).collect()
+}
@@ -1,31 +1,44 @@
 mod html;
+mod json;
+mod lint;
 mod list;
 mod schema;
 mod tree;
+mod validate;
 
 use std::path::{Path, PathBuf};
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use walkdir::WalkDir;
 
 use super::CheckMode;
 use crate::rule::parser::RawConfig;
 use crate::rule::root_config::{RawRootConfig, RootConfig};
+use crate::rule::{ensure_single_config, find_config, find_root_config};
 
-pub fn run(mode: CheckMode) -> Result<Vec<String>> {
-    let current_dir = std::env::current_dir()?;
-
+/// Run the check from `dir` (the root is discovered by walking up from it)
+/// With `depth`, only .rec_lint.yaml files at most `depth` directories below the root are checked
+pub fn run(mode: CheckMode, dir: &Path, depth: Option<usize>) -> Result<Vec<String>> {
     match mode {
-        CheckMode::List => list::run(&current_dir),
-        CheckMode::Tree => tree::run(&current_dir),
-        CheckMode::Schema => schema::run(&current_dir),
-        CheckMode::Html => html::run(&current_dir),
+        CheckMode::List => list::run(dir, depth),
+        CheckMode::Tree => tree::run(dir, depth),
+        CheckMode::Schema => schema::run(dir, depth),
+        CheckMode::Html => html::run(dir, depth),
+        CheckMode::Lint => lint::run(dir, depth),
+        CheckMode::Json => json::run(dir, depth),
+        CheckMode::Validate => validate::run(dir, depth),
     }
 }
 
+/// Write the HTML view to `output` instead of opening it in a browser
+pub fn write_html(dir: &Path, output: &Path, depth: Option<usize>) -> Result<Vec<String>> {
+    html::write(dir, output, depth)
+}
+
 /// Directory with its rule types
 pub struct DirWithRules {
     pub relative_path: PathBuf,
+    pub config_path: PathBuf,
     pub rule_types: Vec<String>,
 }
 
@@ -35,8 +48,7 @@ pub fn find_root_dir(start: &Path) -> Result<PathBuf> {
     let mut current = Some(start.as_path());
 
     while let Some(dir) = current {
-        let root_config_path = dir.join(".rec_lint_config.yaml");
-        if root_config_path.exists() {
+        if find_root_config(dir).is_some() {
             return Ok(dir.to_path_buf());
         }
         current = dir.parent();
@@ -47,29 +59,49 @@ pub fn find_root_dir(start: &Path) -> Result<PathBuf> {
 
 /// Load root config from root directory
 pub fn load_root_config(root: &Path) -> Result<RootConfig> {
-    let path = root.join(".rec_lint_config.yaml");
+    ensure_single_config(root)?;
+    let path = find_root_config(root).ok_or_else(|| anyhow!("No .rec_lint_config.yaml found in {}", root.display()))?;
     let raw = RawRootConfig::load(&path)?;
-    Ok(RootConfig::from_raw(raw, root))
+    RootConfig::from_raw(raw, root)
 }
 
-/// Collect directories with .rec_lint.yaml files
-pub fn collect_dirs_with_rules(root: &Path, root_config: &RootConfig) -> Result<Vec<DirWithRules>> {
-    let mut results = Vec::new();
+/// Config file of a walked directory (an error when both the YAML and the TOML config exist)
+fn dir_config(dir: &Path) -> Result<Option<PathBuf>> {
+    ensure_single_config(dir)?;
+    Ok(find_config(dir))
+}
 
-    for entry in WalkDir::new(root)
+/// Walk `root` and the directories below it, skipping hidden and excluded directories
+/// Directories with their own root config are independent projects and are not walked
+/// With `depth`, only directories at most `depth` levels below the root are walked (the root always is)
+fn walk_dirs<'a>(
+    root: &Path,
+    root_config: &'a RootConfig,
+    depth: Option<usize>,
+) -> impl Iterator<Item = walkdir::Result<walkdir::DirEntry>> + 'a {
+    WalkDir::new(root)
         .follow_links(false)
+        .max_depth(depth.unwrap_or(usize::MAX))
         .into_iter()
-        .filter_entry(|e| !is_hidden(e) && !is_excluded(e, root_config))
-    {
+        .filter_entry(|e| !is_hidden(e) && !is_excluded(e, root_config) && !is_nested_root(e))
+        .filter(|e| e.as_ref().map_or(true, |e| e.file_type().is_dir()))
+}
+
+/// Collect directories with .rec_lint.yaml files
+pub fn collect_dirs_with_rules(
+    root: &Path,
+    root_config: &RootConfig,
+    depth: Option<usize>,
+) -> Result<Vec<DirWithRules>> {
+    let mut results = Vec::new();
+
+    for entry in walk_dirs(root, root_config, depth) {
         let entry = entry?;
-        if entry.file_type().is_dir() {
-            let config_path = entry.path().join(".rec_lint.yaml");
-            if config_path.exists() {
-                let raw = RawConfig::load(&config_path)?;
-                let rule_types = extract_rule_types(&raw);
-                let relative = entry.path().strip_prefix(root)?.to_path_buf();
-                results.push(DirWithRules { relative_path: relative, rule_types });
-            }
+        if let Some(config_path) = dir_config(entry.path())? {
+            let raw = RawConfig::load(&config_path)?;
+            let rule_types = extract_rule_types(&raw);
+            let relative = entry.path().strip_prefix(root)?.to_path_buf();
+            results.push(DirWithRules { relative_path: relative, config_path, rule_types });
         }
     }
 
@@ -97,6 +129,18 @@ pub fn extract_rule_types(config: &RawConfig) -> Vec<String> {
                         Some("require_kotlin_doc".to_string())
                     } else if r.require_rust_doc.is_some() {
                         Some("require_rust_doc".to_string())
+                    } else if r.require_python_doc.is_some() {
+                        Some("require_python_doc".to_string())
+                    } else if r.require_typescript_doc.is_some() {
+                        Some("require_typescript_doc".to_string())
+                    } else if r.require_go_doc.is_some() {
+                        Some("require_go_doc".to_string())
+                    } else if r.require_swift_doc.is_some() {
+                        Some("require_swift_doc".to_string())
+                    } else if r.require_csharp_doc.is_some() {
+                        Some("require_csharp_doc".to_string())
+                    } else if r.require_doc.is_some() {
+                        Some("require_doc".to_string())
                     } else if r.require_english_comment.is_some() {
                         Some("require_english_comment".to_string())
                     } else if r.require_japanese_comment.is_some() {
@@ -107,12 +151,44 @@ pub fn extract_rule_types(config: &RawConfig) -> Vec<String> {
                         Some("require_japanese_kotest_test_name".to_string())
                     } else if r.require_japanese_rust_test_name.is_some() {
                         Some("require_japanese_rust_test_name".to_string())
+                    } else if r.require_japanese_junit_test_name.is_some() {
+                        Some("require_japanese_junit_test_name".to_string())
+                    } else if r.require_japanese_spock_test_name.is_some() {
+                        Some("require_japanese_spock_test_name".to_string())
                     } else if r.require_phpunit_test.is_some() {
                         Some("require_phpunit_test".to_string())
                     } else if r.require_kotest_test.is_some() {
                         Some("require_kotest_test".to_string())
                     } else if r.require_rust_unit_test.is_some() {
                         Some("require_rust_unit_test".to_string())
+                    } else if r.consistent_quotes.is_some() {
+                        Some("consistent_quotes".to_string())
+                    } else if r.no_skipped_tests.is_some() {
+                        Some("no_skipped_tests".to_string())
+                    } else if r.max_line_length.is_some() {
+                        Some("max_line_length".to_string())
+                    } else if r.no_trailing_whitespace.is_some() {
+                        Some("no_trailing_whitespace".to_string())
+                    } else if r.require_trailing_newline.is_some() {
+                        Some("require_trailing_newline".to_string())
+                    } else if r.forbidden_file_names.is_some() {
+                        Some("forbidden_file_names".to_string())
+                    } else if r.max_file_lines.is_some() {
+                        Some("max_file_lines".to_string())
+                    } else if r.require_go_test.is_some() {
+                        Some("require_go_test".to_string())
+                    } else if r.forbidden_imports.is_some() {
+                        Some("forbidden_imports".to_string())
+                    } else if r.required_texts.is_some() {
+                        Some("required_texts".to_string())
+                    } else if r.require_japanese_python_test_name.is_some() {
+                        Some("require_japanese_python_test_name".to_string())
+                    } else if r.require_japanese_jest_test_name.is_some() {
+                        Some("require_japanese_jest_test_name".to_string())
+                    } else if r.preset.is_some() {
+                        Some("preset".to_string())
+                    } else if r.no_stale_todo.is_some() {
+                        Some("no_stale_todo".to_string())
                     } else {
                         None
                     }
@@ -127,10 +203,15 @@ fn is_hidden(entry: &walkdir::DirEntry) -> bool {
     entry.file_name().to_str().map(|s| s.starts_with('.')).unwrap_or(false)
 }
 
+/// Check if entry is the root directory of another project below the walked root
+fn is_nested_root(entry: &walkdir::DirEntry) -> bool {
+    entry.depth() > 0 && entry.file_type().is_dir() && find_root_config(entry.path()).is_some()
+}
+
 /// Check if entry should be excluded based on root config
 fn is_excluded(entry: &walkdir::DirEntry, root_config: &RootConfig) -> bool {
     if !entry.file_type().is_dir() {
         return false;
     }
-    root_config.should_exclude_dir(entry.file_name())
+    root_config.should_exclude_dir(entry.file_name()) || root_config.is_ignored(entry.path(), true)
 }
@@ -1,311 +1,42 @@
-use std::collections::HashMap;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
-use super::{extract_rule_types, find_root_dir, load_root_config};
-use crate::rule::parser::{MatchPattern, RawConfig, RawRuleContent, RawRuleItem};
-use crate::rule::root_config::RootConfig;
+use super::tree::{self, TreeNode};
+use super::{find_root_dir, load_root_config};
 
-/// Rule details for HTML display
-#[derive(Clone)]
-struct RuleDetail {
-    rule_type: String,
-    label: String,
-    message: String,
-    match_info: Vec<String>,
-}
-
-/// Guideline details for HTML display
-#[derive(Clone)]
-struct GuidelineDetail {
-    message: String,
-    match_info: Vec<String>,
-}
-
-/// Combined details for rules and guidelines
-type DetailsEntry = (Vec<RuleDetail>, Vec<GuidelineDetail>);
-
-struct TreeNode {
-    name: String,
-    full_path: PathBuf,
-    config_file: Option<PathBuf>, // Full path to .rec_lint.yaml or .rec_lint.yml
-    rule_types: Option<Vec<String>>,
-    rule_details: Vec<RuleDetail>,
-    guideline_details: Vec<GuidelineDetail>,
-    children: Vec<TreeNode>,
-}
-
-impl TreeNode {
-    fn has_rules_in_subtree(&self) -> bool {
-        if self.rule_types.is_some() {
-            return true;
-        }
-        self.children.iter().any(|c| c.has_rules_in_subtree())
-    }
-
-    fn filter_empty_subtrees(self) -> Self {
-        let children =
-            self.children.into_iter().filter(|c| c.has_rules_in_subtree()).map(|c| c.filter_empty_subtrees()).collect();
-        TreeNode {
-            name: self.name,
-            full_path: self.full_path,
-            config_file: self.config_file,
-            rule_types: self.rule_types,
-            rule_details: self.rule_details,
-            guideline_details: self.guideline_details,
-            children,
-        }
-    }
-}
-
-pub fn run(start: &Path) -> Result<Vec<String>> {
-    let root = find_root_dir(start)?;
-    let root_config = load_root_config(&root)?;
-
-    // Build maps
-    let rules_map = build_rules_map(&root, &root_config)?;
-    let details_map = build_details_map(&root, &root_config)?;
-
-    // Build tree structure
-    let tree = build_tree_node(&root, &root, &rules_map, &details_map, &root_config)?;
-    let tree = tree.filter_empty_subtrees();
-
-    // Generate HTML
-    let html = generate_html(&tree, &root);
+pub fn run(start: &Path, depth: Option<usize>) -> Result<Vec<String>> {
+    let html = build_html(start, depth)?;
 
     // Write to temp file and open in browser
     let temp_dir = std::env::temp_dir();
     let html_path = temp_dir.join("rec_lint_check.html");
     std::fs::write(&html_path, &html)?;
 
-    open::that(&html_path)?;
+    open::that(&html_path).with_context(|| {
+        format!(
+            "Failed to open browser for {} (use --html-out <FILE> to write the HTML without opening it)",
+            html_path.display()
+        )
+    })?;
 
     Ok(vec![format!("Opened: {}", html_path.display())])
 }
 
-/// Returns (rule_types, config_file_path)
-fn build_rules_map(root: &Path, root_config: &RootConfig) -> Result<HashMap<PathBuf, (Vec<String>, PathBuf)>> {
-    let mut map = HashMap::new();
-
-    for entry in walkdir::WalkDir::new(root)
-        .follow_links(false)
-        .into_iter()
-        .filter_entry(|e| !is_hidden(e) && !is_excluded(e, root_config))
-    {
-        let entry = entry?;
-        if entry.file_type().is_dir() {
-            // Try .rec_lint.yaml first, then .rec_lint.yml
-            let yaml_path = entry.path().join(".rec_lint.yaml");
-            let yml_path = entry.path().join(".rec_lint.yml");
-            let config_path = if yaml_path.exists() {
-                Some(yaml_path)
-            } else if yml_path.exists() {
-                Some(yml_path)
-            } else {
-                None
-            };
-
-            if let Some(config_path) = config_path {
-                let raw = RawConfig::load(&config_path)?;
-                let types = extract_rule_types(&raw);
-                let relative = entry.path().strip_prefix(root)?.to_path_buf();
-                map.insert(relative, (types, config_path));
-            }
-        }
-    }
-
-    Ok(map)
-}
-
-/// Returns (rule_details, guideline_details)
-fn build_details_map(root: &Path, root_config: &RootConfig) -> Result<HashMap<PathBuf, DetailsEntry>> {
-    let mut map = HashMap::new();
-
-    for entry in walkdir::WalkDir::new(root)
-        .follow_links(false)
-        .into_iter()
-        .filter_entry(|e| !is_hidden(e) && !is_excluded(e, root_config))
-    {
-        let entry = entry?;
-        if entry.file_type().is_dir() {
-            // Try .rec_lint.yaml first, then .rec_lint.yml
-            let yaml_path = entry.path().join(".rec_lint.yaml");
-            let yml_path = entry.path().join(".rec_lint.yml");
-            let config_path = if yaml_path.exists() {
-                Some(yaml_path)
-            } else if yml_path.exists() {
-                Some(yml_path)
-            } else {
-                None
-            };
-
-            if let Some(config_path) = config_path {
-                let raw = RawConfig::load(&config_path)?;
-                let rule_details = extract_rule_details(&raw);
-                let guideline_details = extract_guideline_details(&raw);
-                let relative = entry.path().strip_prefix(root)?.to_path_buf();
-                map.insert(relative, (rule_details, guideline_details));
-            }
-        }
-    }
-
-    Ok(map)
-}
-
-fn extract_rule_details(config: &RawConfig) -> Vec<RuleDetail> {
-    let mut details = Vec::new();
-
-    if let Some(rules) = &config.rule {
-        for rule in rules {
-            if let Some((rule_type, content)) = get_rule_type_and_content(rule) {
-                let match_info = content
-                    .match_
-                    .iter()
-                    .map(|m| {
-                        let pattern_name = match m.pattern {
-                            MatchPattern::FileStartsWith => "file_starts_with",
-                            MatchPattern::FileEndsWith => "file_ends_with",
-                            MatchPattern::PathContains => "path_contains",
-                            MatchPattern::FileNotStartsWith => "file_not_starts_with",
-                            MatchPattern::FileNotEndsWith => "file_not_ends_with",
-                            MatchPattern::PathNotContains => "path_not_contains",
-                        };
-                        format!("{}: {}", pattern_name, m.keywords.join(", "))
-                    })
-                    .collect();
-
-                details.push(RuleDetail {
-                    rule_type: rule_type.to_string(),
-                    label: content.label.clone(),
-                    message: content.message.clone(),
-                    match_info,
-                });
-            }
-        }
-    }
-
-    details
-}
-
-fn extract_guideline_details(config: &RawConfig) -> Vec<GuidelineDetail> {
-    let mut details = Vec::new();
-
-    if let Some(guidelines) = &config.guideline {
-        for guideline in guidelines {
-            let match_info = guideline
-                .match_
-                .iter()
-                .map(|m| {
-                    let pattern_name = match m.pattern {
-                        MatchPattern::FileStartsWith => "file_starts_with",
-                        MatchPattern::FileEndsWith => "file_ends_with",
-                        MatchPattern::PathContains => "path_contains",
-                        MatchPattern::FileNotStartsWith => "file_not_starts_with",
-                        MatchPattern::FileNotEndsWith => "file_not_ends_with",
-                        MatchPattern::PathNotContains => "path_not_contains",
-                    };
-                    format!("{}: {}", pattern_name, m.keywords.join(", "))
-                })
-                .collect();
-
-            details.push(GuidelineDetail { message: guideline.message.clone(), match_info });
-        }
-    }
-
-    details
-}
-
-fn get_rule_type_and_content(rule: &RawRuleItem) -> Option<(&str, &RawRuleContent)> {
-    if let Some(c) = &rule.forbidden_texts {
-        return Some(("forbidden_texts", c));
-    }
-    if let Some(c) = &rule.forbidden_patterns {
-        return Some(("forbidden_patterns", c));
-    }
-    if let Some(c) = &rule.custom {
-        return Some(("custom", c));
-    }
-    if let Some(c) = &rule.require_php_doc {
-        return Some(("require_php_doc", c));
-    }
-    if let Some(c) = &rule.require_kotlin_doc {
-        return Some(("require_kotlin_doc", c));
-    }
-    if let Some(c) = &rule.require_rust_doc {
-        return Some(("require_rust_doc", c));
-    }
-    if let Some(c) = &rule.require_english_comment {
-        return Some(("require_english_comment", c));
-    }
-    if let Some(c) = &rule.require_japanese_comment {
-        return Some(("require_japanese_comment", c));
-    }
-    if let Some(c) = &rule.require_japanese_phpunit_test_name {
-        return Some(("require_japanese_phpunit_test_name", c));
-    }
-    if let Some(c) = &rule.require_japanese_kotest_test_name {
-        return Some(("require_japanese_kotest_test_name", c));
-    }
-    if let Some(c) = &rule.require_japanese_rust_test_name {
-        return Some(("require_japanese_rust_test_name", c));
-    }
-    if let Some(c) = &rule.require_phpunit_test {
-        return Some(("require_phpunit_test", c));
-    }
-    if let Some(c) = &rule.require_kotest_test {
-        return Some(("require_kotest_test", c));
-    }
-    if let Some(c) = &rule.require_rust_unit_test {
-        return Some(("require_rust_unit_test", c));
-    }
-    None
+/// Write the HTML to `output` without opening a browser (for headless environments such as CI)
+pub fn write(start: &Path, output: &Path, depth: Option<usize>) -> Result<Vec<String>> {
+    let html = build_html(start, depth)?;
+    std::fs::write(output, &html).with_context(|| format!("Failed to write HTML file: {}", output.display()))?;
+    Ok(vec![format!("Written: {}", output.display())])
 }
 
-fn build_tree_node(
-    dir: &Path,
-    root: &Path,
-    rules_map: &HashMap<PathBuf, (Vec<String>, PathBuf)>,
-    details_map: &HashMap<PathBuf, DetailsEntry>,
-    root_config: &RootConfig,
-) -> Result<TreeNode> {
-    let relative = dir.strip_prefix(root).unwrap_or(Path::new("."));
-    let name = if relative.as_os_str().is_empty() {
-        ".".to_string()
-    } else {
-        dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| ".".to_string())
-    };
-
-    let (rule_types, config_file) = match rules_map.get(&relative.to_path_buf()) {
-        Some((types, config_path)) => (Some(types.clone()), Some(config_path.clone())),
-        None => (None, None),
-    };
-    let (rule_details, guideline_details) = details_map.get(&relative.to_path_buf()).cloned().unwrap_or_default();
-
-    let mut children = Vec::new();
-    if let Ok(entries) = std::fs::read_dir(dir) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_dir() {
-                let dir_name = path.file_name().unwrap();
-                if !dir_name.to_string_lossy().starts_with('.') && !root_config.should_exclude_dir(dir_name) {
-                    children.push(build_tree_node(&path, root, rules_map, details_map, root_config)?);
-                }
-            }
-        }
-    }
+fn build_html(start: &Path, depth: Option<usize>) -> Result<String> {
+    let root = find_root_dir(start)?;
+    let root_config = load_root_config(&root)?;
 
-    children.sort_by(|a, b| a.name.cmp(&b.name));
+    let tree = tree::build(&root, &root_config, depth)?;
 
-    Ok(TreeNode {
-        name,
-        full_path: dir.to_path_buf(),
-        config_file,
-        rule_types,
-        rule_details,
-        guideline_details,
-        children,
-    })
+    Ok(generate_html(&tree, &root))
 }
 
 fn generate_html(tree: &TreeNode, root: &Path) -> String {
@@ -646,14 +377,3 @@ fn render_tree_html(node: &TreeNode) -> String {
 fn html_escape(s: &str) -> String {
     s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&#39;")
 }
-
-fn is_hidden(entry: &walkdir::DirEntry) -> bool {
-    entry.file_name().to_str().map(|s| s.starts_with('.')).unwrap_or(false)
-}
-
-fn is_excluded(entry: &walkdir::DirEntry, root_config: &RootConfig) -> bool {
-    if !entry.file_type().is_dir() {
-        return false;
-    }
-    root_config.should_exclude_dir(entry.file_name())
-}
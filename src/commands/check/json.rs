@@ -0,0 +1,55 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use super::tree::{self, TreeNode};
+use super::{find_root_dir, load_root_config};
+
+/// Print the directory tree with rule and guideline details as JSON
+pub fn run(start: &Path, depth: Option<usize>) -> Result<Vec<String>> {
+    let root = find_root_dir(start)?;
+    let root_config = load_root_config(&root)?;
+
+    let tree = tree::build(&root, &root_config, depth)?;
+
+    let document = serde_json::to_string_pretty(&node_json(&tree, &root))?;
+    Ok(document.lines().map(|line| line.to_string()).collect())
+}
+
+/// Paths are relative to the root so the output does not depend on where the project is checked out
+fn node_json(node: &TreeNode, root: &Path) -> Value {
+    let rules: Vec<Value> = node
+        .rule_details
+        .iter()
+        .map(|r| {
+            json!({
+                "type": r.rule_type,
+                "label": r.label,
+                "message": r.message,
+                "match": r.match_info,
+            })
+        })
+        .collect();
+    let guidelines: Vec<Value> =
+        node.guideline_details.iter().map(|g| json!({ "message": g.message, "match": g.match_info })).collect();
+    let children: Vec<Value> = node.children.iter().map(|c| node_json(c, root)).collect();
+
+    json!({
+        "name": node.name,
+        "path": relative(&node.full_path, root),
+        "config_file": node.config_file.as_deref().map(|p| relative(p, root)),
+        "rule_types": node.rule_types,
+        "rules": rules,
+        "guidelines": guidelines,
+        "children": children,
+    })
+}
+
+fn relative(path: &Path, root: &Path) -> String {
+    match path.strip_prefix(root) {
+        Ok(rel) if rel.as_os_str().is_empty() => ".".to_string(),
+        Ok(rel) => rel.display().to_string(),
+        Err(_) => path.display().to_string(),
+    }
+}
@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use super::tree::get_rule_type_and_content;
+use super::{dir_config, find_root_dir, load_root_config, walk_dirs};
+use crate::rule::parser::RawConfig;
+
+pub fn run(start: &Path, depth: Option<usize>) -> Result<Vec<String>> {
+    let root = find_root_dir(start)?;
+    let root_config = load_root_config(&root)?;
+    let mut output = Vec::new();
+
+    for entry in walk_dirs(&root, &root_config, depth).filter_map(|e| e.ok()) {
+        let Some(config_path) = dir_config(entry.path())? else {
+            continue;
+        };
+        let problems = lint_config(&RawConfig::load(&config_path)?);
+        if !problems.is_empty() {
+            let relative = config_path.strip_prefix(&root).unwrap_or(&config_path);
+            output.push(format!("Problems: {}", relative.display()));
+            for problem in problems {
+                output.push(format!("  - {problem}"));
+            }
+        }
+    }
+
+    if output.is_empty() {
+        output.push("No problems found in rule config files.".to_string());
+    }
+
+    Ok(output)
+}
+
+/// Find rules that are valid but produce confusing output
+fn lint_config(config: &RawConfig) -> Vec<String> {
+    let mut problems = Vec::new();
+    for rule in config.rule.iter().flatten() {
+        let Some((rule_type, content)) = get_rule_type_and_content(rule) else {
+            continue;
+        };
+        // A preset falls back to its own message
+        if content.message.trim().is_empty() && rule_type != "preset" {
+            problems.push(format!("rule '{}' ({rule_type}): message is empty", content.label));
+        }
+    }
+    problems
+}
@@ -2,15 +2,14 @@ use std::path::Path;
 
 use anyhow::Result;
 use serde_json::Value;
-use walkdir::WalkDir;
 
-use super::{find_root_dir, load_root_config};
-use crate::rule::root_config::RootConfig;
+use super::{dir_config, find_root_dir, load_root_config, walk_dirs};
+use crate::rule::parser::is_toml;
 
 // Embed the bundled schema at compile time (all definitions are inlined)
 const BUNDLED_SCHEMA: &str = include_str!("../../../schema/rec_lint.schema.json");
 
-pub fn run(start: &Path) -> Result<Vec<String>> {
+pub fn run(start: &Path, depth: Option<usize>) -> Result<Vec<String>> {
     let root = find_root_dir(start)?;
     let root_config = load_root_config(&root)?;
     let mut output = Vec::new();
@@ -20,22 +19,16 @@ pub fn run(start: &Path) -> Result<Vec<String>> {
     let schema_json: Value = serde_json::from_str(BUNDLED_SCHEMA)?;
     let compiled = jsonschema::options().build(&schema_json)?;
 
-    // Find all .rec_lint.yaml files
-    for entry in WalkDir::new(&root)
-        .follow_links(false)
-        .into_iter()
-        .filter_entry(|e| !is_hidden(e) && !is_excluded(e, &root_config))
-        .filter_map(|e| e.ok())
-    {
-        let path = entry.path();
-        if path.file_name() == Some(std::ffi::OsStr::new(".rec_lint.yaml")) {
-            match validate_file(path, &compiled) {
+    // Find all .rec_lint.yaml (or .rec_lint.toml) files
+    for entry in walk_dirs(&root, &root_config, depth).filter_map(|e| e.ok()) {
+        if let Some(path) = dir_config(entry.path())? {
+            match validate_file(&path, &compiled) {
                 Ok(()) => {
                     // File is valid
                 }
                 Err(errors) => {
                     has_errors = true;
-                    let relative = path.strip_prefix(&root).unwrap_or(path);
+                    let relative = path.strip_prefix(&root).unwrap_or(&path);
                     output.push(format!("Invalid: {}", relative.display()));
                     for error in errors {
                         output.push(format!("  - {error}"));
@@ -46,7 +39,7 @@ pub fn run(start: &Path) -> Result<Vec<String>> {
     }
 
     if !has_errors {
-        output.push("All .rec_lint.yaml files are valid.".to_string());
+        output.push("All rule config files are valid.".to_string());
     }
 
     Ok(output)
@@ -60,8 +53,13 @@ fn validate_file(path: &Path, schema: &jsonschema::Validator) -> std::result::Re
         return Ok(());
     }
 
-    let yaml_value: serde_yaml::Value = serde_yaml::from_str(&content).map_err(|e| vec![e.to_string()])?;
-    let json_value: Value = serde_json::to_value(yaml_value).map_err(|e| vec![e.to_string()])?;
+    let json_value: Value = if is_toml(path) {
+        let toml_value: toml::Value = toml::from_str(&content).map_err(|e| vec![e.to_string()])?;
+        serde_json::to_value(toml_value).map_err(|e| vec![e.to_string()])?
+    } else {
+        let yaml_value: serde_yaml::Value = serde_yaml::from_str(&content).map_err(|e| vec![e.to_string()])?;
+        serde_json::to_value(yaml_value).map_err(|e| vec![e.to_string()])?
+    };
 
     // Collect all validation errors
     let errors: Vec<String> =
@@ -73,18 +71,3 @@ fn validate_file(path: &Path, schema: &jsonschema::Validator) -> std::result::Re
         Err(errors)
     }
 }
-
-fn is_hidden(entry: &walkdir::DirEntry) -> bool {
-    // Don't skip .rec_lint.yaml files themselves, only hidden directories
-    if entry.file_type().is_file() {
-        return false;
-    }
-    entry.file_name().to_str().map(|s| s.starts_with('.')).unwrap_or(false)
-}
-
-fn is_excluded(entry: &walkdir::DirEntry, root_config: &RootConfig) -> bool {
-    if !entry.file_type().is_dir() {
-        return false;
-    }
-    root_config.should_exclude_dir(entry.file_name())
-}
@@ -3,8 +3,8 @@ use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 
-use super::{extract_rule_types, find_root_dir, load_root_config};
-use crate::rule::parser::RawConfig;
+use super::{dir_config, extract_rule_types, find_root_dir, load_root_config, walk_dirs};
+use crate::rule::parser::{MatchPattern, RawConfig, RawRuleContent, RawRuleItem};
 use crate::rule::root_config::RootConfig;
 
 // Tree drawing characters (ASCII)
@@ -13,10 +13,34 @@ const LAST_BRANCH: &str = "`-- ";
 const VERTICAL: &str = "|   ";
 const EMPTY: &str = "    ";
 
-struct TreeNode {
-    name: String,
-    rule_types: Option<Vec<String>>,
-    children: Vec<TreeNode>,
+/// Rule details shown by --html and --json
+#[derive(Clone)]
+pub(super) struct RuleDetail {
+    pub(super) rule_type: String,
+    pub(super) label: String,
+    pub(super) message: String,
+    pub(super) match_info: Vec<String>,
+}
+
+/// Guideline details shown by --html and --json
+#[derive(Clone)]
+pub(super) struct GuidelineDetail {
+    pub(super) message: String,
+    pub(super) match_info: Vec<String>,
+}
+
+/// Combined details for rules and guidelines
+type DetailsEntry = (Vec<RuleDetail>, Vec<GuidelineDetail>);
+
+/// Directory tree with the rules defined at each level
+pub(super) struct TreeNode {
+    pub(super) name: String,
+    pub(super) full_path: PathBuf,
+    pub(super) config_file: Option<PathBuf>, // Full path to .rec_lint.yaml or .rec_lint.yml
+    pub(super) rule_types: Option<Vec<String>>,
+    pub(super) rule_details: Vec<RuleDetail>,
+    pub(super) guideline_details: Vec<GuidelineDetail>,
+    pub(super) children: Vec<TreeNode>,
 }
 
 impl TreeNode {
@@ -32,22 +56,23 @@ impl TreeNode {
     fn filter_empty_subtrees(self) -> Self {
         let children =
             self.children.into_iter().filter(|c| c.has_rules_in_subtree()).map(|c| c.filter_empty_subtrees()).collect();
-        TreeNode { name: self.name, rule_types: self.rule_types, children }
+        TreeNode {
+            name: self.name,
+            full_path: self.full_path,
+            config_file: self.config_file,
+            rule_types: self.rule_types,
+            rule_details: self.rule_details,
+            guideline_details: self.guideline_details,
+            children,
+        }
     }
 }
 
-pub fn run(start: &Path) -> Result<Vec<String>> {
+pub fn run(start: &Path, depth: Option<usize>) -> Result<Vec<String>> {
     let root = find_root_dir(start)?;
     let root_config = load_root_config(&root)?;
 
-    // Build a map of relative_path -> rule types
-    let rules_map = build_rules_map(&root, &root_config)?;
-
-    // Build tree structure
-    let tree = build_tree_node(&root, &root, &rules_map, &root_config)?;
-
-    // Filter out directories with no rules in subtree
-    let tree = tree.filter_empty_subtrees();
+    let tree = build(&root, &root_config, depth)?;
 
     // Calculate max width of name column (prefix + name)
     let max_name_width = calculate_max_name_width(&tree, &[]);
@@ -58,65 +83,6 @@ pub fn run(start: &Path) -> Result<Vec<String>> {
     Ok(output)
 }
 
-fn build_rules_map(root: &Path, root_config: &RootConfig) -> Result<HashMap<PathBuf, Vec<String>>> {
-    let mut map = HashMap::new();
-
-    // Walk all directories to find .rec_lint.yaml files
-    for entry in walkdir::WalkDir::new(root)
-        .follow_links(false)
-        .into_iter()
-        .filter_entry(|e| !is_hidden(e) && !is_excluded(e, root_config))
-    {
-        let entry = entry?;
-        if entry.file_type().is_dir() {
-            let config_path = entry.path().join(".rec_lint.yaml");
-            if config_path.exists() {
-                let raw = RawConfig::load(&config_path)?;
-                let types = extract_rule_types(&raw);
-                let relative = entry.path().strip_prefix(root)?.to_path_buf();
-                map.insert(relative, types);
-            }
-        }
-    }
-
-    Ok(map)
-}
-
-fn build_tree_node(
-    dir: &Path,
-    root: &Path,
-    rules_map: &HashMap<PathBuf, Vec<String>>,
-    root_config: &RootConfig,
-) -> Result<TreeNode> {
-    let relative = dir.strip_prefix(root).unwrap_or(Path::new("."));
-    let name = if relative.as_os_str().is_empty() {
-        ".".to_string()
-    } else {
-        dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| ".".to_string())
-    };
-
-    let rule_types = rules_map.get(&relative.to_path_buf()).cloned();
-
-    let mut children = Vec::new();
-    if let Ok(entries) = std::fs::read_dir(dir) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_dir() {
-                let dir_name = path.file_name().unwrap();
-                // Skip hidden and excluded directories
-                if !dir_name.to_string_lossy().starts_with('.') && !root_config.should_exclude_dir(dir_name) {
-                    children.push(build_tree_node(&path, root, rules_map, root_config)?);
-                }
-            }
-        }
-    }
-
-    // Sort children alphabetically
-    children.sort_by(|a, b| a.name.cmp(&b.name));
-
-    Ok(TreeNode { name, rule_types, children })
-}
-
 /// Calculate the maximum width of the name column
 /// ancestors: stack of booleans indicating if each ancestor is the last child
 fn calculate_max_name_width(node: &TreeNode, ancestors: &[bool]) -> usize {
@@ -178,13 +144,297 @@ fn render_tree(node: &TreeNode, ancestors: &[bool], rule_column: usize) -> Vec<S
     output
 }
 
-fn is_hidden(entry: &walkdir::DirEntry) -> bool {
-    entry.file_name().to_str().map(|s| s.starts_with('.')).unwrap_or(false)
+/// Build the directory tree from root, keeping only directories with rules in their subtree
+/// With `depth`, directories more than `depth` levels below the root are left out
+pub(super) fn build(root: &Path, root_config: &RootConfig, depth: Option<usize>) -> Result<TreeNode> {
+    let rules_map = build_rules_map(root, root_config, depth)?;
+    let details_map = build_details_map(root, root_config, depth)?;
+    let tree = build_tree_node(root, root, &rules_map, &details_map, root_config, depth)?;
+    Ok(tree.filter_empty_subtrees())
+}
+
+/// Returns (rule_types, config_file_path)
+fn build_rules_map(
+    root: &Path,
+    root_config: &RootConfig,
+    depth: Option<usize>,
+) -> Result<HashMap<PathBuf, (Vec<String>, PathBuf)>> {
+    let mut map = HashMap::new();
+
+    for entry in walk_dirs(root, root_config, depth) {
+        let entry = entry?;
+        if let Some(config_path) = config_path(entry.path())? {
+            let raw = RawConfig::load(&config_path)?;
+            let types = extract_rule_types(&raw);
+            let relative = entry.path().strip_prefix(root)?.to_path_buf();
+            map.insert(relative, (types, config_path));
+        }
+    }
+
+    Ok(map)
+}
+
+/// Try .rec_lint.yaml and .rec_lint.toml first, then .rec_lint.yml
+fn config_path(dir: &Path) -> Result<Option<PathBuf>> {
+    Ok(dir_config(dir)?.or_else(|| Some(dir.join(".rec_lint.yml")).filter(|path| path.exists())))
+}
+
+/// Returns (rule_details, guideline_details)
+fn build_details_map(
+    root: &Path,
+    root_config: &RootConfig,
+    depth: Option<usize>,
+) -> Result<HashMap<PathBuf, DetailsEntry>> {
+    let mut map = HashMap::new();
+
+    for entry in walk_dirs(root, root_config, depth) {
+        let entry = entry?;
+        if let Some(config_path) = config_path(entry.path())? {
+            let raw = RawConfig::load(&config_path)?;
+            let rule_details = extract_rule_details(&raw);
+            let guideline_details = extract_guideline_details(&raw);
+            let relative = entry.path().strip_prefix(root)?.to_path_buf();
+            map.insert(relative, (rule_details, guideline_details));
+        }
+    }
+
+    Ok(map)
+}
+
+fn extract_rule_details(config: &RawConfig) -> Vec<RuleDetail> {
+    let mut details = Vec::new();
+
+    if let Some(rules) = &config.rule {
+        for rule in rules {
+            if let Some((rule_type, content)) = get_rule_type_and_content(rule) {
+                let match_info = content
+                    .match_
+                    .iter()
+                    .map(|m| {
+                        let pattern_name = match m.pattern {
+                            MatchPattern::FileStartsWith => "file_starts_with",
+                            MatchPattern::FileEndsWith => "file_ends_with",
+                            MatchPattern::PathContains => "path_contains",
+                            MatchPattern::FileNotStartsWith => "file_not_starts_with",
+                            MatchPattern::FileNotEndsWith => "file_not_ends_with",
+                            MatchPattern::PathNotContains => "path_not_contains",
+                            MatchPattern::PathGlob => "path_glob",
+                            MatchPattern::PathNotGlob => "path_not_glob",
+                            MatchPattern::PathMatchesRegex => "path_matches_regex",
+                            MatchPattern::PathNotMatchesRegex => "path_not_matches_regex",
+                            MatchPattern::RelativeDepth => "relative_depth",
+                        };
+                        format!("{}: {}", pattern_name, m.keywords.join(", "))
+                    })
+                    .collect();
+
+                details.push(RuleDetail {
+                    rule_type: rule_type.to_string(),
+                    label: content.label.clone(),
+                    message: content.message.clone(),
+                    match_info,
+                });
+            }
+        }
+    }
+
+    details
+}
+
+fn extract_guideline_details(config: &RawConfig) -> Vec<GuidelineDetail> {
+    let mut details = Vec::new();
+
+    if let Some(guidelines) = &config.guideline {
+        for guideline in guidelines {
+            let match_info = guideline
+                .match_
+                .iter()
+                .map(|m| {
+                    let pattern_name = match m.pattern {
+                        MatchPattern::FileStartsWith => "file_starts_with",
+                        MatchPattern::FileEndsWith => "file_ends_with",
+                        MatchPattern::PathContains => "path_contains",
+                        MatchPattern::FileNotStartsWith => "file_not_starts_with",
+                        MatchPattern::FileNotEndsWith => "file_not_ends_with",
+                        MatchPattern::PathNotContains => "path_not_contains",
+                        MatchPattern::PathGlob => "path_glob",
+                        MatchPattern::PathNotGlob => "path_not_glob",
+                        MatchPattern::PathMatchesRegex => "path_matches_regex",
+                        MatchPattern::PathNotMatchesRegex => "path_not_matches_regex",
+                        MatchPattern::RelativeDepth => "relative_depth",
+                    };
+                    format!("{}: {}", pattern_name, m.keywords.join(", "))
+                })
+                .collect();
+
+            details.push(GuidelineDetail { message: guideline.message.clone(), match_info });
+        }
+    }
+
+    details
 }
 
-fn is_excluded(entry: &walkdir::DirEntry, root_config: &RootConfig) -> bool {
-    if !entry.file_type().is_dir() {
-        return false;
+pub(super) fn get_rule_type_and_content(rule: &RawRuleItem) -> Option<(&str, &RawRuleContent)> {
+    if let Some(c) = &rule.forbidden_texts {
+        return Some(("forbidden_texts", c));
+    }
+    if let Some(c) = &rule.forbidden_patterns {
+        return Some(("forbidden_patterns", c));
+    }
+    if let Some(c) = &rule.custom {
+        return Some(("custom", c));
+    }
+    if let Some(c) = &rule.require_php_doc {
+        return Some(("require_php_doc", c));
+    }
+    if let Some(c) = &rule.require_kotlin_doc {
+        return Some(("require_kotlin_doc", c));
+    }
+    if let Some(c) = &rule.require_rust_doc {
+        return Some(("require_rust_doc", c));
+    }
+    if let Some(c) = &rule.require_python_doc {
+        return Some(("require_python_doc", c));
+    }
+    if let Some(c) = &rule.require_typescript_doc {
+        return Some(("require_typescript_doc", c));
+    }
+    if let Some(c) = &rule.require_go_doc {
+        return Some(("require_go_doc", c));
+    }
+    if let Some(c) = &rule.require_swift_doc {
+        return Some(("require_swift_doc", c));
+    }
+    if let Some(c) = &rule.require_csharp_doc {
+        return Some(("require_csharp_doc", c));
+    }
+    if let Some(c) = &rule.require_doc {
+        return Some(("require_doc", c));
+    }
+    if let Some(c) = &rule.require_english_comment {
+        return Some(("require_english_comment", c));
+    }
+    if let Some(c) = &rule.require_japanese_comment {
+        return Some(("require_japanese_comment", c));
+    }
+    if let Some(c) = &rule.require_japanese_phpunit_test_name {
+        return Some(("require_japanese_phpunit_test_name", c));
+    }
+    if let Some(c) = &rule.require_japanese_kotest_test_name {
+        return Some(("require_japanese_kotest_test_name", c));
+    }
+    if let Some(c) = &rule.require_japanese_rust_test_name {
+        return Some(("require_japanese_rust_test_name", c));
+    }
+    if let Some(c) = &rule.require_japanese_junit_test_name {
+        return Some(("require_japanese_junit_test_name", c));
+    }
+    if let Some(c) = &rule.require_japanese_spock_test_name {
+        return Some(("require_japanese_spock_test_name", c));
+    }
+    if let Some(c) = &rule.require_phpunit_test {
+        return Some(("require_phpunit_test", c));
+    }
+    if let Some(c) = &rule.require_kotest_test {
+        return Some(("require_kotest_test", c));
+    }
+    if let Some(c) = &rule.require_rust_unit_test {
+        return Some(("require_rust_unit_test", c));
+    }
+    if let Some(c) = &rule.consistent_quotes {
+        return Some(("consistent_quotes", c));
+    }
+    if let Some(c) = &rule.no_skipped_tests {
+        return Some(("no_skipped_tests", c));
+    }
+    if let Some(c) = &rule.max_line_length {
+        return Some(("max_line_length", c));
+    }
+    if let Some(c) = &rule.no_trailing_whitespace {
+        return Some(("no_trailing_whitespace", c));
+    }
+    if let Some(c) = &rule.require_trailing_newline {
+        return Some(("require_trailing_newline", c));
+    }
+    if let Some(c) = &rule.forbidden_file_names {
+        return Some(("forbidden_file_names", c));
+    }
+    if let Some(c) = &rule.max_file_lines {
+        return Some(("max_file_lines", c));
     }
-    root_config.should_exclude_dir(entry.file_name())
+    if let Some(c) = &rule.require_go_test {
+        return Some(("require_go_test", c));
+    }
+    if let Some(c) = &rule.forbidden_imports {
+        return Some(("forbidden_imports", c));
+    }
+    if let Some(c) = &rule.required_texts {
+        return Some(("required_texts", c));
+    }
+    if let Some(c) = &rule.require_japanese_python_test_name {
+        return Some(("require_japanese_python_test_name", c));
+    }
+    if let Some(c) = &rule.require_japanese_jest_test_name {
+        return Some(("require_japanese_jest_test_name", c));
+    }
+    if let Some(c) = &rule.preset {
+        return Some(("preset", c));
+    }
+    if let Some(c) = &rule.no_stale_todo {
+        return Some(("no_stale_todo", c));
+    }
+    None
+}
+
+fn build_tree_node(
+    dir: &Path,
+    root: &Path,
+    rules_map: &HashMap<PathBuf, (Vec<String>, PathBuf)>,
+    details_map: &HashMap<PathBuf, DetailsEntry>,
+    root_config: &RootConfig,
+    remaining_depth: Option<usize>,
+) -> Result<TreeNode> {
+    let relative = dir.strip_prefix(root).unwrap_or(Path::new("."));
+    let name = if relative.as_os_str().is_empty() {
+        ".".to_string()
+    } else {
+        dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| ".".to_string())
+    };
+
+    let (rule_types, config_file) = match rules_map.get(&relative.to_path_buf()) {
+        Some((types, config_path)) => (Some(types.clone()), Some(config_path.clone())),
+        None => (None, None),
+    };
+    let (rule_details, guideline_details) = details_map.get(&relative.to_path_buf()).cloned().unwrap_or_default();
+
+    let mut children = Vec::new();
+    // Children are not walked once the depth limit is reached
+    let entries = if remaining_depth == Some(0) { None } else { std::fs::read_dir(dir).ok() };
+    if let Some(entries) = entries {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                let dir_name = path.file_name().unwrap();
+                if !dir_name.to_string_lossy().starts_with('.')
+                    && !root_config.should_exclude_dir(dir_name)
+                    && !root_config.is_ignored(&path, true)
+                {
+                    let child_depth = remaining_depth.map(|d| d - 1);
+                    children.push(build_tree_node(&path, root, rules_map, details_map, root_config, child_depth)?);
+                }
+            }
+        }
+    }
+
+    children.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(TreeNode {
+        name,
+        full_path: dir.to_path_buf(),
+        config_file,
+        rule_types,
+        rule_details,
+        guideline_details,
+        children,
+    })
 }
@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use super::{dir_config, find_root_dir, load_root_config, walk_dirs};
+use crate::rule::parser::RawConfig;
+use crate::rule::Config;
+
+pub fn run(start: &Path, depth: Option<usize>) -> Result<Vec<String>> {
+    let root = find_root_dir(start)?;
+    let root_config = load_root_config(&root)?;
+    let mut output = Vec::new();
+
+    for entry in walk_dirs(&root, &root_config, depth).filter_map(|e| e.ok()) {
+        let Some(config_path) = dir_config(entry.path())? else {
+            continue;
+        };
+        if let Err(e) = load_config(&config_path) {
+            let relative = config_path.strip_prefix(&root).unwrap_or(&config_path);
+            output.push(format!("Invalid: {}", relative.display()));
+            // Regex errors span several lines, so continuation lines are indented under the item
+            for (i, line) in format!("{e:#}").lines().enumerate() {
+                output.push(if i == 0 { format!("  - {line}") } else { format!("    {line}") });
+            }
+        }
+    }
+
+    if output.is_empty() {
+        output.push("All rule config files are valid.".to_string());
+    }
+
+    Ok(output)
+}
+
+/// Load and convert the config the same way validate does, surfacing rule errors such as invalid regexes
+fn load_config(path: &Path) -> Result<Config> {
+    Config::try_from(RawConfig::load(path)?)
+}
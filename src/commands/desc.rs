@@ -1,7 +1,8 @@
 use anyhow::Result;
 
-pub fn run() -> Result<Vec<String>> {
-    Ok(r#"
+use crate::commands::Lang;
+
+const DESC_JA: &str = r#"
 rec_lint は 設定ファイル ( .rec_lint.yaml ) をもとにコードチェックを行うリンタです
 各ディレクトリの設定ファイルは、それより上位ディレクトリの設定ファイルを継承します
 
@@ -10,9 +11,23 @@ rec_lint show <DIR> は、そのディレクトリに適用されるルールと
 rec_lint validate <DIR|FILE>... は、そのディレクトリに適用されているルールをファイルが満たしているか検証します
 
 rec_lint guideline <DIR> は、そのディレクトリの実装ガイドラインが表示されます
-"#
-    .trim()
-    .split("\n")
-    .map(|line| line.to_string())
-    .collect())
+"#;
+
+const DESC_EN: &str = r#"
+rec_lint is a linter that checks code based on config files ( .rec_lint.yaml )
+The config file of each directory inherits the config files of its parent directories
+
+rec_lint show <DIR> shows the rules and guidelines applied to the directory
+
+rec_lint validate <DIR|FILE>... validates that files satisfy the rules applied to their directory
+
+rec_lint guideline <DIR> shows the implementation guidelines of the directory
+"#;
+
+pub fn run(lang: Lang) -> Result<Vec<String>> {
+    let desc = match lang {
+        Lang::Ja => DESC_JA,
+        Lang::En => DESC_EN,
+    };
+    Ok(desc.trim().split("\n").map(|line| line.to_string()).collect())
 }
@@ -0,0 +1,40 @@
+use anyhow::{anyhow, Result};
+
+use crate::commands::Lang;
+use crate::rule::types::{RuleTypeInfo, PRESET_INFOS, RULE_TYPE_INFOS};
+
+pub fn run(rule_type: &str, lang: Lang) -> Result<Vec<String>> {
+    let info = RULE_TYPE_INFOS.iter().find(|info| info.type_name == rule_type).ok_or_else(|| {
+        let types: Vec<String> = RULE_TYPE_INFOS.iter().map(|info| format!("  - {}", info.type_name)).collect();
+        anyhow!("Unknown rule type: {rule_type}\n\nValid rule types:\n{}", types.join("\n"))
+    })?;
+    Ok(render(info, lang))
+}
+
+fn render(info: &RuleTypeInfo, lang: Lang) -> Vec<String> {
+    let (description, example_heading) = match lang {
+        Lang::Ja => (info.description, "例:"),
+        Lang::En => (info.description_en, "Example:"),
+    };
+    let mut lines = vec![format!("{}: {description}", info.type_name), String::new(), example_heading.to_string()];
+    lines.push("rule:".to_string());
+    for (i, line) in info.example.lines().enumerate() {
+        let indent = if i == 0 { "  - " } else { "    " };
+        lines.push(format!("{indent}{line}"));
+    }
+    if info.type_name == "preset" {
+        lines.push(String::new());
+        lines.push(match lang {
+            Lang::Ja => "プリセット:".to_string(),
+            Lang::En => "Presets:".to_string(),
+        });
+        lines.extend(PRESET_INFOS.iter().map(|preset| {
+            let description = match lang {
+                Lang::Ja => preset.description,
+                Lang::En => preset.description_en,
+            };
+            format!("  - {}: {description}", preset.name)
+        }));
+    }
+    lines
+}
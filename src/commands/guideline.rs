@@ -1,13 +1,40 @@
 use std::path::Path;
 
 use anyhow::Result;
+use serde::Serialize;
 
-use crate::rule::collect_rules;
+use crate::commands::GuidelineFormat;
+use crate::rule::parser::RawMatchItem;
+use crate::rule::{collect_rules, CollectedRules};
 
-pub fn run(dir: &Path) -> Result<Vec<String>> {
+/// Guidelines for --format json
+#[derive(Serialize)]
+struct GuidelineDocument<'a> {
+    /// Always false: guidelines are listed for the directory, not filtered per file by `match`
+    match_applied: bool,
+    guidelines: Vec<GuidelineEntry<'a>>,
+}
+
+#[derive(Serialize)]
+struct GuidelineEntry<'a> {
+    /// Directory of the defining .rec_lint.yaml, relative to the root ("." for the root)
+    source_dir: String,
+    message: &'a str,
+    #[serde(rename = "match")]
+    match_: &'a [RawMatchItem],
+}
+
+pub fn run(dir: &Path, format: GuidelineFormat) -> Result<Vec<String>> {
     let rules = collect_rules(dir)?;
 
-    let output: Vec<String> = rules
+    match format {
+        GuidelineFormat::Text => Ok(text_lines(&rules)),
+        GuidelineFormat::Json => json_lines(&rules),
+    }
+}
+
+fn text_lines(rules: &CollectedRules) -> Vec<String> {
+    rules
         .guideline
         .iter()
         .map(|(item, source_dir)| {
@@ -19,7 +46,24 @@ pub fn run(dir: &Path) -> Result<Vec<String>> {
                 format!("[ guideline ] {}", item.message)
             }
         })
+        .collect()
+}
+
+fn json_lines(rules: &CollectedRules) -> Result<Vec<String>> {
+    let guidelines = rules
+        .guideline
+        .iter()
+        .map(|(item, source_dir)| GuidelineEntry {
+            source_dir: match source_dir.strip_prefix(&rules.root_dir) {
+                Ok(rel) if rel.as_os_str().is_empty() => ".".to_string(),
+                Ok(rel) => rel.display().to_string(),
+                Err(_) => source_dir.display().to_string(),
+            },
+            message: &item.message,
+            match_: &item.matcher.items,
+        })
         .collect();
 
-    Ok(output)
+    let document = serde_json::to_string_pretty(&GuidelineDocument { match_applied: false, guidelines })?;
+    Ok(document.lines().map(|line| line.to_string()).collect())
 }
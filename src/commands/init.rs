@@ -3,19 +3,34 @@ use std::path::Path;
 
 use anyhow::{bail, Result};
 
+use crate::rule::find_root_config;
+
 const VERSION: &str = include_str!("../../.version");
 
 const TEMPLATE: &str = r#"# yaml-language-server: $schema=https://raw.githubusercontent.com/suzuki-hoge/rec_lint/refs/tags/v{version}/schema/rec_lint_config.schema.json
 "#;
 
-pub fn run(dir: &Path) -> Result<Vec<String>> {
+/// Create .rec_lint_config.yaml in `dir`
+/// With `stdout`, the content is returned as output lines instead of being written
+/// An existing config (YAML or TOML) is replaced only with `force`
+pub fn run(dir: &Path, stdout: bool, force: bool) -> Result<Vec<String>> {
     let config_path = dir.join(".rec_lint_config.yaml");
+    let content = TEMPLATE.replace("{version}", VERSION.trim());
 
-    if config_path.exists() {
-        bail!("File already exists: {}", config_path.display());
+    if stdout {
+        return Ok(content.lines().map(String::from).collect());
+    }
+
+    if let Some(existing) = find_root_config(dir) {
+        if !force {
+            bail!("File already exists: {} (use --force to overwrite)", existing.display());
+        }
+        // A TOML config left next to the new YAML one would make the directory ambiguous
+        if existing != config_path {
+            fs::remove_file(&existing)?;
+        }
     }
 
-    let content = TEMPLATE.replace("{version}", VERSION.trim());
     fs::write(&config_path, content)?;
 
     Ok(vec![format!("Created: {}", config_path.display())])
@@ -1,16 +1,23 @@
 pub mod add;
 pub mod check;
 pub mod desc;
+pub mod explain;
 pub mod guideline;
 pub mod init;
+pub mod report;
 pub mod show;
+pub mod stats;
 pub mod validate;
 pub mod version;
 
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
+use clap::builder::PossibleValuesParser;
 use clap::{Parser, Subcommand, ValueEnum};
 
+use crate::rule::RULE_TYPES;
+
 /// Sort mode for validate command output
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
 pub enum SortMode {
@@ -21,6 +28,103 @@ pub enum SortMode {
     File,
 }
 
+/// Output format for validate command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Plain text lines (ordered by --sort)
+    #[default]
+    Text,
+    /// JUnit XML report
+    Junit,
+    /// GitHub Actions workflow commands (inline annotations)
+    Github,
+    /// Table of files x rules with pass / fail / - (not applicable) cells
+    Matrix,
+    /// Same as matrix, as CSV
+    MatrixCsv,
+    /// LSP-style diagnostics grouped by file (JSON, zero-based ranges)
+    LspJson,
+    /// Checkstyle XML report grouped by file
+    Checkstyle,
+}
+
+/// Output format for show command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ShowFormat {
+    /// Plain text lines
+    #[default]
+    Text,
+    /// JSON document with the type, label, message and source directory of each rule and guideline
+    Json,
+}
+
+/// Output format for guideline command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum GuidelineFormat {
+    /// Plain text lines
+    #[default]
+    Text,
+    /// JSON document with the match conditions of each guideline
+    Json,
+}
+
+/// Decoding of file content for validate command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Encoding {
+    /// Skip files that are not valid UTF-8 with a warning
+    #[default]
+    Utf8,
+    /// Replace invalid UTF-8 sequences with U+FFFD and validate the file
+    Lossy,
+}
+
+/// Grouping of JUnit testsuites for validate command output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum JunitGroupBy {
+    /// One testsuite per rule, one testcase per violation
+    #[default]
+    Rule,
+    /// One testsuite per file, one testcase per violation
+    File,
+}
+
+/// Language of desc and explain output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Lang {
+    /// Japanese
+    #[default]
+    Ja,
+    /// English
+    En,
+}
+
+impl Lang {
+    /// Environment variable used when --lang is not given
+    pub const ENV: &'static str = "REC_LINT_LANG";
+
+    /// `--lang` if given, otherwise REC_LINT_LANG, otherwise Japanese
+    pub fn resolve(lang: Option<Lang>) -> anyhow::Result<Lang> {
+        if let Some(lang) = lang {
+            return Ok(lang);
+        }
+        match std::env::var(Self::ENV) {
+            Ok(value) if !value.is_empty() => Lang::from_str(&value, true)
+                .map_err(|_| anyhow::anyhow!("Invalid {}: '{value}' (expected ja or en)", Self::ENV)),
+            _ => Ok(Lang::default()),
+        }
+    }
+}
+
+/// When to colorize output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ColorChoice {
+    /// Colorize when stdout is a terminal and NO_COLOR is not set
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
 /// Check mode for check command
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CheckMode {
@@ -28,6 +132,9 @@ pub enum CheckMode {
     Tree,
     Schema,
     Html,
+    Lint,
+    Json,
+    Validate,
 }
 
 #[derive(Parser)]
@@ -40,6 +147,14 @@ The root configuration file must have 'root: true'.")]
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+
+    /// Colorize violation output
+    #[arg(long, global = true, value_enum, default_value = "auto")]
+    pub color: ColorChoice,
+
+    /// Number of threads used to validate files (default: number of CPUs, 1 runs sequentially)
+    #[arg(long, short, global = true, value_name = "N")]
+    pub jobs: Option<NonZeroUsize>,
 }
 
 #[derive(Subcommand)]
@@ -55,6 +170,10 @@ down to the target directory. Output format:\n\n\
         /// Target directory to show rules for (default: current directory)
         #[arg(value_name = "DIR", default_value = ".")]
         dir: PathBuf,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "text")]
+        format: ShowFormat,
     },
 
     /// Validate files against rules
@@ -73,6 +192,127 @@ Validators:\n\
         /// Sort order for output
         #[arg(long, short, value_enum, default_value = "rule")]
         sort: SortMode,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "text")]
+        format: OutputFormat,
+
+        /// Testsuite grouping for --format junit
+        #[arg(long, value_enum, default_value = "rule")]
+        junit_group_by: JunitGroupBy,
+
+        /// Suppress violations recorded in the baseline file
+        #[arg(long, value_name = "FILE")]
+        baseline: Option<PathBuf>,
+
+        /// Record current violations to the baseline file instead of printing them
+        #[arg(long, value_name = "FILE", conflicts_with = "baseline")]
+        write_baseline: Option<PathBuf>,
+
+        /// Disable all rules of the given type (repeatable)
+        #[arg(long, value_name = "TYPE", value_parser = PossibleValuesParser::new(RULE_TYPES))]
+        skip_type: Vec<String>,
+
+        /// Validate only files changed from HEAD (git diff, staged or not) and untracked files
+        #[arg(long)]
+        changed: bool,
+
+        /// Validate only files changed from the given git ref and untracked files
+        #[arg(long, value_name = "REF")]
+        changed_since: Option<String>,
+
+        /// Run only rules with the given label or type (repeatable)
+        #[arg(long, value_name = "LABEL_OR_TYPE")]
+        only: Vec<String>,
+
+        /// Skip rules with the given label or type (repeatable, applied after --only)
+        #[arg(long, value_name = "LABEL_OR_TYPE")]
+        skip: Vec<String>,
+
+        /// Exit with status 2 when no rules apply to any of the target files
+        #[arg(long)]
+        require_rules: bool,
+
+        /// Read file content from stdin instead of walking PATH (requires --stdin-path)
+        #[arg(long, requires = "stdin_path", conflicts_with_all = ["changed", "changed_since", "stream"])]
+        stdin: bool,
+
+        /// Path the stdin content is validated as (rules of its directory apply)
+        #[arg(long, value_name = "PATH", requires = "stdin")]
+        stdin_path: Option<PathBuf>,
+
+        /// Print violations as each file finishes (text and github formats only, no sort across files)
+        #[arg(long, conflicts_with = "write_baseline")]
+        stream: bool,
+
+        /// Fix violations of fixable rules (no_trailing_whitespace, require_trailing_newline) in place and report the rest
+        #[arg(long, conflicts_with = "stdin")]
+        fix: bool,
+
+        /// Warn about rules whose match conditions matched none of the validated files
+        #[arg(long, conflicts_with = "stdin")]
+        warn_unused: bool,
+
+        /// List the files each rule would validate instead of validating them
+        #[arg(long, conflicts_with_all = ["stdin", "stream", "fix", "write_baseline", "count"])]
+        list_files: bool,
+
+        /// Print only the number of violations (config errors are still printed)
+        #[arg(long, conflicts_with = "stream")]
+        count: bool,
+
+        /// How to decode files that are not valid UTF-8
+        #[arg(long, value_enum, default_value = "utf8")]
+        encoding: Encoding,
+
+        /// Do not read or write the result cache of custom rules with `cache: true` (.rec_lint_cache/)
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Apply only this config file to all target files instead of discovering .rec_lint.yaml files
+        /// (rule paths are relative to the first target path)
+        #[arg(long, value_name = "FILE")]
+        config: Option<PathBuf>,
+
+        /// Print the time spent per rule (slowest first) and how many files each rule ran on to stderr
+        #[arg(long, conflicts_with_all = ["stdin", "list_files"])]
+        profile: bool,
+
+        /// Print a final line with the number of violations, files and rules (text format only)
+        #[arg(long, conflicts_with_all = ["count", "list_files", "write_baseline"])]
+        summary: bool,
+
+        /// Validate again each time a file or config under the paths changes (stop with Ctrl-C)
+        #[arg(long, conflicts_with_all = ["stdin", "stream", "list_files", "fix", "write_baseline"])]
+        watch: bool,
+
+        /// Fail only when the number of error violations exceeds N
+        #[arg(long, value_name = "N", default_value_t = 0)]
+        fail_on: usize,
+    },
+
+    /// Summarize violation counts by rule and directory
+    #[command(long_about = "Run the same validation as 'validate' and print violation counts.\n\n\
+Counts are grouped by rule label and by top-level directory (relative to the root),\n\
+sorted by count in descending order, followed by the total.")]
+    Stats {
+        /// Files or directories to validate (default: current directory)
+        #[arg(value_name = "PATH", default_value = ".")]
+        paths: Vec<PathBuf>,
+    },
+
+    /// Render effective rules for a directory as Markdown
+    #[command(long_about = "Render effective rules for the specified directory as a Markdown table.\n\n\
+Each row shows label, rule type, message, target files and the source config file.\n\
+Use --output to write the document to a file.")]
+    Report {
+        /// Target directory to render rules for (default: current directory)
+        #[arg(value_name = "DIR", default_value = ".")]
+        dir: PathBuf,
+
+        /// Write Markdown to the given file instead of stdout
+        #[arg(long, short, value_name = "FILE")]
+        output: Option<PathBuf>,
     },
 
     /// Show guideline points for a directory
@@ -82,6 +322,10 @@ Guideline items are informational reminders for code reviewers.")]
         /// Target directory to show guideline points for (default: current directory)
         #[arg(value_name = "DIR", default_value = ".")]
         dir: PathBuf,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "text")]
+        format: GuidelineFormat,
     },
 
     /// Show version
@@ -92,6 +336,14 @@ Guideline items are informational reminders for code reviewers.")]
         /// Target directory (default: current directory)
         #[arg(value_name = "DIR", default_value = ".")]
         dir: PathBuf,
+
+        /// Print the config to stdout instead of writing the file
+        #[arg(long)]
+        stdout: bool,
+
+        /// Overwrite an existing .rec_lint_config.yaml
+        #[arg(long, conflicts_with = "stdout")]
+        force: bool,
     },
 
     /// Add a new .rec_lint.yaml file
@@ -99,10 +351,33 @@ Guideline items are informational reminders for code reviewers.")]
         /// Target directory (default: current directory)
         #[arg(value_name = "DIR", default_value = ".")]
         dir: PathBuf,
+
+        /// Start from a built-in template instead of an empty file
+        #[arg(long, value_name = "NAME", value_parser = PossibleValuesParser::new(add::TEMPLATES.iter().map(|t| t.name)))]
+        template: Option<String>,
+
+        /// List the built-in templates
+        #[arg(long, conflicts_with = "template")]
+        list_templates: bool,
     },
 
     /// Show description of rec_lint
-    Desc,
+    Desc {
+        /// Output language (default: REC_LINT_LANG, or ja if it is not set)
+        #[arg(long, value_enum, value_name = "LANG")]
+        lang: Option<Lang>,
+    },
+
+    /// Explain a rule type with a minimal example config
+    Explain {
+        /// Rule type (e.g. forbidden_texts)
+        #[arg(value_name = "TYPE")]
+        rule_type: String,
+
+        /// Output language (default: REC_LINT_LANG, or ja if it is not set)
+        #[arg(long, value_enum, value_name = "LANG")]
+        lang: Option<Lang>,
+    },
 
     /// Check project configuration
     #[command(long_about = "Check project configuration structure.\n\n\
@@ -110,8 +385,15 @@ Options:\n\
   --list:   List directories with .rec_lint.yaml and their rule types\n\
   --tree:   Show directory tree with rule types at each level\n\
   --schema: Validate all .rec_lint.yaml files against JSON Schema\n\
-  --html:   Open interactive HTML view in browser")]
+  --html:   Open interactive HTML view in browser\n\
+  --html-out <FILE>: Write the HTML view to a file without opening a browser\n\
+  --json:   Print directory tree with rule and guideline details as JSON\n\
+  --validate: Report rule errors in .rec_lint.yaml files (e.g. invalid regex) that the schema cannot catch")]
     Check {
+        /// Directory to start root discovery from (default: current directory)
+        #[arg(value_name = "DIR", default_value = ".")]
+        dir: PathBuf,
+
         /// List directories with .rec_lint.yaml and their rule types
         #[arg(long, group = "mode")]
         list: bool,
@@ -127,5 +409,25 @@ Options:\n\
         /// Open interactive HTML view in browser
         #[arg(long, group = "mode")]
         html: bool,
+
+        /// Write the HTML view to the given file instead of opening a browser
+        #[arg(long, value_name = "FILE", group = "mode")]
+        html_out: Option<PathBuf>,
+
+        /// Report rules that are valid but likely mistakes (e.g. empty message)
+        #[arg(long, group = "mode")]
+        lint: bool,
+
+        /// Print directory tree with rule and guideline details as JSON
+        #[arg(long, group = "mode")]
+        json: bool,
+
+        /// Load every .rec_lint.yaml as validate does and report rule errors (e.g. invalid regex)
+        #[arg(long, group = "mode")]
+        validate: bool,
+
+        /// Only check .rec_lint.yaml files at most N directories below the root (0: the root only)
+        #[arg(long, value_name = "N")]
+        depth: Option<usize>,
     },
 }
@@ -0,0 +1,97 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::matcher::Matcher;
+use crate::rule::parser::{MatchCond, MatchPattern};
+use crate::rule::{collect_rules, find_config, CollectedRules, CONFIG_FILENAMES};
+
+pub fn run(dir: &Path, output: Option<&Path>) -> Result<Vec<String>> {
+    let rules = collect_rules(dir)?;
+    let lines = render(&rules);
+
+    match output {
+        Some(path) => {
+            fs::write(path, lines.join("\n") + "\n")?;
+            Ok(vec![format!("Created: {}", path.display())])
+        }
+        None => Ok(lines),
+    }
+}
+
+fn render(rules: &CollectedRules) -> Vec<String> {
+    let mut lines = vec![
+        "# rec_lint ルール一覧".to_string(),
+        String::new(),
+        "| ラベル | タイプ | メッセージ | 対象 | 設定ファイル |".to_string(),
+        "| --- | --- | --- | --- | --- |".to_string(),
+    ];
+
+    for (rule, source_dir) in &rules.rule {
+        lines.push(format!(
+            "| {} | `{}` | {} | {} | `{}` |",
+            escape(rule.label()),
+            rule.type_name(),
+            escape(rule.message()),
+            escape(&format_scope(rule.matcher())),
+            format_source(source_dir, &rules.root_dir)
+        ));
+    }
+
+    lines
+}
+
+/// Describe which files a rule applies to
+fn format_scope(matcher: &Matcher) -> String {
+    if matcher.items.is_empty() {
+        return "全ファイル".to_string();
+    }
+    matcher
+        .items
+        .iter()
+        .map(|item| {
+            let cond = match item.cond {
+                MatchCond::And => "",
+                MatchCond::Or => " (or)",
+            };
+            format!("{}: {}{cond}", pattern_name(&item.pattern), item.keywords.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
+
+fn pattern_name(pattern: &MatchPattern) -> &'static str {
+    match pattern {
+        MatchPattern::FileStartsWith => "file_starts_with",
+        MatchPattern::FileEndsWith => "file_ends_with",
+        MatchPattern::PathContains => "path_contains",
+        MatchPattern::FileNotStartsWith => "file_not_starts_with",
+        MatchPattern::FileNotEndsWith => "file_not_ends_with",
+        MatchPattern::PathNotContains => "path_not_contains",
+        MatchPattern::PathGlob => "path_glob",
+        MatchPattern::PathNotGlob => "path_not_glob",
+        MatchPattern::PathMatchesRegex => "path_matches_regex",
+        MatchPattern::PathNotMatchesRegex => "path_not_matches_regex",
+        MatchPattern::RelativeDepth => "relative_depth",
+    }
+}
+
+/// Config file path relative to the root directory
+fn format_source(source_dir: &Path, root_dir: &Path) -> String {
+    let config = find_config(source_dir);
+    let file_name = config
+        .as_deref()
+        .and_then(Path::file_name)
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| CONFIG_FILENAMES[0].to_string());
+    match source_dir.strip_prefix(root_dir) {
+        Ok(relative) if !relative.as_os_str().is_empty() => format!("{}/{}", relative.display(), file_name),
+        _ => file_name,
+    }
+}
+
+/// Escape characters that break a Markdown table cell
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
@@ -1,36 +1,108 @@
 use std::path::Path;
 
 use anyhow::Result;
+use serde::Serialize;
 
+use crate::commands::ShowFormat;
 use crate::rule::{collect_rules, CollectedRules, Rule};
 
-pub fn run(dir: &Path) -> Result<Vec<String>> {
+/// An effective rule or guideline with the directory it is inherited from
+struct EffectiveItem<'a> {
+    kind: ItemKind<'a>,
+    /// Directory of the defining .rec_lint.yaml, relative to the root (None for the root)
+    source_dir: Option<String>,
+}
+
+enum ItemKind<'a> {
+    Rule(&'a Rule),
+    /// Guideline message
+    Guideline(&'a str),
+}
+
+/// Effective rules and guidelines for --format json
+#[derive(Serialize)]
+struct ShowDocument<'a> {
+    rules: Vec<RuleEntry<'a>>,
+    guidelines: Vec<GuidelineEntry<'a>>,
+}
+
+#[derive(Serialize)]
+struct RuleEntry<'a> {
+    #[serde(rename = "type")]
+    type_name: &'static str,
+    label: &'a str,
+    message: &'a str,
+    /// "." for the root
+    source_dir: String,
+}
+
+#[derive(Serialize)]
+struct GuidelineEntry<'a> {
+    message: &'a str,
+    /// "." for the root
+    source_dir: String,
+}
+
+pub fn run(dir: &Path, format: ShowFormat) -> Result<Vec<String>> {
     let rules = collect_rules(dir)?;
-    let mut output = Vec::new();
+    let items = effective_items(&rules);
 
-    for (rule, source_dir) in &rules.rule {
-        output.push(format_rule(rule, source_dir, &rules));
+    match format {
+        ShowFormat::Text => Ok(text_lines(&items)),
+        ShowFormat::Json => json_lines(&items),
     }
+}
 
-    for (item, source_dir) in &rules.guideline {
-        let message = &item.message;
-        output.push(format_item("guideline", message, source_dir, &rules));
-    }
+/// Rules in definition order followed by guidelines
+fn effective_items(rules: &CollectedRules) -> Vec<EffectiveItem<'_>> {
+    let source_dir = |dir: &Path| match dir.strip_prefix(&rules.root_dir) {
+        Ok(rel) if rel.as_os_str().is_empty() => None,
+        Ok(rel) => Some(rel.display().to_string()),
+        Err(_) => None,
+    };
 
-    Ok(output)
+    let rule_items =
+        rules.rule.iter().map(|(rule, dir)| EffectiveItem { kind: ItemKind::Rule(rule), source_dir: source_dir(dir) });
+    let guideline_items = rules
+        .guideline
+        .iter()
+        .map(|(item, dir)| EffectiveItem { kind: ItemKind::Guideline(&item.message), source_dir: source_dir(dir) });
+    rule_items.chain(guideline_items).collect()
 }
 
-fn format_rule(rule: &Rule, source_dir: &Path, rules: &CollectedRules) -> String {
-    let label = rule.label();
-    format_item("rule", label, source_dir, rules)
+fn text_lines(items: &[EffectiveItem]) -> Vec<String> {
+    items
+        .iter()
+        .map(|item| {
+            let (category, text) = match item.kind {
+                ItemKind::Rule(rule) => ("rule", rule.label()),
+                ItemKind::Guideline(message) => ("guideline", message),
+            };
+            match &item.source_dir {
+                Some(source_dir) => format!("[ {category} ] {source_dir}: {text}"),
+                None => format!("[ {category} ] {text}"),
+            }
+        })
+        .collect()
 }
 
-fn format_item(category: &str, message: &str, source_dir: &Path, rules: &CollectedRules) -> String {
-    if source_dir == rules.root_dir {
-        format!("[ {category} ] {message}")
-    } else if let Ok(relative) = source_dir.strip_prefix(&rules.root_dir) {
-        format!("[ {category} ] {}: {message}", relative.display())
-    } else {
-        format!("[ {category} ] {message}")
+fn json_lines(items: &[EffectiveItem]) -> Result<Vec<String>> {
+    let source_dir = |item: &EffectiveItem| item.source_dir.clone().unwrap_or_else(|| ".".to_string());
+
+    let mut rules = Vec::new();
+    let mut guidelines = Vec::new();
+    for item in items {
+        match item.kind {
+            ItemKind::Rule(rule) => rules.push(RuleEntry {
+                type_name: rule.type_name(),
+                label: rule.label(),
+                message: rule.message(),
+                source_dir: source_dir(item),
+            }),
+            ItemKind::Guideline(message) => guidelines.push(GuidelineEntry { message, source_dir: source_dir(item) }),
+        }
     }
+
+    let document = serde_json::to_string_pretty(&ShowDocument { rules, guidelines })?;
+    Ok(document.lines().map(|line| line.to_string()).collect())
 }
@@ -0,0 +1,48 @@
+use std::collections::BTreeMap;
+use std::path::{Component, PathBuf};
+
+use anyhow::Result;
+use unicode_width::UnicodeWidthStr;
+
+use super::validate::{self, FileViolation, RuleCache, ValidateOptions};
+
+pub fn run(paths: &[PathBuf]) -> Result<Vec<String>> {
+    let collected = validate::collect_violations(paths, &ValidateOptions::default(), &mut RuleCache::new())?;
+
+    let mut by_rule: BTreeMap<String, usize> = BTreeMap::new();
+    let mut by_dir: BTreeMap<String, usize> = BTreeMap::new();
+    for v in &collected.violations {
+        *by_rule.entry(v.label.clone()).or_default() += v.count();
+        *by_dir.entry(top_level_dir(v)).or_default() += v.count();
+    }
+    let total: usize = by_rule.values().sum();
+
+    let mut output = collected.errors;
+    output.push("By rule:".to_string());
+    output.extend(render(by_rule));
+    output.push(String::new());
+    output.push("By directory:".to_string());
+    output.extend(render(by_dir));
+    output.push(String::new());
+    output.push(format!("Total: {total}"));
+    Ok(output)
+}
+
+/// First directory of the file relative to the root ("." for files directly under the root)
+fn top_level_dir(v: &FileViolation) -> String {
+    let relative = v.file.strip_prefix(&v.root_dir).unwrap_or(&v.file);
+    let mut components = relative.components().filter(|c| matches!(c, Component::Normal(_)));
+    match (components.next(), components.next()) {
+        (Some(dir), Some(_)) => dir.as_os_str().to_string_lossy().to_string(),
+        _ => ".".to_string(),
+    }
+}
+
+/// Rows sorted by count desc, then name
+fn render(counts: BTreeMap<String, usize>) -> Vec<String> {
+    let mut rows: Vec<(String, usize)> = counts.into_iter().collect();
+    rows.sort_by(|(a_name, a_count), (b_name, b_count)| b_count.cmp(a_count).then_with(|| a_name.cmp(b_name)));
+
+    let width = rows.iter().map(|(name, _)| name.width()).max().unwrap_or(0);
+    rows.into_iter().map(|(name, count)| format!("  {name}{}  {count}", " ".repeat(width - name.width()))).collect()
+}
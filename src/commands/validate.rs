@@ -1,29 +1,75 @@
-use std::collections::HashMap;
+mod baseline;
+mod changed;
+mod checkstyle;
+pub mod color;
+mod github;
+mod junit;
+mod lsp;
+mod matrix;
+mod profile;
+mod rule_cache;
+pub mod watch;
+
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::{Arc, Mutex};
+use std::sync::mpsc;
+use std::time::Instant;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use rayon::prelude::*;
 use walkdir::WalkDir;
 
-use crate::commands::SortMode;
-use crate::rule::{collect_rules, CollectedRules, CommentSource, RootConfig, Rule};
+pub use rule_cache::RuleCache;
+
+use matrix::Applicable;
+use profile::Profile;
+
+use crate::commands::{Encoding, JunitGroupBy, OutputFormat, SortMode};
+use crate::rule::parser::Severity;
+use crate::rule::root_config::RawRootConfig;
+use crate::rule::{
+    collect_rules_from, find_config, find_root_config, CollectedRules, CommentRule, CommentSource, RootConfig, Rule,
+    CONFIG_FILENAMES, ROOT_CONFIG_FILENAMES,
+};
 use crate::validate::comment::{self, CommentViolation};
+use crate::validate::disable::Disables;
 use crate::validate::doc::{self, DocViolation};
 use crate::validate::test::exists::{self as test_exists, TestExistenceViolation};
 use crate::validate::test::{self, TestViolation};
-use crate::validate::{custom, regex, text, CustomViolation, Violation};
+use crate::validate::{
+    custom, file_lines, file_name, final_newline, imports, line_length, line_range, quotes, regex, required_text, text,
+    todo, trailing_whitespace, CustomViolation, Violation,
+};
 
-struct FileViolation {
-    file: PathBuf,
-    root_dir: PathBuf,
+pub(crate) struct FileViolation {
+    pub(crate) file: PathBuf,
+    pub(crate) root_dir: PathBuf,
+    pub(crate) label: String,
+    severity: Severity,
     message: String,
     detail: ViolationDetail,
 }
 
+impl FileViolation {
+    /// Number of violations reported for this file and rule
+    pub(crate) fn count(&self) -> usize {
+        match &self.detail {
+            ViolationDetail::LineViolations(vs) => vs.len(),
+            ViolationDetail::MarkerViolations(vs) => vs.len(),
+            ViolationDetail::CustomViolation(_) => 1,
+            ViolationDetail::DocViolations(vs) => vs.len(),
+            ViolationDetail::CommentViolations(vs) => vs.len(),
+            ViolationDetail::TestViolations(vs) => vs.len(),
+            ViolationDetail::TestExistenceViolations(vs) => vs.len(),
+        }
+    }
+}
+
 enum ViolationDetail {
     LineViolations(Vec<Violation>),
+    /// Line violations whose `found` is shown in the output (e.g. the marker text of no_stale_todo)
+    MarkerViolations(Vec<Violation>),
     CustomViolation(CustomViolation),
     DocViolations(Vec<DocViolation>),
     CommentViolations(Vec<CommentViolation>),
@@ -31,82 +77,727 @@ enum ViolationDetail {
     TestExistenceViolations(Vec<TestExistenceViolation>),
 }
 
+impl ViolationDetail {
+    /// Keep only line-level violations whose line satisfies `keep`
+    /// File-level violations (without a line) are always kept
+    fn retain_lines(&mut self, keep: impl Fn(usize) -> bool) {
+        match self {
+            ViolationDetail::LineViolations(vs) => vs.retain(|v| keep(v.line)),
+            ViolationDetail::MarkerViolations(vs) => vs.retain(|v| keep(v.line)),
+            ViolationDetail::CustomViolation(_) => {}
+            ViolationDetail::DocViolations(vs) => vs.retain(|v| keep(v.line)),
+            ViolationDetail::CommentViolations(vs) => vs.retain(|v| keep(v.line)),
+            ViolationDetail::TestViolations(vs) => vs.retain(|v| keep(v.line)),
+            ViolationDetail::TestExistenceViolations(vs) => vs.retain(|v| match &v.kind {
+                test_exists::TestExistenceViolationKind::UntestedPublicMethod { line, .. }
+                | test_exists::TestExistenceViolationKind::UntestedPublicFunction { line, .. } => keep(*line),
+                _ => true,
+            }),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            ViolationDetail::LineViolations(vs) => vs.is_empty(),
+            ViolationDetail::MarkerViolations(vs) => vs.is_empty(),
+            ViolationDetail::CustomViolation(_) => false,
+            ViolationDetail::DocViolations(vs) => vs.is_empty(),
+            ViolationDetail::CommentViolations(vs) => vs.is_empty(),
+            ViolationDetail::TestViolations(vs) => vs.is_empty(),
+            ViolationDetail::TestExistenceViolations(vs) => vs.is_empty(),
+        }
+    }
+}
+
+/// Options for validate command
+#[derive(Debug, Clone, Default)]
+pub struct ValidateOptions {
+    pub sort_mode: SortMode,
+    pub format: OutputFormat,
+    pub junit_group_by: JunitGroupBy,
+    /// Suppress violations recorded in this baseline file
+    pub baseline: Option<PathBuf>,
+    /// Record current violations to this baseline file instead of printing them
+    pub write_baseline: Option<PathBuf>,
+    /// Rule types to disable (e.g. "custom")
+    pub skip_types: Vec<String>,
+    /// Fail with `NoEffectiveRules` when no rule applies to any target file
+    pub require_rules: bool,
+    /// Validate only files changed from this git ref instead of walking `paths`
+    pub changed: Option<String>,
+    /// Run only rules whose label or type is listed (all rules when empty)
+    pub only: Vec<String>,
+    /// Do not run rules whose label or type is listed (applied after `only`)
+    pub skip: Vec<String>,
+    /// Correct violations of fixable rules in place before validating
+    pub fix: bool,
+    /// Report rules whose matcher matched none of the validated files
+    pub warn_unused: bool,
+    /// Colorize text output (resolved from --color by the caller)
+    pub color: bool,
+    /// Output the violation count instead of the violations
+    pub count: bool,
+    /// Decoding of files that are not valid UTF-8
+    pub encoding: Encoding,
+    /// Ignore the result cache of custom rules with `cache: true`
+    pub no_cache: bool,
+    /// Apply only this config file to all target files instead of discovering configs
+    pub config: Option<PathBuf>,
+    /// Measure the time spent per rule and report it in `ValidateReport::profile`
+    pub profile: bool,
+    /// Append a line with the number of violations, files and rules (text format only)
+    pub summary: bool,
+}
+
+impl ValidateOptions {
+    /// Returns true if the rule is selected by `only` and `skip`
+    fn is_selected(&self, rule: &Rule) -> bool {
+        let matches = |names: &[String]| names.iter().any(|n| n == rule.label() || n == rule.type_name());
+        (self.only.is_empty() || matches(&self.only)) && !matches(&self.skip)
+    }
+}
+
+/// Error returned when `require_rules` is set and no rule applies to any target file
+#[derive(Debug)]
+pub struct NoEffectiveRules;
+
+impl std::fmt::Display for NoEffectiveRules {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No rules apply to any of the target files")
+    }
+}
+
+impl std::error::Error for NoEffectiveRules {}
+
+/// Error returned when no root config file is found from any of the target paths
+#[derive(Debug)]
+pub struct NoRootConfig;
+
+impl std::fmt::Display for NoRootConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "No {} found in the target paths or their ancestors (run 'rec_lint init' at the project root to create one)",
+            ROOT_CONFIG_FILENAMES[0]
+        )
+    }
+}
+
+impl std::error::Error for NoRootConfig {}
+
+/// Result of validate command
+pub struct ValidateReport {
+    pub lines: Vec<String>,
+    pub violation_count: usize,
+    /// Violations of error severity rules
+    pub failure_count: usize,
+    pub error_count: usize,
+    /// Files rewritten by `fix` (relative to the root)
+    pub fixed: Vec<String>,
+    /// Rules that matched none of the validated files, grouped by config file (`warn_unused`)
+    pub unused: Vec<String>,
+    /// Files not validated because they are not valid UTF-8 (relative to the root)
+    pub skipped: Vec<String>,
+    /// Time spent per rule, slowest first (`profile`)
+    pub profile: Vec<String>,
+}
+
+impl ValidateReport {
+    /// Returns true if the command should exit with failure
+    pub fn has_failure(&self) -> bool {
+        self.exceeds(0)
+    }
+
+    /// Returns true if there are more than `fail_on` error violations or any config error
+    pub fn exceeds(&self, fail_on: usize) -> bool {
+        self.failure_count > fail_on || self.error_count > 0
+    }
+}
+
 pub fn run(paths: &[PathBuf], sort_mode: SortMode) -> Result<Vec<String>> {
-    // First, get root_config from the first path
-    let root_config = get_root_config_for_paths(paths);
-    let files = collect_files(paths, &root_config);
-    if files.is_empty() {
-        return Ok(Vec::new());
+    let options = ValidateOptions { sort_mode, ..Default::default() };
+    Ok(run_with_options(paths, &options)?.lines)
+}
+
+pub fn run_with_options(paths: &[PathBuf], options: &ValidateOptions) -> Result<ValidateReport> {
+    run_with_cache(paths, options, &mut new_cache(paths, options)?)
+}
+
+/// Empty cache for `paths`, or a cache of the explicit config when `options.config` is set
+/// The explicit config applies as if it were placed in the first path (its parent for a file)
+pub(crate) fn new_cache(paths: &[PathBuf], options: &ValidateOptions) -> Result<RuleCache> {
+    let Some(config) = &options.config else {
+        return Ok(RuleCache::new());
+    };
+    let Some(path) = paths.first() else {
+        bail!("--config requires a target path");
+    };
+    let root_dir = if path.is_file() { path.parent().unwrap_or(path) } else { path.as_path() };
+    let root_dir = if root_dir.as_os_str().is_empty() { Path::new(".") } else { root_dir };
+    let mut rules =
+        collect_rules_from(config, root_dir).map_err(|err| anyhow::anyhow!("{}: {err}", config.display()))?;
+    rules.rule.retain(|(rule, _)| !options.skip_types.iter().any(|t| t == rule.type_name()));
+    Ok(RuleCache::fixed(rules))
+}
+
+/// Validate with rules kept in `cache` across runs
+/// Call `RuleCache::invalidate` when a .rec_lint.yaml changes before running again
+pub fn run_with_cache(paths: &[PathBuf], options: &ValidateOptions, cache: &mut RuleCache) -> Result<ValidateReport> {
+    let collected = collect_violations(paths, options, cache)?;
+
+    let applicable = match options.format {
+        OutputFormat::Matrix | OutputFormat::MatrixCsv => collect_applicable(&collected.files, cache, options),
+        _ => Vec::new(),
+    };
+
+    let mut report = report(collected.errors, flatten_violations(&collected.violations), applicable, options)?;
+    report.fixed = collected.fixed;
+    report.skipped = collected.skipped;
+    if options.profile {
+        report.profile = collected.profile.format();
     }
+    if options.warn_unused {
+        report.unused = collect_unused(&collected.files, cache, options);
+    }
+    Ok(report)
+}
 
-    let cached = cache_rules(&files);
-    let dir_rules = Arc::new(cached.rules);
-    let errors = Arc::new(Mutex::new(Vec::new()));
+/// Target files with their violations and errors, before formatting
+pub(crate) struct Collected {
+    pub(crate) files: Vec<PathBuf>,
+    pub(crate) violations: Vec<FileViolation>,
+    pub(crate) errors: Vec<String>,
+    /// Files rewritten by `fix`
+    pub(crate) fixed: Vec<String>,
+    /// Files not validated because they are not valid UTF-8
+    pub(crate) skipped: Vec<String>,
+    /// Time spent per rule (empty unless `profile`)
+    pub(crate) profile: Profile,
+}
 
-    let violations: Vec<FileViolation> = files
-        .par_iter()
-        .flat_map({
-            let errors = Arc::clone(&errors);
-            move |file| {
-                let parent = match file.parent() {
-                    Some(p) => p,
-                    None => return Vec::new(),
-                };
-                let rules = match dir_rules.get(parent) {
-                    Some(r) => r,
-                    None => return Vec::new(),
-                };
-                match validate_file(file, rules) {
-                    Ok(result) => result,
-                    Err(err) => {
-                        if let Ok(mut guard) = errors.lock() {
-                            let relative = file
-                                .strip_prefix(&rules.root_dir)
-                                .map(|p| p.display().to_string())
-                                .unwrap_or_else(|_| file.display().to_string());
-                            guard.push(format!("{relative}: {err}"));
-                        }
-                        Vec::new()
-                    }
+/// Validate all target files under `paths`
+pub(crate) fn collect_violations(
+    paths: &[PathBuf],
+    options: &ValidateOptions,
+    cache: &mut RuleCache,
+) -> Result<Collected> {
+    let (files, collect_errors) = prepare(paths, options, cache)?;
+    let dir_rules = &*cache;
+    let mut errors: Vec<String> = collect_errors;
+
+    let fixed = if options.fix { fix_files(&files, dir_rules, options, &mut errors) } else { Vec::new() };
+
+    let results: Vec<std::result::Result<Checked, String>> =
+        files.par_iter().map(|file| check_file(file, dir_rules, options)).collect();
+
+    let mut violations = Vec::new();
+    let mut skipped = Vec::new();
+    let mut profile = Profile::default();
+    for result in results {
+        match result {
+            Ok(checked) => {
+                violations.extend(checked.violations);
+                skipped.extend(checked.skipped);
+                profile.merge(checked.profile);
+            }
+            Err(err) => errors.push(err),
+        }
+    }
+    skipped.sort();
+
+    Ok(Collected { files, violations, errors, fixed, skipped, profile })
+}
+
+/// Validate `content` (e.g. an unsaved editor buffer read from stdin) as if it were at `path`
+/// The rules of the parent directory of `path` apply; `path` itself does not need to exist
+/// Custom rules are skipped because their command reads the file on disk
+pub fn run_with_content(path: &Path, content: &str, options: &ValidateOptions) -> Result<ValidateReport> {
+    let Some(name) = path.file_name() else {
+        bail!("Invalid stdin path: {}", path.display());
+    };
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    let file = parent.canonicalize()?.join(name);
+
+    let mut options = options.clone();
+    options.skip_types.push("custom".to_string());
+
+    let files = vec![file];
+    let mut cache = new_cache(&files, &options)?;
+    let errors = cache.collect(&files, &options.skip_types);
+    if options.require_rules && !has_applicable_rule(&files, &cache, &options) {
+        return Err(NoEffectiveRules.into());
+    }
+
+    let violations = match files[0].parent().and_then(|p| cache.get(p)) {
+        Some(rules) => validate_content(&files[0], content, rules, &options, &mut Profile::default())?,
+        None => Vec::new(),
+    };
+
+    let applicable = match options.format {
+        OutputFormat::Matrix | OutputFormat::MatrixCsv => collect_applicable(&files, &cache, &options),
+        _ => Vec::new(),
+    };
+
+    report(errors, flatten_violations(&violations), applicable, &options)
+}
+
+/// Validate and pass output lines to `sink` as each file finishes
+/// Violations are sorted within each file only; there is no global sort across files
+/// Only text and github formats are supported, and the returned report has no lines
+pub fn run_streaming(
+    paths: &[PathBuf],
+    options: &ValidateOptions,
+    sink: &mut dyn FnMut(String),
+) -> Result<ValidateReport> {
+    if !matches!(options.format, OutputFormat::Text | OutputFormat::Github) {
+        bail!("--stream supports only text and github formats");
+    }
+    if options.write_baseline.is_some() {
+        bail!("--stream cannot be used with --write-baseline");
+    }
+    let baseline = options.baseline.as_deref().map(baseline::load).transpose()?;
+
+    let mut cache = new_cache(paths, options)?;
+    let (files, collect_errors) = prepare(paths, options, &mut cache)?;
+    let dir_rules = &cache;
+
+    let mut errors = collect_errors;
+    let fixed = if options.fix { fix_files(&files, dir_rules, options, &mut errors) } else { Vec::new() };
+
+    let unused = if options.warn_unused { collect_unused(&files, dir_rules, options) } else { Vec::new() };
+    let mut profile = Profile::default();
+    let mut summary = Summary::default();
+    let mut report = ValidateReport {
+        lines: Vec::new(),
+        violation_count: 0,
+        failure_count: 0,
+        error_count: errors.len(),
+        fixed,
+        unused,
+        skipped: Vec::new(),
+        profile: Vec::new(),
+    };
+    errors.into_iter().for_each(&mut *sink);
+
+    let (sender, receiver) = mpsc::channel();
+    std::thread::scope(|scope| {
+        scope.spawn(move || {
+            files.par_iter().for_each_with(sender, |sender, file| {
+                // The receiver lives until all files are sent, so sending cannot fail
+                let _ = sender.send(check_file(file, dir_rules, options));
+            });
+        });
+
+        for result in receiver {
+            let violations = match result {
+                Ok(checked) => {
+                    report.skipped.extend(checked.skipped);
+                    profile.merge(checked.profile);
+                    checked.violations
+                }
+                Err(err) => {
+                    report.error_count += 1;
+                    sink(err);
+                    continue;
                 }
+            };
+            let flat = flatten_violations(&violations);
+            let mut flat = match &baseline {
+                Some(baseline) => baseline::suppress(flat, baseline),
+                None => flat,
+            };
+            report.violation_count += flat.len();
+            report.failure_count += flat.iter().filter(|fv| fv.severity == Severity::Error).count();
+            summary.add(&flat);
+            sort_flat_violations(&mut flat, options.sort_mode);
+            let lines = match options.format {
+                OutputFormat::Github => github::format(&flat),
+                _ => format_text(&flat, options.sort_mode, options.color),
+            };
+            lines.into_iter().for_each(&mut *sink);
+        }
+    });
+    if options.summary && options.format == OutputFormat::Text {
+        sink(summary.line());
+    }
+    report.skipped.sort();
+    if options.profile {
+        report.profile = profile.format();
+    }
+
+    Ok(report)
+}
+
+/// Collect target files and their rules
+/// Returns the files and rule collection errors
+fn prepare(paths: &[PathBuf], options: &ValidateOptions, cache: &mut RuleCache) -> Result<(Vec<PathBuf>, Vec<String>)> {
+    // First, get root_config from the first path (an explicit config has no root config)
+    let (root_config, discover) = match cache.fixed_rules() {
+        Some(rules) => (rules.root_config.clone(), false),
+        None => (get_root_config_for_paths(paths)?, true),
+    };
+    let files = match &options.changed {
+        Some(base) => collect_files(&changed::changed_files(paths, base, &root_config)?, &root_config, discover),
+        None => collect_files(paths, &root_config, discover),
+    };
+
+    let collect_errors = cache.collect(&files, &options.skip_types);
+    if options.require_rules && !has_applicable_rule(&files, cache, options) {
+        return Err(NoEffectiveRules.into());
+    }
+    Ok((files, collect_errors))
+}
+
+/// Violations of a single file
+#[derive(Default)]
+struct Checked {
+    violations: Vec<FileViolation>,
+    /// The file (relative to the root) if its content was not validated because it is not valid UTF-8
+    skipped: Option<String>,
+    profile: Profile,
+}
+
+/// Validate a single file with the rules of its directory
+/// Errors are returned as output lines (relative file path and message)
+fn check_file(file: &Path, dir_rules: &RuleCache, options: &ValidateOptions) -> std::result::Result<Checked, String> {
+    let Some(rules) = file.parent().and_then(|p| dir_rules.get(p)) else {
+        return Ok(Checked::default());
+    };
+    validate_file(file, rules, options).map_err(|err| format!("{}: {err}", relative_display(file, &rules.root_dir)))
+}
+
+/// Apply fixes of fixable rules to the files in place
+/// Returns the rewritten files (relative to the root) and appends errors as output lines
+fn fix_files(
+    files: &[PathBuf],
+    dir_rules: &RuleCache,
+    options: &ValidateOptions,
+    errors: &mut Vec<String>,
+) -> Vec<String> {
+    let results: Vec<std::result::Result<Option<String>, String>> = files
+        .par_iter()
+        .map(|file| {
+            let Some(rules) = file.parent().and_then(|p| dir_rules.get(p)) else {
+                return Ok(None);
+            };
+            let relative = relative_display(file, &rules.root_dir);
+            match fix_file(file, rules, options) {
+                Ok(fixed) => Ok(fixed.then_some(relative)),
+                Err(err) => Err(format!("{relative}: {err}")),
             }
         })
         .collect();
 
-    let mut output: Vec<String> = cached.errors;
-    if let Ok(guard) = errors.lock() {
-        output.extend(guard.iter().cloned());
+    let mut fixed = Vec::new();
+    for result in results {
+        match result {
+            Ok(Some(relative)) => fixed.push(relative),
+            Ok(None) => {}
+            Err(err) => errors.push(err),
+        }
+    }
+    fixed.sort();
+    fixed
+}
+
+/// Apply fixes of the fixable rules applicable to `file` and write it back if changed
+/// Returns true if the file was rewritten
+fn fix_file(file: &Path, rules: &CollectedRules, options: &ValidateOptions) -> Result<bool> {
+    let file = file.canonicalize()?;
+    let bytes = fs::read(&file)?;
+    // Binary files have nothing to fix
+    if is_binary(&bytes) {
+        return Ok(false);
+    }
+    // Files that are not valid UTF-8 are left as they are, even with `--encoding lossy`
+    let Ok(content) = String::from_utf8(bytes) else {
+        return Ok(false);
+    };
+    let disables = Disables::parse(&content);
+
+    let mut fixed = content.clone();
+    for (rule, source) in &rules.rule {
+        if !options.is_selected(rule) || !rule.matcher().matches(&file, source) {
+            continue;
+        }
+        if let Some(fixable) = rule.as_fixable() {
+            fixed = fixable.fix(&fixed, &|line| disables.is_disabled(line, rule.label()));
+        }
+    }
+
+    if fixed == content {
+        return Ok(false);
+    }
+    fs::write(&file, fixed)?;
+    Ok(true)
+}
+
+/// Path of `file` relative to `root_dir` for output (the path as is if outside of it)
+fn relative_display(file: &Path, root_dir: &Path) -> String {
+    file.strip_prefix(root_dir).map(|p| p.display().to_string()).unwrap_or_else(|_| file.display().to_string())
+}
+
+/// Returns true if at least one rule's matcher applies to one of the files
+fn has_applicable_rule(files: &[PathBuf], dir_rules: &RuleCache, options: &ValidateOptions) -> bool {
+    files.iter().any(|file| {
+        let Some(rules) = file.parent().and_then(|p| dir_rules.get(p)) else {
+            return false;
+        };
+        let Ok(file) = file.canonicalize() else {
+            return false;
+        };
+        rules.rule.iter().any(|(rule, source)| options.is_selected(rule) && rule.matcher().matches(&file, source))
+    })
+}
+
+/// Labels of the rules applicable to each file (used by the matrix formats)
+fn collect_applicable(files: &[PathBuf], dir_rules: &RuleCache, options: &ValidateOptions) -> Vec<Applicable> {
+    let mut applicable = Vec::new();
+    for file in files {
+        let Some(rules) = file.parent().and_then(|p| dir_rules.get(p)) else {
+            continue;
+        };
+        let Ok(file) = file.canonicalize() else {
+            continue;
+        };
+        let relative = file
+            .strip_prefix(&rules.root_dir)
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|_| file.display().to_string());
+        let labels = rules
+            .rule
+            .iter()
+            .filter(|(rule, source)| options.is_selected(rule) && rule.matcher().matches(&file, source))
+            .map(|(rule, _)| rule.label().to_string())
+            .collect();
+        applicable.push(Applicable { file: relative, labels });
+    }
+    applicable
+}
+
+/// A rule identified by (config dir, label, type)
+type RuleKey = (PathBuf, String, &'static str);
+
+/// Rules that apply to the directories of the files but whose matcher matched none of them
+/// Only rules inherited by at least one validated file are considered, grouped by the config file defining them
+fn collect_unused(files: &[PathBuf], dir_rules: &RuleCache, options: &ValidateOptions) -> Vec<String> {
+    // Rules in the order first seen, with whether any file matched
+    let mut rules: Vec<(RuleKey, bool)> = Vec::new();
+    let mut root_dir = PathBuf::new();
+    for file in files {
+        let Some(collected) = file.parent().and_then(|p| dir_rules.get(p)) else {
+            continue;
+        };
+        let Ok(file) = file.canonicalize() else {
+            continue;
+        };
+        root_dir = collected.root_dir.clone();
+        for (rule, source_dir) in &collected.rule {
+            if !options.is_selected(rule) {
+                continue;
+            }
+            let key = (source_dir.clone(), rule.label().to_string(), rule.type_name());
+            let matched = rule.matcher().matches(&file, source_dir);
+            match rules.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, used)) => *used |= matched,
+                None => rules.push((key, matched)),
+            }
+        }
+    }
+
+    let mut unused: Vec<&(PathBuf, String, &str)> =
+        rules.iter().filter(|(_, used)| !used).map(|(key, _)| key).collect();
+    unused.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut output = Vec::new();
+    let mut current: Option<&Path> = None;
+    for (source_dir, label, type_name) in unused {
+        if current != Some(source_dir.as_path()) {
+            let config = relative_display(&config_file(source_dir, options), &root_dir);
+            output.push(format!("Unused rules: {config}"));
+            current = Some(source_dir.as_path());
+        }
+        output.push(format!("  - rule '{label}' ({type_name}) matched no files"));
+    }
+    output
+}
+
+/// Files each rule would validate, without running the validators
+/// Rules are grouped by the config file defining them (in config order) and files are sorted
+/// Rule collection errors are listed first, as in the validate output
+pub fn list_files(paths: &[PathBuf], options: &ValidateOptions) -> Result<Vec<String>> {
+    let mut cache = new_cache(paths, options)?;
+    let (mut files, errors) = prepare(paths, options, &mut cache)?;
+    files.sort();
+
+    // Rules in the order first seen, with the matched files
+    let mut rules: Vec<(RuleKey, Vec<String>)> = Vec::new();
+    let mut root_dir = PathBuf::new();
+    for file in &files {
+        let Some(collected) = file.parent().and_then(|p| cache.get(p)) else {
+            continue;
+        };
+        let Ok(file) = file.canonicalize() else {
+            continue;
+        };
+        root_dir = collected.root_dir.clone();
+        for (rule, source_dir) in &collected.rule {
+            if !options.is_selected(rule) {
+                continue;
+            }
+            let key = (source_dir.clone(), rule.label().to_string(), rule.type_name());
+            let index = match rules.iter().position(|(k, _)| *k == key) {
+                Some(index) => index,
+                None => {
+                    rules.push((key, Vec::new()));
+                    rules.len() - 1
+                }
+            };
+            if rule.matcher().matches(&file, source_dir) {
+                rules[index].1.push(relative_display(&file, &root_dir));
+            }
+        }
     }
-    output.extend(format_violations(&violations, sort_mode));
+    rules.sort_by(|a, b| a.0 .0.cmp(&b.0 .0));
 
+    let mut output = errors;
+    for ((source_dir, label, type_name), mut matched) in rules {
+        let config = relative_display(&config_file(&source_dir, options), &root_dir);
+        output.push(format!("{label} ({type_name}) in {config}"));
+        if matched.is_empty() {
+            output.push("  (no files)".to_string());
+        }
+        matched.sort();
+        output.extend(matched.into_iter().map(|file| format!("  - {file}")));
+    }
     Ok(output)
 }
 
-/// Get root config for the given paths (uses the first path's root config)
-fn get_root_config_for_paths(paths: &[PathBuf]) -> RootConfig {
-    for path in paths {
-        let dir = if path.is_file() { path.parent().unwrap_or(path) } else { path.as_path() };
-        if let Ok(rules) = collect_rules(dir) {
-            return rules.root_config;
+/// The config file of a directory defining rules (.rec_lint.yaml unless only .rec_lint.toml exists)
+/// The explicit config file when `options.config` is set
+fn config_file(source_dir: &Path, options: &ValidateOptions) -> PathBuf {
+    if let Some(config) = &options.config {
+        return config.clone();
+    }
+    find_config(source_dir).unwrap_or_else(|| source_dir.join(CONFIG_FILENAMES[0]))
+}
+
+/// Apply baseline options and build the report from errors and flattened violations
+fn report(
+    errors: Vec<String>,
+    flat: Vec<FlatViolation>,
+    applicable: Vec<Applicable>,
+    options: &ValidateOptions,
+) -> Result<ValidateReport> {
+    let error_count = errors.len();
+    let mut output = errors;
+
+    if let Some(path) = &options.write_baseline {
+        baseline::write(path, &flat)?;
+        output.push(format!("Created: {}", path.display()));
+        return Ok(ValidateReport {
+            lines: output,
+            violation_count: 0,
+            failure_count: 0,
+            error_count,
+            fixed: Vec::new(),
+            unused: Vec::new(),
+            skipped: Vec::new(),
+            profile: Vec::new(),
+        });
+    }
+
+    let flat = match &options.baseline {
+        Some(path) => baseline::suppress(flat, &baseline::load(path)?),
+        None => flat,
+    };
+    let violation_count = flat.len();
+    let failure_count = flat.iter().filter(|fv| fv.severity == Severity::Error).count();
+    if options.count {
+        // Errors stay in the output so a broken config is not mistaken for zero violations
+        output.push(violation_count.to_string());
+    } else {
+        let mut summary = Summary::default();
+        summary.add(&flat);
+        output.extend(format_violations(flat, &applicable, options));
+        if options.summary && options.format == OutputFormat::Text {
+            output.push(summary.line());
         }
     }
-    RootConfig::default()
+
+    Ok(ValidateReport {
+        lines: output,
+        violation_count,
+        failure_count,
+        error_count,
+        fixed: Vec::new(),
+        unused: Vec::new(),
+        skipped: Vec::new(),
+        profile: Vec::new(),
+    })
+}
+
+/// Get root config for the given paths (uses the first path's root config)
+/// Fails with `NoRootConfig` when none of the paths is inside a project
+fn get_root_config_for_paths(paths: &[PathBuf]) -> Result<RootConfig> {
+    if let Some(root_config) = paths.iter().find_map(|path| nearest_root_config(path)) {
+        return Ok(root_config);
+    }
+    if !paths.iter().any(|path| nearest_root_dir(path).is_some()) {
+        return Err(NoRootConfig.into());
+    }
+    Ok(RootConfig::default())
 }
 
-fn collect_files(paths: &[PathBuf], root_config: &RootConfig) -> Vec<PathBuf> {
+/// The nearest directory at or above `path` with a root config file (the root of its project)
+fn nearest_root_dir(path: &Path) -> Option<PathBuf> {
+    let dir = if path.is_file() { path.parent().unwrap_or(path) } else { path };
+    let dir = if dir.as_os_str().is_empty() { Path::new(".") } else { dir };
+    let dir = dir.canonicalize().ok()?;
+    dir.ancestors().find(|ancestor| find_root_config(ancestor).is_some()).map(Path::to_path_buf)
+}
+
+/// Root config of the project `path` belongs to (None if outside of any project or the config is invalid)
+/// Invalid root configs are reported by rule collection
+fn nearest_root_config(path: &Path) -> Option<RootConfig> {
+    let root_dir = nearest_root_dir(path)?;
+    let raw = RawRootConfig::load(&find_root_config(&root_dir)?).ok()?;
+    RootConfig::from_raw(raw, &root_dir).ok()
+}
+
+/// Target files under `paths`
+/// With `discover`, each path is walked with the root config of its own project, and directories with
+/// their own root config are walked with theirs, so independent projects do not share include and exclude
+/// settings. `root_config` applies to paths outside of any project (and to all paths without `discover`).
+fn collect_files(paths: &[PathBuf], root_config: &RootConfig, discover: bool) -> Vec<PathBuf> {
     let mut files = Vec::new();
     for path in paths {
+        let own_root_config = if discover { nearest_root_config(path) } else { None };
+        let root_config = own_root_config.as_ref().unwrap_or(root_config);
+        let mut nested_roots = Vec::new();
         if path.is_file() {
-            if !is_config_file(path) && should_include_file(path, root_config) {
+            if !is_config_file(path)
+                && should_include_file(path, root_config)
+                && !root_config.should_exclude_file(path)
+                && !root_config.is_ignored(path, false)
+            {
                 files.push(path.clone());
             }
         } else if path.is_dir() {
             let walker = WalkDir::new(path).into_iter().filter_entry(|e| {
+                // Other projects below the path are walked separately with their own root config
+                if discover && e.depth() > 0 && e.file_type().is_dir() && find_root_config(e.path()).is_some() {
+                    nested_roots.push(e.path().to_path_buf());
+                    return false;
+                }
                 // Skip excluded directories
                 if e.file_type().is_dir() {
                     if let Some(name) = e.file_name().to_str() {
-                        // Always exclude .git directory
-                        if name == ".git" {
+                        // Always exclude .git directory and the custom rule cache
+                        if name == ".git" || name == custom::CACHE_DIR {
                             return false;
                         }
                         if root_config.should_exclude_dir(std::ffi::OsStr::new(name)) {
@@ -114,17 +805,19 @@ fn collect_files(paths: &[PathBuf], root_config: &RootConfig) -> Vec<PathBuf> {
                         }
                     }
                 }
-                true
+                !root_config.is_ignored(e.path(), e.file_type().is_dir())
             });
             for entry in walker.filter_map(|e| e.ok()) {
                 if entry.file_type().is_file()
                     && !is_config_file(entry.path())
                     && should_include_file(entry.path(), root_config)
+                    && !root_config.should_exclude_file(entry.path())
                 {
                     files.push(entry.into_path());
                 }
             }
         }
+        files.extend(collect_files(&nested_roots, root_config, discover));
     }
     files
 }
@@ -136,48 +829,83 @@ fn should_include_file(path: &Path, root_config: &RootConfig) -> bool {
 fn is_config_file(path: &Path) -> bool {
     path.file_name()
         .and_then(|n| n.to_str())
-        .map(|name| name == ".rec_lint.yaml" || name == ".rec_lint_config.yaml")
+        .map(|name| CONFIG_FILENAMES.contains(&name) || ROOT_CONFIG_FILENAMES.contains(&name))
         .unwrap_or(false)
 }
 
-struct CachedRules {
-    rules: HashMap<PathBuf, CollectedRules>,
-    errors: Vec<String>,
+fn validate_file(file: &Path, rules: &CollectedRules, options: &ValidateOptions) -> Result<Checked> {
+    let file = file.canonicalize()?;
+    let bytes = fs::read(&file)?;
+    // Binary files are checked only by the rules that look at the path (none for most files)
+    let mut profile = Profile::default();
+    if is_binary(&bytes) {
+        let violations = validate_path_only(&file, rules, options, &mut profile)?;
+        return Ok(Checked { violations, skipped: None, profile });
+    }
+    let content = match (String::from_utf8(bytes), options.encoding) {
+        (Ok(content), _) => content,
+        (Err(err), Encoding::Lossy) => String::from_utf8_lossy(err.as_bytes()).into_owned(),
+        (Err(_), Encoding::Utf8) => {
+            return Ok(Checked {
+                violations: validate_path_only(&file, rules, options, &mut profile)?,
+                skipped: Some(relative_display(&file, &rules.root_dir)),
+                profile,
+            });
+        }
+    };
+    let violations = validate_content(&file, &content, rules, options, &mut profile)?;
+    Ok(Checked { violations, skipped: None, profile })
 }
 
-fn cache_rules(files: &[PathBuf]) -> CachedRules {
-    let mut dirs: Vec<PathBuf> = files.iter().filter_map(|f| f.parent().map(|p| p.to_path_buf())).collect();
-    dirs.sort();
-    dirs.dedup();
+/// Number of leading bytes sniffed for a NUL byte
+const BINARY_SNIFF_LEN: usize = 8192;
 
-    let mut cache = HashMap::new();
-    let mut errors = Vec::new();
-    for dir in dirs {
-        if let std::collections::hash_map::Entry::Vacant(e) = cache.entry(dir.clone()) {
-            match collect_rules(&dir) {
-                Ok(rules) => {
-                    e.insert(rules);
-                }
-                Err(err) => {
-                    errors.push(format!("{}: {err}", dir.display()));
-                }
-            }
+/// A NUL byte near the start marks a binary file (e.g. images, shared libraries)
+fn is_binary(bytes: &[u8]) -> bool {
+    bytes[..bytes.len().min(BINARY_SNIFF_LEN)].contains(&0)
+}
+
+/// Validate a binary or non UTF-8 file with the rules that look only at the path
+fn validate_path_only(
+    file: &Path,
+    rules: &CollectedRules,
+    options: &ValidateOptions,
+    profile: &mut Profile,
+) -> Result<Vec<FileViolation>> {
+    let mut violations = Vec::new();
+    for (rule, source) in &rules.rule {
+        if !rule.is_path_only() || !options.is_selected(rule) || !rule.matcher().matches(file, source) {
+            continue;
+        }
+        if let Some(v) = timed_validate_rule(file, rules, rule, "", options, profile)? {
+            violations.push(v);
         }
     }
-    CachedRules { rules: cache, errors }
+    Ok(violations)
 }
 
-fn validate_file(file: &Path, rules: &CollectedRules) -> Result<Vec<FileViolation>> {
-    let file = file.canonicalize()?;
-    let content = fs::read_to_string(&file)?;
+/// Validate `content` as if it were the content of `file`
+fn validate_content(
+    file: &Path,
+    content: &str,
+    rules: &CollectedRules,
+    options: &ValidateOptions,
+    profile: &mut Profile,
+) -> Result<Vec<FileViolation>> {
     let mut violations = Vec::new();
-    let root_dir = &rules.root_dir;
+    let disables = Disables::parse(content);
 
-    for (rule, _source) in &rules.rule {
-        if !rule.matcher().matches(&file) {
+    for (rule, source) in &rules.rule {
+        if !options.is_selected(rule) || !rule.matcher().matches(file, source) {
             continue;
         }
-        if let Some(v) = validate_rule(&file, root_dir, &rules.root_config, rule, &content)? {
+        if let Some(mut v) = timed_validate_rule(file, rules, rule, content, options, profile)? {
+            if !disables.is_empty() {
+                v.detail.retain_lines(|line| !disables.is_disabled(line, &v.label));
+                if v.detail.is_empty() {
+                    continue;
+                }
+            }
             violations.push(v);
         }
     }
@@ -185,41 +913,79 @@ fn validate_file(file: &Path, rules: &CollectedRules) -> Result<Vec<FileViolatio
     Ok(violations)
 }
 
+/// `validate_rule`, recording the elapsed time in `profile` when `options.profile` is set
+fn timed_validate_rule(
+    file: &Path,
+    rules: &CollectedRules,
+    rule: &Rule,
+    content: &str,
+    options: &ValidateOptions,
+    profile: &mut Profile,
+) -> Result<Option<FileViolation>> {
+    let start = options.profile.then(Instant::now);
+    let result = validate_rule(file, &rules.root_dir, &rules.root_config, rule, content, !options.no_cache);
+    if let Some(start) = start {
+        profile.record(rule, start.elapsed());
+    }
+    result
+}
+
 fn validate_rule(
     file: &Path,
     root_dir: &Path,
     root_config: &RootConfig,
     rule: &Rule,
     content: &str,
+    use_cache: bool,
 ) -> Result<Option<FileViolation>> {
     match rule {
         Rule::Text(text_rule) => {
-            let line_violations = text::validate(content, text_rule);
+            let line_violations = line_range::validate_within(text_rule.line_range, content, |content| {
+                text::validate(content, text_rule)
+            });
             if !line_violations.is_empty() {
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: text_rule.label.clone(),
+                    severity: text_rule.severity,
                     message: text_rule.message.clone(),
                     detail: ViolationDetail::LineViolations(line_violations),
                 }));
             }
         }
-        Rule::Regex(regex_rule) => {
-            let line_violations = regex::validate(content, regex_rule);
+        Rule::Regex(regex_rule) | Rule::Preset(regex_rule) => {
+            let line_violations = line_range::validate_within(regex_rule.line_range, content, |content| {
+                regex::validate(content, regex_rule)
+            });
             if !line_violations.is_empty() {
+                // The captured group is shown, the whole line is not
+                let detail = if regex_rule.report_group == 0 {
+                    ViolationDetail::LineViolations(line_violations)
+                } else {
+                    ViolationDetail::MarkerViolations(line_violations)
+                };
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: regex_rule.label.clone(),
+                    severity: regex_rule.severity,
                     message: regex_rule.message.clone(),
-                    detail: ViolationDetail::LineViolations(line_violations),
+                    detail,
                 }));
             }
         }
         Rule::Custom(custom_rule) => {
-            if let Some(custom_violation) = custom::validate(file, custom_rule, root_config.script_dir.as_deref())? {
+            let cache_dir = (custom_rule.cache && use_cache).then(|| root_dir.join(custom::CACHE_DIR));
+            let script_dir = root_config.script_dir.as_deref();
+            if let Some(custom_violation) =
+                custom::validate(file, content, custom_rule, script_dir, cache_dir.as_deref())?
+            {
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: custom_rule.label.clone(),
+                    severity: custom_rule.severity,
                     message: custom_rule.message.clone(),
                     detail: ViolationDetail::CustomViolation(custom_violation),
                 }));
@@ -231,6 +997,8 @@ fn validate_rule(
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
                     message: rule.message.clone(),
                     detail: ViolationDetail::DocViolations(violations),
                 }));
@@ -242,6 +1010,8 @@ fn validate_rule(
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
                     message: rule.message.clone(),
                     detail: ViolationDetail::DocViolations(violations),
                 }));
@@ -253,30 +1023,63 @@ fn validate_rule(
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::DocViolations(violations),
+                }));
+            }
+        }
+        Rule::PythonDoc(rule) => {
+            let module_name = file.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+            let violations = doc::python::validate(content, &module_name, &rule.config);
+            if !violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::DocViolations(violations),
+                }));
+            }
+        }
+        Rule::TypeScriptDoc(rule) => {
+            let violations = doc::typescript::validate(content, &rule.config);
+            if !violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
                     message: rule.message.clone(),
                     detail: ViolationDetail::DocViolations(violations),
                 }));
             }
         }
         Rule::JapaneseComment(rule) => {
-            let comments = extract_comments(content, &rule.source);
-            let violations = comment::validate_japanese(&comments);
+            let comments = extract_rule_comments(file, content, rule);
+            let violations = comment::validate_scripts(&comments, &rule.scripts, rule.max_ratio);
             if !violations.is_empty() {
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
                     message: rule.message.clone(),
                     detail: ViolationDetail::CommentViolations(violations),
                 }));
             }
         }
         Rule::EnglishComment(rule) => {
-            let comments = extract_comments(content, &rule.source);
+            let comments = extract_rule_comments(file, content, rule);
             let violations = comment::validate_non_japanese(&comments);
             if !violations.is_empty() {
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
                     message: rule.message.clone(),
                     detail: ViolationDetail::CommentViolations(violations),
                 }));
@@ -288,6 +1091,8 @@ fn validate_rule(
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
                     message: rule.message.clone(),
                     detail: ViolationDetail::TestViolations(violations),
                 }));
@@ -299,6 +1104,8 @@ fn validate_rule(
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
                     message: rule.message.clone(),
                     detail: ViolationDetail::TestViolations(violations),
                 }));
@@ -310,6 +1117,8 @@ fn validate_rule(
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
                     message: rule.message.clone(),
                     detail: ViolationDetail::TestViolations(violations),
                 }));
@@ -321,6 +1130,8 @@ fn validate_rule(
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
                     message: rule.message.clone(),
                     detail: ViolationDetail::TestExistenceViolations(violations),
                 }));
@@ -332,27 +1143,312 @@ fn validate_rule(
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
                     message: rule.message.clone(),
                     detail: ViolationDetail::TestExistenceViolations(violations),
                 }));
             }
         }
         Rule::RustTestExistence(rule) => {
-            let violations = test_exists::rust::validate(content, &rule.config);
+            let violations = test_exists::rust::validate(file, content, root_dir, &rule.config);
             if !violations.is_empty() {
                 return Ok(Some(FileViolation {
                     file: file.to_path_buf(),
                     root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
                     message: rule.message.clone(),
                     detail: ViolationDetail::TestExistenceViolations(violations),
                 }));
             }
         }
+        Rule::NoSkippedTests(rule) => {
+            let violations = test::skip::validate(content, rule.lang);
+            if !violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::TestViolations(violations),
+                }));
+            }
+        }
+        Rule::ConsistentQuotes(rule) => {
+            let line_violations = quotes::validate(content, &rule.config);
+            if !line_violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::LineViolations(line_violations),
+                }));
+            }
+        }
+
+        Rule::MaxLineLength(rule) => {
+            let line_violations = line_length::validate(content, &rule.config);
+            if !line_violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::LineViolations(line_violations),
+                }));
+            }
+        }
+
+        Rule::JunitTest(rule) => {
+            let violations = test::name::junit::validate(content);
+            if !violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::TestViolations(violations),
+                }));
+            }
+        }
+
+        Rule::SpockTest(rule) => {
+            let violations = test::name::spock::validate(content);
+            if !violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::TestViolations(violations),
+                }));
+            }
+        }
+
+        Rule::NoTrailingWhitespace(rule) => {
+            let line_violations = trailing_whitespace::validate(content);
+            if !line_violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::LineViolations(line_violations),
+                }));
+            }
+        }
+
+        Rule::GoDoc(rule) => {
+            let violations = doc::go::validate(content, &rule.config);
+            if !violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::DocViolations(violations),
+                }));
+            }
+        }
+
+        Rule::LanguageDoc(rule) => {
+            if let Some(rule) = rule.rule_for(file) {
+                return validate_rule(file, root_dir, root_config, rule, content, use_cache);
+            }
+        }
+        Rule::GenericDoc(rule) => {
+            let violations = doc::generic::validate(content, &rule.config);
+            if !violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::DocViolations(violations),
+                }));
+            }
+        }
+
+        Rule::RequireTrailingNewline(rule) => {
+            let line_violations = final_newline::validate(content);
+            if !line_violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::LineViolations(line_violations),
+                }));
+            }
+        }
+
+        Rule::ForbiddenFileNames(rule) => {
+            let line_violations = file_name::validate(file, &rule.globs);
+            if !line_violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::LineViolations(line_violations),
+                }));
+            }
+        }
+
+        Rule::MaxFileLines(rule) => {
+            let line_violations = file_lines::validate(content, &rule.config);
+            if !line_violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::LineViolations(line_violations),
+                }));
+            }
+        }
+
+        Rule::GoTestExistence(rule) => {
+            let violations = test_exists::go::validate(file, content, root_dir, &rule.config);
+            if !violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::TestExistenceViolations(violations),
+                }));
+            }
+        }
+
+        Rule::SwiftDoc(rule) => {
+            let violations = doc::swift::validate(content, &rule.config);
+            if !violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::DocViolations(violations),
+                }));
+            }
+        }
+
+        Rule::CSharpDoc(rule) => {
+            let violations = doc::csharp::validate(content, &rule.config);
+            if !violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::DocViolations(violations),
+                }));
+            }
+        }
+
+        Rule::ForbiddenImports(rule) => {
+            let line_violations = imports::validate(file, content, &rule.config);
+            if !line_violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::LineViolations(line_violations),
+                }));
+            }
+        }
+
+        Rule::RequiredTexts(rule) => {
+            let line_violations = line_range::validate_within(rule.line_range, content, |content| {
+                required_text::validate(content, &rule.config)
+            });
+            if !line_violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::LineViolations(line_violations),
+                }));
+            }
+        }
+
+        Rule::PythonTest(rule) => {
+            let violations = test::name::python::validate(content);
+            if !violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::TestViolations(violations),
+                }));
+            }
+        }
+
+        Rule::JestTest(rule) => {
+            let violations = test::name::jest::validate(content);
+            if !violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::TestViolations(violations),
+                }));
+            }
+        }
+
+        Rule::NoStaleTodo(rule) => {
+            let marker_violations = todo::validate(content, &rule.config, todo::today());
+            if !marker_violations.is_empty() {
+                return Ok(Some(FileViolation {
+                    file: file.to_path_buf(),
+                    root_dir: root_dir.to_path_buf(),
+                    label: rule.label.clone(),
+                    severity: rule.severity,
+                    message: rule.message.clone(),
+                    detail: ViolationDetail::MarkerViolations(marker_violations),
+                }));
+            }
+        }
     }
     Ok(None)
 }
 
-fn extract_comments(content: &str, source: &CommentSource) -> Vec<comment::Comment> {
+/// Comments checked by a comment rule
+fn extract_rule_comments(file: &Path, content: &str, rule: &CommentRule) -> Vec<comment::Comment> {
+    let comments = extract_comments(file, content, &rule.source);
+    if rule.skip_code_blocks {
+        comment::skip_code_blocks(comments)
+    } else {
+        comments
+    }
+}
+
+fn extract_comments(file: &Path, content: &str, source: &CommentSource) -> Vec<comment::Comment> {
     match source {
         CommentSource::Lang(lang) => match lang {
             crate::rule::parser::CommentLang::Java => comment::java::extract_comments(content),
@@ -360,6 +1456,10 @@ fn extract_comments(content: &str, source: &CommentSource) -> Vec<comment::Comme
             crate::rule::parser::CommentLang::Rust => comment::rust::extract_comments(content),
         },
         CommentSource::Custom(syntax) => comment::custom::extract_comments(content, syntax),
+        CommentSource::CustomByExt(_) => match source.syntax_for(file) {
+            Some(syntax) => comment::custom::extract_comments(content, syntax),
+            None => Vec::new(),
+        },
     }
 }
 
@@ -368,9 +1468,13 @@ struct FlatViolation {
     file: String,
     line: usize,
     col: usize,
+    label: String,
+    severity: Severity,
     message: String,
     found: Option<String>,
     custom_output: Option<String>,
+    /// Line independent text identifying the violation (used by baseline)
+    text: String,
 }
 
 fn flatten_violations(violations: &[FileViolation]) -> Vec<FlatViolation> {
@@ -389,9 +1493,27 @@ fn flatten_violations(violations: &[FileViolation]) -> Vec<FlatViolation> {
                         file: relative_path.clone(),
                         line: lv.line,
                         col: lv.col,
+                        label: v.label.clone(),
+                        severity: v.severity,
                         message: v.message.clone(),
                         found: None,
                         custom_output: None,
+                        text: lv.found.trim().to_string(),
+                    });
+                }
+            }
+            ViolationDetail::MarkerViolations(marker_violations) => {
+                for mv in marker_violations {
+                    flat.push(FlatViolation {
+                        file: relative_path.clone(),
+                        line: mv.line,
+                        col: mv.col,
+                        label: v.label.clone(),
+                        severity: v.severity,
+                        message: v.message.clone(),
+                        found: Some(mv.found.clone()),
+                        custom_output: None,
+                        text: mv.found.clone(),
                     });
                 }
             }
@@ -400,44 +1522,59 @@ fn flatten_violations(violations: &[FileViolation]) -> Vec<FlatViolation> {
                     file: relative_path.clone(),
                     line: 0,
                     col: 0,
+                    label: v.label.clone(),
+                    severity: v.severity,
                     message: v.message.clone(),
                     found: None,
                     custom_output: if custom.output.is_empty() { None } else { Some(custom.output.clone()) },
+                    text: custom.output.clone(),
                 });
             }
             ViolationDetail::DocViolations(doc_violations) => {
                 for dv in doc_violations {
+                    let found = format!("{} {}", dv.kind, dv.name);
                     flat.push(FlatViolation {
                         file: relative_path.clone(),
                         line: dv.line,
                         col: 1,
+                        label: v.label.clone(),
+                        severity: v.severity,
                         message: v.message.clone(),
-                        found: Some(format!("{} {}", dv.kind, dv.name)),
+                        found: Some(found.clone()),
                         custom_output: None,
+                        text: found,
                     });
                 }
             }
             ViolationDetail::CommentViolations(comment_violations) => {
                 for cv in comment_violations {
+                    let found = truncate_text(&cv.text, 40);
                     flat.push(FlatViolation {
                         file: relative_path.clone(),
                         line: cv.line,
                         col: 1,
+                        label: v.label.clone(),
+                        severity: v.severity,
                         message: v.message.clone(),
-                        found: Some(truncate_text(&cv.text, 40)),
+                        found: Some(found.clone()),
                         custom_output: None,
+                        text: found,
                     });
                 }
             }
             ViolationDetail::TestViolations(test_violations) => {
                 for tv in test_violations {
+                    let found = tv.name.clone();
                     flat.push(FlatViolation {
                         file: relative_path.clone(),
                         line: tv.line,
                         col: 1,
+                        label: v.label.clone(),
+                        severity: v.severity,
                         message: v.message.clone(),
-                        found: Some(tv.name.clone()),
+                        found: Some(found.clone()),
                         custom_output: None,
+                        text: found,
                     });
                 }
             }
@@ -449,13 +1586,17 @@ fn flatten_violations(violations: &[FileViolation]) -> Vec<FlatViolation> {
                         test_exists::TestExistenceViolationKind::UntestedPublicFunction { line, .. } => *line,
                         _ => 0, // File-level violations don't have a line number
                     };
+                    let found = ev.kind.to_string();
                     flat.push(FlatViolation {
                         file: relative_path.clone(),
                         line,
                         col: 1,
+                        label: v.label.clone(),
+                        severity: v.severity,
                         message: v.message.clone(),
-                        found: Some(ev.kind.to_string()),
+                        found: Some(found.clone()),
                         custom_output: None,
+                        text: found,
                     });
                 }
             }
@@ -472,18 +1613,51 @@ fn truncate_text(text: &str, max_len: usize) -> String {
     }
 }
 
-fn format_violations(violations: &[FileViolation], sort_mode: SortMode) -> Vec<String> {
-    let mut flat = flatten_violations(violations);
+fn format_violations(
+    mut flat: Vec<FlatViolation>,
+    applicable: &[Applicable],
+    options: &ValidateOptions,
+) -> Vec<String> {
+    match options.format {
+        OutputFormat::Text => {
+            sort_flat_violations(&mut flat, options.sort_mode);
+            format_text(&flat, options.sort_mode, options.color)
+        }
+        OutputFormat::Junit => {
+            sort_flat_violations(&mut flat, SortMode::File);
+            if options.junit_group_by == JunitGroupBy::Rule {
+                // Stable sort keeps the file order within each rule
+                flat.sort_by(|a, b| a.label.cmp(&b.label));
+            }
+            junit::format(&flat, options.junit_group_by)
+        }
+        OutputFormat::Github => {
+            sort_flat_violations(&mut flat, options.sort_mode);
+            github::format(&flat)
+        }
+        OutputFormat::Checkstyle => {
+            sort_flat_violations(&mut flat, SortMode::File);
+            checkstyle::format(&flat)
+        }
+        OutputFormat::LspJson => {
+            sort_flat_violations(&mut flat, SortMode::File);
+            lsp::format(&flat)
+        }
+        OutputFormat::Matrix => matrix::format_text(applicable, &flat),
+        OutputFormat::MatrixCsv => matrix::format_csv(applicable, &flat),
+    }
+}
 
+fn sort_flat_violations(flat: &mut [FlatViolation], sort_mode: SortMode) {
     match sort_mode {
         SortMode::Rule => {
-            // Already in rule order from parallel collection, just stable sort by message
             flat.sort_by(|a, b| {
                 a.message
                     .cmp(&b.message)
                     .then_with(|| a.file.cmp(&b.file))
                     .then_with(|| a.line.cmp(&b.line))
                     .then_with(|| a.col.cmp(&b.col))
+                    .then_with(|| tiebreak(a, b))
             });
         }
         SortMode::File => {
@@ -493,14 +1667,24 @@ fn format_violations(violations: &[FileViolation], sort_mode: SortMode) -> Vec<S
                     .then_with(|| a.line.cmp(&b.line))
                     .then_with(|| a.col.cmp(&b.col))
                     .then_with(|| a.message.cmp(&b.message))
+                    .then_with(|| tiebreak(a, b))
             });
         }
     }
+}
+
+/// Orders violations that share the sort key, so that the output does not depend on the collection order
+fn tiebreak(a: &FlatViolation, b: &FlatViolation) -> std::cmp::Ordering {
+    a.text.cmp(&b.text).then_with(|| a.label.cmp(&b.label))
+}
 
+fn format_text(flat: &[FlatViolation], sort_mode: SortMode, color: bool) -> Vec<String> {
+    let painter = color::Painter { enabled: color };
     let mut output = Vec::new();
     for fv in flat {
+        let message = painter.severity(fv.severity, &fv.message);
         let found_suffix = match &fv.found {
-            Some(found) => format!(" [ found: {found} ]"),
+            Some(found) => painter.dim(&format!(" [ found: {found} ]")),
             None => String::new(),
         };
         let custom_suffix = match &fv.custom_output {
@@ -511,21 +1695,57 @@ fn format_violations(violations: &[FileViolation], sort_mode: SortMode) -> Vec<S
             SortMode::Rule => {
                 // message: file:line:col [ found: xxx ]
                 if fv.line == 0 {
-                    format!("{}: {}{}{}", fv.message, fv.file, found_suffix, custom_suffix)
+                    format!("{}: {}{}{}", message, fv.file, found_suffix, custom_suffix)
                 } else {
-                    format!("{}: {}:{}:{}{}{}", fv.message, fv.file, fv.line, fv.col, found_suffix, custom_suffix)
+                    format!("{}: {}:{}:{}{}{}", message, fv.file, fv.line, fv.col, found_suffix, custom_suffix)
                 }
             }
             SortMode::File => {
                 // file:line:col: message [ found: xxx ]
                 if fv.line == 0 {
-                    format!("{}: {}{}{}", fv.file, fv.message, found_suffix, custom_suffix)
+                    format!("{}: {}{}{}", fv.file, message, found_suffix, custom_suffix)
                 } else {
-                    format!("{}:{}:{}: {}{}{}", fv.file, fv.line, fv.col, fv.message, found_suffix, custom_suffix)
+                    format!("{}:{}:{}: {}{}{}", fv.file, fv.line, fv.col, message, found_suffix, custom_suffix)
                 }
             }
         };
-        output.push(formatted);
+        output.push(format!("{}{formatted}", painter.severity(fv.severity, severity_prefix(fv.severity))));
     }
     output
 }
+
+/// Tally of violations for the `summary` line
+#[derive(Default)]
+struct Summary {
+    violations: usize,
+    files: HashSet<String>,
+    rules: HashSet<String>,
+}
+
+impl Summary {
+    fn add(&mut self, flat: &[FlatViolation]) {
+        self.violations += flat.len();
+        self.files.extend(flat.iter().map(|fv| fv.file.clone()));
+        self.rules.extend(flat.iter().map(|fv| fv.label.clone()));
+    }
+
+    /// e.g. `3 violations in 2 files (2 rules triggered)`
+    fn line(&self) -> String {
+        let plural =
+            |count: usize, word: &str| if count == 1 { format!("1 {word}") } else { format!("{count} {word}s") };
+        format!(
+            "{} in {} ({} triggered)",
+            plural(self.violations, "violation"),
+            plural(self.files.len(), "file"),
+            plural(self.rules.len(), "rule")
+        )
+    }
+}
+
+fn severity_prefix(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "[ error ] ",
+        Severity::Warn => "[ warn ] ",
+        Severity::Info => "[ info ] ",
+    }
+}
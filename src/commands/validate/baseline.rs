@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::FlatViolation;
+
+/// Recorded set of known violations
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub violations: Vec<BaselineEntry>,
+}
+
+/// A known violation, identified without line numbers so that shifted code still matches
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub file: String,
+    pub label: String,
+    pub found: String,
+}
+
+impl BaselineEntry {
+    fn from_violation(fv: &FlatViolation) -> Self {
+        Self { file: fv.file.clone(), label: fv.label.clone(), found: fv.text.clone() }
+    }
+}
+
+pub fn load(path: &Path) -> Result<Baseline> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read baseline file: {}", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("Failed to parse baseline file: {}", path.display()))
+}
+
+pub fn write(path: &Path, flat: &[FlatViolation]) -> Result<()> {
+    let mut violations: Vec<BaselineEntry> = flat.iter().map(BaselineEntry::from_violation).collect();
+    violations.sort();
+    let content = serde_json::to_string_pretty(&Baseline { violations })?;
+    fs::write(path, content + "\n").with_context(|| format!("Failed to write baseline file: {}", path.display()))
+}
+
+/// Remove violations recorded in the baseline
+/// Each entry suppresses at most one violation, so duplicates beyond the recorded count still surface
+pub fn suppress(flat: Vec<FlatViolation>, baseline: &Baseline) -> Vec<FlatViolation> {
+    let mut remaining: HashMap<&BaselineEntry, usize> = HashMap::new();
+    for entry in &baseline.violations {
+        *remaining.entry(entry).or_default() += 1;
+    }
+
+    flat.into_iter()
+        .filter(|fv| match remaining.get_mut(&BaselineEntry::from_violation(fv)) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                false
+            }
+            _ => true,
+        })
+        .collect()
+}
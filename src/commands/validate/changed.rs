@@ -0,0 +1,66 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+
+use crate::rule::RootConfig;
+
+/// Files that differ from `base` (staged or not) and untracked files, limited to those under `paths`
+/// Deleted files, ignored files and files in excluded directories are dropped
+pub fn changed_files(paths: &[PathBuf], base: &str, root_config: &RootConfig) -> Result<Vec<PathBuf>> {
+    let work_dir = work_dir(paths);
+    let repo_root = git(&work_dir, &["rev-parse", "--show-toplevel"])?;
+    let repo_root = PathBuf::from(repo_root.trim());
+    // -z keeps non-ASCII paths unquoted
+    let diff = git(&work_dir, &["diff", "--name-only", "-z", "--diff-filter=d", base, "--"])?;
+    let untracked = git(&repo_root, &["ls-files", "--others", "--exclude-standard", "-z"])?;
+
+    let targets: Vec<PathBuf> = paths.iter().filter_map(|p| p.canonicalize().ok()).collect();
+    let mut files = Vec::new();
+    for name in diff.split('\0').chain(untracked.split('\0')).filter(|name| !name.is_empty()) {
+        // Deleted files cannot be canonicalized
+        let Ok(file) = repo_root.join(name).canonicalize() else {
+            continue;
+        };
+        let Some(target) = targets.iter().find(|t| file.starts_with(t)) else {
+            continue;
+        };
+        if file.is_file() && !in_excluded_dir(&file, target, root_config) && !files.contains(&file) {
+            files.push(file);
+        }
+    }
+    Ok(files)
+}
+
+/// Directory to run git in (the first path, or its parent for a file)
+fn work_dir(paths: &[PathBuf]) -> PathBuf {
+    match paths.first() {
+        Some(path) if path.is_file() => match path.parent() {
+            // A bare relative file name has an empty parent
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        },
+        Some(path) => path.clone(),
+        None => PathBuf::from("."),
+    }
+}
+
+/// Same rule as the directory walk: directories below `target` are checked against exclude_dirs
+fn in_excluded_dir(file: &Path, target: &Path, root_config: &RootConfig) -> bool {
+    let Some(dir) = file.strip_prefix(target).ok().and_then(Path::parent) else {
+        return false;
+    };
+    dir.components().any(|c| c.as_os_str() == ".git" || root_config.should_exclude_dir(c.as_os_str()))
+}
+
+fn git(work_dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .current_dir(work_dir)
+        .args(args)
+        .output()
+        .with_context(|| format!("Failed to run git {}", args.join(" ")))?;
+    if !output.status.success() {
+        bail!("git {} failed: {}", args.join(" "), String::from_utf8_lossy(&output.stderr).trim());
+    }
+    String::from_utf8(output.stdout).context("git output is not valid UTF-8")
+}
@@ -0,0 +1,42 @@
+use super::junit::escape;
+use super::FlatViolation;
+use crate::rule::parser::Severity;
+
+/// Format flattened violations as a Checkstyle XML document
+/// Violations must already be sorted by file so that each file is contiguous
+pub fn format(flat: &[FlatViolation]) -> Vec<String> {
+    let mut output = Vec::new();
+    output.push(r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string());
+    output.push(r#"<checkstyle version="4.3">"#.to_string());
+    let mut current: Option<&str> = None;
+    for fv in flat {
+        if current != Some(fv.file.as_str()) {
+            if current.is_some() {
+                output.push("  </file>".to_string());
+            }
+            output.push(format!(r#"  <file name="{}">"#, escape(&fv.file)));
+            current = Some(&fv.file);
+        }
+        // File-level violations have no position, so line and column are omitted
+        let position = if fv.line == 0 { String::new() } else { format!(r#" line="{}" column="{}""#, fv.line, fv.col) };
+        output.push(format!(
+            r#"    <error{position} severity="{}" message="{}" source="{}"/>"#,
+            severity(fv.severity),
+            escape(&fv.message),
+            escape(&fv.label)
+        ));
+    }
+    if current.is_some() {
+        output.push("  </file>".to_string());
+    }
+    output.push("</checkstyle>".to_string());
+    output
+}
+
+fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warn => "warning",
+        Severity::Info => "info",
+    }
+}
@@ -0,0 +1,49 @@
+use std::io::IsTerminal;
+
+use crate::commands::ColorChoice;
+use crate::rule::parser::Severity;
+
+const RED: &str = "\x1b[31m";
+const YELLOW: &str = "\x1b[33m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// Resolve --color for stdout (`auto` follows TTY detection and the NO_COLOR convention)
+pub fn enabled(choice: ColorChoice) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty()) && std::io::stdout().is_terminal()
+        }
+    }
+}
+
+/// Applies colors only when enabled, so disabled output stays byte-for-byte plain
+#[derive(Clone, Copy)]
+pub struct Painter {
+    pub enabled: bool,
+}
+
+impl Painter {
+    /// Red for errors, yellow for warnings (info is left plain)
+    pub fn severity(&self, severity: Severity, text: &str) -> String {
+        match severity {
+            Severity::Error => self.paint(RED, text),
+            Severity::Warn => self.paint(YELLOW, text),
+            Severity::Info => text.to_string(),
+        }
+    }
+
+    pub fn dim(&self, text: &str) -> String {
+        self.paint(DIM, text)
+    }
+
+    fn paint(&self, code: &str, text: &str) -> String {
+        if self.enabled && !text.is_empty() {
+            format!("{code}{text}{RESET}")
+        } else {
+            text.to_string()
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use super::FlatViolation;
+use crate::rule::parser::Severity;
+
+/// Format flattened violations as GitHub Actions workflow commands
+pub fn format(flat: &[FlatViolation]) -> Vec<String> {
+    flat.iter()
+        .map(|fv| {
+            let mut params = format!("file={}", escape_property(&fv.file));
+            if fv.line != 0 {
+                params.push_str(&format!(",line={},col={}", fv.line, fv.col));
+            }
+            format!("::{} {params}::{}", command(fv.severity), escape_data(&fv.message))
+        })
+        .collect()
+}
+
+fn command(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warn => "warning",
+        Severity::Info => "notice",
+    }
+}
+
+/// Escape a workflow command message
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
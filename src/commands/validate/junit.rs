@@ -0,0 +1,87 @@
+use super::FlatViolation;
+use crate::commands::JunitGroupBy;
+
+/// A testsuite built from flattened violations
+struct Suite<'a> {
+    name: &'a str,
+    cases: Vec<&'a FlatViolation>,
+}
+
+/// Format flattened violations as a JUnit XML document
+/// Violations must already be sorted so that each group is contiguous
+pub fn format(flat: &[FlatViolation], group_by: JunitGroupBy) -> Vec<String> {
+    let suites = group(flat, group_by);
+
+    let mut output = Vec::new();
+    output.push(r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string());
+    let total = flat.len();
+    output.push(format!(r#"<testsuites name="rec_lint" tests="{total}" failures="{total}">"#));
+    for suite in &suites {
+        let count = suite.cases.len();
+        output.push(format!(r#"  <testsuite name="{}" tests="{count}" failures="{count}">"#, escape(suite.name)));
+        for fv in &suite.cases {
+            let (name, classname) = match group_by {
+                JunitGroupBy::Rule => (location(fv), fv.label.clone()),
+                JunitGroupBy::File => (position_with_message(fv), fv.file.clone()),
+            };
+            output.push(format!(r#"    <testcase name="{}" classname="{}">"#, escape(&name), escape(&classname)));
+            output.push(format!(
+                r#"      <failure message="{}">{}</failure>"#,
+                escape(&fv.message),
+                escape(&detail(fv))
+            ));
+            output.push("    </testcase>".to_string());
+        }
+        output.push("  </testsuite>".to_string());
+    }
+    output.push("</testsuites>".to_string());
+    output
+}
+
+fn group(flat: &[FlatViolation], group_by: JunitGroupBy) -> Vec<Suite<'_>> {
+    let mut suites: Vec<Suite> = Vec::new();
+    for fv in flat {
+        let key = match group_by {
+            JunitGroupBy::Rule => fv.label.as_str(),
+            JunitGroupBy::File => fv.file.as_str(),
+        };
+        match suites.last_mut() {
+            Some(suite) if suite.name == key => suite.cases.push(fv),
+            _ => suites.push(Suite { name: key, cases: vec![fv] }),
+        }
+    }
+    suites
+}
+
+/// file:line:col (file only for file-level violations)
+fn location(fv: &FlatViolation) -> String {
+    if fv.line == 0 {
+        fv.file.clone()
+    } else {
+        format!("{}:{}:{}", fv.file, fv.line, fv.col)
+    }
+}
+
+/// line:col: message (message only for file-level violations)
+fn position_with_message(fv: &FlatViolation) -> String {
+    if fv.line == 0 {
+        fv.message.clone()
+    } else {
+        format!("{}:{}: {}", fv.line, fv.col, fv.message)
+    }
+}
+
+fn detail(fv: &FlatViolation) -> String {
+    let mut detail = location(fv);
+    if let Some(found) = &fv.found {
+        detail.push_str(&format!(" [ found: {found} ]"));
+    }
+    if let Some(output) = &fv.custom_output {
+        detail.push_str(&format!(" [ {output} ]"));
+    }
+    detail
+}
+
+pub(super) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&apos;")
+}
@@ -0,0 +1,46 @@
+use std::collections::BTreeMap;
+
+use serde_json::{json, Value};
+
+use super::FlatViolation;
+use crate::rule::parser::Severity;
+
+/// Format flattened violations as LSP-style diagnostics keyed by file
+/// Violations must already be sorted by file
+pub fn format(flat: &[FlatViolation]) -> Vec<String> {
+    let mut files: BTreeMap<&str, Vec<Value>> = BTreeMap::new();
+    for fv in flat {
+        files.entry(&fv.file).or_default().push(diagnostic(fv));
+    }
+    let document = serde_json::to_string_pretty(&files).unwrap_or_default();
+    document.lines().map(|line| line.to_string()).collect()
+}
+
+fn diagnostic(fv: &FlatViolation) -> Value {
+    json!({
+        "range": range(fv),
+        "message": fv.message,
+        "severity": severity(fv.severity),
+        "code": fv.label,
+    })
+}
+
+/// Zero-based range from the violation to the end of its line
+/// The matched text length is unknown, so the range ends at the start of the next line
+/// File-level violations cover the first line
+fn range(fv: &FlatViolation) -> Value {
+    let (line, character) = if fv.line == 0 { (0, 0) } else { (fv.line - 1, fv.col.saturating_sub(1)) };
+    json!({
+        "start": { "line": line, "character": character },
+        "end": { "line": line + 1, "character": 0 },
+    })
+}
+
+/// LSP DiagnosticSeverity
+fn severity(severity: Severity) -> u8 {
+    match severity {
+        Severity::Error => 1,
+        Severity::Warn => 2,
+        Severity::Info => 3,
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use super::FlatViolation;
+
+/// Labels of the rules whose matcher applies to a file
+pub struct Applicable {
+    /// File path relative to the root directory
+    pub file: String,
+    pub labels: Vec<String>,
+}
+
+/// Cell of the file x rule matrix
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Pass,
+    Fail,
+    NotApplicable,
+}
+
+impl Cell {
+    fn as_str(self) -> &'static str {
+        match self {
+            Cell::Pass => "pass",
+            Cell::Fail => "fail",
+            Cell::NotApplicable => "-",
+        }
+    }
+}
+
+struct Matrix {
+    labels: Vec<String>,
+    rows: Vec<(String, Vec<Cell>)>,
+}
+
+impl Matrix {
+    /// Rows are sorted by file, columns keep the order in which labels first appear
+    fn build(applicable: &[Applicable], flat: &[FlatViolation]) -> Self {
+        let mut sorted: Vec<&Applicable> = applicable.iter().collect();
+        sorted.sort_by(|a, b| a.file.cmp(&b.file));
+
+        let mut labels: Vec<String> = Vec::new();
+        for a in &sorted {
+            for label in &a.labels {
+                if !labels.contains(label) {
+                    labels.push(label.clone());
+                }
+            }
+        }
+
+        let failed: HashSet<(&str, &str)> = flat.iter().map(|fv| (fv.file.as_str(), fv.label.as_str())).collect();
+        let rows = sorted
+            .iter()
+            .map(|a| {
+                let cells = labels
+                    .iter()
+                    .map(|label| {
+                        if !a.labels.contains(label) {
+                            Cell::NotApplicable
+                        } else if failed.contains(&(a.file.as_str(), label.as_str())) {
+                            Cell::Fail
+                        } else {
+                            Cell::Pass
+                        }
+                    })
+                    .collect();
+                (a.file.clone(), cells)
+            })
+            .collect();
+
+        Self { labels, rows }
+    }
+}
+
+/// Format as an aligned text table (files as rows, rule labels as columns)
+pub fn format_text(applicable: &[Applicable], flat: &[FlatViolation]) -> Vec<String> {
+    let matrix = Matrix::build(applicable, flat);
+
+    let mut widths: Vec<usize> = Vec::with_capacity(matrix.labels.len() + 1);
+    widths.push(matrix.rows.iter().map(|(file, _)| width(file)).max().unwrap_or(0).max(width("file")));
+    for label in &matrix.labels {
+        widths.push(width(label).max(width(Cell::Pass.as_str())));
+    }
+
+    let mut output = Vec::new();
+    let header: Vec<&str> = std::iter::once("file").chain(matrix.labels.iter().map(String::as_str)).collect();
+    output.push(align(&header, &widths));
+    for (file, cells) in &matrix.rows {
+        let row: Vec<&str> = std::iter::once(file.as_str()).chain(cells.iter().map(|c| c.as_str())).collect();
+        output.push(align(&row, &widths));
+    }
+    output
+}
+
+/// Format as CSV with a header row
+pub fn format_csv(applicable: &[Applicable], flat: &[FlatViolation]) -> Vec<String> {
+    let matrix = Matrix::build(applicable, flat);
+
+    let mut output = Vec::new();
+    let header: Vec<String> =
+        std::iter::once("file").chain(matrix.labels.iter().map(String::as_str)).map(csv_field).collect();
+    output.push(header.join(","));
+    for (file, cells) in &matrix.rows {
+        let row: Vec<String> =
+            std::iter::once(csv_field(file)).chain(cells.iter().map(|c| csv_field(c.as_str()))).collect();
+        output.push(row.join(","));
+    }
+    output
+}
+
+fn width(text: &str) -> usize {
+    text.chars().count()
+}
+
+fn align(columns: &[&str], widths: &[usize]) -> String {
+    let padded: Vec<String> =
+        columns.iter().zip(widths).map(|(text, w)| format!("{text}{}", " ".repeat(w - width(text)))).collect();
+    padded.join("  ").trim_end().to_string()
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
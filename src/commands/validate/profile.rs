@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::rule::Rule;
+
+/// Wall-clock time spent per rule (`--profile`)
+///
+/// Each file is validated on its own thread with its own `Profile`, and the profiles are
+/// merged after validation, so no lock is shared between the validating threads.
+#[derive(Default)]
+pub(crate) struct Profile {
+    rules: HashMap<(String, &'static str), Timing>,
+}
+
+#[derive(Default, Clone, Copy)]
+struct Timing {
+    total: Duration,
+    count: usize,
+}
+
+impl Profile {
+    /// Record one run of `rule` on a file
+    pub(crate) fn record(&mut self, rule: &Rule, elapsed: Duration) {
+        let timing = self.rules.entry((rule.label().to_string(), rule.type_name())).or_default();
+        timing.total += elapsed;
+        timing.count += 1;
+    }
+
+    pub(crate) fn merge(&mut self, other: Profile) {
+        for (key, other) in other.rules {
+            let timing = self.rules.entry(key).or_default();
+            timing.total += other.total;
+            timing.count += other.count;
+        }
+    }
+
+    /// Summary lines, slowest rule first
+    pub(crate) fn format(&self) -> Vec<String> {
+        let mut rules: Vec<(&(String, &'static str), &Timing)> = self.rules.iter().collect();
+        rules.sort_by(|(a_key, a), (b_key, b)| b.total.cmp(&a.total).then_with(|| a_key.cmp(b_key)));
+
+        let mut output = vec!["Rule timings (total, runs, rule):".to_string()];
+        output.extend(rules.into_iter().map(|((label, type_name), timing)| {
+            format!("  {:>10.3}ms {:>6} runs  {label} ({type_name})", timing.total.as_secs_f64() * 1000.0, timing.count)
+        }));
+        output
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::rule::{collect_rules, CollectedRules};
+
+/// CollectedRules cached per directory
+///
+/// Rules are inherited downward, so a config change in a directory affects that directory
+/// and every cached descendant. `descendants` maps each config directory to the cached
+/// directories that inherit from it, which makes invalidation a single lookup.
+/// A cache created by `fixed` applies the same rules to every directory instead.
+#[derive(Default)]
+pub struct RuleCache {
+    rules: HashMap<PathBuf, CollectedRules>,
+    descendants: HashMap<PathBuf, HashSet<PathBuf>>,
+    fixed: Option<CollectedRules>,
+}
+
+impl RuleCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cache applying `rules` to every directory without discovering configs
+    pub fn fixed(rules: CollectedRules) -> Self {
+        Self { fixed: Some(rules), ..Self::default() }
+    }
+
+    /// Rules given to `fixed`, if any
+    pub fn fixed_rules(&self) -> Option<&CollectedRules> {
+        self.fixed.as_ref()
+    }
+
+    pub fn get(&self, dir: &Path) -> Option<&CollectedRules> {
+        self.fixed.as_ref().or_else(|| self.rules.get(dir))
+    }
+
+    /// Collect rules for the parent directories of `files` that are not cached yet
+    /// Returns collection errors (failed directories are not cached and are retried next time)
+    pub fn collect(&mut self, files: &[PathBuf], skip_types: &[String]) -> Vec<String> {
+        if self.fixed.is_some() {
+            return Vec::new();
+        }
+        let mut dirs: Vec<PathBuf> = files.iter().filter_map(|f| f.parent().map(|p| p.to_path_buf())).collect();
+        dirs.sort();
+        dirs.dedup();
+
+        let mut errors = Vec::new();
+        for dir in dirs {
+            if self.rules.contains_key(&dir) {
+                continue;
+            }
+            match collect_rules(&dir) {
+                Ok(mut rules) => {
+                    rules.rule.retain(|(rule, _)| !skip_types.iter().any(|t| t == rule.type_name()));
+                    self.register(&dir, &rules.root_dir);
+                    self.rules.insert(dir, rules);
+                }
+                Err(err) => {
+                    errors.push(format!("{}: {err}", dir.display()));
+                }
+            }
+        }
+        errors
+    }
+
+    /// Invalidate cached rules affected by a change of `config_file` (.rec_lint.yaml)
+    /// Returns the invalidated directories; files directly under them need re-validation
+    pub fn invalidate(&mut self, config_file: &Path) -> Vec<PathBuf> {
+        let Some(config_dir) = config_file.parent().and_then(|d| d.canonicalize().ok()) else {
+            return Vec::new();
+        };
+        let mut invalidated: Vec<PathBuf> =
+            self.descendants.remove(&config_dir).unwrap_or_default().into_iter().collect();
+        for dir in &invalidated {
+            self.rules.remove(dir);
+        }
+        for set in self.descendants.values_mut() {
+            set.retain(|dir| !invalidated.contains(dir));
+        }
+        invalidated.sort();
+        invalidated
+    }
+
+    /// Register `dir` under itself and every ancestor up to the root directory
+    fn register(&mut self, dir: &Path, root_dir: &Path) {
+        let Ok(canonical) = dir.canonicalize() else {
+            return;
+        };
+        for ancestor in canonical.ancestors() {
+            self.descendants.entry(ancestor.to_path_buf()).or_default().insert(dir.to_path_buf());
+            if ancestor == root_dir {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+
+use anyhow::Result;
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use super::{
+    get_root_config_for_paths, is_config_file, new_cache, run_with_cache, should_include_file, RuleCache,
+    ValidateOptions, ValidateReport,
+};
+use crate::rule::{RootConfig, ROOT_CONFIG_FILENAMES};
+use crate::validate::custom;
+
+/// Changes arriving within this interval of the previous one are validated together
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// How often `stop` is checked while nothing changes
+const POLL: Duration = Duration::from_millis(100);
+
+/// Validate `paths`, then validate them again each time a target file or config under them changes
+/// Every report (the initial one included) is passed to `on_report`. Rules of a changed .rec_lint.yaml
+/// are collected again, and a changed root config or `--config` file rebuilds all rules.
+/// Returns when `stop` is set (e.g. by a Ctrl-C handler).
+pub fn run(
+    paths: &[PathBuf],
+    options: &ValidateOptions,
+    stop: &AtomicBool,
+    on_report: &mut dyn FnMut(ValidateReport),
+) -> Result<()> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        // The receiver lives until watching ends, so sending cannot fail while it matters
+        let _ = sender.send(event);
+    })?;
+    for path in paths {
+        let mode = if path.is_dir() { RecursiveMode::Recursive } else { RecursiveMode::NonRecursive };
+        watcher.watch(path, mode)?;
+    }
+    // The explicit config may be outside of the paths
+    let explicit_config = options.config.as_ref().map(|config| config.canonicalize()).transpose()?;
+    if let Some(config) = &explicit_config {
+        watcher.watch(config, RecursiveMode::NonRecursive)?;
+    }
+
+    let mut cache = new_cache(paths, options)?;
+    let mut root_config = current_root_config(paths, &cache)?;
+    on_report(run_with_cache(paths, options, &mut cache)?);
+
+    while !stop.load(Ordering::SeqCst) {
+        let event = match receiver.recv_timeout(POLL) {
+            Ok(event) => event,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => break,
+        };
+        let mut changed = changed_paths(event?);
+        while let Ok(event) = receiver.recv_timeout(DEBOUNCE) {
+            changed.extend(changed_paths(event?));
+        }
+        changed.retain(|path| explicit_config.as_ref() == Some(path) || is_watched(path, &root_config));
+        changed.sort();
+        changed.dedup();
+        if changed.is_empty() {
+            continue;
+        }
+
+        let rebuild = changed.iter().any(|path| is_root_config_file(path) || explicit_config.as_ref() == Some(path));
+        if rebuild {
+            cache = new_cache(paths, options)?;
+            root_config = current_root_config(paths, &cache)?;
+        } else {
+            for config in changed.iter().filter(|path| is_config_file(path)) {
+                cache.invalidate(config);
+            }
+        }
+        on_report(run_with_cache(paths, options, &mut cache)?);
+    }
+    Ok(())
+}
+
+/// Root config used to filter the changed paths, as in `prepare`
+fn current_root_config(paths: &[PathBuf], cache: &RuleCache) -> Result<RootConfig> {
+    match cache.fixed_rules() {
+        Some(rules) => Ok(rules.root_config.clone()),
+        None => get_root_config_for_paths(paths),
+    }
+}
+
+/// Paths created, written or removed by `event`
+/// Access and metadata events are ignored; validation itself reads the files and would trigger them
+fn changed_paths(event: Event) -> Vec<PathBuf> {
+    match event.kind {
+        EventKind::Create(_) | EventKind::Remove(_) => event.paths,
+        EventKind::Modify(ModifyKind::Metadata(_)) => Vec::new(),
+        EventKind::Modify(_) => event.paths,
+        _ => Vec::new(),
+    }
+}
+
+/// Returns true if a change of `path` can change the report (a config file or a target file)
+fn is_watched(path: &Path, root_config: &RootConfig) -> bool {
+    if is_config_file(path) {
+        return true;
+    }
+    // Directories above the root do not count (e.g. a project checked out under a `build` directory)
+    let relative = path.strip_prefix(&root_config.root_dir).unwrap_or(path);
+    let excluded_dir = relative.parent().into_iter().flat_map(Path::components).any(|component| match component {
+        Component::Normal(name) => name == ".git" || name == custom::CACHE_DIR || root_config.should_exclude_dir(name),
+        _ => false,
+    });
+    !excluded_dir
+        && should_include_file(path, root_config)
+        && !root_config.should_exclude_file(path)
+        && !root_config.is_ignored(path, false)
+}
+
+fn is_root_config_file(path: &Path) -> bool {
+    path.file_name().and_then(|name| name.to_str()).is_some_and(|name| ROOT_CONFIG_FILENAMES.contains(&name))
+}
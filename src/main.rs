@@ -1,16 +1,24 @@
+use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 use clap::Parser;
 
 use rec_lint::commands;
-use rec_lint::commands::{CheckMode, Cli, Commands};
+use rec_lint::commands::validate::{NoEffectiveRules, ValidateOptions};
+use rec_lint::commands::{CheckMode, Cli, Commands, Lang};
 
 fn main() -> ExitCode {
     match run() {
         Ok(exit_code) => exit_code,
         Err(e) => {
             eprintln!("Error: {e}");
-            ExitCode::FAILURE
+            if e.downcast_ref::<NoEffectiveRules>().is_some() {
+                ExitCode::from(2)
+            } else {
+                ExitCode::FAILURE
+            }
         }
     }
 }
@@ -18,38 +26,135 @@ fn main() -> ExitCode {
 fn run() -> anyhow::Result<ExitCode> {
     let cli = Cli::parse();
 
-    let is_validate = matches!(cli.command, Commands::Validate { .. });
+    if let Some(jobs) = cli.jobs {
+        rayon::ThreadPoolBuilder::new().num_threads(jobs.get()).build_global()?;
+    }
+
+    let mut has_violations = false;
 
     let output = match cli.command {
-        Commands::Show { dir } => commands::show::run(&dir)?,
-        Commands::Validate { paths, sort } => commands::validate::run(&paths, sort)?,
-        Commands::Guideline { dir } => commands::guideline::run(&dir)?,
+        Commands::Show { dir, format } => commands::show::run(&dir, format)?,
+        Commands::Validate {
+            paths,
+            sort,
+            format,
+            junit_group_by,
+            baseline,
+            write_baseline,
+            skip_type,
+            changed,
+            changed_since,
+            only,
+            skip,
+            require_rules,
+            stream,
+            stdin,
+            stdin_path,
+            fix,
+            warn_unused,
+            list_files,
+            count,
+            encoding,
+            no_cache,
+            config,
+            profile,
+            summary,
+            watch,
+            fail_on,
+        } => {
+            let options = ValidateOptions {
+                sort_mode: sort,
+                format,
+                junit_group_by,
+                baseline,
+                write_baseline,
+                skip_types: skip_type,
+                require_rules,
+                changed: changed_since.or_else(|| changed.then(|| "HEAD".to_string())),
+                only,
+                skip,
+                fix,
+                warn_unused,
+                color: commands::validate::color::enabled(cli.color),
+                count,
+                encoding,
+                no_cache,
+                config,
+                profile,
+                summary,
+            };
+            if watch {
+                watch_validate(&paths, &options)?;
+                Vec::new()
+            } else if list_files {
+                commands::validate::list_files(&paths, &options)?
+            } else if let (true, Some(stdin_path)) = (stdin, stdin_path) {
+                let content = std::io::read_to_string(std::io::stdin())?;
+                let report = commands::validate::run_with_content(&stdin_path, &content, &options)?;
+                has_violations = report.exceeds(fail_on);
+                report.lines
+            } else if stream {
+                let report = commands::validate::run_streaming(&paths, &options, &mut |line| println!("{line}"))?;
+                has_violations = report.exceeds(fail_on);
+                print_fixed(&report.fixed);
+                print_skipped(&report.skipped);
+                print_unused(&report.unused);
+                print_profile(&report.profile);
+                Vec::new()
+            } else {
+                let report = commands::validate::run_with_options(&paths, &options)?;
+                has_violations = report.exceeds(fail_on);
+                print_fixed(&report.fixed);
+                print_skipped(&report.skipped);
+                print_unused(&report.unused);
+                print_profile(&report.profile);
+                report.lines
+            }
+        }
+        Commands::Stats { paths } => commands::stats::run(&paths)?,
+        Commands::Report { dir, output } => commands::report::run(&dir, output.as_deref())?,
+        Commands::Guideline { dir, format } => commands::guideline::run(&dir, format)?,
         Commands::Version => commands::version::run()?,
-        Commands::Init { dir } => commands::init::run(&dir)?,
-        Commands::Add { dir } => commands::add::run(&dir)?,
-        Commands::Desc => commands::desc::run()?,
-        Commands::Check { list, tree, schema, html } => {
-            let mode = if list {
-                CheckMode::List
-            } else if tree {
-                CheckMode::Tree
-            } else if schema {
-                CheckMode::Schema
-            } else if html {
-                CheckMode::Html
+        Commands::Init { dir, stdout, force } => commands::init::run(&dir, stdout, force)?,
+        Commands::Add { dir, template, list_templates } => {
+            if list_templates {
+                commands::add::list_templates()
             } else {
-                // No option provided, show help
-                return Err(anyhow::anyhow!(
-                    "No option specified. Use --list, --tree, --schema, or --html.\n\n\
-                    Run 'rec_lint check --help' for more information."
-                ));
-            };
-            commands::check::run(mode)?
+                commands::add::run(&dir, template.as_deref())?
+            }
+        }
+        Commands::Desc { lang } => commands::desc::run(Lang::resolve(lang)?)?,
+        Commands::Explain { rule_type, lang } => commands::explain::run(&rule_type, Lang::resolve(lang)?)?,
+        Commands::Check { dir, list, tree, schema, html, html_out, lint, json, validate, depth } => {
+            if let Some(output) = html_out {
+                commands::check::write_html(&dir, &output, depth)?
+            } else {
+                let mode = if list {
+                    CheckMode::List
+                } else if tree {
+                    CheckMode::Tree
+                } else if schema {
+                    CheckMode::Schema
+                } else if html {
+                    CheckMode::Html
+                } else if lint {
+                    CheckMode::Lint
+                } else if json {
+                    CheckMode::Json
+                } else if validate {
+                    CheckMode::Validate
+                } else {
+                    // No option provided, show help
+                    return Err(anyhow::anyhow!(
+                        "No option specified. Use --list, --tree, --schema, --html, --html-out, --lint, --json, or --validate.\n\n\
+                        Run 'rec_lint check --help' for more information."
+                    ));
+                };
+                commands::check::run(mode, &dir, depth)?
+            }
         }
     };
 
-    let has_violations = is_validate && !output.is_empty();
-
     for line in output {
         println!("{line}");
     }
@@ -60,3 +165,47 @@ fn run() -> anyhow::Result<ExitCode> {
         Ok(ExitCode::SUCCESS)
     }
 }
+
+/// Print rules reported by validate --warn-unused to stderr (they do not affect the exit code)
+fn print_unused(unused: &[String]) {
+    for line in unused {
+        eprintln!("{line}");
+    }
+}
+
+/// Run validate --watch until Ctrl-C, clearing the screen before each report
+fn watch_validate(paths: &[PathBuf], options: &ValidateOptions) -> anyhow::Result<()> {
+    let stop = Arc::new(AtomicBool::new(false));
+    let handler_stop = Arc::clone(&stop);
+    ctrlc::set_handler(move || handler_stop.store(true, Ordering::SeqCst))?;
+    commands::validate::watch::run(paths, options, &stop, &mut |report| {
+        print!("\x1B[2J\x1B[1;1H");
+        for line in &report.lines {
+            println!("{line}");
+        }
+        print_skipped(&report.skipped);
+        print_unused(&report.unused);
+        print_profile(&report.profile);
+    })
+}
+
+/// Print the per rule timings of validate --profile to stderr
+fn print_profile(profile: &[String]) {
+    for line in profile {
+        eprintln!("{line}");
+    }
+}
+
+/// Print files skipped by validate because they are not valid UTF-8 to stderr
+fn print_skipped(skipped: &[String]) {
+    for file in skipped {
+        eprintln!("Warning: skipped non UTF-8 file (use --encoding lossy to validate it): {file}");
+    }
+}
+
+/// Print files rewritten by validate --fix to stderr (stdout is kept for the report)
+fn print_fixed(fixed: &[String]) {
+    for file in fixed {
+        eprintln!("Fixed: {file}");
+    }
+}
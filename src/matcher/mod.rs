@@ -1,20 +1,38 @@
 use std::path::Path;
 
+use anyhow::{anyhow, Result};
+use globset::{GlobBuilder, GlobMatcher};
+use regex::Regex;
+
 use crate::rule::parser::{MatchCond, MatchPattern, RawMatchItem};
 
 /// Parsed matcher for file path matching
 #[derive(Clone, Debug, Default)]
 pub struct Matcher {
     pub items: Vec<RawMatchItem>,
+    /// Keywords compiled once per item (same order as `items`)
+    compiled: Vec<CompiledKeywords>,
+}
+
+/// Keywords of a match item that need compiling before matching
+#[derive(Clone, Debug)]
+enum CompiledKeywords {
+    /// Plain string keywords are used as they are
+    None,
+    Globs(Vec<GlobMatcher>),
+    Regexes(Vec<Regex>),
+    Depths(Vec<usize>),
 }
 
 impl Matcher {
-    pub fn new(items: Vec<RawMatchItem>) -> Self {
-        Self { items }
+    pub fn new(items: Vec<RawMatchItem>) -> Result<Self> {
+        let compiled = items.iter().map(compile).collect::<Result<_>>()?;
+        Ok(Self { items, compiled })
     }
 
     /// Returns true if the file matches all conditions (AND logic between items)
-    pub fn matches(&self, file_path: &Path) -> bool {
+    /// `source_dir` is the directory of the config defining the rule (used by `relative_depth`)
+    pub fn matches(&self, file_path: &Path, source_dir: &Path) -> bool {
         if self.items.is_empty() {
             return true;
         }
@@ -23,15 +41,47 @@ impl Matcher {
         let path_str = file_path.to_string_lossy();
 
         // All items must match (AND logic)
-        for item in &self.items {
-            if !self.item_matches(item, filename, &path_str) {
+        for (item, compiled) in self.items.iter().zip(&self.compiled) {
+            if let CompiledKeywords::Depths(depths) = compiled {
+                if !depth_matches(item, depths, file_path, source_dir) {
+                    return false;
+                }
+                continue;
+            }
+            if !self.item_matches(item, compiled, filename, &path_str) {
                 return false;
             }
         }
         true
     }
 
-    fn item_matches(&self, item: &RawMatchItem, filename: &str, path_str: &str) -> bool {
+    fn item_matches(&self, item: &RawMatchItem, compiled: &CompiledKeywords, filename: &str, path_str: &str) -> bool {
+        if let CompiledKeywords::Globs(globs) = compiled {
+            let check_glob = |glob: &GlobMatcher| -> bool {
+                match item.pattern {
+                    MatchPattern::PathNotGlob => !glob.is_match(path_str),
+                    _ => glob.is_match(path_str),
+                }
+            };
+            return match item.cond {
+                MatchCond::And => globs.iter().all(check_glob),
+                MatchCond::Or => globs.iter().any(check_glob),
+            };
+        }
+
+        if let CompiledKeywords::Regexes(regexes) = compiled {
+            let check_regex = |regex: &Regex| -> bool {
+                match item.pattern {
+                    MatchPattern::PathNotMatchesRegex => !regex.is_match(path_str),
+                    _ => regex.is_match(path_str),
+                }
+            };
+            return match item.cond {
+                MatchCond::And => regexes.iter().all(check_regex),
+                MatchCond::Or => regexes.iter().any(check_regex),
+            };
+        }
+
         let check_keyword = |keyword: &str| -> bool {
             match item.pattern {
                 MatchPattern::FileStartsWith => filename.starts_with(keyword),
@@ -40,6 +90,12 @@ impl Matcher {
                 MatchPattern::FileNotStartsWith => !filename.starts_with(keyword),
                 MatchPattern::FileNotEndsWith => !filename.ends_with(keyword),
                 MatchPattern::PathNotContains => !path_str.contains(keyword),
+                // Compiled patterns are handled above
+                MatchPattern::PathGlob
+                | MatchPattern::PathNotGlob
+                | MatchPattern::PathMatchesRegex
+                | MatchPattern::PathNotMatchesRegex
+                | MatchPattern::RelativeDepth => false,
             }
         };
 
@@ -49,3 +105,53 @@ impl Matcher {
         }
     }
 }
+
+/// Compare the number of directories between `source_dir` and the file (false if the file is not below it)
+fn depth_matches(item: &RawMatchItem, depths: &[usize], file_path: &Path, source_dir: &Path) -> bool {
+    let depth =
+        file_path.parent().and_then(|dir| dir.strip_prefix(source_dir).ok()).map(|rel| rel.components().count());
+    let Some(depth) = depth else {
+        return false;
+    };
+    match item.cond {
+        MatchCond::And => depths.iter().all(|d| *d == depth),
+        MatchCond::Or => depths.contains(&depth),
+    }
+}
+
+fn compile(item: &RawMatchItem) -> Result<CompiledKeywords> {
+    match item.pattern {
+        MatchPattern::PathGlob | MatchPattern::PathNotGlob => {
+            let globs = item
+                .keywords
+                .iter()
+                .map(|keyword| {
+                    // `*` stays within a path segment; use `**` to cross directories
+                    GlobBuilder::new(keyword)
+                        .literal_separator(true)
+                        .build()
+                        .map(|glob| glob.compile_matcher())
+                        .map_err(|e| anyhow!("invalid glob '{keyword}': {}", e.kind()))
+                })
+                .collect::<Result<_>>()?;
+            Ok(CompiledKeywords::Globs(globs))
+        }
+        MatchPattern::PathMatchesRegex | MatchPattern::PathNotMatchesRegex => {
+            let regexes = item
+                .keywords
+                .iter()
+                .map(|keyword| Regex::new(keyword).map_err(|e| anyhow!("invalid regex '{keyword}': {e}")))
+                .collect::<Result<_>>()?;
+            Ok(CompiledKeywords::Regexes(regexes))
+        }
+        MatchPattern::RelativeDepth => {
+            let depths = item
+                .keywords
+                .iter()
+                .map(|keyword| keyword.parse().map_err(|_| anyhow!("invalid depth '{keyword}': expected 0 or more")))
+                .collect::<Result<_>>()?;
+            Ok(CompiledKeywords::Depths(depths))
+        }
+        _ => Ok(CompiledKeywords::None),
+    }
+}
@@ -1,13 +1,15 @@
 use std::path::{Path, PathBuf};
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 
 use super::parser::RawConfig;
 use super::root_config::{RawRootConfig, RootConfig};
 use super::{Config, GuidelineItem, Rule};
 
-const CONFIG_FILENAME: &str = ".rec_lint.yaml";
-const ROOT_CONFIG_FILENAME: &str = ".rec_lint_config.yaml";
+/// Config file names looked up in each directory (the first existing one is used)
+pub const CONFIG_FILENAMES: &[&str] = &[".rec_lint.yaml", ".rec_lint.toml"];
+/// Root config file names marking the root directory (the first existing one is used)
+pub const ROOT_CONFIG_FILENAMES: &[&str] = &[".rec_lint_config.yaml", ".rec_lint_config.toml"];
 
 pub struct CollectedRules {
     pub root_dir: PathBuf,
@@ -18,28 +20,26 @@ pub struct CollectedRules {
 
 pub fn collect_rules(target_dir: &Path) -> Result<CollectedRules> {
     let target_dir = target_dir.canonicalize()?;
-    let mut configs: Vec<(Config, PathBuf)> = Vec::new();
+    let mut configs: Vec<(RawConfig, PathBuf)> = Vec::new();
     let mut current = Some(target_dir.as_path());
     let mut root_dir: Option<PathBuf> = None;
     let mut root_config: Option<RootConfig> = None;
 
     while let Some(dir) = current {
+        ensure_single_config(dir)?;
+
         // Check for root marker file
-        let root_config_path = dir.join(ROOT_CONFIG_FILENAME);
-        let is_root = root_config_path.exists();
-
-        // Load rec_lint.yaml if it exists
-        let config_path = dir.join(CONFIG_FILENAME);
-        if config_path.exists() {
-            let raw = RawConfig::load(&config_path)?;
-            let config = Config::try_from(raw)?;
-            configs.push((config, dir.to_path_buf()));
+        let root_config_path = find_root_config(dir);
+
+        // Load rec_lint.yaml (or rec_lint.toml) if it exists
+        if let Some(config_path) = find_config(dir) {
+            configs.push((RawConfig::load(&config_path)?, dir.to_path_buf()));
         }
 
-        if is_root {
+        if let Some(root_config_path) = root_config_path {
             // Load root config
             let raw_root = RawRootConfig::load(&root_config_path)?;
-            root_config = Some(RootConfig::from_raw(raw_root, dir));
+            root_config = Some(RootConfig::from_raw(raw_root, dir)?);
             root_dir = Some(dir.to_path_buf());
             break;
         }
@@ -52,7 +52,9 @@ pub fn collect_rules(target_dir: &Path) -> Result<CollectedRules> {
     configs.reverse();
 
     let mut collected = CollectedRules { root_dir, root_config, rule: Vec::new(), guideline: Vec::new() };
-    for (config, dir) in configs {
+    // Converted after the walk so that root config defaults apply to every rule
+    for (raw, dir) in configs {
+        let config = Config::from_raw(raw, &collected.root_config)?;
         for r in config.rule {
             collected.rule.push((r, dir.clone()));
         }
@@ -63,3 +65,43 @@ pub fn collect_rules(target_dir: &Path) -> Result<CollectedRules> {
 
     Ok(collected)
 }
+
+/// Rules of a single explicit config file, applied as if it were placed in `root_dir`
+/// No directory is searched, so neither inherited configs nor a root config apply
+pub fn collect_rules_from(config_path: &Path, root_dir: &Path) -> Result<CollectedRules> {
+    let root_dir = root_dir.canonicalize()?;
+    let root_config = RootConfig::default();
+    let config = Config::from_raw(RawConfig::load(config_path)?, &root_config)?;
+    Ok(CollectedRules {
+        rule: config.rule.into_iter().map(|r| (r, root_dir.clone())).collect(),
+        guideline: config.guideline.into_iter().map(|item| (item, root_dir.clone())).collect(),
+        root_dir,
+        root_config,
+    })
+}
+
+/// The config file of a directory, if any
+pub fn find_config(dir: &Path) -> Option<PathBuf> {
+    find_first(dir, CONFIG_FILENAMES)
+}
+
+/// The root config file of a directory, if any
+pub fn find_root_config(dir: &Path) -> Option<PathBuf> {
+    find_first(dir, ROOT_CONFIG_FILENAMES)
+}
+
+/// Fails when a directory has both the YAML and the TOML variant of a config (or root config)
+/// Otherwise one of them would be silently ignored
+pub fn ensure_single_config(dir: &Path) -> Result<()> {
+    for names in [CONFIG_FILENAMES, ROOT_CONFIG_FILENAMES] {
+        let found: Vec<&str> = names.iter().copied().filter(|name| dir.join(name).exists()).collect();
+        if found.len() > 1 {
+            bail!("Both {} exist in {} (keep only one)", found.join(" and "), dir.display());
+        }
+    }
+    Ok(())
+}
+
+fn find_first(dir: &Path, names: &[&str]) -> Option<PathBuf> {
+    names.iter().map(|name| dir.join(name)).find(|path| path.exists())
+}
@@ -1,18 +1,82 @@
 mod collector;
 pub mod parser;
 pub mod root_config;
+pub mod types;
 
-pub use collector::{collect_rules, CollectedRules};
+pub use collector::{
+    collect_rules, collect_rules_from, ensure_single_config, find_config, find_root_config, CollectedRules,
+    CONFIG_FILENAMES, ROOT_CONFIG_FILENAMES,
+};
 pub use root_config::RootConfig;
 
+use std::collections::BTreeMap;
+use std::path::Path;
+
 use anyhow::{anyhow, Result};
+use globset::{Glob, GlobMatcher};
 use regex::Regex;
 
 use crate::matcher::Matcher;
+use crate::validate::allowlist::AllowList;
 use crate::validate::comment::custom::{BlockSyntax, CustomCommentSyntax};
-use crate::validate::doc::{KotlinDocConfig, PhpDocConfig, RustDocConfig};
-use crate::validate::test::exists::{ExternalFileTestConfig, SameFileTestConfig};
-use parser::{CommentLang, RawConfig, RawGuidelineItem, RawRuleContent, RawRuleItem, TestRequireLevel, Visibility};
+use crate::validate::doc::{
+    CSharpDocConfig, GenericDocConfig, GoDocConfig, KotlinDocConfig, PhpDocConfig, PythonDocConfig, RustDocConfig,
+    SwiftDocConfig, TypeScriptDocConfig,
+};
+use crate::validate::file_lines::FileLinesConfig;
+use crate::validate::imports::ImportsConfig;
+use crate::validate::line_length::LineLengthConfig;
+use crate::validate::line_range::LineRange;
+use crate::validate::quotes::QuoteConfig;
+use crate::validate::required_text::RequiredTextConfig;
+use crate::validate::test::exists::{ExternalFileTestConfig, RustUnitTestConfig, SameFileTestConfig};
+use crate::validate::todo::TodoConfig;
+use crate::validate::{final_newline, trailing_whitespace};
+use parser::{
+    Anchor, CommentLang, Lang, RawConfig, RawCustomComment, RawGuidelineItem, RawRuleContent, RawRuleItem, Script,
+    Severity, TestRequireLevel, Visibility,
+};
+use types::PRESET_INFOS;
+
+/// All rule type names as written in .rec_lint.yaml
+pub const RULE_TYPES: &[&str] = &[
+    "forbidden_texts",
+    "forbidden_patterns",
+    "custom",
+    "require_php_doc",
+    "require_kotlin_doc",
+    "require_rust_doc",
+    "require_python_doc",
+    "require_typescript_doc",
+    "require_go_doc",
+    "require_swift_doc",
+    "require_csharp_doc",
+    "require_doc",
+    "require_english_comment",
+    "require_japanese_comment",
+    "require_japanese_phpunit_test_name",
+    "require_japanese_kotest_test_name",
+    "require_japanese_rust_test_name",
+    "require_japanese_junit_test_name",
+    "require_japanese_spock_test_name",
+    "require_phpunit_test",
+    "require_kotest_test",
+    "require_rust_unit_test",
+    "consistent_quotes",
+    "no_skipped_tests",
+    "max_line_length",
+    "no_trailing_whitespace",
+    "require_trailing_newline",
+    "forbidden_file_names",
+    "max_file_lines",
+    "require_go_test",
+    "forbidden_imports",
+    "required_texts",
+    "require_japanese_python_test_name",
+    "require_japanese_jest_test_name",
+    "preset",
+    "no_stale_todo",
+];
 
 #[derive(Clone, Debug)]
 pub enum Rule {
@@ -22,15 +86,38 @@ pub enum Rule {
     PhpDoc(PhpDocRule),
     KotlinDoc(KotlinDocRule),
     RustDoc(RustDocRule),
+    PythonDoc(PythonDocRule),
+    TypeScriptDoc(TypeScriptDocRule),
+    GoDoc(GoDocRule),
+    SwiftDoc(SwiftDocRule),
+    CSharpDoc(CSharpDocRule),
+    GenericDoc(GenericDocRule),
+    LanguageDoc(LanguageDocRule),
     JapaneseComment(CommentRule),
     EnglishComment(CommentRule),
     PhpUnitTest(TestRule),
     KotestTest(TestRule),
     RustTest(TestRule),
+    JunitTest(TestRule),
+    SpockTest(TestRule),
     // Test existence rules
     PhpUnitTestExistence(TestExistenceRule<ExternalFileTestConfig>),
     KotestTestExistence(TestExistenceRule<ExternalFileTestConfig>),
-    RustTestExistence(TestExistenceRule<SameFileTestConfig>),
+    RustTestExistence(TestExistenceRule<RustUnitTestConfig>),
+    ConsistentQuotes(QuoteRule),
+    NoSkippedTests(SkippedTestRule),
+    MaxLineLength(LineLengthRule),
+    NoTrailingWhitespace(TrailingWhitespaceRule),
+    RequireTrailingNewline(TrailingNewlineRule),
+    ForbiddenFileNames(FileNameRule),
+    MaxFileLines(FileLinesRule),
+    GoTestExistence(TestExistenceRule<SameFileTestConfig>),
+    ForbiddenImports(ImportsRule),
+    RequiredTexts(RequiredTextsRule),
+    PythonTest(TestRule),
+    JestTest(TestRule),
+    Preset(RegexRule),
+    NoStaleTodo(TodoRule),
 }
 
 impl Rule {
@@ -42,14 +129,164 @@ impl Rule {
             Rule::PhpDoc(r) => &r.label,
             Rule::KotlinDoc(r) => &r.label,
             Rule::RustDoc(r) => &r.label,
+            Rule::PythonDoc(r) => &r.label,
+            Rule::TypeScriptDoc(r) => &r.label,
+            Rule::GoDoc(r) => &r.label,
+            Rule::SwiftDoc(r) => &r.label,
+            Rule::CSharpDoc(r) => &r.label,
+            Rule::GenericDoc(r) => &r.label,
+            Rule::LanguageDoc(r) => &r.label,
             Rule::JapaneseComment(r) => &r.label,
             Rule::EnglishComment(r) => &r.label,
             Rule::PhpUnitTest(r) => &r.label,
             Rule::KotestTest(r) => &r.label,
             Rule::RustTest(r) => &r.label,
+            Rule::JunitTest(r) => &r.label,
+            Rule::SpockTest(r) => &r.label,
             Rule::PhpUnitTestExistence(r) => &r.label,
             Rule::KotestTestExistence(r) => &r.label,
             Rule::RustTestExistence(r) => &r.label,
+            Rule::ConsistentQuotes(r) => &r.label,
+            Rule::NoSkippedTests(r) => &r.label,
+            Rule::MaxLineLength(r) => &r.label,
+            Rule::NoTrailingWhitespace(r) => &r.label,
+            Rule::RequireTrailingNewline(r) => &r.label,
+            Rule::ForbiddenFileNames(r) => &r.label,
+            Rule::MaxFileLines(r) => &r.label,
+            Rule::GoTestExistence(r) => &r.label,
+            Rule::ForbiddenImports(r) => &r.label,
+            Rule::RequiredTexts(r) => &r.label,
+            Rule::PythonTest(r) => &r.label,
+            Rule::JestTest(r) => &r.label,
+            Rule::Preset(r) => &r.label,
+            Rule::NoStaleTodo(r) => &r.label,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Rule::Text(r) => &r.message,
+            Rule::Regex(r) => &r.message,
+            Rule::Custom(r) => &r.message,
+            Rule::PhpDoc(r) => &r.message,
+            Rule::KotlinDoc(r) => &r.message,
+            Rule::RustDoc(r) => &r.message,
+            Rule::PythonDoc(r) => &r.message,
+            Rule::TypeScriptDoc(r) => &r.message,
+            Rule::GoDoc(r) => &r.message,
+            Rule::SwiftDoc(r) => &r.message,
+            Rule::CSharpDoc(r) => &r.message,
+            Rule::GenericDoc(r) => &r.message,
+            Rule::LanguageDoc(r) => &r.message,
+            Rule::JapaneseComment(r) => &r.message,
+            Rule::EnglishComment(r) => &r.message,
+            Rule::PhpUnitTest(r) => &r.message,
+            Rule::KotestTest(r) => &r.message,
+            Rule::RustTest(r) => &r.message,
+            Rule::JunitTest(r) => &r.message,
+            Rule::SpockTest(r) => &r.message,
+            Rule::PhpUnitTestExistence(r) => &r.message,
+            Rule::KotestTestExistence(r) => &r.message,
+            Rule::RustTestExistence(r) => &r.message,
+            Rule::ConsistentQuotes(r) => &r.message,
+            Rule::NoSkippedTests(r) => &r.message,
+            Rule::MaxLineLength(r) => &r.message,
+            Rule::NoTrailingWhitespace(r) => &r.message,
+            Rule::RequireTrailingNewline(r) => &r.message,
+            Rule::ForbiddenFileNames(r) => &r.message,
+            Rule::MaxFileLines(r) => &r.message,
+            Rule::GoTestExistence(r) => &r.message,
+            Rule::ForbiddenImports(r) => &r.message,
+            Rule::RequiredTexts(r) => &r.message,
+            Rule::PythonTest(r) => &r.message,
+            Rule::JestTest(r) => &r.message,
+            Rule::Preset(r) => &r.message,
+            Rule::NoStaleTodo(r) => &r.message,
+        }
+    }
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            Rule::Text(r) => r.severity,
+            Rule::Regex(r) => r.severity,
+            Rule::Custom(r) => r.severity,
+            Rule::PhpDoc(r) => r.severity,
+            Rule::KotlinDoc(r) => r.severity,
+            Rule::RustDoc(r) => r.severity,
+            Rule::PythonDoc(r) => r.severity,
+            Rule::TypeScriptDoc(r) => r.severity,
+            Rule::GoDoc(r) => r.severity,
+            Rule::SwiftDoc(r) => r.severity,
+            Rule::CSharpDoc(r) => r.severity,
+            Rule::GenericDoc(r) => r.severity,
+            Rule::LanguageDoc(r) => r.severity,
+            Rule::JapaneseComment(r) => r.severity,
+            Rule::EnglishComment(r) => r.severity,
+            Rule::PhpUnitTest(r) => r.severity,
+            Rule::KotestTest(r) => r.severity,
+            Rule::RustTest(r) => r.severity,
+            Rule::JunitTest(r) => r.severity,
+            Rule::SpockTest(r) => r.severity,
+            Rule::PhpUnitTestExistence(r) => r.severity,
+            Rule::KotestTestExistence(r) => r.severity,
+            Rule::RustTestExistence(r) => r.severity,
+            Rule::ConsistentQuotes(r) => r.severity,
+            Rule::NoSkippedTests(r) => r.severity,
+            Rule::MaxLineLength(r) => r.severity,
+            Rule::NoTrailingWhitespace(r) => r.severity,
+            Rule::RequireTrailingNewline(r) => r.severity,
+            Rule::ForbiddenFileNames(r) => r.severity,
+            Rule::MaxFileLines(r) => r.severity,
+            Rule::GoTestExistence(r) => r.severity,
+            Rule::ForbiddenImports(r) => r.severity,
+            Rule::RequiredTexts(r) => r.severity,
+            Rule::PythonTest(r) => r.severity,
+            Rule::JestTest(r) => r.severity,
+            Rule::Preset(r) => r.severity,
+            Rule::NoStaleTodo(r) => r.severity,
+        }
+    }
+
+    /// Rule type name as written in .rec_lint.yaml
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Rule::Text(_) => "forbidden_texts",
+            Rule::Regex(_) => "forbidden_patterns",
+            Rule::Custom(_) => "custom",
+            Rule::PhpDoc(_) => "require_php_doc",
+            Rule::KotlinDoc(_) => "require_kotlin_doc",
+            Rule::RustDoc(_) => "require_rust_doc",
+            Rule::PythonDoc(_) => "require_python_doc",
+            Rule::TypeScriptDoc(_) => "require_typescript_doc",
+            Rule::GoDoc(_) => "require_go_doc",
+            Rule::SwiftDoc(_) => "require_swift_doc",
+            Rule::CSharpDoc(_) => "require_csharp_doc",
+            Rule::GenericDoc(_) => "require_doc",
+            Rule::LanguageDoc(_) => "require_doc",
+            Rule::JapaneseComment(_) => "require_english_comment",
+            Rule::EnglishComment(_) => "require_japanese_comment",
+            Rule::PhpUnitTest(_) => "require_japanese_phpunit_test_name",
+            Rule::KotestTest(_) => "require_japanese_kotest_test_name",
+            Rule::RustTest(_) => "require_japanese_rust_test_name",
+            Rule::JunitTest(_) => "require_japanese_junit_test_name",
+            Rule::SpockTest(_) => "require_japanese_spock_test_name",
+            Rule::PhpUnitTestExistence(_) => "require_phpunit_test",
+            Rule::KotestTestExistence(_) => "require_kotest_test",
+            Rule::RustTestExistence(_) => "require_rust_unit_test",
+            Rule::ConsistentQuotes(_) => "consistent_quotes",
+            Rule::NoSkippedTests(_) => "no_skipped_tests",
+            Rule::MaxLineLength(_) => "max_line_length",
+            Rule::NoTrailingWhitespace(_) => "no_trailing_whitespace",
+            Rule::RequireTrailingNewline(_) => "require_trailing_newline",
+            Rule::ForbiddenFileNames(_) => "forbidden_file_names",
+            Rule::MaxFileLines(_) => "max_file_lines",
+            Rule::GoTestExistence(_) => "require_go_test",
+            Rule::ForbiddenImports(_) => "forbidden_imports",
+            Rule::RequiredTexts(_) => "required_texts",
+            Rule::PythonTest(_) => "require_japanese_python_test_name",
+            Rule::JestTest(_) => "require_japanese_jest_test_name",
+            Rule::Preset(_) => "preset",
+            Rule::NoStaleTodo(_) => "no_stale_todo",
         }
     }
 
@@ -61,14 +298,37 @@ impl Rule {
             Rule::PhpDoc(r) => &r.matcher,
             Rule::KotlinDoc(r) => &r.matcher,
             Rule::RustDoc(r) => &r.matcher,
+            Rule::PythonDoc(r) => &r.matcher,
+            Rule::TypeScriptDoc(r) => &r.matcher,
+            Rule::GoDoc(r) => &r.matcher,
+            Rule::SwiftDoc(r) => &r.matcher,
+            Rule::CSharpDoc(r) => &r.matcher,
+            Rule::GenericDoc(r) => &r.matcher,
+            Rule::LanguageDoc(r) => &r.matcher,
             Rule::JapaneseComment(r) => &r.matcher,
             Rule::EnglishComment(r) => &r.matcher,
             Rule::PhpUnitTest(r) => &r.matcher,
             Rule::KotestTest(r) => &r.matcher,
             Rule::RustTest(r) => &r.matcher,
+            Rule::JunitTest(r) => &r.matcher,
+            Rule::SpockTest(r) => &r.matcher,
             Rule::PhpUnitTestExistence(r) => &r.matcher,
             Rule::KotestTestExistence(r) => &r.matcher,
             Rule::RustTestExistence(r) => &r.matcher,
+            Rule::ConsistentQuotes(r) => &r.matcher,
+            Rule::NoSkippedTests(r) => &r.matcher,
+            Rule::MaxLineLength(r) => &r.matcher,
+            Rule::NoTrailingWhitespace(r) => &r.matcher,
+            Rule::RequireTrailingNewline(r) => &r.matcher,
+            Rule::ForbiddenFileNames(r) => &r.matcher,
+            Rule::MaxFileLines(r) => &r.matcher,
+            Rule::GoTestExistence(r) => &r.matcher,
+            Rule::ForbiddenImports(r) => &r.matcher,
+            Rule::RequiredTexts(r) => &r.matcher,
+            Rule::PythonTest(r) => &r.matcher,
+            Rule::JestTest(r) => &r.matcher,
+            Rule::Preset(r) => &r.matcher,
+            Rule::NoStaleTodo(r) => &r.matcher,
         }
     }
 
@@ -80,23 +340,75 @@ impl Rule {
             Rule::PhpDoc(_) => None,
             Rule::KotlinDoc(_) => None,
             Rule::RustDoc(_) => None,
+            Rule::PythonDoc(_) => None,
+            Rule::TypeScriptDoc(_) => None,
+            Rule::GoDoc(_) => None,
+            Rule::SwiftDoc(_) => None,
+            Rule::CSharpDoc(_) => None,
+            Rule::GenericDoc(_) => None,
+            Rule::LanguageDoc(_) => None,
             Rule::JapaneseComment(_) => None,
             Rule::EnglishComment(_) => None,
             Rule::PhpUnitTest(_) => None,
             Rule::KotestTest(_) => None,
             Rule::RustTest(_) => None,
+            Rule::JunitTest(_) => None,
+            Rule::SpockTest(_) => None,
             Rule::PhpUnitTestExistence(_) => None,
             Rule::KotestTestExistence(_) => None,
             Rule::RustTestExistence(_) => None,
+            Rule::ConsistentQuotes(_) => None,
+            Rule::NoSkippedTests(_) => None,
+            Rule::MaxLineLength(_) => None,
+            Rule::NoTrailingWhitespace(_) => None,
+            Rule::RequireTrailingNewline(_) => None,
+            Rule::ForbiddenFileNames(_) => None,
+            Rule::MaxFileLines(_) => None,
+            Rule::GoTestExistence(_) => None,
+            Rule::ForbiddenImports(r) => Some(&r.config.imports),
+            Rule::RequiredTexts(r) => Some(&r.config.keywords),
+            Rule::PythonTest(_) => None,
+            Rule::JestTest(_) => None,
+            Rule::Preset(r) => Some(&r.keywords),
+            Rule::NoStaleTodo(r) => Some(&r.config.markers),
         }
     }
+
+    /// Returns true if the rule looks only at the file path, not its content
+    pub fn is_path_only(&self) -> bool {
+        matches!(self, Rule::ForbiddenFileNames(_))
+    }
+
+    /// Returns the rule as `Fixable` if its violations can be corrected automatically (validate --fix)
+    pub fn as_fixable(&self) -> Option<&dyn Fixable> {
+        match self {
+            Rule::NoTrailingWhitespace(r) => Some(r),
+            Rule::RequireTrailingNewline(r) => Some(r),
+            _ => None,
+        }
+    }
+}
+
+/// A rule whose violations can be corrected automatically
+pub trait Fixable {
+    /// Returns `content` with the violations corrected, leaving lines for which `is_disabled` is true as is
+    /// Line endings (LF / CRLF) are preserved
+    fn fix(&self, content: &str, is_disabled: &dyn Fn(usize) -> bool) -> String;
 }
 
 #[derive(Clone, Debug)]
 pub struct TextRule {
     pub label: String,
     pub keywords: Vec<String>,
+    pub allowlist: AllowList,
+    /// Match only keywords bounded by non-identifier characters
+    pub word_boundary: bool,
+    /// Where in a line keywords match
+    pub anchor: Anchor,
+    /// Lines checked by the rule (the whole file when None)
+    pub line_range: Option<LineRange>,
     pub message: String,
+    pub severity: Severity,
     pub matcher: Matcher,
 }
 
@@ -105,7 +417,14 @@ pub struct RegexRule {
     pub label: String,
     pub patterns: Vec<Regex>,
     pub keywords: Vec<String>,
+    /// Includes the `allow` regexes as partial entries
+    pub allowlist: AllowList,
+    /// Capture group reported instead of the whole match (0 reports the whole match)
+    pub report_group: usize,
+    /// Lines checked by the rule (the whole file when None)
+    pub line_range: Option<LineRange>,
     pub message: String,
+    pub severity: Severity,
     pub matcher: Matcher,
 }
 
@@ -113,7 +432,12 @@ pub struct RegexRule {
 pub struct CustomRule {
     pub label: String,
     pub exec: String,
+    /// Pipe the file content to the command's stdin
+    pub stdin: bool,
+    /// Reuse results stored in the cache directory for unchanged content
+    pub cache: bool,
     pub message: String,
+    pub severity: Severity,
     pub matcher: Matcher,
 }
 
@@ -122,6 +446,7 @@ pub struct PhpDocRule {
     pub label: String,
     pub config: PhpDocConfig,
     pub message: String,
+    pub severity: Severity,
     pub matcher: Matcher,
 }
 
@@ -130,6 +455,7 @@ pub struct KotlinDocRule {
     pub label: String,
     pub config: KotlinDocConfig,
     pub message: String,
+    pub severity: Severity,
     pub matcher: Matcher,
 }
 
@@ -138,6 +464,25 @@ pub struct RustDocRule {
     pub label: String,
     pub config: RustDocConfig,
     pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct PythonDocRule {
+    pub label: String,
+    pub config: PythonDocConfig,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct TypeScriptDocRule {
+    pub label: String,
+    pub config: TypeScriptDocConfig,
+    pub message: String,
+    pub severity: Severity,
     pub matcher: Matcher,
 }
 
@@ -146,13 +491,34 @@ pub struct RustDocRule {
 pub enum CommentSource {
     Lang(CommentLang),
     Custom(CustomCommentSyntax),
+    /// Custom syntax per file name suffix, longest suffix first
+    CustomByExt(Vec<(String, CustomCommentSyntax)>),
+}
+
+impl CommentSource {
+    /// Custom syntax for the file, if this source is per file type
+    /// Returns None for `Lang` and `Custom`, and for files matching no suffix
+    pub fn syntax_for(&self, file: &Path) -> Option<&CustomCommentSyntax> {
+        let CommentSource::CustomByExt(syntaxes) = self else {
+            return None;
+        };
+        let name = file.file_name()?.to_str()?;
+        syntaxes.iter().find(|(ext, _)| name.ends_with(ext.as_str())).map(|(_, syntax)| syntax)
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct CommentRule {
     pub label: String,
     pub source: CommentSource,
+    /// Scripts forbidden by require_english_comment (unused by require_japanese_comment)
+    pub scripts: Vec<Script>,
+    /// Fraction of script characters a comment may contain before it violates (require_english_comment only)
+    pub max_ratio: Option<f64>,
+    /// Ignore comments inside fenced code blocks of doc comments
+    pub skip_code_blocks: bool,
     pub message: String,
+    pub severity: Severity,
     pub matcher: Matcher,
 }
 
@@ -160,6 +526,7 @@ pub struct CommentRule {
 pub struct TestRule {
     pub label: String,
     pub message: String,
+    pub severity: Severity,
     pub matcher: Matcher,
 }
 
@@ -168,13 +535,186 @@ pub struct TestExistenceRule<C> {
     pub label: String,
     pub config: C,
     pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct QuoteRule {
+    pub label: String,
+    pub config: QuoteConfig,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct SkippedTestRule {
+    pub label: String,
+    pub lang: Lang,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct LineLengthRule {
+    pub label: String,
+    pub config: LineLengthConfig,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct TrailingWhitespaceRule {
+    pub label: String,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+impl Fixable for TrailingWhitespaceRule {
+    fn fix(&self, content: &str, is_disabled: &dyn Fn(usize) -> bool) -> String {
+        trailing_whitespace::fix(content, is_disabled)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GoDocRule {
+    pub label: String,
+    pub config: GoDocConfig,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct GenericDocRule {
+    pub label: String,
+    pub config: GenericDocConfig,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+/// require_doc with `languages`: a per-language doc rule for each file extension
+#[derive(Clone, Debug)]
+pub struct LanguageDocRule {
+    pub label: String,
+    /// File extension and the doc rule applied to files with it
+    pub rules: Vec<(String, Rule)>,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+impl LanguageDocRule {
+    /// The doc rule for the extension of `file`
+    pub fn rule_for(&self, file: &Path) -> Option<&Rule> {
+        let ext = file.extension()?.to_str()?;
+        self.rules.iter().find(|(e, _)| e == ext).map(|(_, rule)| rule)
+    }
+}
+
+/// Doc rule type used by require_doc `languages` for each file extension
+const LANGUAGE_DOC_TYPES: &[(&str, &str)] = &[
+    ("php", "require_php_doc"),
+    ("kt", "require_kotlin_doc"),
+    ("kts", "require_kotlin_doc"),
+    ("rs", "require_rust_doc"),
+    ("py", "require_python_doc"),
+    ("ts", "require_typescript_doc"),
+    ("tsx", "require_typescript_doc"),
+    ("go", "require_go_doc"),
+    ("swift", "require_swift_doc"),
+    ("cs", "require_csharp_doc"),
+];
+
+#[derive(Clone, Debug)]
+pub struct TrailingNewlineRule {
+    pub label: String,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+impl Fixable for TrailingNewlineRule {
+    fn fix(&self, content: &str, is_disabled: &dyn Fn(usize) -> bool) -> String {
+        final_newline::fix(content, is_disabled)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FileNameRule {
+    pub label: String,
+    pub patterns: Vec<String>,
+    /// `patterns` compiled as globs matched against the file name
+    pub globs: Vec<GlobMatcher>,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct FileLinesRule {
+    pub label: String,
+    pub config: FileLinesConfig,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct SwiftDocRule {
+    pub label: String,
+    pub config: SwiftDocConfig,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct CSharpDocRule {
+    pub label: String,
+    pub config: CSharpDocConfig,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct ImportsRule {
+    pub label: String,
+    pub config: ImportsConfig,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct RequiredTextsRule {
+    pub label: String,
+    pub config: RequiredTextConfig,
+    /// Lines checked by the rule (the whole file when None)
+    pub line_range: Option<LineRange>,
+    pub message: String,
+    pub severity: Severity,
+    pub matcher: Matcher,
+}
+
+#[derive(Clone, Debug)]
+pub struct TodoRule {
+    pub label: String,
+    pub config: TodoConfig,
+    pub message: String,
+    pub severity: Severity,
     pub matcher: Matcher,
 }
 
 #[derive(Clone, Debug)]
 pub struct GuidelineItem {
     pub message: String,
-    #[allow(dead_code)]
     pub matcher: Matcher,
 }
 
@@ -188,15 +728,36 @@ impl TryFrom<RawConfig> for Config {
     type Error = anyhow::Error;
 
     fn try_from(raw: RawConfig) -> Result<Self> {
-        let rule = raw.rule.unwrap_or_default().into_iter().map(convert_rule).collect::<Result<Vec<_>>>()?;
+        Config::from_raw(raw, &RootConfig::default())
+    }
+}
+
+impl Config {
+    /// Convert with the defaults of the root config (e.g. `default_match`) applied to every rule
+    pub fn from_raw(raw: RawConfig, root_config: &RootConfig) -> Result<Self> {
+        let rule = raw
+            .rule
+            .unwrap_or_default()
+            .into_iter()
+            .map(|item| convert_rule(item, root_config))
+            .collect::<Result<Vec<_>>>()?;
 
-        let guideline = raw.guideline.unwrap_or_default().into_iter().map(convert_guideline).collect::<Vec<_>>();
+        let guideline =
+            raw.guideline.unwrap_or_default().into_iter().map(convert_guideline).collect::<Result<Vec<_>>>()?;
 
         Ok(Config { rule, guideline })
     }
 }
 
-fn convert_rule(item: RawRuleItem) -> Result<Rule> {
+/// Compile the `allow` regexes of forbidden_patterns style rules
+fn compile_allow(label: &str, allow: &[String]) -> Result<Vec<Regex>> {
+    allow
+        .iter()
+        .map(|k| Regex::new(k).map_err(|e| anyhow!("Rule '{}': invalid allow regex '{}': {}", label, k, e)))
+        .collect()
+}
+
+fn convert_rule(item: RawRuleItem, root_config: &RootConfig) -> Result<Rule> {
     // Count how many rule types are specified (should be exactly 1)
     let specified: Vec<(&str, Option<RawRuleContent>)> = vec![
         ("forbidden_texts", item.forbidden_texts),
@@ -205,14 +766,36 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
         ("require_php_doc", item.require_php_doc),
         ("require_kotlin_doc", item.require_kotlin_doc),
         ("require_rust_doc", item.require_rust_doc),
+        ("require_python_doc", item.require_python_doc),
+        ("require_typescript_doc", item.require_typescript_doc),
+        ("require_go_doc", item.require_go_doc),
+        ("require_swift_doc", item.require_swift_doc),
+        ("require_csharp_doc", item.require_csharp_doc),
+        ("require_doc", item.require_doc),
         ("require_english_comment", item.require_english_comment),
         ("require_japanese_comment", item.require_japanese_comment),
         ("require_japanese_phpunit_test_name", item.require_japanese_phpunit_test_name),
         ("require_japanese_kotest_test_name", item.require_japanese_kotest_test_name),
         ("require_japanese_rust_test_name", item.require_japanese_rust_test_name),
+        ("require_japanese_junit_test_name", item.require_japanese_junit_test_name),
+        ("require_japanese_spock_test_name", item.require_japanese_spock_test_name),
         ("require_phpunit_test", item.require_phpunit_test),
         ("require_kotest_test", item.require_kotest_test),
         ("require_rust_unit_test", item.require_rust_unit_test),
+        ("consistent_quotes", item.consistent_quotes),
+        ("no_skipped_tests", item.no_skipped_tests),
+        ("max_line_length", item.max_line_length),
+        ("no_trailing_whitespace", item.no_trailing_whitespace),
+        ("require_trailing_newline", item.require_trailing_newline),
+        ("forbidden_file_names", item.forbidden_file_names),
+        ("max_file_lines", item.max_file_lines),
+        ("require_go_test", item.require_go_test),
+        ("forbidden_imports", item.forbidden_imports),
+        ("required_texts", item.required_texts),
+        ("require_japanese_python_test_name", item.require_japanese_python_test_name),
+        ("require_japanese_jest_test_name", item.require_japanese_jest_test_name),
+        ("preset", item.preset),
+        ("no_stale_todo", item.no_stale_todo),
     ];
 
     let found: Vec<_> = specified.into_iter().filter(|(_, v)| v.is_some()).collect();
@@ -226,8 +809,33 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
     }
 
     let (rule_type, content) = found.into_iter().next().unwrap();
-    let raw = content.unwrap();
-    let matcher = Matcher::new(raw.match_.clone());
+    convert_content(rule_type, content.unwrap(), root_config)
+}
+
+/// Rule types whose validators can be limited to a line window
+const LINE_RANGE_TYPES: &[&str] = &["forbidden_texts", "forbidden_patterns", "required_texts", "preset"];
+
+fn convert_line_range(rule_type: &str, raw: &RawRuleContent) -> Result<Option<LineRange>> {
+    let Some(range) = &raw.line_range else {
+        return Ok(None);
+    };
+    if !LINE_RANGE_TYPES.contains(&rule_type) {
+        return Err(anyhow!("Rule '{}': 'line_range' is supported only by {}", raw.label, LINE_RANGE_TYPES.join(", ")));
+    }
+    if range.start == 0 {
+        return Err(anyhow!("Rule '{}': line_range start must be 1 or greater", raw.label));
+    }
+    if let Some(end) = range.end.filter(|end| *end < range.start) {
+        return Err(anyhow!("Rule '{}': line_range end {} is before start {}", raw.label, end, range.start));
+    }
+    Ok(Some(LineRange { start: range.start, end: range.end }))
+}
+
+fn convert_content(rule_type: &str, raw: RawRuleContent, root_config: &RootConfig) -> Result<Rule> {
+    let matcher =
+        Matcher::new(root_config.rule_match(raw.match_.clone())).map_err(|e| anyhow!("Rule '{}': {}", raw.label, e))?;
+    let severity = raw.severity.unwrap_or_default();
+    let line_range = convert_line_range(rule_type, &raw)?;
 
     match rule_type {
         "forbidden_texts" => {
@@ -235,7 +843,17 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
             if raw.exec.is_some() {
                 return Err(anyhow!("Rule '{}': 'forbidden_texts' must not have 'exec'", raw.label));
             }
-            Ok(Rule::Text(TextRule { label: raw.label, keywords: texts, message: raw.message, matcher }))
+            Ok(Rule::Text(TextRule {
+                label: raw.label,
+                keywords: texts,
+                allowlist: AllowList::new(raw.allowlist),
+                word_boundary: raw.word_boundary.unwrap_or(false),
+                anchor: raw.anchor.unwrap_or_default(),
+                line_range,
+                message: raw.message,
+                severity,
+                matcher,
+            }))
         }
         "forbidden_patterns" => {
             let pattern_strs = raw
@@ -248,11 +866,26 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
                 .iter()
                 .map(|k| Regex::new(k).map_err(|e| anyhow!("Rule '{}': invalid regex '{}': {}", raw.label, k, e)))
                 .collect::<Result<Vec<_>>>()?;
+            let allow = compile_allow(&raw.label, &raw.allow)?;
+            let report_group = raw.report_group.unwrap_or(0);
+            if let Some(pattern) = patterns.iter().find(|p| p.captures_len() <= report_group) {
+                return Err(anyhow!(
+                    "Rule '{}': report_group {} exceeds the capture groups of pattern '{}' ({})",
+                    raw.label,
+                    report_group,
+                    pattern,
+                    pattern.captures_len() - 1
+                ));
+            }
             Ok(Rule::Regex(RegexRule {
                 label: raw.label,
                 patterns,
                 keywords: pattern_strs,
+                allowlist: AllowList::new(raw.allowlist).with_partial(allow),
+                report_group,
+                line_range,
                 message: raw.message,
+                severity,
                 matcher,
             }))
         }
@@ -261,7 +894,17 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
             if raw.texts.is_some() || raw.patterns.is_some() {
                 return Err(anyhow!("Rule '{}': 'custom' must not have 'texts' or 'patterns'", raw.label));
             }
-            Ok(Rule::Custom(CustomRule { label: raw.label, exec, message: raw.message, matcher }))
+            let stdin = raw.stdin.unwrap_or(false);
+            let cache = raw.cache.unwrap_or(false);
+            Ok(Rule::Custom(CustomRule {
+                label: raw.label,
+                exec,
+                stdin,
+                cache,
+                message: raw.message,
+                severity,
+                matcher,
+            }))
         }
         "require_php_doc" => {
             let raw_config = raw
@@ -284,8 +927,10 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
                 trait_: raw_config.trait_.map(convert_visibility),
                 enum_: raw_config.enum_.map(convert_visibility),
                 function: raw_config.function.map(convert_visibility),
+                require_non_empty: raw.require_non_empty.unwrap_or(false),
+                top_level_only: raw.top_level_only.unwrap_or(false),
             };
-            Ok(Rule::PhpDoc(PhpDocRule { label: raw.label, config, message: raw.message, matcher }))
+            Ok(Rule::PhpDoc(PhpDocRule { label: raw.label, config, message: raw.message, severity, matcher }))
         }
         "require_kotlin_doc" => {
             let raw_config = raw
@@ -302,6 +947,7 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
                 && raw_config.annotation_class.is_none()
                 && raw_config.typealias.is_none()
                 && raw_config.function.is_none()
+                && raw_config.property.is_none()
             {
                 return Err(anyhow!("Rule '{}': 'option' config requires at least one element", raw.label));
             }
@@ -317,8 +963,12 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
                 annotation_class: raw_config.annotation_class.map(convert_visibility),
                 typealias: raw_config.typealias.map(convert_visibility),
                 function: raw_config.function.map(convert_visibility),
+                property: raw_config.property.map(convert_visibility),
+                include_companion: raw_config.include_companion.unwrap_or(false),
+                require_non_empty: raw.require_non_empty.unwrap_or(false),
+                top_level_only: raw.top_level_only.unwrap_or(false),
             };
-            Ok(Rule::KotlinDoc(KotlinDocRule { label: raw.label, config, message: raw.message, matcher }))
+            Ok(Rule::KotlinDoc(KotlinDocRule { label: raw.label, config, message: raw.message, severity, matcher }))
         }
         "require_rust_doc" => {
             let raw_config = raw
@@ -330,11 +980,16 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
                 && raw_config.type_alias.is_none()
                 && raw_config.union.is_none()
                 && raw_config.fn_.is_none()
+                && raw_config.impl_fn.is_none()
                 && raw_config.macro_rules.is_none()
                 && raw_config.mod_.is_none()
+                && raw_config.const_.is_none()
+                && raw_config.static_.is_none()
             {
                 return Err(anyhow!("Rule '{}': 'option' config requires at least one element", raw.label));
             }
+            // Methods fall back to the fn setting when impl_fn is not given
+            let impl_fn = raw_config.impl_fn.or_else(|| raw_config.fn_.clone());
             let config = RustDocConfig {
                 struct_: raw_config.struct_.map(convert_visibility),
                 enum_: raw_config.enum_.map(convert_visibility),
@@ -342,27 +997,102 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
                 type_alias: raw_config.type_alias.map(convert_visibility),
                 union: raw_config.union.map(convert_visibility),
                 fn_: raw_config.fn_.map(convert_visibility),
+                impl_fn: impl_fn.map(convert_visibility),
                 macro_rules: raw_config.macro_rules.map(convert_visibility),
                 mod_: raw_config.mod_.map(convert_visibility),
+                const_: raw_config.const_.map(convert_visibility),
+                static_: raw_config.static_.map(convert_visibility),
+                require_non_empty: raw.require_non_empty.unwrap_or(false),
+                top_level_only: raw.top_level_only.unwrap_or(false),
             };
-            Ok(Rule::RustDoc(RustDocRule { label: raw.label, config, message: raw.message, matcher }))
+            Ok(Rule::RustDoc(RustDocRule { label: raw.label, config, message: raw.message, severity, matcher }))
+        }
+        "require_python_doc" => {
+            let raw_config = raw
+                .option
+                .ok_or_else(|| anyhow!("Rule '{}': 'require_python_doc' requires 'option' config", raw.label))?;
+            if raw_config.class.is_none() && raw_config.function.is_none() && raw_config.module.is_none() {
+                return Err(anyhow!("Rule '{}': 'option' config requires at least one element", raw.label));
+            }
+            let config = PythonDocConfig {
+                class: raw_config.class.map(convert_visibility),
+                function: raw_config.function.map(convert_visibility),
+                module: raw_config.module.map(convert_visibility),
+                top_level_only: raw.top_level_only.unwrap_or(false),
+            };
+            Ok(Rule::PythonDoc(PythonDocRule { label: raw.label, config, message: raw.message, severity, matcher }))
+        }
+        "require_typescript_doc" => {
+            let raw_config = raw
+                .option
+                .ok_or_else(|| anyhow!("Rule '{}': 'require_typescript_doc' requires 'option' config", raw.label))?;
+            if raw_config.class.is_none()
+                && raw_config.interface.is_none()
+                && raw_config.enum_.is_none()
+                && raw_config.type_alias.is_none()
+                && raw_config.function.is_none()
+            {
+                return Err(anyhow!("Rule '{}': 'option' config requires at least one element", raw.label));
+            }
+            let config = TypeScriptDocConfig {
+                class: raw_config.class.map(convert_visibility),
+                interface: raw_config.interface.map(convert_visibility),
+                enum_: raw_config.enum_.map(convert_visibility),
+                type_alias: raw_config.type_alias.map(convert_visibility),
+                function: raw_config.function.map(convert_visibility),
+                top_level_only: raw.top_level_only.unwrap_or(false),
+            };
+            Ok(Rule::TypeScriptDoc(TypeScriptDocRule {
+                label: raw.label,
+                config,
+                message: raw.message,
+                severity,
+                matcher,
+            }))
         }
         "require_english_comment" => {
             let source = convert_comment_source(&raw)?;
-            Ok(Rule::JapaneseComment(CommentRule { label: raw.label, source, message: raw.message, matcher }))
+            let scripts = raw.scripts.unwrap_or_else(|| vec![Script::Japanese]);
+            if scripts.is_empty() {
+                return Err(anyhow!("Rule '{}': 'scripts' requires at least one script", raw.label));
+            }
+            if let Some(max_ratio) = raw.max_ratio {
+                if !(0.0..=1.0).contains(&max_ratio) {
+                    return Err(anyhow!("Rule '{}': 'max_ratio' must be between 0 and 1", raw.label));
+                }
+            }
+            Ok(Rule::JapaneseComment(CommentRule {
+                label: raw.label,
+                source,
+                scripts,
+                max_ratio: raw.max_ratio,
+                skip_code_blocks: raw.skip_code_blocks.unwrap_or(false),
+                message: raw.message,
+                severity,
+                matcher,
+            }))
         }
         "require_japanese_comment" => {
             let source = convert_comment_source(&raw)?;
-            Ok(Rule::EnglishComment(CommentRule { label: raw.label, source, message: raw.message, matcher }))
+            Ok(Rule::EnglishComment(CommentRule {
+                label: raw.label,
+                source,
+                scripts: Vec::new(),
+                max_ratio: None,
+                skip_code_blocks: raw.skip_code_blocks.unwrap_or(false),
+                message: raw.message,
+                severity,
+                matcher,
+            }))
         }
         "require_japanese_phpunit_test_name" => {
-            Ok(Rule::PhpUnitTest(TestRule { label: raw.label, message: raw.message, matcher }))
+            Ok(Rule::PhpUnitTest(TestRule { label: raw.label, message: raw.message, severity, matcher }))
         }
         "require_japanese_kotest_test_name" => {
-            Ok(Rule::KotestTest(TestRule { label: raw.label, message: raw.message, matcher }))
+            Ok(Rule::KotestTest(TestRule { label: raw.label, message: raw.message, severity, matcher }))
         }
         "require_japanese_rust_test_name" => {
-            Ok(Rule::RustTest(TestRule { label: raw.label, message: raw.message, matcher }))
+            Ok(Rule::RustTest(TestRule { label: raw.label, message: raw.message, severity, matcher }))
         }
         "require_phpunit_test" => {
             let raw_config = raw.option.unwrap_or_default();
@@ -375,6 +1105,7 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
                 label: raw.label,
                 config,
                 message: raw.message,
+                severity,
                 matcher,
             }))
         }
@@ -385,12 +1116,336 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
                 require: raw_config.require.unwrap_or(TestRequireLevel::Exists),
                 test_file_suffix: raw_config.test_file_suffix.unwrap_or_else(|| "Test".to_string()),
             };
-            Ok(Rule::KotestTestExistence(TestExistenceRule { label: raw.label, config, message: raw.message, matcher }))
+            Ok(Rule::KotestTestExistence(TestExistenceRule {
+                label: raw.label,
+                config,
+                message: raw.message,
+                severity,
+                matcher,
+            }))
         }
         "require_rust_unit_test" => {
+            let raw_config = raw.option.unwrap_or_default();
+            let config = RustUnitTestConfig {
+                require: raw_config.require.unwrap_or(TestRequireLevel::Exists),
+                style: raw_config.style.unwrap_or_default(),
+            };
+            Ok(Rule::RustTestExistence(TestExistenceRule {
+                label: raw.label,
+                config,
+                message: raw.message,
+                severity,
+                matcher,
+            }))
+        }
+        "consistent_quotes" => {
+            let raw_config = raw
+                .option
+                .ok_or_else(|| anyhow!("Rule '{}': 'consistent_quotes' requires 'option' config", raw.label))?;
+            let lang =
+                raw_config.lang.ok_or_else(|| anyhow!("Rule '{}': 'consistent_quotes' requires 'lang'", raw.label))?;
+            if !matches!(lang, Lang::Javascript | Lang::Typescript | Lang::Python) {
+                return Err(anyhow!("Rule '{}': 'consistent_quotes' does not support lang '{:?}'", raw.label, lang));
+            }
+            let quote = raw_config
+                .quote
+                .ok_or_else(|| anyhow!("Rule '{}': 'consistent_quotes' requires 'quote'", raw.label))?;
+            let config = QuoteConfig { lang, quote };
+            Ok(Rule::ConsistentQuotes(QuoteRule { label: raw.label, config, message: raw.message, severity, matcher }))
+        }
+        "no_skipped_tests" => {
+            let lang = raw
+                .option
+                .and_then(|o| o.lang)
+                .ok_or_else(|| anyhow!("Rule '{}': 'no_skipped_tests' requires 'option.lang'", raw.label))?;
+            if lang == Lang::Python {
+                return Err(anyhow!("Rule '{}': 'no_skipped_tests' does not support lang '{:?}'", raw.label, lang));
+            }
+            Ok(Rule::NoSkippedTests(SkippedTestRule {
+                label: raw.label,
+                lang,
+                message: raw.message,
+                severity,
+                matcher,
+            }))
+        }
+
+        "max_line_length" => {
+            let raw_config = raw
+                .option
+                .ok_or_else(|| anyhow!("Rule '{}': 'max_line_length' requires 'option' config", raw.label))?;
+            let limit =
+                raw_config.limit.ok_or_else(|| anyhow!("Rule '{}': 'max_line_length' requires 'limit'", raw.label))?;
+            let tab_width = raw_config.tab_width.unwrap_or(4);
+            if tab_width == 0 {
+                return Err(anyhow!("Rule '{}': 'tab_width' must be greater than 0", raw.label));
+            }
+            let config = LineLengthConfig { limit, tab_width };
+            Ok(Rule::MaxLineLength(LineLengthRule {
+                label: raw.label,
+                config,
+                message: raw.message,
+                severity,
+                matcher,
+            }))
+        }
+
+        "require_japanese_junit_test_name" => {
+            Ok(Rule::JunitTest(TestRule { label: raw.label, message: raw.message, severity, matcher }))
+        }
+
+        "require_japanese_spock_test_name" => {
+            Ok(Rule::SpockTest(TestRule { label: raw.label, message: raw.message, severity, matcher }))
+        }
+
+        "no_trailing_whitespace" => Ok(Rule::NoTrailingWhitespace(TrailingWhitespaceRule {
+            label: raw.label,
+            message: raw.message,
+            severity,
+            matcher,
+        })),
+
+        "require_go_doc" => {
+            let raw_config =
+                raw.option.ok_or_else(|| anyhow!("Rule '{}': 'require_go_doc' requires 'option' config", raw.label))?;
+            if raw_config.struct_.is_none()
+                && raw_config.interface.is_none()
+                && raw_config.func.is_none()
+                && raw_config.const_.is_none()
+            {
+                return Err(anyhow!("Rule '{}': 'option' config requires at least one element", raw.label));
+            }
+            let config = GoDocConfig {
+                struct_: raw_config.struct_.map(convert_visibility),
+                interface: raw_config.interface.map(convert_visibility),
+                func: raw_config.func.map(convert_visibility),
+                const_: raw_config.const_.map(convert_visibility),
+                top_level_only: raw.top_level_only.unwrap_or(false),
+            };
+            Ok(Rule::GoDoc(GoDocRule { label: raw.label, config, message: raw.message, severity, matcher }))
+        }
+
+        "require_doc" if !raw.languages.is_empty() => {
+            if raw.format.is_some() || !raw.declarations.is_empty() {
+                return Err(anyhow!(
+                    "Rule '{}': 'require_doc' with 'languages' must not have 'format' or 'declarations'",
+                    raw.label
+                ));
+            }
+            let rules = raw
+                .languages
+                .iter()
+                .map(|(ext, option)| {
+                    let (_, doc_type) = LANGUAGE_DOC_TYPES.iter().find(|(e, _)| e == ext).ok_or_else(|| {
+                        let supported: Vec<_> = LANGUAGE_DOC_TYPES.iter().map(|(e, _)| *e).collect();
+                        anyhow!(
+                            "Rule '{}': unsupported extension '{}' in 'languages' (supported: {})",
+                            raw.label,
+                            ext,
+                            supported.join(", ")
+                        )
+                    })?;
+                    let content =
+                        RawRuleContent { languages: BTreeMap::new(), option: Some(option.clone()), ..raw.clone() };
+                    Ok((ext.clone(), convert_content(doc_type, content, root_config)?))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Rule::LanguageDoc(LanguageDocRule { label: raw.label, rules, message: raw.message, severity, matcher }))
+        }
+        "require_doc" => {
+            let syntax = match convert_comment_source(&raw)? {
+                CommentSource::Custom(syntax) => syntax,
+                _ => return Err(anyhow!("Rule '{}': 'require_doc' supports only 'custom' format", raw.label)),
+            };
+            if raw.declarations.is_empty() {
+                return Err(anyhow!("Rule '{}': 'require_doc' requires 'declarations'", raw.label));
+            }
+            let declarations = raw
+                .declarations
+                .iter()
+                .map(|k| Regex::new(k).map_err(|e| anyhow!("Rule '{}': invalid regex '{}': {}", raw.label, k, e)))
+                .collect::<Result<Vec<_>>>()?;
+            let config = GenericDocConfig { syntax, declarations };
+            Ok(Rule::GenericDoc(GenericDocRule { label: raw.label, config, message: raw.message, severity, matcher }))
+        }
+
+        "require_trailing_newline" => Ok(Rule::RequireTrailingNewline(TrailingNewlineRule {
+            label: raw.label,
+            message: raw.message,
+            severity,
+            matcher,
+        })),
+
+        "forbidden_file_names" => {
+            let patterns = raw.patterns.unwrap_or_default();
+            if patterns.is_empty() {
+                return Err(anyhow!("Rule '{}': 'forbidden_file_names' requires 'patterns'", raw.label));
+            }
+            let globs = patterns
+                .iter()
+                .map(|p| {
+                    Glob::new(p)
+                        .map(|glob| glob.compile_matcher())
+                        .map_err(|e| anyhow!("Rule '{}': invalid glob '{}': {}", raw.label, p, e.kind()))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Rule::ForbiddenFileNames(FileNameRule {
+                label: raw.label,
+                patterns,
+                globs,
+                message: raw.message,
+                severity,
+                matcher,
+            }))
+        }
+
+        "max_file_lines" => {
+            let raw_config =
+                raw.option.ok_or_else(|| anyhow!("Rule '{}': 'max_file_lines' requires 'option' config", raw.label))?;
+            let limit =
+                raw_config.limit.ok_or_else(|| anyhow!("Rule '{}': 'max_file_lines' requires 'limit'", raw.label))?;
+            Ok(Rule::MaxFileLines(FileLinesRule {
+                label: raw.label,
+                config: FileLinesConfig { limit },
+                message: raw.message,
+                severity,
+                matcher,
+            }))
+        }
+
+        "require_go_test" => {
             let raw_config = raw.option.unwrap_or_default();
             let config = SameFileTestConfig { require: raw_config.require.unwrap_or(TestRequireLevel::Exists) };
-            Ok(Rule::RustTestExistence(TestExistenceRule { label: raw.label, config, message: raw.message, matcher }))
+            Ok(Rule::GoTestExistence(TestExistenceRule {
+                label: raw.label,
+                config,
+                message: raw.message,
+                severity,
+                matcher,
+            }))
+        }
+
+        "require_swift_doc" => {
+            let raw_config = raw
+                .option
+                .ok_or_else(|| anyhow!("Rule '{}': 'require_swift_doc' requires 'option' config", raw.label))?;
+            if raw_config.class.is_none()
+                && raw_config.struct_.is_none()
+                && raw_config.enum_.is_none()
+                && raw_config.protocol.is_none()
+                && raw_config.func.is_none()
+            {
+                return Err(anyhow!("Rule '{}': 'option' config requires at least one element", raw.label));
+            }
+            let config = SwiftDocConfig {
+                class: raw_config.class.map(convert_visibility),
+                struct_: raw_config.struct_.map(convert_visibility),
+                enum_: raw_config.enum_.map(convert_visibility),
+                protocol: raw_config.protocol.map(convert_visibility),
+                func: raw_config.func.map(convert_visibility),
+                require_non_empty: raw.require_non_empty.unwrap_or(false),
+                top_level_only: raw.top_level_only.unwrap_or(false),
+            };
+            Ok(Rule::SwiftDoc(SwiftDocRule { label: raw.label, config, message: raw.message, severity, matcher }))
+        }
+
+        "require_csharp_doc" => {
+            let raw_config = raw
+                .option
+                .ok_or_else(|| anyhow!("Rule '{}': 'require_csharp_doc' requires 'option' config", raw.label))?;
+            if raw_config.class.is_none()
+                && raw_config.interface.is_none()
+                && raw_config.struct_.is_none()
+                && raw_config.enum_.is_none()
+                && raw_config.method.is_none()
+            {
+                return Err(anyhow!("Rule '{}': 'option' config requires at least one element", raw.label));
+            }
+            let config = CSharpDocConfig {
+                class: raw_config.class.map(convert_visibility),
+                interface: raw_config.interface.map(convert_visibility),
+                struct_: raw_config.struct_.map(convert_visibility),
+                enum_: raw_config.enum_.map(convert_visibility),
+                method: raw_config.method.map(convert_visibility),
+                require_non_empty: raw.require_non_empty.unwrap_or(false),
+                top_level_only: raw.top_level_only.unwrap_or(false),
+            };
+            Ok(Rule::CSharpDoc(CSharpDocRule { label: raw.label, config, message: raw.message, severity, matcher }))
+        }
+
+        "forbidden_imports" => {
+            let imports =
+                raw.imports.ok_or_else(|| anyhow!("Rule '{}': 'forbidden_imports' requires 'imports'", raw.label))?;
+            if imports.is_empty() {
+                return Err(anyhow!("Rule '{}': 'imports' must not be empty", raw.label));
+            }
+            let config = ImportsConfig { imports };
+            Ok(Rule::ForbiddenImports(ImportsRule {
+                label: raw.label,
+                config,
+                message: raw.message,
+                severity,
+                matcher,
+            }))
+        }
+
+        "required_texts" => {
+            let keywords =
+                raw.keywords.ok_or_else(|| anyhow!("Rule '{}': 'required_texts' requires 'keywords'", raw.label))?;
+            if keywords.is_empty() {
+                return Err(anyhow!("Rule '{}': 'keywords' must not be empty", raw.label));
+            }
+            let config = RequiredTextConfig { keywords, cond: raw.cond.unwrap_or_default() };
+            Ok(Rule::RequiredTexts(RequiredTextsRule {
+                label: raw.label,
+                config,
+                line_range,
+                message: raw.message,
+                severity,
+                matcher,
+            }))
+        }
+
+        "require_japanese_python_test_name" => {
+            Ok(Rule::PythonTest(TestRule { label: raw.label, message: raw.message, severity, matcher }))
+        }
+
+        "require_japanese_jest_test_name" => {
+            Ok(Rule::JestTest(TestRule { label: raw.label, message: raw.message, severity, matcher }))
+        }
+
+        "preset" => {
+            let name = raw.name.ok_or_else(|| anyhow!("Rule '{}': 'preset' requires 'name'", raw.label))?;
+            let preset = PRESET_INFOS.iter().find(|preset| preset.name == name).ok_or_else(|| {
+                let names: Vec<&str> = PRESET_INFOS.iter().map(|preset| preset.name).collect();
+                anyhow!("Rule '{}': unknown preset '{}' (valid presets: {})", raw.label, name, names.join(", "))
+            })?;
+            if raw.texts.is_some() || raw.patterns.is_some() || raw.exec.is_some() {
+                return Err(anyhow!("Rule '{}': 'preset' must not have 'texts', 'patterns' or 'exec'", raw.label));
+            }
+            let allow = compile_allow(&raw.label, &raw.allow)?;
+            let message = if raw.message.is_empty() { preset.message.to_string() } else { raw.message };
+            Ok(Rule::Preset(RegexRule {
+                label: raw.label,
+                // Preset patterns are tested to compile
+                patterns: preset.patterns.iter().map(|p| Regex::new(p).unwrap()).collect(),
+                keywords: preset.patterns.iter().map(|p| p.to_string()).collect(),
+                allowlist: AllowList::new(raw.allowlist).with_partial(allow),
+                report_group: 0,
+                line_range,
+                message,
+                severity,
+                matcher,
+            }))
+        }
+
+        "no_stale_todo" => {
+            let markers = raw.markers.unwrap_or_else(|| vec!["TODO".to_string()]);
+            if markers.is_empty() || markers.iter().any(|marker| marker.is_empty()) {
+                return Err(anyhow!("Rule '{}': 'markers' must not be empty", raw.label));
+            }
+            let config = TodoConfig { markers, max_age_days: raw.max_age_days };
+            Ok(Rule::NoStaleTodo(TodoRule { label: raw.label, config, message: raw.message, severity, matcher }))
         }
         _ => unreachable!(),
     }
@@ -399,6 +1454,7 @@ fn convert_rule(item: RawRuleItem) -> Result<Rule> {
 fn convert_visibility(vis: parser::Visibility) -> Visibility {
     match vis {
         parser::Visibility::Public => Visibility::Public,
+        parser::Visibility::Exported => Visibility::Exported,
         parser::Visibility::All => Visibility::All,
     }
 }
@@ -406,20 +1462,30 @@ fn convert_visibility(vis: parser::Visibility) -> Visibility {
 fn convert_comment_source(raw: &RawRuleContent) -> Result<CommentSource> {
     let config = raw.format.as_ref().ok_or_else(|| anyhow!("Rule '{}': format config is required", raw.label))?;
 
-    // lang and custom are mutually exclusive
-    match (&config.lang, &config.custom) {
-        (Some(lang), None) => Ok(CommentSource::Lang(lang.clone())),
-        (None, Some(custom)) => {
-            let blocks =
-                custom.blocks.iter().map(|b| BlockSyntax { start: b.start.clone(), end: b.end.clone() }).collect();
-            let syntax = CustomCommentSyntax { lines: custom.lines.clone(), blocks };
-            Ok(CommentSource::Custom(syntax))
-        }
-        (Some(_), Some(_)) => Err(anyhow!("Rule '{}': cannot specify both 'lang' and 'custom'", raw.label)),
-        (None, None) => Err(anyhow!("Rule '{}': either 'lang' or 'custom' is required", raw.label)),
+    // lang, custom and custom_by_ext are mutually exclusive
+    match (&config.lang, &config.custom, &config.custom_by_ext) {
+        (Some(lang), None, None) => Ok(CommentSource::Lang(lang.clone())),
+        (None, Some(custom), None) => Ok(CommentSource::Custom(convert_custom_comment(custom))),
+        (None, None, Some(by_ext)) => {
+            let mut syntaxes: Vec<(String, CustomCommentSyntax)> =
+                by_ext.iter().map(|(ext, custom)| (ext.clone(), convert_custom_comment(custom))).collect();
+            // Prefer the most specific suffix (e.g. ".d.ts" over ".ts")
+            syntaxes.sort_by_key(|(ext, _)| std::cmp::Reverse(ext.len()));
+            Ok(CommentSource::CustomByExt(syntaxes))
+        }
+        (None, None, None) => {
+            Err(anyhow!("Rule '{}': one of 'lang', 'custom' or 'custom_by_ext' is required", raw.label))
+        }
+        _ => Err(anyhow!("Rule '{}': specify only one of 'lang', 'custom' or 'custom_by_ext'", raw.label)),
     }
 }
 
-fn convert_guideline(raw: RawGuidelineItem) -> GuidelineItem {
-    GuidelineItem { message: raw.message, matcher: Matcher::new(raw.match_.clone()) }
+fn convert_custom_comment(custom: &RawCustomComment) -> CustomCommentSyntax {
+    let blocks = custom.blocks.iter().map(|b| BlockSyntax { start: b.start.clone(), end: b.end.clone() }).collect();
+    CustomCommentSyntax { lines: custom.lines.clone(), blocks }
+}
+
+fn convert_guideline(raw: RawGuidelineItem) -> Result<GuidelineItem> {
+    let matcher = Matcher::new(raw.match_.clone()).map_err(|e| anyhow!("Guideline '{}': {}", raw.message, e))?;
+    Ok(GuidelineItem { message: raw.message, matcher })
 }
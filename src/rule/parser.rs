@@ -1,11 +1,12 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
 use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Match pattern type
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum MatchPattern {
     /// Match if filename starts with keyword
@@ -20,10 +21,21 @@ pub enum MatchPattern {
     FileNotEndsWith,
     /// Match if path does NOT contain keyword
     PathNotContains,
+    /// Match if path matches keyword as a glob (e.g. `**/fixtures/*.json`)
+    PathGlob,
+    /// Match if path does NOT match keyword as a glob
+    PathNotGlob,
+    /// Match if path matches keyword as a regex
+    PathMatchesRegex,
+    /// Match if path does NOT match keyword as a regex
+    PathNotMatchesRegex,
+    /// Match if the file is keyword directories below the directory of the config defining the rule
+    /// (`0` matches only files directly in that directory)
+    RelativeDepth,
 }
 
 /// Match condition for keywords
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum MatchCond {
     #[default]
@@ -31,8 +43,33 @@ pub enum MatchCond {
     Or,
 }
 
+/// Where in a line a forbidden_texts keyword must appear
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Anchor {
+    #[default]
+    Anywhere,
+    /// At the start of the line, after leading whitespace
+    LineStart,
+    /// At the end of the line, before trailing whitespace
+    LineEnd,
+}
+
+/// Severity of a rule's violations
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// Fails the validate command
+    #[default]
+    Error,
+    /// Printed but does not fail
+    Warn,
+    /// Printed but does not fail
+    Info,
+}
+
 /// Single match item entry
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RawMatchItem {
     pub pattern: MatchPattern,
     pub keywords: Vec<String>,
@@ -49,6 +86,9 @@ pub struct RawMatchItem {
 #[serde(rename_all = "snake_case")]
 pub enum Visibility {
     Public,
+    /// Rust only: bare `pub`, excluding `pub(crate)`, `pub(super)` and `pub(in ...)`
+    /// Other languages treat it the same as `Public`
+    Exported,
     All,
 }
 
@@ -78,6 +118,8 @@ pub struct RawKotlinDocConfig {
     pub annotation_class: Option<Visibility>,
     pub typealias: Option<Visibility>,
     pub function: Option<Visibility>,
+    pub property: Option<Visibility>,
+    pub include_companion: Option<bool>,
 }
 
 /// Config for no_rust_doc validator
@@ -93,9 +135,68 @@ pub struct RawRustDocConfig {
     pub union: Option<Visibility>,
     #[serde(rename = "fn")]
     pub fn_: Option<Visibility>,
+    pub impl_fn: Option<Visibility>,
     pub macro_rules: Option<Visibility>,
     #[serde(rename = "mod")]
     pub mod_: Option<Visibility>,
+    #[serde(rename = "const")]
+    pub const_: Option<Visibility>,
+    #[serde(rename = "static")]
+    pub static_: Option<Visibility>,
+}
+
+/// Config for require_python_doc validator
+#[derive(Clone, Debug, Deserialize, Default)]
+pub struct RawPythonDocConfig {
+    pub class: Option<Visibility>,
+    pub function: Option<Visibility>,
+    pub module: Option<Visibility>,
+}
+
+/// Config for require_typescript_doc validator
+#[derive(Clone, Debug, Deserialize, Default)]
+pub struct RawTypeScriptDocConfig {
+    pub class: Option<Visibility>,
+    pub interface: Option<Visibility>,
+    #[serde(rename = "enum")]
+    pub enum_: Option<Visibility>,
+    pub type_alias: Option<Visibility>,
+    pub function: Option<Visibility>,
+}
+
+/// Config for require_go_doc validator
+#[derive(Clone, Debug, Deserialize, Default)]
+pub struct RawGoDocConfig {
+    #[serde(rename = "struct")]
+    pub struct_: Option<Visibility>,
+    pub interface: Option<Visibility>,
+    pub func: Option<Visibility>,
+    #[serde(rename = "const")]
+    pub const_: Option<Visibility>,
+}
+
+/// Config for require_swift_doc validator
+#[derive(Clone, Debug, Deserialize, Default)]
+pub struct RawSwiftDocConfig {
+    pub class: Option<Visibility>,
+    #[serde(rename = "struct")]
+    pub struct_: Option<Visibility>,
+    #[serde(rename = "enum")]
+    pub enum_: Option<Visibility>,
+    pub protocol: Option<Visibility>,
+    pub func: Option<Visibility>,
+}
+
+/// Config for require_csharp_doc validator
+#[derive(Clone, Debug, Deserialize, Default)]
+pub struct RawCSharpDocConfig {
+    pub class: Option<Visibility>,
+    pub interface: Option<Visibility>,
+    #[serde(rename = "struct")]
+    pub struct_: Option<Visibility>,
+    #[serde(rename = "enum")]
+    pub enum_: Option<Visibility>,
+    pub method: Option<Visibility>,
 }
 
 // =============================================================================
@@ -132,10 +233,12 @@ pub struct RawCustomComment {
 pub struct RawCommentConfig {
     pub lang: Option<CommentLang>,
     pub custom: Option<RawCustomComment>,
+    /// Custom syntax per file name suffix (e.g. ".py", ".sql")
+    pub custom_by_ext: Option<BTreeMap<String, RawCustomComment>>,
 }
 
 // =============================================================================
-// Test existence validator config (require_phpunit_test, require_kotest_test, require_rust_unit_test)
+// Test existence validator config (require_phpunit_test, require_kotest_test, require_rust_unit_test, require_go_test)
 // =============================================================================
 
 /// Require level for test existence
@@ -148,8 +251,58 @@ pub enum TestRequireLevel {
     AllPublic,
 }
 
+/// Where Rust unit tests are written (require_rust_unit_test)
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RustTestStyle {
+    /// `#[cfg(test)] mod tests` in the same file
+    #[default]
+    InlineModule,
+    /// `foo_test.rs` next to `foo.rs`
+    SiblingFile,
+}
+
+// =============================================================================
+// Language option (consistent_quotes, no_skipped_tests)
+// =============================================================================
+
+/// Source language for language-aware validators
+/// Each rule accepts only the languages it supports
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Lang {
+    Java,
+    Kotlin,
+    Rust,
+    Javascript,
+    Typescript,
+    Python,
+    Php,
+}
+
+// =============================================================================
+// Quote validator config (consistent_quotes)
+// =============================================================================
+
+/// Quote style to enforce
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteStyle {
+    Single,
+    Double,
+}
+
+/// Script forbidden in comments by require_english_comment
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Script {
+    Japanese,
+    Chinese,
+    Korean,
+}
+
 /// Unified option config for doc/test validators
-/// Contains all possible fields from PhpDoc, KotlinDoc, RustDoc, and Test configs
+/// Contains all possible fields from PhpDoc, KotlinDoc, RustDoc, PythonDoc, TypeScriptDoc, GoDoc, SwiftDoc, CSharpDoc, and Test configs
 #[derive(Clone, Debug, Deserialize, Default)]
 #[serde(default)]
 pub struct RawOptionConfig {
@@ -170,6 +323,8 @@ pub struct RawOptionConfig {
     pub value_class: Option<Visibility>,
     pub annotation_class: Option<Visibility>,
     pub typealias: Option<Visibility>,
+    pub property: Option<Visibility>,
+    pub include_companion: Option<bool>,
     // RustDocConfig additional fields
     #[serde(rename = "struct")]
     pub struct_: Option<Visibility>,
@@ -177,23 +332,44 @@ pub struct RawOptionConfig {
     pub union: Option<Visibility>,
     #[serde(rename = "fn")]
     pub fn_: Option<Visibility>,
+    pub impl_fn: Option<Visibility>,
     pub macro_rules: Option<Visibility>,
     #[serde(rename = "mod")]
     pub mod_: Option<Visibility>,
+    #[serde(rename = "static")]
+    pub static_: Option<Visibility>,
+    // PythonDocConfig additional fields
+    pub module: Option<Visibility>,
+    // GoDocConfig additional fields (const is shared with RustDocConfig)
+    pub func: Option<Visibility>,
+    #[serde(rename = "const")]
+    pub const_: Option<Visibility>,
+    // SwiftDocConfig additional fields (class, struct, enum and func are shared)
+    pub protocol: Option<Visibility>,
+    // CSharpDocConfig additional fields (class, interface, struct and enum are shared)
+    pub method: Option<Visibility>,
     // Test config fields (PHPUnit/Kotest/Rust)
     pub test_directory: Option<String>,
     pub require: Option<TestRequireLevel>,
     pub test_file_suffix: Option<String>,
+    pub style: Option<RustTestStyle>,
+    // Language-aware validator fields (consistent_quotes, no_skipped_tests)
+    pub lang: Option<Lang>,
+    // Quote config fields
+    pub quote: Option<QuoteStyle>,
+    // Line length config fields (max_line_length)
+    pub limit: Option<usize>,
+    pub tab_width: Option<usize>,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize)]
 pub struct RawConfig {
     pub rule: Option<Vec<RawRuleItem>>,
     pub guideline: Option<Vec<RawGuidelineItem>>,
 }
 
 /// Rule item with rule name as key
-#[derive(Deserialize, Default)]
+#[derive(Debug, Deserialize, Default)]
 pub struct RawRuleItem {
     pub forbidden_texts: Option<RawRuleContent>,
     pub forbidden_patterns: Option<RawRuleContent>,
@@ -201,34 +377,91 @@ pub struct RawRuleItem {
     pub require_php_doc: Option<RawRuleContent>,
     pub require_kotlin_doc: Option<RawRuleContent>,
     pub require_rust_doc: Option<RawRuleContent>,
+    pub require_python_doc: Option<RawRuleContent>,
+    pub require_typescript_doc: Option<RawRuleContent>,
+    pub require_go_doc: Option<RawRuleContent>,
+    pub require_swift_doc: Option<RawRuleContent>,
+    pub require_csharp_doc: Option<RawRuleContent>,
+    pub require_doc: Option<RawRuleContent>,
     pub require_english_comment: Option<RawRuleContent>,
     pub require_japanese_comment: Option<RawRuleContent>,
     pub require_japanese_phpunit_test_name: Option<RawRuleContent>,
     pub require_japanese_kotest_test_name: Option<RawRuleContent>,
     pub require_japanese_rust_test_name: Option<RawRuleContent>,
+    pub require_japanese_junit_test_name: Option<RawRuleContent>,
+    pub require_japanese_spock_test_name: Option<RawRuleContent>,
     pub require_phpunit_test: Option<RawRuleContent>,
     pub require_kotest_test: Option<RawRuleContent>,
     pub require_rust_unit_test: Option<RawRuleContent>,
+    pub consistent_quotes: Option<RawRuleContent>,
+    pub no_skipped_tests: Option<RawRuleContent>,
+    pub max_line_length: Option<RawRuleContent>,
+    pub no_trailing_whitespace: Option<RawRuleContent>,
+    pub require_trailing_newline: Option<RawRuleContent>,
+    pub forbidden_file_names: Option<RawRuleContent>,
+    pub max_file_lines: Option<RawRuleContent>,
+    pub require_go_test: Option<RawRuleContent>,
+    pub forbidden_imports: Option<RawRuleContent>,
+    pub required_texts: Option<RawRuleContent>,
+    pub require_japanese_python_test_name: Option<RawRuleContent>,
+    pub require_japanese_jest_test_name: Option<RawRuleContent>,
+    pub preset: Option<RawRuleContent>,
+    pub no_stale_todo: Option<RawRuleContent>,
 }
 
 /// Rule content (common fields for all rule types)
-#[derive(Deserialize, Default, Clone)]
+#[derive(Debug, Deserialize, Default, Clone)]
 pub struct RawRuleContent {
     #[serde(default)]
     pub label: String,
     pub texts: Option<Vec<String>>,
     pub patterns: Option<Vec<String>>,
+    pub imports: Option<Vec<String>>,
+    pub keywords: Option<Vec<String>>,
+    pub markers: Option<Vec<String>>,
+    pub max_age_days: Option<u64>,
+    pub cond: Option<MatchCond>,
     pub exec: Option<String>,
+    pub stdin: Option<bool>,
+    pub cache: Option<bool>,
+    #[serde(default)]
+    pub allowlist: Vec<String>,
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub declarations: Vec<String>,
+    /// Per-language doc config keyed by file extension (require_doc)
+    #[serde(default)]
+    pub languages: BTreeMap<String, RawOptionConfig>,
+    pub word_boundary: Option<bool>,
+    pub anchor: Option<Anchor>,
+    pub report_group: Option<usize>,
+    /// Preset name of the `preset` rule type
+    pub name: Option<String>,
+    pub require_non_empty: Option<bool>,
+    pub top_level_only: Option<bool>,
+    pub scripts: Option<Vec<Script>>,
+    pub max_ratio: Option<f64>,
+    pub skip_code_blocks: Option<bool>,
+    pub line_range: Option<RawLineRange>,
     #[serde(default)]
     pub message: String,
     #[serde(default, rename = "match")]
     pub match_: Vec<RawMatchItem>,
+    pub severity: Option<Severity>,
     // Doc/Comment/Test validator configs (unified as "option" or "format")
     pub option: Option<RawOptionConfig>,
     pub format: Option<RawCommentConfig>,
 }
 
-#[derive(Deserialize)]
+/// Line window of forbidden_texts, forbidden_patterns, required_texts and preset
+#[derive(Debug, Deserialize, Clone)]
+pub struct RawLineRange {
+    pub start: usize,
+    pub end: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
 pub struct RawGuidelineItem {
     pub message: String,
     #[serde(default, rename = "match")]
@@ -236,9 +469,15 @@ pub struct RawGuidelineItem {
 }
 
 impl RawConfig {
+    /// Load a config file, picking the parser by extension (`.toml` is TOML, anything else is YAML)
     pub fn load(path: &Path) -> Result<Self> {
         let content =
             fs::read_to_string(path).with_context(|| format!("Failed to read config file: {}", path.display()))?;
+        if is_toml(path) {
+            let config: RawConfig =
+                toml::from_str(&content).with_context(|| format!("Failed to parse TOML: {}", path.display()))?;
+            return Ok(config);
+        }
         let config: RawConfig =
             serde_yaml::from_str(&content).with_context(|| format!("Failed to parse YAML: {}", path.display()))?;
         Ok(config)
@@ -248,4 +487,14 @@ impl RawConfig {
         let config: RawConfig = serde_yaml::from_str(content).with_context(|| "Failed to parse YAML")?;
         Ok(config)
     }
+
+    pub fn parse_toml(content: &str) -> Result<Self> {
+        let config: RawConfig = toml::from_str(content).with_context(|| "Failed to parse TOML")?;
+        Ok(config)
+    }
+}
+
+/// Whether a config file is TOML (`.rec_lint.toml` / `.rec_lint_config.toml`)
+pub fn is_toml(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "toml")
 }
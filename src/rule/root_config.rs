@@ -3,9 +3,16 @@ use std::ffi::OsString;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use serde::Deserialize;
 
+use crate::rule::parser::{is_toml, RawMatchItem};
+
+/// Ignore file at the root directory (gitignore syntax) excluding paths from all rules
+pub const IGNORE_FILENAME: &str = ".rec_lintignore";
+
 #[derive(Deserialize, Default)]
 pub struct RawRootConfig {
     #[serde(default)]
@@ -13,14 +20,39 @@ pub struct RawRootConfig {
     #[serde(default)]
     pub exclude_dirs: Vec<String>,
     #[serde(default)]
+    pub exclude_files: Vec<String>,
+    #[serde(default)]
     pub script_dir: Option<String>,
+    #[serde(default)]
+    pub default_match: Vec<RawMatchItem>,
+    #[serde(default)]
+    pub default_match_mode: DefaultMatchMode,
+}
+
+/// How `default_match` combines with a rule's own `match`
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DefaultMatchMode {
+    /// The rule's conditions are added to the defaults (all must match)
+    #[default]
+    Extend,
+    /// A rule with its own conditions ignores the defaults
+    Override,
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct RootConfig {
     pub include_extensions: HashSet<OsString>,
     pub exclude_dirs: HashSet<String>,
+    /// `exclude_files` compiled once, matched against the path relative to `root_dir`
+    pub exclude_files: GlobSet,
+    /// Patterns of `.rec_lintignore`, if the root directory has one
+    pub ignore: Option<Gitignore>,
+    pub root_dir: PathBuf,
     pub script_dir: Option<PathBuf>,
+    /// Match conditions applied to every rule
+    pub default_match: Vec<RawMatchItem>,
+    pub default_match_mode: DefaultMatchMode,
 }
 
 impl RawRootConfig {
@@ -31,12 +63,15 @@ impl RawRootConfig {
         if content.trim().is_empty() || content.trim().starts_with('#') && !content.contains(':') {
             return Ok(Self::default());
         }
+        if is_toml(path) {
+            return toml::from_str(&content).with_context(|| format!("Failed to parse TOML: {}", path.display()));
+        }
         serde_yaml::from_str(&content).with_context(|| format!("Failed to parse YAML: {}", path.display()))
     }
 }
 
 impl RootConfig {
-    pub fn from_raw(raw: RawRootConfig, base_dir: &Path) -> Self {
+    pub fn from_raw(raw: RawRootConfig, base_dir: &Path) -> Result<Self> {
         let script_dir = raw.script_dir.map(|dir| {
             let configured = PathBuf::from(dir);
             if configured.is_absolute() {
@@ -46,11 +81,43 @@ impl RootConfig {
             }
         });
 
-        RootConfig {
+        let mut exclude_files = GlobSetBuilder::new();
+        for pattern in &raw.exclude_files {
+            let glob =
+                Glob::new(pattern).map_err(|e| anyhow!("exclude_files: invalid glob '{}': {}", pattern, e.kind()))?;
+            exclude_files.add(glob);
+        }
+
+        let ignore_path = base_dir.join(IGNORE_FILENAME);
+        let ignore = if ignore_path.exists() {
+            let mut builder = GitignoreBuilder::new(base_dir);
+            if let Some(err) = builder.add(&ignore_path) {
+                return Err(anyhow!("{IGNORE_FILENAME}: {err}"));
+            }
+            Some(builder.build().map_err(|e| anyhow!("{IGNORE_FILENAME}: {e}"))?)
+        } else {
+            None
+        };
+
+        Ok(RootConfig {
             include_extensions: raw.include_extensions.into_iter().map(OsString::from).collect(),
             exclude_dirs: raw.exclude_dirs.into_iter().collect(),
+            exclude_files: exclude_files.build()?,
+            ignore,
+            root_dir: base_dir.to_path_buf(),
             script_dir,
+            default_match: raw.default_match,
+            default_match_mode: raw.default_match_mode,
+        })
+    }
+
+    /// Combine `default_match` with the match conditions of a rule
+    pub fn rule_match(&self, items: Vec<RawMatchItem>) -> Vec<RawMatchItem> {
+        if self.default_match.is_empty() || (self.default_match_mode == DefaultMatchMode::Override && !items.is_empty())
+        {
+            return items;
         }
+        self.default_match.iter().cloned().chain(items).collect()
     }
 
     /// Check if a file extension should be included
@@ -72,4 +139,28 @@ impl RootConfig {
         let name = dir_name.to_string_lossy();
         self.exclude_dirs.contains(name.as_ref())
     }
+
+    /// Check if a file matches one of the `exclude_files` globs (e.g. `**/*.generated.kt`)
+    pub fn should_exclude_file(&self, path: &Path) -> bool {
+        if self.exclude_files.is_empty() {
+            return false;
+        }
+        self.exclude_files.is_match(self.relative_to_root(path))
+    }
+
+    /// Check if a file or directory is ignored by `.rec_lintignore`
+    /// Paths outside of the root directory are never ignored
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        let Some(ignore) = &self.ignore else {
+            return false;
+        };
+        let relative = self.relative_to_root(path);
+        !relative.has_root() && ignore.matched_path_or_any_parents(&relative, is_dir).is_ignore()
+    }
+
+    /// Path relative to the root directory (the path as is if outside of it)
+    fn relative_to_root(&self, path: &Path) -> PathBuf {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        path.strip_prefix(&self.root_dir).map(Path::to_path_buf).unwrap_or(path)
+    }
 }
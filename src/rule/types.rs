@@ -0,0 +1,399 @@
+// Rule type metadata
+// Also included by tools/schema-tools, so this file must not depend on the rest of the crate
+
+/// Description and minimal example config of a rule type
+pub struct RuleTypeInfo {
+    pub type_name: &'static str,
+    pub description: &'static str,
+    /// English description (`--lang en`)
+    pub description_en: &'static str,
+    /// Rule item in .rec_lint.yaml (without the leading `- `)
+    pub example: &'static str,
+}
+
+pub const RULE_TYPE_INFOS: &[RuleTypeInfo] = &[
+    RuleTypeInfo {
+        type_name: "forbidden_texts",
+        description: "禁止キーワードを完全一致で検出",
+        description_en: "Detects forbidden keywords by exact match",
+        example: r#"forbidden_texts:
+  label: TODO の禁止
+  texts: [TODO]
+  message: TODO は issue にすること"#,
+    },
+    RuleTypeInfo {
+        type_name: "forbidden_patterns",
+        description: "禁止パターンを正規表現で検出",
+        description_en: "Detects forbidden patterns by regular expression",
+        example: r#"forbidden_patterns:
+  label: System.out の禁止
+  patterns: ["System\\.out\\.print"]
+  message: Logger を使うこと"#,
+    },
+    RuleTypeInfo {
+        type_name: "custom",
+        description: "任意のコマンドを実行して検証",
+        description_en: "Validates by running an arbitrary command",
+        example: r#"custom:
+  label: ファイル名の検証
+  exec: ./check.sh {path}
+  message: ファイル名を見直すこと"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_php_doc",
+        description: "PHPDoc がないファイルを検出",
+        description_en: "Detects files missing PHPDoc",
+        example: r#"require_php_doc:
+  label: PHPDoc 必須
+  option:
+    class: public
+  message: PHPDoc を記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_kotlin_doc",
+        description: "KDoc がないファイルを検出",
+        description_en: "Detects files missing KDoc",
+        example: r#"require_kotlin_doc:
+  label: KDoc 必須
+  option:
+    class: public
+  message: KDoc を記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_rust_doc",
+        description: "rustdoc がないファイルを検出",
+        description_en: "Detects files missing rustdoc",
+        example: r#"require_rust_doc:
+  label: rustdoc 必須
+  option:
+    fn: public
+  message: rustdoc を記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_python_doc",
+        description: "docstring がないファイルを検出",
+        description_en: "Detects files missing docstrings",
+        example: r#"require_python_doc:
+  label: docstring 必須
+  option:
+    function: public
+  message: docstring を記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_typescript_doc",
+        description: "TSDoc がないファイルを検出",
+        description_en: "Detects files missing TSDoc",
+        example: r#"require_typescript_doc:
+  label: TSDoc 必須
+  option:
+    function: public
+  message: TSDoc を記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_go_doc",
+        description: "Go の doc コメントがないファイルを検出",
+        description_en: "Detects files missing Go doc comments",
+        example: r#"require_go_doc:
+  label: doc コメント必須
+  option:
+    func: public
+  message: doc コメントを記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_swift_doc",
+        description: "Swift の doc コメントがないファイルを検出",
+        description_en: "Detects files missing Swift doc comments",
+        example: r#"require_swift_doc:
+  label: doc コメント必須
+  option:
+    class: public
+    func: public
+  message: doc コメントを記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_csharp_doc",
+        description: "C# の XML ドキュメントコメントがないファイルを検出",
+        description_en: "Detects files missing C# XML documentation comments",
+        example: r#"require_csharp_doc:
+  label: XML ドキュメントコメント必須
+  option:
+    class: public
+    method: public
+  message: XML ドキュメントコメントを記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_doc",
+        description: "独自のコメント構文で宣言の doc コメントがないファイルを検出",
+        description_en: "Detects declarations missing doc comments in a custom comment syntax",
+        example: r#"require_doc:
+  label: doc コメント必須
+  format:
+    custom:
+      lines: ['##']
+  declarations:
+    - '^def (\w+)'
+  message: doc コメントを記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_english_comment",
+        description: "コメントが日本語のファイルを検出",
+        description_en: "Detects files with Japanese comments",
+        example: r#"require_english_comment:
+  label: コメントは英語
+  format:
+    lang: java
+  message: コメントは英語で記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_japanese_comment",
+        description: "コメントが英語のファイルを検出",
+        description_en: "Detects files with English comments",
+        example: r#"require_japanese_comment:
+  label: コメントは日本語
+  format:
+    lang: kotlin
+  message: コメントは日本語で記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_japanese_phpunit_test_name",
+        description: "PHPUnit テスト名が日本語でないファイルを検出",
+        description_en: "Detects PHPUnit test names that are not in Japanese",
+        example: r#"require_japanese_phpunit_test_name:
+  label: テスト名は日本語
+  message: テストメソッド名は日本語にすること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_japanese_kotest_test_name",
+        description: "Kotest テスト名が日本語でないファイルを検出",
+        description_en: "Detects Kotest test names that are not in Japanese",
+        example: r#"require_japanese_kotest_test_name:
+  label: テスト名は日本語
+  message: テスト名は日本語にすること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_japanese_rust_test_name",
+        description: "Rust テスト名が日本語でないファイルを検出",
+        description_en: "Detects Rust test names that are not in Japanese",
+        example: r#"require_japanese_rust_test_name:
+  label: テスト名は日本語
+  message: テスト関数名は日本語にすること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_japanese_junit_test_name",
+        description: "JUnit テスト名が日本語でないファイルを検出",
+        description_en: "Detects JUnit test names that are not in Japanese",
+        example: r#"require_japanese_junit_test_name:
+  label: テスト名は日本語
+  message: テスト名は日本語にすること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_japanese_spock_test_name",
+        description: "Spock テスト名が日本語でないファイルを検出",
+        description_en: "Detects Spock test names that are not in Japanese",
+        example: r#"require_japanese_spock_test_name:
+  label: テスト名は日本語
+  message: テスト名は日本語にすること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_phpunit_test",
+        description: "PHPUnit テストファイルの存在を検証",
+        description_en: "Validates that PHPUnit test files exist",
+        example: r#"require_phpunit_test:
+  label: テスト必須
+  option:
+    test_directory: tests
+    require: exists
+  message: テストファイルを作成すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_kotest_test",
+        description: "Kotest テストファイルの存在を検証",
+        description_en: "Validates that Kotest test files exist",
+        example: r#"require_kotest_test:
+  label: テスト必須
+  option:
+    test_directory: src/test/kotlin
+    require: exists
+  message: テストファイルを作成すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_rust_unit_test",
+        description: "Rust ユニットテストの存在を検証",
+        description_en: "Validates that Rust unit tests exist",
+        example: r#"require_rust_unit_test:
+  label: テスト必須
+  option:
+    require: exists
+  message: ユニットテストを記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "consistent_quotes",
+        description: "指定と異なる引用符の文字列リテラルを検出",
+        description_en: "Detects string literals quoted differently from the configured style",
+        example: r#"consistent_quotes:
+  label: シングルクォート
+  option:
+    lang: typescript
+    quote: single
+  message: 文字列はシングルクォートで記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "no_skipped_tests",
+        description: "スキップまたはフォーカスされたテストを検出",
+        description_en: "Detects skipped or focused tests",
+        example: r#"no_skipped_tests:
+  label: スキップ禁止
+  option:
+    lang: kotlin
+  message: スキップしたテストを残さないこと"#,
+    },
+    RuleTypeInfo {
+        type_name: "max_line_length",
+        description: "指定の長さを超える行を検出",
+        description_en: "Detects lines longer than the limit",
+        example: r#"max_line_length:
+  label: 120 桁まで
+  option:
+    limit: 120
+  message: 1 行は 120 桁以内にすること"#,
+    },
+    RuleTypeInfo {
+        type_name: "no_trailing_whitespace",
+        description: "行末に空白がある行を検出",
+        description_en: "Detects lines with trailing whitespace",
+        example: r#"no_trailing_whitespace:
+  label: 行末空白の禁止
+  message: 行末の空白を削除すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_trailing_newline",
+        description: "末尾が改行で終わらないファイルを検出",
+        description_en: "Detects files that do not end with a newline",
+        example: r#"require_trailing_newline:
+  label: 末尾改行の必須
+  message: ファイルの末尾に改行を入れること"#,
+    },
+    RuleTypeInfo {
+        type_name: "forbidden_file_names",
+        description: "ファイル名が禁止パターンに一致するファイルを検出",
+        description_en: "Detects files whose names match forbidden patterns",
+        example: r#"forbidden_file_names:
+  label: OS の生成ファイル禁止
+  patterns: [.DS_Store, Thumbs.db, '*.local.php']
+  message: OS やローカル環境のファイルをコミットしないこと"#,
+    },
+    RuleTypeInfo {
+        type_name: "max_file_lines",
+        description: "指定の行数を超えるファイルを検出",
+        description_en: "Detects files with more lines than the limit",
+        example: r#"max_file_lines:
+  label: ファイルの行数制限
+  message: ファイルを 500 行以内に分割すること
+  option:
+    limit: 500"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_go_test",
+        description: "Go テストファイル (_test.go) の存在を検証",
+        description_en: "Validates that Go test files (_test.go) exist",
+        example: r#"require_go_test:
+  label: テスト必須
+  option:
+    require: exists
+  message: _test.go にテストを記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "forbidden_imports",
+        description: "禁止された import / use / require / #include を検出",
+        description_en: "Detects forbidden import / use / require / #include",
+        example: r#"forbidden_imports:
+  label: java.util.Date の禁止
+  imports: [ java.util.Date ]
+  message: java.time を使うこと"#,
+    },
+    RuleTypeInfo {
+        type_name: "required_texts",
+        description: "必須の文字列を含まないファイルを検出",
+        description_en: "Detects files missing required texts",
+        example: r#"required_texts:
+  label: ライセンス表記は必須
+  keywords: [ SPDX-License-Identifier ]
+  message: ライセンス表記を記述すること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_japanese_python_test_name",
+        description: "Python テスト名が日本語でないファイルを検出",
+        description_en: "Detects Python test names that are not in Japanese",
+        example: r#"require_japanese_python_test_name:
+  label: テスト名は日本語
+  message: テスト関数名は日本語にすること"#,
+    },
+    RuleTypeInfo {
+        type_name: "require_japanese_jest_test_name",
+        description: "Jest テスト名が日本語でないファイルを検出",
+        description_en: "Detects Jest test names that are not in Japanese",
+        example: r#"require_japanese_jest_test_name:
+  label: テスト名は日本語
+  message: テスト名は日本語にすること"#,
+    },
+    RuleTypeInfo {
+        type_name: "preset",
+        description: "組み込みの禁止パターンで検出",
+        description_en: "Detects built-in forbidden patterns",
+        example: r#"preset:
+  label: console の禁止
+  name: no_console"#,
+    },
+    RuleTypeInfo {
+        type_name: "no_stale_todo",
+        description: "期限切れの TODO などのマーカーを検出",
+        description_en: "Detects TODO-like markers dated too long ago",
+        example: r#"no_stale_todo:
+  label: 古い TODO の禁止
+  markers: [ TODO, FIXME ]
+  max_age_days: 90
+  message: TODO(2024-01-01) の形式で書いた日付から 90 日以内に対応すること"#,
+    },
+];
+
+/// Built-in forbidden_patterns rule selected by name in the `preset` rule type
+pub struct PresetInfo {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// English description (`--lang en`)
+    pub description_en: &'static str,
+    pub patterns: &'static [&'static str],
+    /// Message used when the rule does not specify one
+    pub message: &'static str,
+}
+
+pub const PRESET_INFOS: &[PresetInfo] = &[
+    PresetInfo {
+        name: "no_console",
+        description: "console.log などの console の呼び出しを検出",
+        description_en: "Detects console calls such as console.log",
+        patterns: &[r"\bconsole\.\w+\s*\("],
+        message: "console の呼び出しを削除すること",
+    },
+    PresetInfo {
+        name: "no_debugger",
+        description: "debugger 文を検出",
+        description_en: "Detects debugger statements",
+        patterns: &[r"\bdebugger\s*(?:;|$)"],
+        message: "debugger 文を削除すること",
+    },
+    PresetInfo {
+        name: "no_var",
+        description: "var による変数宣言を検出",
+        description_en: "Detects variable declarations with var",
+        patterns: &[r"\bvar\s+[A-Za-z_$]"],
+        message: "var ではなく let か const を使うこと",
+    },
+    PresetInfo {
+        name: "no_focused_test",
+        description: "it.only や fdescribe などのフォーカスされたテストを検出",
+        description_en: "Detects focused tests such as it.only and fdescribe",
+        patterns: &[r"\b(?:it|test|describe)\.only\s*\(", r"\b(?:fit|fdescribe)\s*\("],
+        message: "フォーカスされたテストを元に戻すこと",
+    },
+];
@@ -0,0 +1,35 @@
+use regex::Regex;
+
+/// Known-safe matches that must not be reported
+/// Each entry matches either as an exact string or as a regex matching the whole value
+/// Partial entries (`allow`) are regexes matching any part of the value
+#[derive(Clone, Debug, Default)]
+pub struct AllowList {
+    values: Vec<String>,
+    patterns: Vec<Regex>,
+    partial: Vec<Regex>,
+}
+
+impl AllowList {
+    pub fn new(entries: Vec<String>) -> Self {
+        // Entries that are not valid regexes are still usable as exact strings
+        let patterns = entries.iter().filter_map(|entry| Regex::new(&format!("^(?:{entry})$")).ok()).collect();
+        Self { values: entries, patterns, partial: Vec::new() }
+    }
+
+    /// Add regexes that allow a value when they match any part of it
+    pub fn with_partial(mut self, partial: Vec<Regex>) -> Self {
+        self.partial = partial;
+        self
+    }
+
+    pub fn allows(&self, matched: &str) -> bool {
+        self.values.iter().any(|v| v == matched)
+            || self.patterns.iter().any(|p| p.is_match(matched))
+            || self.partial.iter().any(|p| p.is_match(matched))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty() && self.partial.is_empty()
+    }
+}
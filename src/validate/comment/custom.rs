@@ -15,6 +15,7 @@ pub struct CustomCommentSyntax {
 }
 
 /// Extract all comments from source code using custom syntax
+/// Comment markers inside double-quoted string literals (including raw strings like `r#"..."#`) are ignored
 pub fn extract_comments(content: &str, syntax: &CustomCommentSyntax) -> Vec<Comment> {
     let mut comments = Vec::new();
     let lines: Vec<&str> = content.lines().collect();
@@ -24,6 +25,9 @@ pub fn extract_comments(content: &str, syntax: &CustomCommentSyntax) -> Vec<Comm
     let mut in_block_comment = false;
     let mut current_block_end = String::new();
 
+    // Closing delimiter of a raw string that continues onto the next line
+    let mut raw_string_end: Option<String> = None;
+
     while i < lines.len() {
         let line = lines[i];
         let trimmed = line.trim();
@@ -40,10 +44,14 @@ pub fn extract_comments(content: &str, syntax: &CustomCommentSyntax) -> Vec<Comm
 
                 in_block_comment = false;
 
-                // Check for line comment after block end
+                // Check for comments after block end
                 let after_end = &line[end_pos + current_block_end.len()..];
-                if let Some(comment) = find_line_comment(after_end, line_num, &syntax.lines) {
-                    comments.push(comment);
+                let mut state = ScanState { raw_string_end: None, block_end: None };
+                scan_code(after_end, line_num, syntax, &mut comments, &mut state);
+                raw_string_end = state.raw_string_end;
+                if let Some(end) = state.block_end {
+                    in_block_comment = true;
+                    current_block_end = end;
                 }
             } else {
                 // Middle of block comment - each line is a separate comment
@@ -52,87 +60,145 @@ pub fn extract_comments(content: &str, syntax: &CustomCommentSyntax) -> Vec<Comm
                 }
             }
         } else {
-            // Check for line comment
-            if let Some(comment) = find_line_comment(line, line_num, &syntax.lines) {
-                comments.push(comment);
+            let mut state = ScanState { raw_string_end: raw_string_end.take(), block_end: None };
+            scan_code(line, line_num, syntax, &mut comments, &mut state);
+            raw_string_end = state.raw_string_end;
+            if let Some(end) = state.block_end {
+                in_block_comment = true;
+                current_block_end = end;
             }
+        }
 
-            // Check for block comments (may be multiple on one line)
-            let mut remaining = line;
-            while let Some((block, pos)) = find_block_start(remaining, &syntax.blocks) {
-                let after = &remaining[pos + block.start.len()..];
+        i += 1;
+    }
 
-                // Check for single-line block comment
-                if let Some(end_pos) = after.find(block.end.as_str()) {
+    comments
+}
+
+/// Lexer state carried out of a scanned line
+struct ScanState {
+    /// Closing delimiter of an unterminated raw string
+    raw_string_end: Option<String>,
+    /// End marker of an unterminated block comment
+    block_end: Option<String>,
+}
+
+/// Scan code (outside of block comments) for line and block comments, skipping string literals
+fn scan_code(
+    line: &str,
+    line_num: usize,
+    syntax: &CustomCommentSyntax,
+    comments: &mut Vec<Comment>,
+    state: &mut ScanState,
+) {
+    let mut pos = 0;
+
+    // Inside a raw string continued from the previous line
+    if let Some(end) = state.raw_string_end.take() {
+        match line.find(end.as_str()) {
+            Some(end_pos) => pos = end_pos + end.len(),
+            None => {
+                state.raw_string_end = Some(end);
+                return;
+            }
+        }
+    }
+
+    while pos < line.len() {
+        let rest = &line[pos..];
+
+        // Block comment start
+        if let Some(block) = syntax.blocks.iter().find(|b| rest.starts_with(b.start.as_str())) {
+            let after = &rest[block.start.len()..];
+            match after.find(block.end.as_str()) {
+                Some(end_pos) => {
                     // Single-line block comment
-                    let comment_text = after[..end_pos].trim();
-                    comments.push(Comment { line: line_num, text: comment_text.to_string() });
-                    // Continue checking the rest of the line
-                    remaining = &after[end_pos + block.end.len()..];
-                } else {
-                    // Start of multi-line block comment
-                    in_block_comment = true;
-                    current_block_end = block.end.clone();
-                    // Extract first line content if any
+                    comments.push(Comment { line: line_num, text: after[..end_pos].trim().to_string() });
+                    pos += block.start.len() + end_pos + block.end.len();
+                    continue;
+                }
+                None => {
+                    // Start of multi-line block comment - extract first line content if any
                     let first_line_text = after.trim();
                     if !first_line_text.is_empty() {
                         comments.push(Comment { line: line_num, text: first_line_text.to_string() });
                     }
-                    break;
+                    state.block_end = Some(block.end.clone());
+                    return;
                 }
             }
         }
 
-        i += 1;
-    }
-
-    comments
-}
-
-/// Find a line comment using any of the line markers
-fn find_line_comment(line: &str, line_num: usize, markers: &[String]) -> Option<Comment> {
-    // Find the earliest matching marker
-    let mut best_match: Option<(usize, &str)> = None;
-
-    for marker in markers {
-        let mut search_start = 0;
-        while let Some(rel_pos) = line[search_start..].find(marker.as_str()) {
-            let pos = search_start + rel_pos;
-
+        // Line comment
+        if let Some(marker) = syntax.lines.iter().find(|m| rest.starts_with(m.as_str())) {
             // Skip "://" patterns (e.g., http://, https://, ftp://)
-            if marker == "//" && pos > 0 && line.as_bytes().get(pos - 1) == Some(&b':') {
-                search_start = pos + marker.len();
-                continue;
+            let is_url = marker == "//" && pos > 0 && line.as_bytes()[pos - 1] == b':';
+            if !is_url {
+                comments.push(Comment { line: line_num, text: rest[marker.len()..].trim().to_string() });
+                return;
             }
+        }
 
-            match best_match {
-                None => best_match = Some((pos, marker)),
-                Some((best_pos, _)) if pos < best_pos => best_match = Some((pos, marker)),
-                _ => {}
+        // Raw string literal (r"...", r#"..."#)
+        if let Some((open_len, end)) = raw_string_start(line, pos) {
+            match line[pos + open_len..].find(end.as_str()) {
+                Some(end_pos) => pos += open_len + end_pos + end.len(),
+                None => {
+                    state.raw_string_end = Some(end);
+                    return;
+                }
             }
-            break;
+            continue;
+        }
+
+        // Char literal of a double quote ('"' or '\"')
+        if let Some(literal) = ["'\"'", "'\\\"'"].iter().find(|l| rest.starts_with(**l)) {
+            pos += literal.len();
+            continue;
+        }
+
+        // String literal (ends at the closing quote or the end of line)
+        if rest.starts_with('"') {
+            pos = skip_string(line, pos);
+            continue;
         }
+
+        pos += rest.chars().next().map_or(1, char::len_utf8);
     }
+}
 
-    let (pos, marker) = best_match?;
-    let comment_text = line[pos + marker.len()..].trim();
-    Some(Comment { line: line_num, text: comment_text.to_string() })
+/// If a raw string starts at `pos`, returns the length of its opening and its closing delimiter
+fn raw_string_start(line: &str, pos: usize) -> Option<(usize, String)> {
+    let bytes = line.as_bytes();
+    if bytes[pos] != b'r' || (pos > 0 && is_ident_byte(bytes[pos - 1]) && !is_byte_string_prefix(bytes, pos)) {
+        return None;
+    }
+    let hashes = bytes[pos + 1..].iter().take_while(|b| **b == b'#').count();
+    if bytes.get(pos + 1 + hashes) != Some(&b'"') {
+        return None;
+    }
+    Some((hashes + 2, format!("\"{}", "#".repeat(hashes))))
 }
 
-/// Find a block comment start using any of the block patterns
-fn find_block_start<'a>(line: &str, blocks: &'a [BlockSyntax]) -> Option<(&'a BlockSyntax, usize)> {
-    // Find the earliest matching block start
-    let mut best_match: Option<(&BlockSyntax, usize)> = None;
-
-    for block in blocks {
-        if let Some(pos) = line.find(block.start.as_str()) {
-            match best_match {
-                None => best_match = Some((block, pos)),
-                Some((_, best_pos)) if pos < best_pos => best_match = Some((block, pos)),
-                _ => {}
-            }
+/// `br"..."` (byte raw string): `b` preceded by a non-identifier character
+fn is_byte_string_prefix(bytes: &[u8], pos: usize) -> bool {
+    bytes[pos - 1] == b'b' && (pos < 2 || !is_ident_byte(bytes[pos - 2]))
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+/// Returns the position just after the closing quote (or end of line)
+fn skip_string(line: &str, start: usize) -> usize {
+    let bytes = line.as_bytes();
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
         }
     }
-
-    best_match
+    bytes.len()
 }
@@ -3,6 +3,8 @@ pub mod java;
 pub mod kotlin;
 pub mod rust;
 
+use crate::rule::parser::Script;
+
 /// Extracted comment from source file
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Comment {
@@ -18,7 +20,7 @@ pub struct CommentViolation {
 }
 
 /// Check if comment is empty or just decoration (e.g., `*` in block comments)
-fn is_empty_or_decoration(text: &str) -> bool {
+pub(crate) fn is_empty_or_decoration(text: &str) -> bool {
     let trimmed = text.trim();
     if trimmed.is_empty() {
         return true;
@@ -30,25 +32,119 @@ fn is_empty_or_decoration(text: &str) -> bool {
     false
 }
 
+/// Drop comments inside fenced code blocks (``` or ~~~) of doc comments, including the fence lines
+/// A fence is tracked across consecutive comment lines only, so an unclosed fence ends with its comment
+pub fn skip_code_blocks(comments: Vec<Comment>) -> Vec<Comment> {
+    let mut kept = Vec::new();
+    // Opening fence marker and the line of the last comment inside the block
+    let mut open: Option<(String, usize)> = None;
+    for comment in comments {
+        let text = doc_text(&comment.text);
+        if let Some((marker, last_line)) = &open {
+            if comment.line > last_line + 1 {
+                open = None;
+            } else {
+                let closes = text.starts_with(marker.as_str());
+                open = if closes { None } else { Some((marker.clone(), comment.line)) };
+                continue;
+            }
+        }
+        match fence_marker(text) {
+            Some(marker) => open = Some((marker, comment.line)),
+            None => kept.push(comment),
+        }
+    }
+    kept
+}
+
+/// Comment text without doc comment decoration (`*` of block comments, `/` and `!` of `///` and `//!`)
+fn doc_text(text: &str) -> &str {
+    text.trim_start_matches(['*', '/', '!']).trim_start()
+}
+
+/// The fence marker (three or more backticks or tildes) if `text` opens a fenced code block
+fn fence_marker(text: &str) -> Option<String> {
+    let first = text.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let marker: String = text.chars().take_while(|c| *c == first).collect();
+    (marker.len() >= 3).then_some(marker)
+}
+
 /// Check if text contains Japanese characters (Hiragana, Katakana, CJK)
 pub fn contains_japanese(text: &str) -> bool {
-    text.chars().any(|c| {
-        let code = c as u32;
-        // Hiragana: U+3040-U+309F
-        // Katakana: U+30A0-U+30FF
-        // CJK Unified Ideographs: U+4E00-U+9FFF
-        // Katakana Phonetic Extensions: U+31F0-U+31FF
-        // Halfwidth Katakana: U+FF65-U+FF9F
-        (0x3040..=0x309F).contains(&code)
-            || (0x30A0..=0x30FF).contains(&code)
-            || (0x4E00..=0x9FFF).contains(&code)
-            || (0x31F0..=0x31FF).contains(&code)
-            || (0xFF65..=0xFF9F).contains(&code)
-    })
+    text.chars().any(is_japanese_char)
+}
+
+/// Check if text contains Chinese characters (CJK Unified Ideographs and extensions)
+pub fn contains_chinese(text: &str) -> bool {
+    text.chars().any(is_chinese_char)
+}
+
+/// Check if text contains Korean characters (Hangul)
+pub fn contains_korean(text: &str) -> bool {
+    text.chars().any(is_korean_char)
+}
+
+/// Check if text contains any character of the script
+pub fn contains_script(text: &str, script: Script) -> bool {
+    text.chars().any(|c| is_script_char(c, script))
+}
+
+fn is_japanese_char(c: char) -> bool {
+    let code = c as u32;
+    // Hiragana: U+3040-U+309F
+    // Katakana: U+30A0-U+30FF
+    // CJK Unified Ideographs: U+4E00-U+9FFF
+    // Katakana Phonetic Extensions: U+31F0-U+31FF
+    // Halfwidth Katakana: U+FF65-U+FF9F
+    (0x3040..=0x309F).contains(&code)
+        || (0x30A0..=0x30FF).contains(&code)
+        || (0x4E00..=0x9FFF).contains(&code)
+        || (0x31F0..=0x31FF).contains(&code)
+        || (0xFF65..=0xFF9F).contains(&code)
+}
+
+fn is_chinese_char(c: char) -> bool {
+    let code = c as u32;
+    // CJK Unified Ideographs: U+4E00-U+9FFF
+    // CJK Unified Ideographs Extension A: U+3400-U+4DBF
+    // CJK Compatibility Ideographs: U+F900-U+FAFF
+    (0x4E00..=0x9FFF).contains(&code) || (0x3400..=0x4DBF).contains(&code) || (0xF900..=0xFAFF).contains(&code)
+}
+
+fn is_korean_char(c: char) -> bool {
+    let code = c as u32;
+    // Hangul Syllables: U+AC00-U+D7A3
+    // Hangul Jamo: U+1100-U+11FF
+    // Hangul Compatibility Jamo: U+3130-U+318F
+    (0xAC00..=0xD7A3).contains(&code) || (0x1100..=0x11FF).contains(&code) || (0x3130..=0x318F).contains(&code)
+}
+
+fn is_script_char(c: char, script: Script) -> bool {
+    match script {
+        Script::Japanese => is_japanese_char(c),
+        Script::Chinese => is_chinese_char(c),
+        Script::Korean => is_korean_char(c),
+    }
+}
+
+/// Fraction of non-whitespace characters that belong to any of the scripts
+fn script_ratio(text: &str, scripts: &[Script]) -> f64 {
+    let chars: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+    if chars.is_empty() {
+        return 0.0;
+    }
+    let matched = chars.iter().filter(|c| scripts.iter().any(|script| is_script_char(**c, *script))).count();
+    matched as f64 / chars.len() as f64
 }
 
 /// Validate comments for Japanese content
 pub fn validate_japanese(comments: &[Comment]) -> Vec<CommentViolation> {
+    validate_scripts(comments, &[Script::Japanese], None)
+}
+
+/// Validate comments for content in any of the given scripts
+/// With `max_ratio`, a comment violates only when the fraction of script characters exceeds it
+pub fn validate_scripts(comments: &[Comment], scripts: &[Script], max_ratio: Option<f64>) -> Vec<CommentViolation> {
     comments
         .iter()
         .filter(|c| {
@@ -56,7 +152,10 @@ pub fn validate_japanese(comments: &[Comment]) -> Vec<CommentViolation> {
             if is_empty_or_decoration(&c.text) {
                 return false;
             }
-            contains_japanese(&c.text)
+            match max_ratio {
+                Some(max_ratio) => script_ratio(&c.text, scripts) > max_ratio,
+                None => scripts.iter().any(|script| contains_script(&c.text, *script)),
+            }
         })
         .map(|c| CommentViolation { line: c.line, text: c.text.clone() })
         .collect()
@@ -1,14 +1,30 @@
-use std::path::Path;
-use std::process::Command;
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::thread;
 
 use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
 
 use crate::rule::CustomRule;
 use crate::validate::CustomViolation;
 
-pub fn validate(file_path: &Path, rule: &CustomRule, script_dir: Option<&Path>) -> Result<Option<CustomViolation>> {
+/// Directory (under the root directory) where results of rules with `cache: true` are stored
+pub const CACHE_DIR: &str = ".rec_lint_cache";
+
+/// Run the command of a custom rule
+/// With `cache_dir`, a result stored for the same label, content and command is reused instead
+pub fn validate(
+    file_path: &Path,
+    content: &str,
+    rule: &CustomRule,
+    script_dir: Option<&Path>,
+    cache_dir: Option<&Path>,
+) -> Result<Option<CustomViolation>> {
     let file_path_str = file_path.to_string_lossy();
-    let mut exec_command = rule.exec.replace("{file}", file_path_str.as_ref());
+    // Environment variables are expanded first so that `${` in file names is kept as is
+    let mut exec_command = interpolate_env(&rule.exec)?.replace("{file}", file_path_str.as_ref());
 
     if exec_command.contains("{script_dir}") {
         let script_dir = script_dir
@@ -23,10 +39,45 @@ pub fn validate(file_path: &Path, rule: &CustomRule, script_dir: Option<&Path>)
         return Ok(None);
     }
 
-    let output = Command::new(parts[0]).args(&parts[1..]).output()?;
+    let cache_path = cache_dir.map(|dir| cache_path(dir, &rule.label, content, &exec_command));
+    if let Some(cached) = cache_path.as_deref().and_then(read_cache) {
+        return Ok(cached.output.map(|output| CustomViolation { output }));
+    }
+
+    let mut command = Command::new(parts[0]);
+    command.args(&parts[1..]);
+    let output = if rule.stdin { output_with_stdin(command, content)? } else { command.output()? };
+    let violation = violation(&output);
+
+    if let Some(path) = &cache_path {
+        // The cache only saves time, so a failure to write it does not fail the validation
+        let _ = write_cache(path, &CachedResult { output: violation.as_ref().map(|v| v.output.clone()) });
+    }
+
+    Ok(violation)
+}
+
+/// Expand `${VAR}` with the environment variable (an unset variable is an error, not an empty string)
+fn interpolate_env(exec: &str) -> Result<String> {
+    let mut result = String::with_capacity(exec.len());
+    let mut rest = exec;
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find('}').ok_or_else(|| anyhow!("Unclosed '${{' in exec: {exec}"))?;
+        let name = &after[..end];
+        let value =
+            std::env::var(name).map_err(|_| anyhow!("Environment variable '{name}' used in exec is not set"))?;
+        result.push_str(&value);
+        rest = &after[end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
 
+fn violation(output: &Output) -> Option<CustomViolation> {
     if output.status.success() {
-        Ok(None)
+        None
     } else {
         let stdout = String::from_utf8_lossy(&output.stdout);
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -37,6 +88,54 @@ pub fn validate(file_path: &Path, rule: &CustomRule, script_dir: Option<&Path>)
         } else {
             format!("{stdout}{stderr}")
         };
-        Ok(Some(CustomViolation { output: combined.trim().to_string() }))
+        Some(CustomViolation { output: combined.trim().to_string() })
+    }
+}
+
+/// Run the command with `content` piped to its stdin
+fn output_with_stdin(mut command: Command, content: &str) -> Result<Output> {
+    let mut child = command.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+    let mut stdin = child.stdin.take().ok_or_else(|| anyhow!("Failed to open stdin of custom command"))?;
+
+    // Write from another thread so that a command filling its stdout pipe does not block the write
+    thread::scope(|scope| {
+        let writer = scope.spawn(move || match stdin.write_all(content.as_bytes()) {
+            // The command may exit without reading all of its input
+            Err(e) if e.kind() == ErrorKind::BrokenPipe => Ok(()),
+            result => result,
+        });
+        let output = child.wait_with_output()?;
+        writer.join().map_err(|_| anyhow!("Failed to write stdin of custom command"))??;
+        Ok(output)
+    })
+}
+
+/// A cached command result (`output` is None when the command succeeded)
+#[derive(Serialize, Deserialize)]
+struct CachedResult {
+    output: Option<String>,
+}
+
+/// `<cache_dir>/<hash of label, command and content>.json`
+fn cache_path(cache_dir: &Path, label: &str, content: &str, exec_command: &str) -> PathBuf {
+    let key = [label, exec_command, &format!("{:016x}", fnv1a(content.as_bytes()))].join("\0");
+    cache_dir.join(format!("{:016x}.json", fnv1a(key.as_bytes())))
+}
+
+fn read_cache(path: &Path) -> Option<CachedResult> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_cache(path: &Path, result: &CachedResult) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
     }
+    fs::write(path, serde_json::to_string(result)?)?;
+    Ok(())
+}
+
+/// 64-bit FNV-1a (stable across builds, unlike `DefaultHasher`)
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3))
 }
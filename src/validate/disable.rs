@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+const DISABLE_NEXT_LINE: &str = "rec_lint-disable-next-line";
+const DISABLE_LINE: &str = "rec_lint-disable-line";
+
+/// Rules disabled on a line
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Disabled {
+    All,
+    Labels(Vec<String>),
+}
+
+/// Inline disable directives found in a file
+///
+/// `rec_lint-disable-line [label, ...]` disables rules on its own line and
+/// `rec_lint-disable-next-line [label, ...]` on the following line.
+/// Without labels every rule is disabled.
+#[derive(Debug, Default)]
+pub struct Disables {
+    lines: HashMap<usize, Vec<Disabled>>,
+}
+
+impl Disables {
+    pub fn parse(content: &str) -> Self {
+        let mut lines: HashMap<usize, Vec<Disabled>> = HashMap::new();
+
+        for (i, line) in content.lines().enumerate() {
+            let line_num = i + 1;
+            // A line may hold several directives (e.g. disable-line and disable-next-line)
+            let mut directives: Vec<(usize, &str)> = line.match_indices(DISABLE_NEXT_LINE).collect();
+            directives.extend(line.match_indices(DISABLE_LINE));
+            directives.sort();
+
+            for (idx, (pos, directive)) in directives.iter().enumerate() {
+                let start = pos + directive.len();
+                let end = directives.get(idx + 1).map(|(next, _)| *next).unwrap_or(line.len());
+                let target = if *directive == DISABLE_NEXT_LINE { line_num + 1 } else { line_num };
+                lines.entry(target).or_default().push(parse_labels(&line[start..end]));
+            }
+        }
+
+        Self { lines }
+    }
+
+    /// Returns true if the rule with `label` is disabled on `line`
+    pub fn is_disabled(&self, line: usize, label: &str) -> bool {
+        self.lines.get(&line).is_some_and(|disabled| {
+            disabled.iter().any(|d| match d {
+                Disabled::All => true,
+                Disabled::Labels(labels) => labels.iter().any(|l| l == label),
+            })
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+/// Parse comma separated labels, ignoring block comment terminators
+fn parse_labels(text: &str) -> Disabled {
+    let text = text.trim().trim_end_matches("*/").trim_end_matches("-->").trim();
+    let labels: Vec<String> =
+        text.split(',').map(|label| label.trim()).filter(|label| !label.is_empty()).map(String::from).collect();
+    if labels.is_empty() {
+        Disabled::All
+    } else {
+        Disabled::Labels(labels)
+    }
+}
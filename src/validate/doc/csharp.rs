@@ -0,0 +1,231 @@
+use super::{is_empty_doc, retain_top_level, CSharpDocConfig, DocKind, DocViolation};
+use crate::rule::parser::Visibility;
+
+/// Modifiers that may precede a type or member declaration
+const MODIFIERS: &[&str] = &[
+    "public",
+    "private",
+    "protected",
+    "internal",
+    "file",
+    "static",
+    "abstract",
+    "sealed",
+    "partial",
+    "readonly",
+    "ref",
+    "unsafe",
+    "virtual",
+    "override",
+    "async",
+    "extern",
+];
+
+/// Words that start statements or other constructs with parentheses, never method declarations
+const NON_METHOD_KEYWORDS: &[&str] = &[
+    "if", "else", "for", "foreach", "while", "do", "switch", "case", "catch", "using", "lock", "return", "throw",
+    "yield", "await", "new", "var", "when", "nameof", "typeof", "base", "this", "fixed", "delegate", "event", "record",
+];
+
+/// Validate C# file for missing XML doc comments
+pub fn validate(content: &str, config: &CSharpDocConfig) -> Vec<DocViolation> {
+    let mut violations = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+
+        // Skip empty lines, comments and attributes
+        if line.is_empty() || is_comment_line(line) || line.starts_with('[') {
+            i += 1;
+            continue;
+        }
+
+        // Skip block comments
+        if line.starts_with("/*") {
+            i = skip_block_comment(&lines, i);
+            continue;
+        }
+
+        if let Some((kind, modifiers, name)) = parse_declaration(line) {
+            let has_doc = check_xmldoc_before(&lines, i, config.require_non_empty);
+            if let Some(v) = check_declaration(kind, &modifiers, name, i + 1, has_doc, config) {
+                violations.push(v);
+            }
+        }
+
+        i += 1;
+    }
+
+    if config.top_level_only {
+        retain_top_level(&mut violations, content);
+    }
+
+    violations
+}
+
+fn skip_block_comment(lines: &[&str], start: usize) -> usize {
+    let mut i = start;
+    while i < lines.len() {
+        if lines[i].contains("*/") {
+            return i + 1;
+        }
+        i += 1;
+    }
+    lines.len()
+}
+
+/// Returns true if `///` lines precede the line (and have text when `require_non_empty` is set)
+fn check_xmldoc_before(lines: &[&str], current: usize, require_non_empty: bool) -> bool {
+    if current == 0 {
+        return false;
+    }
+
+    let mut i = current - 1;
+
+    // Skip attributes (including ones split across lines)
+    while i > 0 {
+        let line = lines[i].trim();
+        if line.starts_with('[') && line.ends_with(']') {
+            i -= 1;
+            continue;
+        }
+        if line.ends_with(']') {
+            if let Some(start) = find_attribute_start(lines, i) {
+                if start == 0 {
+                    return false;
+                }
+                i = start - 1;
+                continue;
+            }
+        }
+        break;
+    }
+
+    let line = lines[i].trim();
+
+    let end = i;
+
+    if line.starts_with("///") {
+        while i > 0 && lines[i - 1].trim().starts_with("///") {
+            i -= 1;
+        }
+        return !require_non_empty || !is_empty_doc(&lines[i..=end]);
+    }
+
+    false
+}
+
+/// Returns the index of the `[` line that opens the attribute ending at `end`
+fn find_attribute_start(lines: &[&str], end: usize) -> Option<usize> {
+    let mut depth = 0i32;
+    for i in (0..=end).rev() {
+        let line = lines[i].trim();
+        depth += line.matches(']').count() as i32 - line.matches('[').count() as i32;
+        if depth <= 0 {
+            return line.starts_with('[').then_some(i);
+        }
+    }
+    None
+}
+
+fn is_comment_line(line: &str) -> bool {
+    // XML doc comments (///) are looked up from the declaration line, so they are skipped too
+    line.starts_with("//") || line.starts_with("*")
+}
+
+/// Parse a type or method declaration into its kind, modifiers and name
+fn parse_declaration(line: &str) -> Option<(DocKind, Vec<&str>, String)> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let mut pos = 0;
+    // `new` hides an inherited member only after another modifier (`public new void Foo()`)
+    while pos < words.len() && (MODIFIERS.contains(&words[pos]) || (pos > 0 && words[pos] == "new")) {
+        pos += 1;
+    }
+    let modifiers = words[..pos].to_vec();
+
+    let kind = match *words.get(pos)? {
+        "class" => DocKind::Class,
+        "interface" => DocKind::Interface,
+        "struct" => DocKind::Struct,
+        "enum" => DocKind::Enum,
+        _ => return parse_method(line, &words[pos..]).map(|name| (DocKind::Method, modifiers, name)),
+    };
+    let name = extract_name(words.get(pos + 1)?);
+    (!name.is_empty()).then_some((kind, modifiers, name))
+}
+
+/// `ReturnType Name(` (or `Name(` for constructors with a modifier) -> Name
+fn parse_method(line: &str, words: &[&str]) -> Option<String> {
+    let first = *words.first()?;
+    if NON_METHOD_KEYWORDS.contains(&first) {
+        return None;
+    }
+
+    // Assignments, lambdas and expression-bodied properties are not method declarations
+    let head = &line[..line.find('(')?];
+    if head.contains('=') {
+        return None;
+    }
+
+    // Every word before the parameters is a modifier, a type or the name (e.g. not `}` or `:`)
+    let head_words: Vec<&str> = head.split_whitespace().filter(|w| !MODIFIERS.contains(w)).collect();
+    if !head_words.iter().all(|w| w.starts_with(|c: char| c.is_alphanumeric() || c == '_')) {
+        return None;
+    }
+
+    // At least a return type and a name, unless a modifier marks a constructor
+    let is_constructor = head_words.len() == 1 && head.split_whitespace().count() > 1;
+    if head_words.len() < 2 && !is_constructor {
+        return None;
+    }
+
+    let name = extract_name(head_words.last()?);
+    (!name.is_empty()).then_some(name)
+}
+
+/// Strip generics and qualifiers: `Foo<T>` -> Foo
+fn extract_name(word: &str) -> String {
+    word.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect()
+}
+
+fn check_visibility(modifiers: &[&str], visibility: &Visibility) -> bool {
+    let is_public = modifiers.contains(&"public");
+    match visibility {
+        Visibility::Public | Visibility::Exported => is_public,
+        Visibility::All => true,
+    }
+}
+
+// ============================================================================
+// Individual element checkers
+// ============================================================================
+
+fn check_declaration(
+    kind: DocKind,
+    modifiers: &[&str],
+    name: String,
+    line_num: usize,
+    has_doc: bool,
+    config: &CSharpDocConfig,
+) -> Option<DocViolation> {
+    let visibility = match kind {
+        DocKind::Class => config.class.as_ref()?,
+        DocKind::Interface => config.interface.as_ref()?,
+        DocKind::Struct => config.struct_.as_ref()?,
+        DocKind::Enum => config.enum_.as_ref()?,
+        DocKind::Method => config.method.as_ref()?,
+        _ => return None,
+    };
+
+    if !check_visibility(modifiers, visibility) {
+        return None;
+    }
+
+    if has_doc {
+        return None;
+    }
+
+    Some(DocViolation { line: line_num, kind, name })
+}
@@ -0,0 +1,96 @@
+use super::{DocKind, DocViolation, GenericDocConfig};
+use crate::validate::comment::custom::{BlockSyntax, CustomCommentSyntax};
+
+/// Validate file for declarations without a preceding doc comment in the configured syntax
+pub fn validate(content: &str, config: &GenericDocConfig) -> Vec<DocViolation> {
+    let mut violations = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+
+        // Skip comments (declarations inside comments are not checked)
+        if is_line_comment(line, &config.syntax) {
+            i += 1;
+            continue;
+        }
+        if let Some(block) = block_start(line, &config.syntax) {
+            i = skip_block_comment(&lines, i, block);
+            continue;
+        }
+
+        if let Some(name) = match_declaration(lines[i], config) {
+            if !check_doc_before(&lines, i, &config.syntax) {
+                violations.push(DocViolation { line: i + 1, kind: DocKind::Declaration, name });
+            }
+        }
+
+        i += 1;
+    }
+
+    violations
+}
+
+/// Returns the first capture group (or the whole match) of the first matching declaration pattern
+fn match_declaration(line: &str, config: &GenericDocConfig) -> Option<String> {
+    config.declarations.iter().find_map(|re| {
+        let caps = re.captures(line)?;
+        let m = caps.get(1).or_else(|| caps.get(0))?;
+        Some(m.as_str().trim().to_string())
+    })
+}
+
+fn is_line_comment(line: &str, syntax: &CustomCommentSyntax) -> bool {
+    syntax.lines.iter().any(|marker| line.starts_with(marker.as_str()))
+}
+
+/// Returns the block syntax if the line opens a block comment
+fn block_start<'a>(line: &str, syntax: &'a CustomCommentSyntax) -> Option<&'a BlockSyntax> {
+    syntax.blocks.iter().find(|b| line.starts_with(b.start.as_str()))
+}
+
+fn skip_block_comment(lines: &[&str], start: usize, block: &BlockSyntax) -> usize {
+    let mut i = start;
+    while i < lines.len() {
+        let line = lines[i].trim();
+        // The end marker on the opening line must come after the start marker
+        let searched = if i == start { &line[block.start.len()..] } else { line };
+        if searched.contains(block.end.as_str()) {
+            return i + 1;
+        }
+        i += 1;
+    }
+    lines.len()
+}
+
+/// Returns true if a line comment or a block comment ends immediately before the line
+fn check_doc_before(lines: &[&str], current: usize, syntax: &CustomCommentSyntax) -> bool {
+    if current == 0 {
+        return false;
+    }
+
+    let line = lines[current - 1].trim();
+    if is_line_comment(line, syntax) {
+        return true;
+    }
+
+    // Block comment: look back for its start
+    for block in &syntax.blocks {
+        if !line.ends_with(block.end.as_str()) {
+            continue;
+        }
+        let mut i = current - 1;
+        loop {
+            if lines[i].trim().starts_with(block.start.as_str()) {
+                return true;
+            }
+            if i == 0 {
+                break;
+            }
+            i -= 1;
+        }
+    }
+
+    false
+}
@@ -0,0 +1,176 @@
+use super::{retain_top_level, DocKind, DocViolation, GoDocConfig};
+use crate::rule::parser::Visibility;
+
+/// Validate Go file for missing doc comments
+/// Top-level declarations start at column 0 (gofmt), so indented lines are not declarations
+pub fn validate(content: &str, config: &GoDocConfig) -> Vec<DocViolation> {
+    let mut violations = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+
+        // Skip block comments
+        if line.trim_start().starts_with("/*") {
+            i = skip_block_comment(&lines, i);
+            continue;
+        }
+
+        let has_doc = check_doc_before(&lines, i);
+
+        // Grouped constants: the group doc or an entry doc documents each entry
+        if line.starts_with("const (") {
+            i = check_const_group(&lines, i, has_doc, config, &mut violations);
+            continue;
+        }
+
+        if let Some(v) = check_struct(line, i + 1, has_doc, config) {
+            violations.push(v);
+        } else if let Some(v) = check_interface(line, i + 1, has_doc, config) {
+            violations.push(v);
+        } else if let Some(v) = check_func(line, i + 1, has_doc, config) {
+            violations.push(v);
+        } else if let Some(v) = check_const(line, i + 1, has_doc, config) {
+            violations.push(v);
+        }
+
+        i += 1;
+    }
+
+    if config.top_level_only {
+        retain_top_level(&mut violations, content);
+    }
+
+    violations
+}
+
+fn skip_block_comment(lines: &[&str], start: usize) -> usize {
+    let mut i = start;
+    while i < lines.len() {
+        if lines[i].contains("*/") {
+            return i + 1;
+        }
+        i += 1;
+    }
+    lines.len()
+}
+
+/// Returns true if `//` comment lines with some text immediately precede the line
+/// Compiler directives such as `//go:generate` are not doc comments
+fn check_doc_before(lines: &[&str], current: usize) -> bool {
+    let mut i = current;
+    while i > 0 && lines[i - 1].trim_start().starts_with("//go:") {
+        i -= 1;
+    }
+
+    let mut has_text = false;
+    while i > 0 {
+        let Some(text) = lines[i - 1].trim_start().strip_prefix("//") else {
+            break;
+        };
+        if !text.trim().is_empty() && !text.starts_with("go:") {
+            has_text = true;
+        }
+        i -= 1;
+    }
+    has_text
+}
+
+fn check_visibility(name: &str, visibility: &Visibility) -> bool {
+    match visibility {
+        Visibility::Public | Visibility::Exported => is_exported(name),
+        Visibility::All => true,
+    }
+}
+
+/// Exported identifiers start with an uppercase letter
+fn is_exported(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_uppercase())
+}
+
+// ============================================================================
+// Individual element checkers
+// ============================================================================
+
+fn check_struct(line: &str, line_num: usize, has_doc: bool, config: &GoDocConfig) -> Option<DocViolation> {
+    let visibility = config.struct_.as_ref()?;
+    let name = type_name(line, "struct")?;
+    if !check_visibility(&name, visibility) || has_doc {
+        return None;
+    }
+    Some(DocViolation { line: line_num, kind: DocKind::Struct, name })
+}
+
+fn check_interface(line: &str, line_num: usize, has_doc: bool, config: &GoDocConfig) -> Option<DocViolation> {
+    let visibility = config.interface.as_ref()?;
+    let name = type_name(line, "interface")?;
+    if !check_visibility(&name, visibility) || has_doc {
+        return None;
+    }
+    Some(DocViolation { line: line_num, kind: DocKind::Interface, name })
+}
+
+fn check_func(line: &str, line_num: usize, has_doc: bool, config: &GoDocConfig) -> Option<DocViolation> {
+    let visibility = config.func.as_ref()?;
+    let rest = line.strip_prefix("func ")?;
+
+    // Skip the receiver of a method: func (s *Server) Start()
+    let rest = if rest.starts_with('(') { rest[rest.find(')')? + 1..].trim_start() } else { rest };
+    let name = extract_identifier(rest);
+    if name.is_empty() || !check_visibility(&name, visibility) || has_doc {
+        return None;
+    }
+    Some(DocViolation { line: line_num, kind: DocKind::Func, name })
+}
+
+fn check_const(line: &str, line_num: usize, has_doc: bool, config: &GoDocConfig) -> Option<DocViolation> {
+    let visibility = config.const_.as_ref()?;
+    let name = extract_identifier(line.strip_prefix("const ")?);
+    if name.is_empty() || !check_visibility(&name, visibility) || has_doc {
+        return None;
+    }
+    Some(DocViolation { line: line_num, kind: DocKind::Const, name })
+}
+
+/// Check each entry of `const ( ... )` and return the line index after the group
+fn check_const_group(
+    lines: &[&str],
+    start: usize,
+    group_has_doc: bool,
+    config: &GoDocConfig,
+    violations: &mut Vec<DocViolation>,
+) -> usize {
+    let mut i = start + 1;
+    while i < lines.len() {
+        let entry = lines[i].trim();
+        if entry.starts_with(')') {
+            return i + 1;
+        }
+        if let Some(visibility) = config.const_.as_ref() {
+            let name = extract_identifier(entry);
+            if !name.is_empty() && check_visibility(&name, visibility) && !group_has_doc && !check_doc_before(lines, i)
+            {
+                violations.push(DocViolation { line: i + 1, kind: DocKind::Const, name });
+            }
+        }
+        i += 1;
+    }
+    lines.len()
+}
+
+/// Name of `type Name <keyword>` declarations (type parameters are allowed)
+fn type_name(line: &str, keyword: &str) -> Option<String> {
+    let rest = line.strip_prefix("type ")?.trim_start();
+    let name = extract_identifier(rest);
+    let after = rest[name.len()..].trim_start();
+    let after = if after.starts_with('[') { after[after.find(']')? + 1..].trim_start() } else { after };
+    if name.is_empty() || !after.starts_with(keyword) {
+        return None;
+    }
+    Some(name)
+}
+
+fn extract_identifier(s: &str) -> String {
+    s.trim().chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect()
+}
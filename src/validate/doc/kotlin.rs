@@ -1,10 +1,11 @@
-use super::{DocKind, DocViolation, KotlinDocConfig};
+use super::{is_empty_doc, retain_top_level, DocKind, DocViolation, KotlinDocConfig};
 use crate::rule::parser::Visibility;
 
 /// Validate Kotlin file for missing KDoc
 pub fn validate(content: &str, config: &KotlinDocConfig) -> Vec<DocViolation> {
     let mut violations = Vec::new();
     let lines: Vec<&str> = content.lines().collect();
+    let mut scopes = Scopes::default();
     let mut i = 0;
 
     while i < lines.len() {
@@ -22,8 +23,12 @@ pub fn validate(content: &str, config: &KotlinDocConfig) -> Vec<DocViolation> {
             continue;
         }
 
+        // Properties are checked only where they are members (not locals or constructor parameters)
+        let is_member = scopes.is_member_position(config.include_companion);
+        scopes.update(line);
+
         // Check if there's a KDoc before this line
-        let has_kdoc = check_kdoc_before(&lines, i);
+        let has_kdoc = check_kdoc_before(&lines, i, config.require_non_empty);
 
         // Check each element type independently (order matters for specificity)
         if let Some(v) = check_enum_class(line, i + 1, has_kdoc, config) {
@@ -48,14 +53,104 @@ pub fn validate(content: &str, config: &KotlinDocConfig) -> Vec<DocViolation> {
             violations.push(v);
         } else if let Some(v) = check_function(line, i + 1, has_kdoc, config) {
             violations.push(v);
+        } else if let Some(v) = check_property(line, i + 1, has_kdoc, is_member, config) {
+            violations.push(v);
         }
 
         i += 1;
     }
 
+    if config.top_level_only {
+        retain_top_level(&mut violations, content);
+    }
+
     violations
 }
 
+/// Kind of a `{ }` block
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Scope {
+    /// Body of a class, interface or (non-companion) object
+    Type,
+    /// Body of a companion object
+    Companion,
+    /// Function body, initializer, accessor, lambda or control flow
+    Code,
+}
+
+/// Blocks and parentheses open at the current line
+#[derive(Default)]
+struct Scopes {
+    stack: Vec<Scope>,
+    paren_depth: usize,
+    /// Kind of the next `{`, set by the declaration it belongs to
+    pending: Option<Scope>,
+}
+
+impl Scopes {
+    /// Returns true if a declaration at the current line is a member of the file, a type or
+    /// (with `include_companion`) a companion object
+    fn is_member_position(&self, include_companion: bool) -> bool {
+        self.paren_depth == 0
+            && self.stack.iter().all(|scope| match scope {
+                Scope::Type => true,
+                Scope::Companion => include_companion,
+                Scope::Code => false,
+            })
+    }
+
+    /// Track the braces and parentheses of `line` (string literals and line comments are ignored)
+    fn update(&mut self, line: &str) {
+        // Continuation lines (e.g. constructor parameters) belong to the declaration that opened the parentheses
+        if self.paren_depth == 0 {
+            self.pending = Some(declared_scope(line));
+        }
+        let mut quote: Option<char> = None;
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            if let Some(q) = quote {
+                if c == '\\' {
+                    chars.next();
+                } else if c == q {
+                    quote = None;
+                }
+                continue;
+            }
+            match c {
+                '"' | '\'' => quote = Some(c),
+                '/' if chars.peek() == Some(&'/') => break,
+                '(' => self.paren_depth += 1,
+                ')' => self.paren_depth = self.paren_depth.saturating_sub(1),
+                '{' => self.stack.push(self.pending.take().unwrap_or(Scope::Code)),
+                '}' => {
+                    self.stack.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Kind of the block opened by the declaration on `line`
+fn declared_scope(line: &str) -> Scope {
+    if line.contains("companion object") {
+        return Scope::Companion;
+    }
+    // `val x = object : Runnable {` is an expression, not a member type
+    let declaration = line.split('=').next().unwrap_or(line);
+    if declaration.contains("fun ") {
+        return Scope::Code;
+    }
+    let is_type = declaration
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|word| matches!(word, "class" | "interface" | "object"));
+    if is_type {
+        Scope::Type
+    } else {
+        Scope::Code
+    }
+}
+
 fn skip_block_comment(lines: &[&str], start: usize) -> usize {
     let mut i = start;
     while i < lines.len() {
@@ -67,7 +162,8 @@ fn skip_block_comment(lines: &[&str], start: usize) -> usize {
     lines.len()
 }
 
-fn check_kdoc_before(lines: &[&str], current: usize) -> bool {
+/// Returns true if a KDoc precedes the line (and has text when `require_non_empty` is set)
+fn check_kdoc_before(lines: &[&str], current: usize, require_non_empty: bool) -> bool {
     if current == 0 {
         return false;
     }
@@ -86,16 +182,18 @@ fn check_kdoc_before(lines: &[&str], current: usize) -> bool {
 
     let line = lines[i].trim();
 
+    let end = i;
+
     // Check for end of KDoc
     if line.ends_with("*/") {
         if line.starts_with("/**") {
-            return true;
+            return !require_non_empty || !is_empty_doc(&lines[i..=end]);
         }
         while i > 0 {
             i -= 1;
             let prev = lines[i].trim();
             if prev.starts_with("/**") {
-                return true;
+                return !require_non_empty || !is_empty_doc(&lines[i..=end]);
             }
             if prev.starts_with("/*") && !prev.starts_with("/**") {
                 return false;
@@ -113,7 +211,7 @@ fn is_comment_line(line: &str) -> bool {
 fn check_visibility(line: &str, visibility: &Visibility) -> bool {
     let is_public = !line.contains("private ") && !line.contains("internal ") && !line.contains("protected ");
     match visibility {
-        Visibility::Public => is_public,
+        Visibility::Public | Visibility::Exported => is_public,
         Visibility::All => true,
     }
 }
@@ -375,6 +473,62 @@ fn check_function(line: &str, line_num: usize, has_kdoc: bool, config: &KotlinDo
     Some(DocViolation { line: line_num, kind: DocKind::Function, name })
 }
 
+/// Modifiers that may precede `val` / `var` in a property declaration
+const PROPERTY_MODIFIERS: &[&str] = &[
+    "public",
+    "private",
+    "internal",
+    "protected",
+    "override",
+    "open",
+    "final",
+    "abstract",
+    "const",
+    "lateinit",
+    "external",
+    "actual",
+    "expect",
+];
+
+fn check_property(
+    line: &str,
+    line_num: usize,
+    has_kdoc: bool,
+    is_member: bool,
+    config: &KotlinDocConfig,
+) -> Option<DocViolation> {
+    let visibility = config.property.as_ref()?;
+
+    if !is_member {
+        return None;
+    }
+
+    let mut words =
+        line.split_whitespace().skip_while(|word| word.starts_with('@') || PROPERTY_MODIFIERS.contains(word));
+    if !matches!(words.next(), Some("val" | "var")) {
+        return None;
+    }
+
+    if !check_visibility(line, visibility) {
+        return None;
+    }
+
+    if has_kdoc {
+        return None;
+    }
+
+    // Skip type parameters and the receiver of extension properties: `val <T> List<T>.second`
+    let target = words.find(|word| !word.starts_with('<'))?;
+    let target = target.split(':').next().unwrap_or(target);
+    let name: String = target.rsplit('.').next()?.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+
+    if name.is_empty() {
+        return None;
+    }
+
+    Some(DocViolation { line: line_num, kind: DocKind::Property, name })
+}
+
 fn extract_class_name(line: &str, keyword: &str) -> String {
     let parts: Vec<&str> = line.split(keyword).collect();
     if parts.len() < 2 {
@@ -1,8 +1,17 @@
+pub mod csharp;
+pub mod generic;
+pub mod go;
 pub mod kotlin;
 pub mod php;
+pub mod python;
 pub mod rust;
+pub mod swift;
+pub mod typescript;
 
 use crate::rule::parser::Visibility;
+use crate::validate::comment::custom::CustomCommentSyntax;
+use crate::validate::comment::is_empty_or_decoration;
+use regex::Regex;
 
 /// A missing doc violation
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -30,6 +39,7 @@ pub enum DocKind {
     ValueClass,
     AnnotationClass,
     Typealias,
+    Property,
     // Rust types (additional)
     Struct,
     TypeAlias,
@@ -37,6 +47,18 @@ pub enum DocKind {
     Fn,
     MacroRules,
     Mod,
+    // Python types (additional)
+    Module,
+    // Go types (additional)
+    Func,
+    Const,
+    Static,
+    // Swift types (additional)
+    Protocol,
+    // C# types (additional)
+    Method,
+    // Generic (require_doc)
+    Declaration,
 }
 
 impl std::fmt::Display for DocKind {
@@ -55,16 +77,88 @@ impl std::fmt::Display for DocKind {
             DocKind::ValueClass => write!(f, "value class"),
             DocKind::AnnotationClass => write!(f, "annotation class"),
             DocKind::Typealias => write!(f, "typealias"),
+            DocKind::Property => write!(f, "property"),
             DocKind::Struct => write!(f, "struct"),
             DocKind::TypeAlias => write!(f, "type"),
             DocKind::Union => write!(f, "union"),
             DocKind::Fn => write!(f, "fn"),
             DocKind::MacroRules => write!(f, "macro_rules"),
             DocKind::Mod => write!(f, "mod"),
+            DocKind::Module => write!(f, "module"),
+            DocKind::Func => write!(f, "func"),
+            DocKind::Const => write!(f, "const"),
+            DocKind::Static => write!(f, "static"),
+            DocKind::Protocol => write!(f, "protocol"),
+            DocKind::Method => write!(f, "method"),
+            DocKind::Declaration => write!(f, "declaration"),
         }
     }
 }
 
+/// Returns true if every line of a doc comment is empty or decoration (e.g. `///`, `/** */`, `*`)
+pub(crate) fn is_empty_doc(doc_lines: &[&str]) -> bool {
+    doc_lines.iter().all(|line| {
+        let text = line.trim();
+        let text = ["///", "//!", "/**", "/*!"].iter().find_map(|p| text.strip_prefix(p)).unwrap_or(text);
+        let text = text.strip_suffix("*/").unwrap_or(text);
+        is_empty_or_decoration(text)
+    })
+}
+
+/// Returns the brace depth at the start of each line
+/// Braces inside string literals, char literals and comments are not counted
+pub(crate) fn brace_depths(content: &str) -> Vec<usize> {
+    let mut depths = Vec::new();
+    let mut depth = 0usize;
+    let mut in_block_comment = false;
+
+    for line in content.lines() {
+        depths.push(depth);
+        let chars: Vec<char> = line.chars().collect();
+        let mut quote: Option<char> = None;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+            if in_block_comment {
+                if c == '*' && next == Some('/') {
+                    in_block_comment = false;
+                    i += 1;
+                }
+            } else if let Some(q) = quote {
+                if c == '\\' {
+                    i += 1;
+                } else if c == q {
+                    quote = None;
+                }
+            } else {
+                match c {
+                    '/' if next == Some('/') => break,
+                    '/' if next == Some('*') => {
+                        in_block_comment = true;
+                        i += 1;
+                    }
+                    '"' | '`' => quote = Some(c),
+                    // Only one-char literals are skipped so that Rust lifetimes are left alone
+                    '\'' if chars.get(i + 2) == Some(&'\'') => i += 2,
+                    '{' => depth += 1,
+                    '}' => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+            }
+            i += 1;
+        }
+    }
+
+    depths
+}
+
+/// Drops violations whose declaration line is nested inside braces (for `top_level_only`)
+pub(crate) fn retain_top_level(violations: &mut Vec<DocViolation>, content: &str) {
+    let depths = brace_depths(content);
+    violations.retain(|v| depths.get(v.line.wrapping_sub(1)).copied().unwrap_or(0) == 0);
+}
+
 /// Config for PHP doc checks
 #[derive(Debug, Clone, Default)]
 pub struct PhpDocConfig {
@@ -73,6 +167,9 @@ pub struct PhpDocConfig {
     pub trait_: Option<Visibility>,
     pub enum_: Option<Visibility>,
     pub function: Option<Visibility>,
+    pub require_non_empty: bool,
+    /// Check only declarations that are not nested in a block
+    pub top_level_only: bool,
 }
 
 /// Config for Kotlin doc checks
@@ -89,6 +186,13 @@ pub struct KotlinDocConfig {
     pub annotation_class: Option<Visibility>,
     pub typealias: Option<Visibility>,
     pub function: Option<Visibility>,
+    /// `val` / `var` members of the file or a type (not locals or constructor parameters)
+    pub property: Option<Visibility>,
+    /// Check properties of companion objects too
+    pub include_companion: bool,
+    pub require_non_empty: bool,
+    /// Check only declarations that are not nested in a block
+    pub top_level_only: bool,
 }
 
 /// Config for Rust doc checks
@@ -100,6 +204,78 @@ pub struct RustDocConfig {
     pub type_alias: Option<Visibility>,
     pub union: Option<Visibility>,
     pub fn_: Option<Visibility>,
+    pub impl_fn: Option<Visibility>,
     pub macro_rules: Option<Visibility>,
     pub mod_: Option<Visibility>,
+    pub const_: Option<Visibility>,
+    pub static_: Option<Visibility>,
+    pub require_non_empty: bool,
+    /// Check only declarations that are not nested in a block
+    pub top_level_only: bool,
+}
+
+/// Config for Python doc checks
+#[derive(Debug, Clone, Default)]
+pub struct PythonDocConfig {
+    pub class: Option<Visibility>,
+    pub function: Option<Visibility>,
+    pub module: Option<Visibility>,
+    /// Check only declarations that are not nested in a block
+    pub top_level_only: bool,
+}
+
+/// Config for TypeScript doc checks
+#[derive(Debug, Clone, Default)]
+pub struct TypeScriptDocConfig {
+    pub class: Option<Visibility>,
+    pub interface: Option<Visibility>,
+    pub enum_: Option<Visibility>,
+    pub type_alias: Option<Visibility>,
+    pub function: Option<Visibility>,
+    /// Check only declarations that are not nested in a block
+    pub top_level_only: bool,
+}
+
+/// Config for Go doc checks
+#[derive(Debug, Clone, Default)]
+pub struct GoDocConfig {
+    pub struct_: Option<Visibility>,
+    pub interface: Option<Visibility>,
+    pub func: Option<Visibility>,
+    pub const_: Option<Visibility>,
+    /// Check only declarations that are not nested in a block
+    pub top_level_only: bool,
+}
+
+/// Config for Swift doc checks
+#[derive(Debug, Clone, Default)]
+pub struct SwiftDocConfig {
+    pub class: Option<Visibility>,
+    pub struct_: Option<Visibility>,
+    pub enum_: Option<Visibility>,
+    pub protocol: Option<Visibility>,
+    pub func: Option<Visibility>,
+    pub require_non_empty: bool,
+    /// Check only declarations that are not nested in a block
+    pub top_level_only: bool,
+}
+
+/// Config for C# doc checks
+#[derive(Debug, Clone, Default)]
+pub struct CSharpDocConfig {
+    pub class: Option<Visibility>,
+    pub interface: Option<Visibility>,
+    pub struct_: Option<Visibility>,
+    pub enum_: Option<Visibility>,
+    pub method: Option<Visibility>,
+    pub require_non_empty: bool,
+    /// Check only declarations that are not nested in a block
+    pub top_level_only: bool,
+}
+
+/// Config for generic doc checks (require_doc)
+#[derive(Debug, Clone)]
+pub struct GenericDocConfig {
+    pub syntax: CustomCommentSyntax,
+    pub declarations: Vec<Regex>,
 }
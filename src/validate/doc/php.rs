@@ -1,4 +1,4 @@
-use super::{DocKind, DocViolation, PhpDocConfig};
+use super::{is_empty_doc, retain_top_level, DocKind, DocViolation, PhpDocConfig};
 use crate::rule::parser::Visibility;
 
 /// Validate PHP file for missing PHPDoc
@@ -23,7 +23,7 @@ pub fn validate(content: &str, config: &PhpDocConfig) -> Vec<DocViolation> {
         }
 
         // Check if there's a PHPDoc before this line
-        let has_phpdoc = check_phpdoc_before(&lines, i);
+        let has_phpdoc = check_phpdoc_before(&lines, i, config.require_non_empty);
 
         // Check each element type independently
         if let Some(v) = check_class(line, i + 1, has_phpdoc, config) {
@@ -41,6 +41,10 @@ pub fn validate(content: &str, config: &PhpDocConfig) -> Vec<DocViolation> {
         i += 1;
     }
 
+    if config.top_level_only {
+        retain_top_level(&mut violations, content);
+    }
+
     violations
 }
 
@@ -55,7 +59,8 @@ fn skip_block_comment(lines: &[&str], start: usize) -> usize {
     lines.len()
 }
 
-fn check_phpdoc_before(lines: &[&str], current: usize) -> bool {
+/// Returns true if a PHPDoc precedes the line (and has text when `require_non_empty` is set)
+fn check_phpdoc_before(lines: &[&str], current: usize, require_non_empty: bool) -> bool {
     if current == 0 {
         return false;
     }
@@ -75,18 +80,20 @@ fn check_phpdoc_before(lines: &[&str], current: usize) -> bool {
 
     let line = lines[i].trim();
 
+    let end = i;
+
     // Check for end of PHPDoc on this line
     if line.ends_with("*/") {
         // Could be single-line: /** comment */
         if line.starts_with("/**") {
-            return true;
+            return !require_non_empty || !is_empty_doc(&lines[i..=end]);
         }
         // Multi-line PHPDoc - look for start
         while i > 0 {
             i -= 1;
             let prev = lines[i].trim();
             if prev.starts_with("/**") {
-                return true;
+                return !require_non_empty || !is_empty_doc(&lines[i..=end]);
             }
             if prev.starts_with("/*") && !prev.starts_with("/**") {
                 return false; // Regular comment, not PHPDoc
@@ -236,7 +243,7 @@ fn is_comment_line(line: &str) -> bool {
 fn check_visibility(before: &str, visibility: &Visibility) -> bool {
     let is_public = before.contains("public");
     match visibility {
-        Visibility::Public => is_public,
+        Visibility::Public | Visibility::Exported => is_public,
         Visibility::All => true,
     }
 }
@@ -0,0 +1,175 @@
+use super::{DocKind, DocViolation, PythonDocConfig};
+use crate::rule::parser::Visibility;
+
+/// Validate Python file for missing docstrings
+/// Visibility is inferred from a leading underscore in the name
+pub fn validate(content: &str, module_name: &str, config: &PythonDocConfig) -> Vec<DocViolation> {
+    let mut violations = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+
+    if let Some(v) = check_module(&lines, module_name, config) {
+        violations.push(v);
+    }
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i].trim();
+
+        // Skip docstrings and other triple-quoted strings
+        if let Some(quote) = triple_quote_start(line) {
+            i = skip_triple_quoted(&lines, i, quote);
+            continue;
+        }
+
+        if let Some(v) = check_class(&lines, i, config) {
+            violations.push(v);
+        } else if let Some(v) = check_function(&lines, i, config) {
+            violations.push(v);
+        }
+
+        i += 1;
+    }
+
+    // Python nests by indentation instead of braces
+    if config.top_level_only {
+        violations.retain(|v| !lines.get(v.line - 1).is_some_and(|l| l.starts_with(char::is_whitespace)));
+    }
+
+    violations
+}
+
+/// Check for module docstring (first statement of the file)
+fn check_module(lines: &[&str], module_name: &str, config: &PythonDocConfig) -> Option<DocViolation> {
+    let visibility = config.module.as_ref()?;
+
+    if !check_visibility(module_name, visibility) {
+        return None;
+    }
+
+    // Skip blank lines and comments (shebang, encoding declaration)
+    let first = lines.iter().position(|l| {
+        let trimmed = l.trim();
+        !trimmed.is_empty() && !trimmed.starts_with('#')
+    })?;
+
+    if triple_quote_start(lines[first].trim()).is_some() {
+        return None;
+    }
+
+    Some(DocViolation { line: 1, kind: DocKind::Module, name: module_name.to_string() })
+}
+
+/// Check for class declaration
+fn check_class(lines: &[&str], index: usize, config: &PythonDocConfig) -> Option<DocViolation> {
+    let visibility = config.class.as_ref()?;
+
+    let after = lines[index].trim().strip_prefix("class ")?;
+    let name = extract_identifier(after);
+
+    if name.is_empty() || !check_visibility(&name, visibility) {
+        return None;
+    }
+
+    if has_docstring(lines, index) {
+        return None;
+    }
+
+    Some(DocViolation { line: index + 1, kind: DocKind::Class, name })
+}
+
+/// Check for function/method declaration (def / async def)
+fn check_function(lines: &[&str], index: usize, config: &PythonDocConfig) -> Option<DocViolation> {
+    let visibility = config.function.as_ref()?;
+
+    let line = lines[index].trim();
+    let after = line.strip_prefix("def ").or_else(|| line.strip_prefix("async def "))?;
+    let name = extract_identifier(after);
+
+    // Skip dunder methods (__init__, __repr__, ...); they follow the protocol they implement
+    if name.is_empty() || (name.starts_with("__") && name.ends_with("__")) {
+        return None;
+    }
+
+    if !check_visibility(&name, visibility) {
+        return None;
+    }
+
+    if has_docstring(lines, index) {
+        return None;
+    }
+
+    Some(DocViolation { line: index + 1, kind: DocKind::Function, name })
+}
+
+/// Returns true if the body of the declaration starting at `index` begins with a docstring
+fn has_docstring(lines: &[&str], index: usize) -> bool {
+    // Find the colon that ends the header (signatures may span multiple lines)
+    let mut depth = 0i32;
+    for (i, line) in lines.iter().enumerate().skip(index) {
+        let code = line.split('#').next().unwrap_or("");
+        for (pos, ch) in code.char_indices() {
+            match ch {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                ':' if depth == 0 => {
+                    // One-line body: `def f(): """doc"""`
+                    let rest = code[pos + 1..].trim();
+                    if !rest.is_empty() {
+                        return triple_quote_start(rest).is_some();
+                    }
+                    return next_non_blank(lines, i + 1).is_some_and(|l| triple_quote_start(l).is_some());
+                }
+                _ => {}
+            }
+        }
+    }
+    false
+}
+
+fn next_non_blank<'a>(lines: &[&'a str], start: usize) -> Option<&'a str> {
+    lines.iter().skip(start).map(|l| l.trim()).find(|l| !l.is_empty())
+}
+
+/// Returns the triple quote if the line starts with a (possibly prefixed) triple-quoted string
+fn triple_quote_start(line: &str) -> Option<&'static str> {
+    let unprefixed = line.trim_start_matches(['r', 'R', 'u', 'U', 'b', 'B', 'f', 'F']);
+    if unprefixed.len() + 2 < line.len() {
+        return None;
+    }
+    if unprefixed.starts_with("\"\"\"") {
+        Some("\"\"\"")
+    } else if unprefixed.starts_with("'''") {
+        Some("'''")
+    } else {
+        None
+    }
+}
+
+/// Returns the index of the line after the closing triple quote
+fn skip_triple_quoted(lines: &[&str], start: usize, quote: &str) -> usize {
+    let first = lines[start].trim();
+    let open = first.find(quote).unwrap_or(0) + quote.len();
+    if first[open..].contains(quote) {
+        return start + 1;
+    }
+    let mut i = start + 1;
+    while i < lines.len() {
+        if lines[i].contains(quote) {
+            return i + 1;
+        }
+        i += 1;
+    }
+    lines.len()
+}
+
+fn check_visibility(name: &str, visibility: &Visibility) -> bool {
+    let is_public = !name.starts_with('_') || (name.starts_with("__") && name.ends_with("__"));
+    match visibility {
+        Visibility::Public | Visibility::Exported => is_public,
+        Visibility::All => true,
+    }
+}
+
+fn extract_identifier(s: &str) -> String {
+    s.trim().chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect()
+}
@@ -1,10 +1,13 @@
-use super::{DocKind, DocViolation, RustDocConfig};
+use super::{brace_depths, is_empty_doc, retain_top_level, DocKind, DocViolation, RustDocConfig};
 use crate::rule::parser::Visibility;
 
 /// Validate Rust file for missing RustDoc
 pub fn validate(content: &str, config: &RustDocConfig) -> Vec<DocViolation> {
     let mut violations = Vec::new();
     let lines: Vec<&str> = content.lines().collect();
+    let depths = brace_depths(content);
+    let in_test_module = test_module_lines(&lines, &depths);
+    let in_impl = impl_body_lines(&lines, &depths);
     let mut i = 0;
 
     while i < lines.len() {
@@ -16,14 +19,18 @@ pub fn validate(content: &str, config: &RustDocConfig) -> Vec<DocViolation> {
             continue;
         }
 
-        // Check for block comment (skip non-doc comments)
-        if line.starts_with("/*") && !line.starts_with("/**") && !line.starts_with("/*!") {
+        // Skip block comments (doc comments are looked up from the declaration line)
+        if line.starts_with("/*") {
             i = skip_block_comment(&lines, i);
             continue;
         }
 
         // Check if there's a RustDoc before this line
-        let has_rustdoc = check_rustdoc_before(&lines, i);
+        let has_rustdoc = check_rustdoc_before(&lines, i, config.require_non_empty);
+
+        // A fn signature may wrap its parameters and where clause onto later lines
+        let signature_end = if is_fn_start(line) { find_signature_end(&lines, i) } else { i };
+        let signature = lines[i..=signature_end].iter().map(|l| l.trim()).collect::<Vec<_>>().join(" ");
 
         // Check each element type independently
         if let Some(v) = check_struct(line, i + 1, has_rustdoc, config) {
@@ -36,7 +43,11 @@ pub fn validate(content: &str, config: &RustDocConfig) -> Vec<DocViolation> {
             violations.push(v);
         } else if let Some(v) = check_union(line, i + 1, has_rustdoc, config) {
             violations.push(v);
-        } else if let Some(v) = check_fn(line, i + 1, has_rustdoc, config) {
+        } else if let Some(v) = check_fn(&signature, i + 1, has_rustdoc, in_impl[i], config) {
+            violations.push(v);
+        } else if let Some(v) = check_const(line, i + 1, has_rustdoc, config) {
+            violations.push(v);
+        } else if let Some(v) = check_static(line, i + 1, has_rustdoc, config) {
             violations.push(v);
         } else if let Some(v) = check_macro_rules(line, i + 1, has_rustdoc, config) {
             violations.push(v);
@@ -44,12 +55,91 @@ pub fn validate(content: &str, config: &RustDocConfig) -> Vec<DocViolation> {
             violations.push(v);
         }
 
-        i += 1;
+        i = signature_end + 1;
+    }
+
+    violations.retain(|v| !in_test_module[v.line - 1]);
+    if config.top_level_only {
+        retain_top_level(&mut violations, content);
     }
 
     violations
 }
 
+/// Marks the lines inside `mod tests { ... }` blocks, whose helpers need no RustDoc
+fn test_module_lines(lines: &[&str], depths: &[usize]) -> Vec<bool> {
+    let mut marks = vec![false; lines.len()];
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i].trim();
+        let line = line.strip_prefix("pub ").unwrap_or(line);
+        if line.strip_prefix("mod tests").is_some_and(|rest| rest.trim() == "{") {
+            let mut j = i + 1;
+            while j < lines.len() && depths[j] > depths[i] {
+                marks[j] = true;
+                j += 1;
+            }
+            i = j;
+        } else {
+            i += 1;
+        }
+    }
+    marks
+}
+
+/// Marks the lines directly inside `impl ... { ... }` blocks, whose fns are checked as methods
+fn impl_body_lines(lines: &[&str], depths: &[usize]) -> Vec<bool> {
+    let mut marks = vec![false; lines.len()];
+    for (i, line) in lines.iter().enumerate() {
+        if !is_impl_start(line.trim()) {
+            continue;
+        }
+        // The header may wrap onto later lines (e.g. a where clause) before the body `{`
+        let Some(open) = (i..lines.len()).find(|&k| lines[k].contains('{')) else {
+            continue;
+        };
+        let mut j = open + 1;
+        while j < lines.len() && depths[j] > depths[i] {
+            if depths[j] == depths[i] + 1 {
+                marks[j] = true;
+            }
+            j += 1;
+        }
+    }
+    marks
+}
+
+/// Returns true if the line starts an `impl` block (inherent or trait impl)
+fn is_impl_start(line: &str) -> bool {
+    let line = line.strip_prefix("unsafe ").unwrap_or(line);
+    line.starts_with("impl ") || line.starts_with("impl<")
+}
+
+/// Returns true if the line starts a fn declaration (the name may be on the next line)
+fn is_fn_start(line: &str) -> bool {
+    line.contains("fn ") || line == "fn" || line.ends_with(" fn")
+}
+
+/// Returns the index of the line where the fn signature ends (the body `{` or a trailing `;`)
+/// Falls back to `start` when the end is not found
+fn find_signature_end(lines: &[&str], start: usize) -> usize {
+    let mut depth = 0i32;
+    for (i, line) in lines.iter().enumerate().skip(start) {
+        for c in line.chars() {
+            match c {
+                '(' | '[' => depth += 1,
+                ')' | ']' => depth -= 1,
+                '{' if depth <= 0 => return i,
+                _ => {}
+            }
+        }
+        if depth <= 0 && line.trim_end().ends_with(';') {
+            return i;
+        }
+    }
+    start
+}
+
 fn skip_block_comment(lines: &[&str], start: usize) -> usize {
     let mut i = start;
     while i < lines.len() {
@@ -61,41 +151,56 @@ fn skip_block_comment(lines: &[&str], start: usize) -> usize {
     lines.len()
 }
 
-fn check_rustdoc_before(lines: &[&str], current: usize) -> bool {
+/// Returns true if a doc comment precedes the line (and has text when `require_non_empty` is set)
+fn check_rustdoc_before(lines: &[&str], current: usize, require_non_empty: bool) -> bool {
     if current == 0 {
         return false;
     }
 
     let mut i = current - 1;
 
-    // Skip attributes
+    // Skip attributes (including ones split across lines)
     while i > 0 {
         let line = lines[i].trim();
         if line.starts_with("#[") || line.starts_with("#![") {
             i -= 1;
             continue;
         }
+        if line.ends_with(']') {
+            if let Some(start) = find_attribute_start(lines, i) {
+                if start == 0 {
+                    return false;
+                }
+                i = start - 1;
+                continue;
+            }
+        }
         break;
     }
 
     let line = lines[i].trim();
 
+    let end = i;
+
     // Check for /// doc comment
     if line.starts_with("///") {
-        return true;
+        while i > 0 && lines[i - 1].trim().starts_with("///") {
+            i -= 1;
+        }
+        return !require_non_empty || !is_empty_doc(&lines[i..=end]);
     }
 
     // Check for /** doc comment */
     if line.ends_with("*/") {
         if line.starts_with("/**") {
-            return true;
+            return !require_non_empty || !is_empty_doc(&lines[i..=end]);
         }
         // Multi-line doc comment
         while i > 0 {
             i -= 1;
             let prev = lines[i].trim();
             if prev.starts_with("/**") {
-                return true;
+                return !require_non_empty || !is_empty_doc(&lines[i..=end]);
             }
             if prev.starts_with("/*") && !prev.starts_with("/**") {
                 return false;
@@ -106,21 +211,30 @@ fn check_rustdoc_before(lines: &[&str], current: usize) -> bool {
     false
 }
 
-fn is_comment_line(line: &str) -> bool {
-    // Skip regular comments but NOT doc comments (///, //!, /**, /*!)
-    if line.starts_with("///") || line.starts_with("//!") {
-        return false;
-    }
-    if line.starts_with("/**") || line.starts_with("/*!") {
-        return false;
+/// Returns the index of the `#[` line that opens the attribute ending at `end`
+fn find_attribute_start(lines: &[&str], end: usize) -> Option<usize> {
+    let mut depth = 0i32;
+    for i in (0..=end).rev() {
+        let line = lines[i].trim();
+        depth += line.matches(']').count() as i32 - line.matches('[').count() as i32;
+        if depth <= 0 {
+            return (line.starts_with("#[") || line.starts_with("#![")).then_some(i);
+        }
     }
-    line.starts_with("//") || line.starts_with("/*") || line.starts_with("*")
+    None
+}
+
+fn is_comment_line(line: &str) -> bool {
+    // Doc comments (///, //!) are looked up from the declaration line, so they are skipped too
+    line.starts_with("//") || line.starts_with("*")
 }
 
 fn check_visibility(line: &str, visibility: &Visibility) -> bool {
     let is_public = line.contains("pub ") || line.contains("pub(");
     match visibility {
         Visibility::Public => is_public,
+        // pub(crate), pub(super) and pub(in ...) are not part of the external API
+        Visibility::Exported => line.starts_with("pub ") || line.contains(" pub "),
         Visibility::All => true,
     }
 }
@@ -243,8 +357,15 @@ fn check_union(line: &str, line_num: usize, has_rustdoc: bool, config: &RustDocC
     Some(DocViolation { line: line_num, kind: DocKind::Union, name })
 }
 
-fn check_fn(line: &str, line_num: usize, has_rustdoc: bool, config: &RustDocConfig) -> Option<DocViolation> {
-    let visibility = config.fn_.as_ref()?;
+fn check_fn(
+    line: &str,
+    line_num: usize,
+    has_rustdoc: bool,
+    in_impl: bool,
+    config: &RustDocConfig,
+) -> Option<DocViolation> {
+    // Methods are checked by impl_fn, free functions by fn
+    let visibility = if in_impl { config.impl_fn.as_ref()? } else { config.fn_.as_ref()? };
 
     if !line.contains("fn ") {
         return None;
@@ -262,6 +383,57 @@ fn check_fn(line: &str, line_num: usize, has_rustdoc: bool, config: &RustDocConf
     Some(DocViolation { line: line_num, kind: DocKind::Fn, name })
 }
 
+fn check_const(line: &str, line_num: usize, has_rustdoc: bool, config: &RustDocConfig) -> Option<DocViolation> {
+    let visibility = config.const_.as_ref()?;
+
+    // Exclude const fn (checked as fn) and const generics in fn signatures
+    if is_fn_start(line) || !is_item_declaration(line, "const ") {
+        return None;
+    }
+
+    if !check_visibility(line, visibility) {
+        return None;
+    }
+
+    if has_rustdoc {
+        return None;
+    }
+
+    let name = extract_name_after(line, "const ");
+    Some(DocViolation { line: line_num, kind: DocKind::Const, name })
+}
+
+fn check_static(line: &str, line_num: usize, has_rustdoc: bool, config: &RustDocConfig) -> Option<DocViolation> {
+    let visibility = config.static_.as_ref()?;
+
+    let keyword = if line.contains("static mut ") { "static mut " } else { "static " };
+    if is_fn_start(line) || !is_item_declaration(line, keyword) {
+        return None;
+    }
+
+    if !check_visibility(line, visibility) {
+        return None;
+    }
+
+    if has_rustdoc {
+        return None;
+    }
+
+    let name = extract_name_after(line, keyword);
+    Some(DocViolation { line: line_num, kind: DocKind::Static, name })
+}
+
+/// Returns true if the line declares `keyword NAME:` (const / static items)
+fn is_item_declaration(line: &str, keyword: &str) -> bool {
+    let name = extract_name_after(line, keyword);
+    if name.is_empty() {
+        return false;
+    }
+    line.find(keyword)
+        .map(|pos| line[pos + keyword.len()..].trim_start()[name.len()..].trim_start().starts_with(':'))
+        .unwrap_or(false)
+}
+
 fn check_macro_rules(line: &str, line_num: usize, has_rustdoc: bool, config: &RustDocConfig) -> Option<DocViolation> {
     let visibility = config.macro_rules.as_ref()?;
 
@@ -272,7 +444,7 @@ fn check_macro_rules(line: &str, line_num: usize, has_rustdoc: bool, config: &Ru
     // macro_rules visibility is determined by #[macro_export] attribute, not pub
     // For simplicity, we treat all macro_rules as "public" if visibility is Public
     // and check all if visibility is All
-    if *visibility == Visibility::Public {
+    if *visibility != Visibility::All {
         // Would need to check for #[macro_export] in previous lines
         // For simplicity, skip this check - always check when visibility is All
         return None;
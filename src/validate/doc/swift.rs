@@ -0,0 +1,207 @@
+use super::{is_empty_doc, retain_top_level, DocKind, DocViolation, SwiftDocConfig};
+use crate::rule::parser::Visibility;
+
+/// Modifiers that may precede a declaration keyword
+const MODIFIERS: &[&str] = &[
+    "public",
+    "open",
+    "internal",
+    "fileprivate",
+    "private",
+    "final",
+    "static",
+    "override",
+    "required",
+    "convenience",
+    "mutating",
+    "nonmutating",
+    "indirect",
+    "lazy",
+    "dynamic",
+    "optional",
+    "nonisolated",
+];
+
+/// Validate Swift file for missing doc comments
+pub fn validate(content: &str, config: &SwiftDocConfig) -> Vec<DocViolation> {
+    let mut violations = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+
+        // Skip empty lines and comments
+        if line.is_empty() || is_comment_line(line) {
+            i += 1;
+            continue;
+        }
+
+        // Skip block comments (doc comments are looked up from the declaration line)
+        if line.starts_with("/*") {
+            i = skip_block_comment(&lines, i);
+            continue;
+        }
+
+        if let Some(declaration) = parse_declaration(line) {
+            let has_doc = check_swiftdoc_before(&lines, i, config.require_non_empty);
+            if let Some(v) = check_declaration(&declaration, i + 1, has_doc, config) {
+                violations.push(v);
+            }
+        }
+
+        i += 1;
+    }
+
+    if config.top_level_only {
+        retain_top_level(&mut violations, content);
+    }
+
+    violations
+}
+
+fn skip_block_comment(lines: &[&str], start: usize) -> usize {
+    let mut i = start;
+    while i < lines.len() {
+        if lines[i].contains("*/") {
+            return i + 1;
+        }
+        i += 1;
+    }
+    lines.len()
+}
+
+/// Returns true if a doc comment precedes the line (and has text when `require_non_empty` is set)
+fn check_swiftdoc_before(lines: &[&str], current: usize, require_non_empty: bool) -> bool {
+    if current == 0 {
+        return false;
+    }
+
+    let mut i = current - 1;
+
+    // Skip attributes (e.g. @available(iOS 15, *), @MainActor)
+    while i > 0 && lines[i].trim().starts_with('@') {
+        i -= 1;
+    }
+
+    let line = lines[i].trim();
+
+    let end = i;
+
+    // Check for /// doc comment
+    if line.starts_with("///") {
+        while i > 0 && lines[i - 1].trim().starts_with("///") {
+            i -= 1;
+        }
+        return !require_non_empty || !is_empty_doc(&lines[i..=end]);
+    }
+
+    // Check for /** doc comment */
+    if line.ends_with("*/") {
+        if line.starts_with("/**") {
+            return !require_non_empty || !is_empty_doc(&lines[i..=end]);
+        }
+        // Multi-line doc comment
+        while i > 0 {
+            i -= 1;
+            let prev = lines[i].trim();
+            if prev.starts_with("/**") {
+                return !require_non_empty || !is_empty_doc(&lines[i..=end]);
+            }
+            if prev.starts_with("/*") && !prev.starts_with("/**") {
+                return false;
+            }
+        }
+    }
+
+    false
+}
+
+fn is_comment_line(line: &str) -> bool {
+    // Doc comments (///) are looked up from the declaration line, so they are skipped too
+    line.starts_with("//") || line.starts_with("*")
+}
+
+/// A declaration line split into its modifiers, keyword and name
+struct Declaration<'a> {
+    modifiers: Vec<&'a str>,
+    keyword: &'a str,
+    name: String,
+}
+
+/// Parse `[@attr] [modifiers] keyword Name` (None if the line is not a declaration)
+fn parse_declaration(line: &str) -> Option<Declaration<'_>> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let mut modifiers = Vec::new();
+    let mut pos = 0;
+
+    while pos < words.len() {
+        let word = words[pos];
+        // Inline attributes (e.g. @objc func) and modifiers with a detail (e.g. private(set))
+        let base = word.split('(').next().unwrap_or(word);
+        // `class func` / `class var` declare type members, so `class` is a modifier there
+        let is_class_modifier = word == "class" && words.get(pos + 1).is_some_and(|next| is_member_keyword(next));
+        if word.starts_with('@') || MODIFIERS.contains(&base) || is_class_modifier {
+            modifiers.push(base);
+            pos += 1;
+            continue;
+        }
+        break;
+    }
+
+    let keyword = *words.get(pos)?;
+    if !matches!(keyword, "class" | "struct" | "enum" | "protocol" | "func") {
+        return None;
+    }
+
+    let name: String =
+        words.get(pos + 1)?.chars().take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '`').collect();
+    if name.is_empty() {
+        return None;
+    }
+
+    Some(Declaration { modifiers, keyword, name })
+}
+
+fn is_member_keyword(word: &str) -> bool {
+    matches!(word, "func" | "var" | "let" | "subscript") || MODIFIERS.contains(&word.split('(').next().unwrap_or(word))
+}
+
+/// `public` and `open` are public, no modifier means `internal`
+fn check_visibility(modifiers: &[&str], visibility: &Visibility) -> bool {
+    let is_public = modifiers.iter().any(|m| *m == "public" || *m == "open");
+    match visibility {
+        Visibility::Public | Visibility::Exported => is_public,
+        Visibility::All => true,
+    }
+}
+
+// ============================================================================
+// Individual element checkers
+// ============================================================================
+
+fn check_declaration(
+    declaration: &Declaration,
+    line_num: usize,
+    has_doc: bool,
+    config: &SwiftDocConfig,
+) -> Option<DocViolation> {
+    let (visibility, kind) = match declaration.keyword {
+        "class" => (config.class.as_ref()?, DocKind::Class),
+        "struct" => (config.struct_.as_ref()?, DocKind::Struct),
+        "enum" => (config.enum_.as_ref()?, DocKind::Enum),
+        "protocol" => (config.protocol.as_ref()?, DocKind::Protocol),
+        "func" => (config.func.as_ref()?, DocKind::Func),
+        _ => return None,
+    };
+
+    if !check_visibility(&declaration.modifiers, visibility) {
+        return None;
+    }
+
+    if has_doc {
+        return None;
+    }
+
+    Some(DocViolation { line: line_num, kind, name: declaration.name.clone() })
+}
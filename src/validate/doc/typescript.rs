@@ -0,0 +1,291 @@
+use super::{retain_top_level, DocKind, DocViolation, TypeScriptDocConfig};
+use crate::rule::parser::Visibility;
+
+/// Validate TypeScript file for missing TSDoc
+pub fn validate(content: &str, config: &TypeScriptDocConfig) -> Vec<DocViolation> {
+    let mut violations = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim();
+
+        // Skip empty lines and comments
+        if line.is_empty() || is_comment_line(line) {
+            i += 1;
+            continue;
+        }
+
+        // Check for block comment (skip non-TSDoc comments)
+        if line.starts_with("/*") && !line.starts_with("/**") {
+            i = skip_block_comment(&lines, i);
+            continue;
+        }
+
+        // Check if there's a TSDoc before this line
+        let has_tsdoc = check_tsdoc_before(&lines, i);
+        let declaration = Declaration::parse(line);
+
+        // Check each element type independently
+        if let Some(v) = check_class(&declaration, i + 1, has_tsdoc, config) {
+            violations.push(v);
+        } else if let Some(v) = check_interface(&declaration, i + 1, has_tsdoc, config) {
+            violations.push(v);
+        } else if let Some(v) = check_enum(&declaration, i + 1, has_tsdoc, config) {
+            violations.push(v);
+        } else if let Some(v) = check_type_alias(&declaration, i + 1, has_tsdoc, config) {
+            violations.push(v);
+        } else if let Some(v) = check_function(&declaration, i + 1, has_tsdoc, config) {
+            violations.push(v);
+        }
+
+        i += 1;
+    }
+
+    if config.top_level_only {
+        retain_top_level(&mut violations, content);
+    }
+
+    violations
+}
+
+/// A declaration line with leading modifiers removed
+struct Declaration<'a> {
+    exported: bool,
+    is_default: bool,
+    body: &'a str,
+}
+
+impl<'a> Declaration<'a> {
+    fn parse(line: &'a str) -> Self {
+        let mut body = line;
+        let mut exported = false;
+        let mut is_default = false;
+
+        if let Some(rest) = body.strip_prefix("export ") {
+            exported = true;
+            body = rest.trim_start();
+            if let Some(rest) = body.strip_prefix("default ") {
+                is_default = true;
+                body = rest.trim_start();
+            }
+        }
+        for modifier in ["declare ", "abstract "] {
+            if let Some(rest) = body.strip_prefix(modifier) {
+                body = rest.trim_start();
+            }
+        }
+
+        Self { exported, is_default, body }
+    }
+
+    /// Name after `keyword`, or "default" for anonymous default exports
+    fn name_after(&self, keyword: &str) -> Option<String> {
+        let rest = self.body.strip_prefix(keyword)?;
+        let name = extract_identifier(rest);
+        if !name.is_empty() {
+            return Some(name);
+        }
+        if self.is_default {
+            return Some("default".to_string());
+        }
+        None
+    }
+}
+
+fn skip_block_comment(lines: &[&str], start: usize) -> usize {
+    let mut i = start;
+    while i < lines.len() {
+        if lines[i].contains("*/") {
+            return i + 1;
+        }
+        i += 1;
+    }
+    lines.len()
+}
+
+fn check_tsdoc_before(lines: &[&str], current: usize) -> bool {
+    if current == 0 {
+        return false;
+    }
+
+    let mut i = current - 1;
+
+    // Skip decorators
+    while i > 0 {
+        let line = lines[i].trim();
+        if line.starts_with('@') {
+            i -= 1;
+            continue;
+        }
+        break;
+    }
+
+    let line = lines[i].trim();
+
+    // Check for end of TSDoc
+    if line.ends_with("*/") {
+        if line.starts_with("/**") {
+            return true;
+        }
+        if line.starts_with("/*") {
+            return false;
+        }
+        while i > 0 {
+            i -= 1;
+            let prev = lines[i].trim();
+            if prev.starts_with("/**") {
+                return true;
+            }
+            if prev.starts_with("/*") && !prev.starts_with("/**") {
+                return false;
+            }
+        }
+    }
+
+    false
+}
+
+fn is_comment_line(line: &str) -> bool {
+    if line.starts_with("/**") {
+        return false;
+    }
+    line.starts_with("//") || line.starts_with("/*") || line.starts_with("*")
+}
+
+fn check_visibility(declaration: &Declaration, visibility: &Visibility) -> bool {
+    match visibility {
+        Visibility::Public | Visibility::Exported => declaration.exported,
+        Visibility::All => true,
+    }
+}
+
+fn check_class(
+    declaration: &Declaration,
+    line_num: usize,
+    has_tsdoc: bool,
+    config: &TypeScriptDocConfig,
+) -> Option<DocViolation> {
+    let visibility = config.class.as_ref()?;
+    let name = declaration.name_after("class ")?;
+
+    if !check_visibility(declaration, visibility) || has_tsdoc {
+        return None;
+    }
+
+    Some(DocViolation { line: line_num, kind: DocKind::Class, name })
+}
+
+fn check_interface(
+    declaration: &Declaration,
+    line_num: usize,
+    has_tsdoc: bool,
+    config: &TypeScriptDocConfig,
+) -> Option<DocViolation> {
+    let visibility = config.interface.as_ref()?;
+    let name = declaration.name_after("interface ")?;
+
+    if !check_visibility(declaration, visibility) || has_tsdoc {
+        return None;
+    }
+
+    Some(DocViolation { line: line_num, kind: DocKind::Interface, name })
+}
+
+fn check_enum(
+    declaration: &Declaration,
+    line_num: usize,
+    has_tsdoc: bool,
+    config: &TypeScriptDocConfig,
+) -> Option<DocViolation> {
+    let visibility = config.enum_.as_ref()?;
+    let name = declaration.name_after("enum ").or_else(|| declaration.name_after("const enum "))?;
+
+    if !check_visibility(declaration, visibility) || has_tsdoc {
+        return None;
+    }
+
+    Some(DocViolation { line: line_num, kind: DocKind::Enum, name })
+}
+
+fn check_type_alias(
+    declaration: &Declaration,
+    line_num: usize,
+    has_tsdoc: bool,
+    config: &TypeScriptDocConfig,
+) -> Option<DocViolation> {
+    let visibility = config.type_alias.as_ref()?;
+    let name = declaration.name_after("type ")?;
+
+    // Must be an alias declaration: type Foo = ... / type Foo<T> = ...
+    if !declaration.body.contains('=') {
+        return None;
+    }
+
+    if !check_visibility(declaration, visibility) || has_tsdoc {
+        return None;
+    }
+
+    Some(DocViolation { line: line_num, kind: DocKind::TypeAlias, name })
+}
+
+/// Check for function declaration or arrow function const
+fn check_function(
+    declaration: &Declaration,
+    line_num: usize,
+    has_tsdoc: bool,
+    config: &TypeScriptDocConfig,
+) -> Option<DocViolation> {
+    let visibility = config.function.as_ref()?;
+    let body = declaration.body.strip_prefix("async ").unwrap_or(declaration.body);
+
+    let name = if body.starts_with("function") {
+        let rest = body.strip_prefix("function")?.trim_start_matches('*');
+        if !rest.starts_with([' ', '(']) {
+            return None;
+        }
+        let name = extract_identifier(rest);
+        if name.is_empty() {
+            if !declaration.is_default {
+                return None;
+            }
+            "default".to_string()
+        } else {
+            name
+        }
+    } else {
+        arrow_function_name(body)?
+    };
+
+    if !check_visibility(declaration, visibility) || has_tsdoc {
+        return None;
+    }
+
+    Some(DocViolation { line: line_num, kind: DocKind::Function, name })
+}
+
+/// Name of `const foo = (...) =>` / `const foo = async () =>` / `const foo = function`
+/// Arrow functions are detected only when `=>` appears on the declaration line
+fn arrow_function_name(body: &str) -> Option<String> {
+    let rest = body.strip_prefix("const ").or_else(|| body.strip_prefix("let "))?;
+    let name = extract_identifier(rest);
+    if name.is_empty() {
+        return None;
+    }
+
+    let (_, value) = rest.split_once('=')?;
+    let value = value.trim();
+    if value.starts_with('>') {
+        return None;
+    }
+    let value = value.strip_prefix("async").map(str::trim_start).unwrap_or(value);
+    if value.starts_with("function") || value.contains("=>") {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+fn extract_identifier(s: &str) -> String {
+    s.trim().chars().take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$').collect()
+}
@@ -0,0 +1,17 @@
+use crate::validate::Violation;
+
+/// Config for max file lines checks
+#[derive(Debug, Clone)]
+pub struct FileLinesConfig {
+    pub limit: usize,
+}
+
+/// Validate the file fits within the limit
+/// Reports a single violation at the first line over the limit with `found` being the actual line count
+pub fn validate(content: &str, config: &FileLinesConfig) -> Vec<Violation> {
+    let count = content.lines().count();
+    if count <= config.limit {
+        return Vec::new();
+    }
+    vec![Violation { line: config.limit + 1, col: 1, found: count.to_string() }]
+}
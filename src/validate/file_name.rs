@@ -0,0 +1,17 @@
+use std::path::Path;
+
+use globset::GlobMatcher;
+
+use crate::validate::Violation;
+
+/// Validate the file name matches none of the forbidden patterns (glob or exact name)
+/// The violation is file-level (line 0) with `found` being the file name
+pub fn validate(file: &Path, patterns: &[GlobMatcher]) -> Vec<Violation> {
+    let Some(name) = file.file_name().and_then(|n| n.to_str()) else {
+        return Vec::new();
+    };
+    if !patterns.iter().any(|glob| glob.is_match(name)) {
+        return Vec::new();
+    }
+    vec![Violation { line: 0, col: 0, found: name.to_string() }]
+}
@@ -0,0 +1,21 @@
+use crate::validate::Violation;
+
+/// Validate a non-empty file ends with a newline (`\n`, which also covers `\r\n`)
+/// The violation is reported on the last line with `found` being that line
+pub fn validate(content: &str) -> Vec<Violation> {
+    if content.is_empty() || content.ends_with('\n') {
+        return Vec::new();
+    }
+    let last = content.lines().last().unwrap_or("");
+    vec![Violation { line: content.lines().count(), col: 1, found: last.to_string() }]
+}
+
+/// Append a newline to a non-empty file lacking one unless `is_disabled` returns true for the last line
+/// CRLF is appended if the file already uses CRLF line endings
+pub fn fix(content: &str, is_disabled: &dyn Fn(usize) -> bool) -> String {
+    if content.is_empty() || content.ends_with('\n') || is_disabled(content.lines().count()) {
+        return content.to_string();
+    }
+    let newline = if content.contains("\r\n") { "\r\n" } else { "\n" };
+    format!("{content}{newline}")
+}
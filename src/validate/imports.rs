@@ -0,0 +1,178 @@
+use std::path::Path;
+
+use crate::validate::Violation;
+
+/// Config for forbidden import checks
+#[derive(Debug, Clone)]
+pub struct ImportsConfig {
+    /// Banned import targets (a target also matches its sub-modules, e.g. `lodash` bans `lodash/fp`)
+    pub imports: Vec<String>,
+}
+
+/// Import statement syntax
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Syntax {
+    /// `import a.b.C` (Java, Kotlin, Scala), `import a, b` (Python)
+    Import,
+    /// `from a.b import c` (Python)
+    From,
+    /// `import x from 'm'`, `import 'm'`, `export * from 'm'` (JavaScript, TypeScript)
+    EsImport,
+    /// `require('m')`, `require 'm'`, `require_once 'm'` (JavaScript, Ruby, PHP)
+    Require,
+    /// `use a::b::C;` (Rust), `use A\B\C;` (PHP)
+    Use,
+    /// `#include <m>` / `#include "m"` (C, C++, Objective-C)
+    Include,
+    /// `import "m"` and `import ( "m" )` blocks (Go)
+    GoImport,
+}
+
+/// Syntaxes recognized for a file extension (all line-based syntaxes for unknown extensions)
+fn syntaxes_for(file: &Path) -> &'static [Syntax] {
+    let ext = file.extension().and_then(|e| e.to_str()).unwrap_or("");
+    match ext {
+        "java" | "kt" | "kts" | "scala" | "groovy" => &[Syntax::Import],
+        "py" => &[Syntax::Import, Syntax::From],
+        "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "mts" | "cts" | "vue" | "svelte" => {
+            &[Syntax::EsImport, Syntax::Require]
+        }
+        "rs" => &[Syntax::Use],
+        "php" => &[Syntax::Use, Syntax::Require],
+        "rb" => &[Syntax::Require],
+        "c" | "h" | "cc" | "cpp" | "cxx" | "hpp" | "hh" | "m" | "mm" => &[Syntax::Include],
+        "go" => &[Syntax::GoImport],
+        _ => &[Syntax::Import, Syntax::From, Syntax::EsImport, Syntax::Require, Syntax::Use, Syntax::Include],
+    }
+}
+
+/// Validate that no import statement targets a banned import
+pub fn validate(file: &Path, content: &str, config: &ImportsConfig) -> Vec<Violation> {
+    let syntaxes = syntaxes_for(file);
+    let mut violations = Vec::new();
+    let mut in_go_block = false;
+
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+
+        let targets = if syntaxes.contains(&Syntax::GoImport) {
+            go_targets(trimmed, &mut in_go_block)
+        } else {
+            syntaxes.iter().flat_map(|syntax| targets(*syntax, trimmed)).collect()
+        };
+
+        for target in targets {
+            if config.imports.iter().any(|banned| is_banned(target, banned)) {
+                // target is a slice of line, so its offset is the column
+                let col = target.as_ptr() as usize - line.as_ptr() as usize + 1;
+                violations.push(Violation { line: i + 1, col, found: target.to_string() });
+            }
+        }
+    }
+
+    violations
+}
+
+/// Import targets of a line for a line-based syntax
+fn targets(syntax: Syntax, line: &str) -> Vec<&str> {
+    match syntax {
+        Syntax::Import => {
+            let Some(rest) = line.strip_prefix("import ") else {
+                return Vec::new();
+            };
+            // JavaScript imports start with a quote, a brace or a default binding followed by `from`
+            if rest.contains(" from ") || rest.starts_with(['\'', '"', '{', '*']) {
+                return Vec::new();
+            }
+            let rest = rest.strip_prefix("static ").unwrap_or(rest);
+            rest.trim_end_matches(';').split(',').filter_map(|part| part.split_whitespace().next()).collect()
+        }
+        Syntax::From => match line.strip_prefix("from ") {
+            Some(rest) if rest.contains(" import") => rest.split_whitespace().next().into_iter().collect(),
+            _ => Vec::new(),
+        },
+        Syntax::EsImport => {
+            let is_statement = line.starts_with("import ") || line.starts_with("export ") || line.starts_with('}');
+            if !is_statement {
+                return Vec::new();
+            }
+            if let Some(pos) = line.find(" from ") {
+                return quoted(&line[pos + " from ".len()..]).into_iter().collect();
+            }
+            line.strip_prefix("import ").and_then(quoted).into_iter().collect()
+        }
+        Syntax::Require => {
+            for keyword in ["require(", "require ", "require_once(", "require_once ", "include_once ", "include "] {
+                if let Some(pos) = line.find(keyword) {
+                    let is_word_start = line[..pos].chars().last().is_none_or(|c| !c.is_alphanumeric() && c != '_');
+                    if is_word_start {
+                        let rest = line[pos + keyword.len()..].trim_start_matches(['(', ' ']);
+                        return quoted(rest).into_iter().collect();
+                    }
+                }
+            }
+            Vec::new()
+        }
+        Syntax::Use => {
+            let Some(rest) = line.strip_prefix("use ").or_else(|| line.strip_prefix("pub use ")) else {
+                return Vec::new();
+            };
+            let target = rest.trim_end_matches(';').split_whitespace().next().unwrap_or("");
+            (!target.is_empty()).then_some(target).into_iter().collect()
+        }
+        Syntax::Include => {
+            let Some(rest) = line.strip_prefix('#').map(str::trim_start).and_then(|r| r.strip_prefix("include")) else {
+                return Vec::new();
+            };
+            let rest = rest.trim_start();
+            let close = match rest.chars().next() {
+                Some('<') => '>',
+                Some('"') => '"',
+                _ => return Vec::new(),
+            };
+            rest[1..].find(close).map(|end| &rest[1..1 + end]).into_iter().collect()
+        }
+        Syntax::GoImport => Vec::new(),
+    }
+}
+
+/// Go imports: single `import "m"` lines and the entries of `import ( ... )` blocks
+fn go_targets<'a>(line: &'a str, in_block: &mut bool) -> Vec<&'a str> {
+    if *in_block {
+        if line.starts_with(')') {
+            *in_block = false;
+            return Vec::new();
+        }
+        return quoted_anywhere(line).into_iter().collect();
+    }
+    let Some(rest) = line.strip_prefix("import") else {
+        return Vec::new();
+    };
+    if rest.trim_start().starts_with('(') {
+        *in_block = !rest.contains(')');
+        return Vec::new();
+    }
+    quoted_anywhere(rest).into_iter().collect()
+}
+
+/// Contents of a string literal at the start of `s`
+fn quoted(s: &str) -> Option<&str> {
+    let s = s.trim_start();
+    let quote = s.chars().next().filter(|c| matches!(c, '\'' | '"' | '`'))?;
+    let end = s[1..].find(quote)?;
+    Some(&s[1..1 + end])
+}
+
+/// Contents of the first string literal in `s` (e.g. after a Go import alias)
+fn quoted_anywhere(s: &str) -> Option<&str> {
+    let start = s.find(['"', '`'])?;
+    quoted(&s[start..])
+}
+
+/// Exact match or a sub-module of the banned import (`java.util` bans `java.util.Date`)
+fn is_banned(target: &str, banned: &str) -> bool {
+    let Some(rest) = target.strip_prefix(banned) else {
+        return false;
+    };
+    rest.is_empty() || rest.starts_with(['.', '/', ':', '\\'])
+}
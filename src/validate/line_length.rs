@@ -0,0 +1,35 @@
+use unicode_width::UnicodeWidthChar;
+
+use crate::validate::Violation;
+
+/// Config for max line length checks
+#[derive(Debug, Clone)]
+pub struct LineLengthConfig {
+    pub limit: usize,
+    pub tab_width: usize,
+}
+
+/// Validate each line fits within the limit
+/// Length is measured in display width: tabs expand to the next tab stop and wide characters count as 2
+pub fn validate(content: &str, config: &LineLengthConfig) -> Vec<Violation> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let width = display_width(line, config.tab_width);
+            (width > config.limit).then(|| Violation { line: i + 1, col: config.limit + 1, found: width.to_string() })
+        })
+        .collect()
+}
+
+fn display_width(line: &str, tab_width: usize) -> usize {
+    let mut width = 0;
+    for c in line.chars() {
+        if c == '\t' {
+            width += tab_width - width % tab_width;
+        } else {
+            width += c.width().unwrap_or(0);
+        }
+    }
+    width
+}
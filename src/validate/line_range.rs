@@ -0,0 +1,51 @@
+use crate::validate::Violation;
+
+/// Window of lines a rule checks (1-based, both ends inclusive, no end means up to the last line)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineRange {
+    pub start: usize,
+    pub end: Option<usize>,
+}
+
+impl LineRange {
+    /// The lines of `content` inside the window, line endings kept
+    pub fn slice<'a>(&self, content: &'a str) -> &'a str {
+        let mut begin = content.len();
+        let mut finish = content.len();
+        let mut offset = 0;
+        for (index, line) in content.split_inclusive('\n').enumerate() {
+            let number = index + 1;
+            if number == self.start {
+                begin = offset;
+            }
+            offset += line.len();
+            if Some(number) == self.end {
+                finish = offset;
+                break;
+            }
+        }
+        if begin > finish {
+            return "";
+        }
+        &content[begin..finish]
+    }
+}
+
+/// Run `validate` on the `range` window of `content` (the whole content without a range)
+/// Line numbers are reported in the whole file; file-level violations (line 0) are kept as they are
+pub fn validate_within(
+    range: Option<LineRange>,
+    content: &str,
+    validate: impl FnOnce(&str) -> Vec<Violation>,
+) -> Vec<Violation> {
+    let Some(range) = range else {
+        return validate(content);
+    };
+    let mut violations = validate(range.slice(content));
+    for violation in &mut violations {
+        if violation.line != 0 {
+            violation.line += range.start - 1;
+        }
+    }
+    violations
+}
@@ -1,9 +1,21 @@
+pub mod allowlist;
 pub mod comment;
 pub mod custom;
+pub mod disable;
 pub mod doc;
+pub mod file_lines;
+pub mod file_name;
+pub mod final_newline;
+pub mod imports;
+pub mod line_length;
+pub mod line_range;
+pub mod quotes;
 pub mod regex;
+pub mod required_text;
 pub mod test;
 pub mod text;
+pub mod todo;
+pub mod trailing_whitespace;
 
 /// A violation found by text or regex validator
 #[derive(Debug)]
@@ -0,0 +1,135 @@
+use crate::rule::parser::{Lang, QuoteStyle};
+use crate::validate::Violation;
+
+/// Config for consistent quote checks
+#[derive(Debug, Clone)]
+pub struct QuoteConfig {
+    pub lang: Lang,
+    pub quote: QuoteStyle,
+}
+
+/// Validate string literals use the configured quote style
+/// Literals that contain the preferred quote are allowed to use the other quote to avoid escaping
+/// `col` is the 1-based character position of the opening quote
+pub fn validate(content: &str, config: &QuoteConfig) -> Vec<Violation> {
+    let (preferred, disallowed) = match config.quote {
+        QuoteStyle::Single => (b'\'', b'"'),
+        QuoteStyle::Double => (b'"', b'\''),
+    };
+    let is_python = config.lang == Lang::Python;
+
+    let bytes = content.as_bytes();
+    let mut violations = Vec::new();
+    let mut line = 1;
+    let mut line_start = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let rest = &content[i..];
+
+        // Line comments
+        if (is_python && rest.starts_with('#')) || (!is_python && rest.starts_with("//")) {
+            i = skip_until(bytes, i, b"\n");
+            continue;
+        }
+
+        // Block comments (JS/TS)
+        if !is_python && rest.starts_with("/*") {
+            let end = skip_after(bytes, i + 2, b"*/");
+            (line, line_start) = count_lines(bytes, i, end, line, line_start);
+            i = end;
+            continue;
+        }
+
+        // Triple-quoted strings (Python docstrings) are never checked
+        if is_python && (rest.starts_with("\"\"\"") || rest.starts_with("'''")) {
+            let end = skip_after(bytes, i + 3, &bytes[i..i + 3]);
+            (line, line_start) = count_lines(bytes, i, end, line, line_start);
+            i = end;
+            continue;
+        }
+
+        // Template literals (JS/TS) are never checked
+        if !is_python && bytes[i] == b'`' {
+            let end = skip_string(bytes, i, b'`', true);
+            (line, line_start) = count_lines(bytes, i, end, line, line_start);
+            i = end;
+            continue;
+        }
+
+        if bytes[i] == b'"' || bytes[i] == b'\'' {
+            let quote = bytes[i];
+            let end = skip_string(bytes, i, quote, false);
+            let literal = &content[i..end];
+            let body = literal.get(1..literal.len().saturating_sub(1)).unwrap_or("");
+            if quote == disallowed && !body.as_bytes().contains(&preferred) {
+                let col = content[line_start..i].chars().count() + 1;
+                violations.push(Violation { line, col, found: literal.to_string() });
+            }
+            // An escaped newline continues the literal on the next line
+            (line, line_start) = count_lines(bytes, i, end, line, line_start);
+            i = end;
+            continue;
+        }
+
+        if bytes[i] == b'\n' {
+            line += 1;
+            line_start = i + 1;
+        }
+        i = next_char(bytes, i);
+    }
+
+    violations
+}
+
+/// Returns the position of the char after the one at `i`, so that positions stay on char boundaries
+fn next_char(bytes: &[u8], i: usize) -> usize {
+    let mut next = i + 1;
+    while next < bytes.len() && (bytes[next] & 0xC0) == 0x80 {
+        next += 1;
+    }
+    next
+}
+
+/// Returns the position of `marker` (or end of content)
+fn skip_until(bytes: &[u8], start: usize, marker: &[u8]) -> usize {
+    let mut i = start;
+    while i < bytes.len() && !bytes[i..].starts_with(marker) {
+        i += 1;
+    }
+    i
+}
+
+/// Returns the position just after `marker` (or end of content)
+fn skip_after(bytes: &[u8], start: usize, marker: &[u8]) -> usize {
+    let pos = skip_until(bytes, start, marker);
+    (pos + marker.len()).min(bytes.len())
+}
+
+/// Returns the position just after the closing quote
+/// Single-line strings also end at a newline to recover from unterminated literals
+fn skip_string(bytes: &[u8], start: usize, quote: u8, multiline: bool) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i = next_char(bytes, i + 1),
+            b'\n' if !multiline => return i,
+            b if b == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Advance line tracking over a skipped range
+fn count_lines(bytes: &[u8], start: usize, end: usize, line: usize, line_start: usize) -> (usize, usize) {
+    let mut line = line;
+    let mut line_start = line_start;
+    for (offset, b) in bytes[start..end].iter().enumerate() {
+        if *b == b'\n' {
+            line += 1;
+            line_start = start + offset + 1;
+        }
+    }
+    (line, line_start)
+}
@@ -1,14 +1,24 @@
 use crate::rule::RegexRule;
 use crate::validate::Violation;
 
+/// Report every match of every pattern, one violation per (line, col)
+/// With `report_group`, the capture group is reported (its column and text) instead of the whole match
 pub fn validate(content: &str, rule: &RegexRule) -> Vec<Violation> {
     let mut violations = Vec::new();
     for (line_num, line) in content.lines().enumerate() {
-        for pattern in &rule.patterns {
-            if let Some(m) = pattern.find(line) {
-                violations.push(Violation { line: line_num + 1, col: m.start() + 1, found: line.to_string() });
-                break;
-            }
+        let mut found: Vec<(usize, &str)> = rule
+            .patterns
+            .iter()
+            .flat_map(|pattern| pattern.captures_iter(line))
+            .filter_map(|captures| captures.get(rule.report_group))
+            .filter(|m| !rule.allowlist.allows(m.as_str()))
+            .map(|m| (m.start(), m.as_str()))
+            .collect();
+        found.sort_unstable();
+        found.dedup_by_key(|(col, _)| *col);
+        for (col, text) in found {
+            let found = if rule.report_group == 0 { line } else { text };
+            violations.push(Violation { line: line_num + 1, col: col + 1, found: found.to_string() });
         }
     }
     violations
@@ -0,0 +1,25 @@
+use crate::rule::parser::MatchCond;
+use crate::validate::Violation;
+
+/// Config for required text checks
+#[derive(Debug, Clone)]
+pub struct RequiredTextConfig {
+    pub keywords: Vec<String>,
+    /// `and`: every keyword is required, `or`: any one of them is enough
+    pub cond: MatchCond,
+}
+
+/// Validate the file contains the required keywords
+/// The violation is file-level (line 0) with `found` being the missing keywords
+pub fn validate(content: &str, config: &RequiredTextConfig) -> Vec<Violation> {
+    let missing: Vec<&str> =
+        config.keywords.iter().filter(|keyword| !content.contains(keyword.as_str())).map(String::as_str).collect();
+    let satisfied = match config.cond {
+        MatchCond::And => missing.is_empty(),
+        MatchCond::Or => missing.len() < config.keywords.len(),
+    };
+    if satisfied {
+        return Vec::new();
+    }
+    vec![Violation { line: 0, col: 0, found: missing.join(", ") }]
+}
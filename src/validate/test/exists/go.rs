@@ -0,0 +1,133 @@
+use std::path::{Path, PathBuf};
+
+use super::{SameFileTestConfig, TestExistenceViolation, TestExistenceViolationKind};
+use crate::rule::parser::TestRequireLevel;
+
+/// Validate test existence for a Go source file
+/// The test file is the `_test.go` sibling in the same package directory
+pub fn validate(
+    file_path: &Path,
+    content: &str,
+    root_dir: &Path,
+    config: &SameFileTestConfig,
+) -> Vec<TestExistenceViolation> {
+    let mut violations = Vec::new();
+
+    // Test files themselves are not validated
+    let Some(test_path) = test_file_path(file_path) else {
+        return violations;
+    };
+
+    // Skip validation if there are no exported functions to test
+    let exported_functions = extract_exported_functions(content);
+    if exported_functions.is_empty() {
+        return violations;
+    }
+
+    if !test_path.exists() {
+        let expected = test_path.strip_prefix(root_dir).unwrap_or(&test_path);
+        violations.push(TestExistenceViolation {
+            kind: TestExistenceViolationKind::MissingTestFile { expected_path: expected.display().to_string() },
+        });
+        return violations;
+    }
+
+    // For all_public mode, check that all exported functions have a TestXxx function
+    if config.require == TestRequireLevel::AllPublic {
+        let test_content = std::fs::read_to_string(&test_path).unwrap_or_default();
+        let test_names = extract_test_names(&test_content);
+
+        for function in exported_functions {
+            if !test_names.iter().any(|test_name| is_test_for(test_name, &function.name)) {
+                violations.push(TestExistenceViolation {
+                    kind: TestExistenceViolationKind::UntestedPublicFunction {
+                        line: function.line,
+                        expected_test: function.expected_test(),
+                        function_name: function.name,
+                    },
+                });
+            }
+        }
+    }
+
+    violations
+}
+
+/// foo.go -> foo_test.go (None for test files and non-Go files)
+fn test_file_path(file_path: &Path) -> Option<PathBuf> {
+    let name = file_path.file_name()?.to_str()?;
+    let stem = name.strip_suffix(".go")?;
+    if stem.ends_with("_test") {
+        return None;
+    }
+    Some(file_path.with_file_name(format!("{stem}_test.go")))
+}
+
+/// An exported function or method
+struct ExportedFunction {
+    line: usize,
+    name: String,
+    /// Receiver type name for methods (e.g. `Server` for `func (s *Server) Start()`)
+    receiver: Option<String>,
+}
+
+impl ExportedFunction {
+    /// TestFoo for functions, TestServer_Start for methods
+    fn expected_test(&self) -> String {
+        match &self.receiver {
+            Some(receiver) => format!("Test{receiver}_{}", self.name),
+            None => format!("Test{}", self.name),
+        }
+    }
+}
+
+/// Extract exported top-level functions and methods from Go source
+fn extract_exported_functions(content: &str) -> Vec<ExportedFunction> {
+    let mut functions = Vec::new();
+
+    for (i, line) in content.lines().enumerate() {
+        let Some(rest) = line.strip_prefix("func ") else {
+            continue;
+        };
+
+        // Split off the receiver of methods: func (s *Server) Start()
+        let (receiver, rest) = if let Some(after_paren) = rest.strip_prefix('(') {
+            match after_paren.find(')') {
+                Some(pos) => (receiver_type(&after_paren[..pos]), after_paren[pos + 1..].trim_start()),
+                None => continue,
+            }
+        } else {
+            (None, rest)
+        };
+
+        let name: String = rest.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+        if name.chars().next().is_some_and(|c| c.is_uppercase()) {
+            functions.push(ExportedFunction { line: i + 1, name, receiver });
+        }
+    }
+
+    functions
+}
+
+/// `s *Server` or `s Server[T]` -> Server
+fn receiver_type(receiver: &str) -> Option<String> {
+    let type_part = receiver.split_whitespace().last()?.trim_start_matches('*');
+    let name: String = type_part.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+    (!name.is_empty()).then_some(name)
+}
+
+/// Extract test function names (without the `Test` prefix) from a Go test file
+fn extract_test_names(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| line.strip_prefix("func Test"))
+        .map(|rest| rest.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect())
+        .collect()
+}
+
+/// TestFoo and TestFoo_case test Foo, TestServer_Start tests the Start method
+fn is_test_for(test_name: &str, function_name: &str) -> bool {
+    test_name == function_name
+        || test_name.starts_with(&format!("{function_name}_"))
+        || test_name.split('_').skip(1).any(|part| part == function_name)
+}
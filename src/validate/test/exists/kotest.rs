@@ -93,8 +93,10 @@ pub fn validate(
 
         for (line, method_name) in public_methods {
             if !test_content.contains(&method_name) {
+                // Kotest names tests with strings, so any test name containing the method name counts
+                let expected_test = format!("\"{method_name}\"");
                 violations.push(TestExistenceViolation {
-                    kind: TestExistenceViolationKind::UntestedPublicMethod { line, method_name },
+                    kind: TestExistenceViolationKind::UntestedPublicMethod { line, method_name, expected_test },
                 });
             }
         }
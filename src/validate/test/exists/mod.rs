@@ -1,8 +1,9 @@
+pub mod go;
 pub mod kotest;
 pub mod phpunit;
 pub mod rust;
 
-use crate::rule::parser::TestRequireLevel;
+use crate::rule::parser::{RustTestStyle, TestRequireLevel};
 
 /// A test existence violation (missing test file or untested public method)
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -15,12 +16,12 @@ pub struct TestExistenceViolation {
 pub enum TestExistenceViolationKind {
     /// Test file does not exist
     MissingTestFile { expected_path: String },
-    /// Public method not tested
-    UntestedPublicMethod { line: usize, method_name: String },
+    /// Public method not tested (`expected_test` is the test name to add, e.g. `testFoo`)
+    UntestedPublicMethod { line: usize, method_name: String, expected_test: String },
     /// Unit test does not exist
     MissingUnitTest,
-    /// Public function not tested
-    UntestedPublicFunction { line: usize, function_name: String },
+    /// Public function not tested (`expected_test` is the test name to add, e.g. `test_foo`)
+    UntestedPublicFunction { line: usize, function_name: String, expected_test: String },
 }
 
 impl std::fmt::Display for TestExistenceViolationKind {
@@ -29,14 +30,17 @@ impl std::fmt::Display for TestExistenceViolationKind {
             TestExistenceViolationKind::MissingTestFile { expected_path } => {
                 write!(f, "テストファイルが存在しません: {expected_path}")
             }
-            TestExistenceViolationKind::UntestedPublicMethod { line, method_name } => {
-                write!(f, "L{line}: public メソッド `{method_name}` がテストされていません")
+            TestExistenceViolationKind::UntestedPublicMethod { line, method_name, expected_test } => {
+                write!(f, "L{line}: public メソッド `{method_name}` がテストされていません (期待するテスト: `{expected_test}`)")
             }
             TestExistenceViolationKind::MissingUnitTest => {
                 write!(f, "ユニットテストが存在しません")
             }
-            TestExistenceViolationKind::UntestedPublicFunction { line, function_name } => {
-                write!(f, "L{line}: pub 関数 `{function_name}` がテストされていません")
+            TestExistenceViolationKind::UntestedPublicFunction { line, function_name, expected_test } => {
+                write!(
+                    f,
+                    "L{line}: pub 関数 `{function_name}` がテストされていません (期待するテスト: `{expected_test}`)"
+                )
             }
         }
     }
@@ -50,7 +54,7 @@ pub struct ExternalFileTestConfig {
     pub test_file_suffix: String,
 }
 
-/// Config for same file test existence checks (Rust unit test)
+/// Config for same directory test existence checks (Go test)
 #[derive(Debug, Clone)]
 pub struct SameFileTestConfig {
     pub require: TestRequireLevel,
@@ -61,3 +65,16 @@ impl Default for SameFileTestConfig {
         Self { require: TestRequireLevel::Exists }
     }
 }
+
+/// Config for Rust unit test existence checks
+#[derive(Debug, Clone)]
+pub struct RustUnitTestConfig {
+    pub require: TestRequireLevel,
+    pub style: RustTestStyle,
+}
+
+impl Default for RustUnitTestConfig {
+    fn default() -> Self {
+        Self { require: TestRequireLevel::Exists, style: RustTestStyle::InlineModule }
+    }
+}
@@ -93,8 +93,9 @@ pub fn validate(
 
         for (line, method_name) in public_methods {
             if !test_content.contains(&method_name) {
+                let expected_test = expected_test_name(&method_name);
                 violations.push(TestExistenceViolation {
-                    kind: TestExistenceViolationKind::UntestedPublicMethod { line, method_name },
+                    kind: TestExistenceViolationKind::UntestedPublicMethod { line, method_name, expected_test },
                 });
             }
         }
@@ -103,6 +104,15 @@ pub fn validate(
     violations
 }
 
+/// findUser -> testFindUser
+fn expected_test_name(method_name: &str) -> String {
+    let mut chars = method_name.chars();
+    match chars.next() {
+        Some(first) => format!("test{}{}", first.to_uppercase(), chars.as_str()),
+        None => "test".to_string(),
+    }
+}
+
 /// Build test file path from source file path
 fn build_test_path_from_file(
     relative_path: &Path,
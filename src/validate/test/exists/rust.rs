@@ -1,8 +1,23 @@
-use super::{SameFileTestConfig, TestExistenceViolation, TestExistenceViolationKind};
-use crate::rule::parser::TestRequireLevel;
+use std::path::{Path, PathBuf};
+
+use super::{RustUnitTestConfig, TestExistenceViolation, TestExistenceViolationKind};
+use crate::rule::parser::{RustTestStyle, TestRequireLevel};
 
 /// Validate test existence for a Rust source file
-pub fn validate(content: &str, config: &SameFileTestConfig) -> Vec<TestExistenceViolation> {
+pub fn validate(
+    file_path: &Path,
+    content: &str,
+    root_dir: &Path,
+    config: &RustUnitTestConfig,
+) -> Vec<TestExistenceViolation> {
+    match config.style {
+        RustTestStyle::InlineModule => validate_inline_module(content, config),
+        RustTestStyle::SiblingFile => validate_sibling_file(file_path, content, root_dir, config),
+    }
+}
+
+/// Tests are written in `#[cfg(test)]` modules or `#[test]` functions of the same file
+fn validate_inline_module(content: &str, config: &RustUnitTestConfig) -> Vec<TestExistenceViolation> {
     let mut violations = Vec::new();
 
     // Skip validation if there are no public functions to test
@@ -21,8 +36,61 @@ pub fn validate(content: &str, config: &SameFileTestConfig) -> Vec<TestExistence
 
         for (line, func_name) in public_functions {
             if !test_content.contains(&func_name) {
+                let expected_test = format!("test_{func_name}");
+                violations.push(TestExistenceViolation {
+                    kind: TestExistenceViolationKind::UntestedPublicFunction {
+                        line,
+                        function_name: func_name,
+                        expected_test,
+                    },
+                });
+            }
+        }
+    }
+
+    violations
+}
+
+/// Tests are written in `foo_test.rs` next to `foo.rs`
+fn validate_sibling_file(
+    file_path: &Path,
+    content: &str,
+    root_dir: &Path,
+    config: &RustUnitTestConfig,
+) -> Vec<TestExistenceViolation> {
+    let mut violations = Vec::new();
+
+    // Test files themselves are not validated
+    let Some(test_path) = sibling_test_path(file_path) else {
+        return violations;
+    };
+
+    // Skip validation if there are no public functions to test
+    let public_functions = extract_public_functions(content);
+    if public_functions.is_empty() {
+        return violations;
+    }
+
+    if !test_path.exists() {
+        let expected = test_path.strip_prefix(root_dir).unwrap_or(&test_path);
+        violations.push(TestExistenceViolation {
+            kind: TestExistenceViolationKind::MissingTestFile { expected_path: expected.display().to_string() },
+        });
+        return violations;
+    }
+
+    if config.require == TestRequireLevel::AllPublic {
+        let test_content = std::fs::read_to_string(&test_path).unwrap_or_default();
+
+        for (line, func_name) in public_functions {
+            if !test_content.contains(&func_name) {
+                let expected_test = format!("test_{func_name}");
                 violations.push(TestExistenceViolation {
-                    kind: TestExistenceViolationKind::UntestedPublicFunction { line, function_name: func_name },
+                    kind: TestExistenceViolationKind::UntestedPublicFunction {
+                        line,
+                        function_name: func_name,
+                        expected_test,
+                    },
                 });
             }
         }
@@ -31,6 +99,16 @@ pub fn validate(content: &str, config: &SameFileTestConfig) -> Vec<TestExistence
     violations
 }
 
+/// foo.rs -> foo_test.rs (None for test files and non-Rust files)
+fn sibling_test_path(file_path: &Path) -> Option<PathBuf> {
+    let name = file_path.file_name()?.to_str()?;
+    let stem = name.strip_suffix(".rs")?;
+    if stem.ends_with("_test") {
+        return None;
+    }
+    Some(file_path.with_file_name(format!("{stem}_test.rs")))
+}
+
 /// Check if content has a test module or test functions
 fn has_test_module_or_function(content: &str) -> bool {
     // Check for #[cfg(test)] module
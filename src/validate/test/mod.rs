@@ -1,4 +1,5 @@
 pub mod exists;
 pub mod name;
+pub mod skip;
 
 pub use name::TestViolation;
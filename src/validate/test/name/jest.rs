@@ -0,0 +1,74 @@
+use super::{filter_non_japanese, TestViolation};
+
+/// Functions whose first argument is a test title
+const TEST_FUNCTIONS: &[&str] = &["describe", "test", "it"];
+
+/// Modifiers that may follow a test function (e.g. `it.only(`, `describe.skip(`)
+const MODIFIERS: &[&str] = &["only", "skip", "todo", "concurrent", "failing"];
+
+/// Extract Jest test titles and validate for Japanese
+pub fn validate(content: &str) -> Vec<TestViolation> {
+    let tests = extract_test_titles(content);
+    filter_non_japanese(tests)
+}
+
+/// Extract titles of `it(...)`, `test(...)` and `describe(...)` calls with the line of the call
+fn extract_test_titles(content: &str) -> Vec<(usize, String)> {
+    let mut tests = Vec::new();
+
+    for (i, line) in content.lines().enumerate() {
+        let mut pos = 0;
+        while pos < line.len() {
+            let rest = &line[pos..];
+            let Some((offset, function)) = find_function(rest) else {
+                break;
+            };
+            let start = pos + offset;
+            pos = start + function.len();
+            if !is_word_start(line, start) {
+                continue;
+            }
+            if let Some(title) = title_arg(&line[pos..]) {
+                tests.push((i + 1, title));
+            }
+        }
+    }
+
+    tests
+}
+
+/// The first test function name in `s` with its offset
+fn find_function(s: &str) -> Option<(usize, &'static str)> {
+    TEST_FUNCTIONS.iter().filter_map(|function| s.find(function).map(|offset| (offset, *function))).min()
+}
+
+/// The function name is not the tail of another identifier or a property (e.g. `submit(`, `foo.test(`)
+fn is_word_start(line: &str, start: usize) -> bool {
+    line[..start].chars().last().is_none_or(|c| !c.is_alphanumeric() && !matches!(c, '_' | '$' | '.'))
+}
+
+/// The string literal title after the function name: `('title'`, `.only("title"` or `` (`title` ``
+fn title_arg(after_function: &str) -> Option<String> {
+    let mut rest = after_function;
+    while let Some(after_dot) = rest.strip_prefix('.') {
+        let modifier = MODIFIERS.iter().find(|m| after_dot.starts_with(**m))?;
+        rest = &after_dot[modifier.len()..];
+    }
+
+    let rest = rest.strip_prefix('(')?.trim_start();
+    let quote = rest.chars().next().filter(|c| matches!(c, '\'' | '"' | '`'))?;
+    let mut title = String::new();
+    let mut escaped = false;
+    for c in rest[1..].chars() {
+        match c {
+            _ if escaped => {
+                title.push(c);
+                escaped = false;
+            }
+            '\\' => escaped = true,
+            _ if c == quote => return (!title.is_empty()).then_some(title),
+            _ => title.push(c),
+        }
+    }
+    None
+}
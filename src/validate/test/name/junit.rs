@@ -0,0 +1,93 @@
+use super::{filter_non_japanese, TestViolation};
+
+/// Annotations that mark a JUnit 5 test method
+const TEST_ANNOTATIONS: &[&str] = &["@Test", "@ParameterizedTest", "@RepeatedTest", "@TestFactory", "@TestTemplate"];
+
+/// Extract JUnit 5 test names and validate for Japanese
+pub fn validate(content: &str) -> Vec<TestViolation> {
+    let tests = extract_test_names(content);
+    filter_non_japanese(tests)
+}
+
+/// Extract test names from JUnit 5 test files
+/// A @DisplayName("...") takes precedence; test methods without it are checked by method name
+fn extract_test_names(content: &str) -> Vec<(usize, String)> {
+    let mut tests = Vec::new();
+    let mut display_name: Option<(usize, String)> = None;
+    let mut pending_test_line: Option<usize> = None;
+
+    for (i, line) in content.lines().enumerate() {
+        let line_num = i + 1;
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with("//") {
+            continue;
+        }
+
+        if trimmed.starts_with("@DisplayName(") {
+            display_name = extract_string_arg(trimmed, "@DisplayName(\"").map(|name| (line_num, name));
+            continue;
+        }
+
+        if TEST_ANNOTATIONS.iter().any(|a| trimmed == *a || trimmed.starts_with(&format!("{a}("))) {
+            pending_test_line = Some(line_num);
+            continue;
+        }
+
+        // Skip other annotations
+        if trimmed.starts_with('@') {
+            continue;
+        }
+
+        // Declaration line following the annotations (class or method)
+        if let Some(name) = display_name.take() {
+            tests.push(name);
+        } else if let Some(test_line) = pending_test_line {
+            if let Some(name) = extract_method_name(trimmed) {
+                tests.push((test_line, name));
+            }
+        }
+        pending_test_line = None;
+    }
+
+    tests
+}
+
+/// Extract string argument like: @DisplayName("name")
+fn extract_string_arg(line: &str, pattern: &str) -> Option<String> {
+    let start = line.find(pattern)?;
+    let after_pattern = &line[start + pattern.len()..];
+    let end = after_pattern.find('"')?;
+    let name = &after_pattern[..end];
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+/// Extract method name from: void name() / fun name() / fun `name`()
+fn extract_method_name(line: &str) -> Option<String> {
+    let before_paren = line[..line.find('(')?].trim_end();
+
+    if let Some(quoted) = before_paren.strip_suffix('`') {
+        let start = quoted.rfind('`')?;
+        return Some(quoted[start + 1..].to_string());
+    }
+
+    let name: String = before_paren
+        .chars()
+        .rev()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
@@ -1,6 +1,10 @@
+pub mod jest;
+pub mod junit;
 pub mod kotest;
 pub mod phpunit;
+pub mod python;
 pub mod rust;
+pub mod spock;
 
 use crate::validate::comment::contains_japanese;
 
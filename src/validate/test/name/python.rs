@@ -0,0 +1,28 @@
+use super::{filter_non_japanese, TestViolation};
+
+/// Extract pytest test function names and validate for Japanese
+pub fn validate(content: &str) -> Vec<TestViolation> {
+    let tests = extract_test_functions(content);
+    filter_non_japanese(tests)
+}
+
+/// Extract `def test*` function names like pytest collects them (including methods and `async def`)
+fn extract_test_functions(content: &str) -> Vec<(usize, String)> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| extract_test_name(line.trim()).map(|name| (i + 1, name)))
+        .collect()
+}
+
+/// Extract the function name from a `def test...(` line
+fn extract_test_name(line: &str) -> Option<String> {
+    let rest = line.strip_prefix("async ").unwrap_or(line);
+    let rest = rest.strip_prefix("def ")?.trim_start();
+    if !rest.starts_with("test") {
+        return None;
+    }
+
+    let name: String = rest.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+    rest[name.len()..].trim_start().starts_with('(').then_some(name)
+}
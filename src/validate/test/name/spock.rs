@@ -0,0 +1,41 @@
+use super::{filter_non_japanese, TestViolation};
+
+/// Extract Spock feature method names and validate for Japanese
+pub fn validate(content: &str) -> Vec<TestViolation> {
+    let tests = extract_feature_names(content);
+    filter_non_japanese(tests)
+}
+
+/// Extract feature method names like: def "name"() { / def 'name'() {
+/// Fixture methods (setup, cleanup, ...) are unquoted and not extracted
+fn extract_feature_names(content: &str) -> Vec<(usize, String)> {
+    let mut tests = Vec::new();
+
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        let Some(after_def) = trimmed.strip_prefix("def ") else {
+            continue;
+        };
+        let after_def = after_def.trim_start();
+
+        for quote in ['"', '\''] {
+            if let Some(name) = extract_quoted(after_def, quote) {
+                tests.push((i + 1, name));
+            }
+        }
+    }
+
+    tests
+}
+
+fn extract_quoted(s: &str, quote: char) -> Option<String> {
+    let rest = s.strip_prefix(quote)?;
+    let end = rest.find(quote)?;
+    let name = &rest[..end];
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
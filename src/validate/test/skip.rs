@@ -0,0 +1,107 @@
+use super::TestViolation;
+use crate::rule::parser::Lang;
+
+/// JS/TS test functions that skip or focus a test
+const JS_PREFIXED: &[&str] = &["xit", "xtest", "xdescribe", "fit", "fdescribe"];
+const JS_BASES: &[&str] = &["it", "test", "describe"];
+const JS_MODIFIERS: &[&str] = &["only", "skip"];
+
+/// Validate test file for skipped or focused tests
+/// Each violation reports the skip construct as its name
+pub fn validate(content: &str, lang: Lang) -> Vec<TestViolation> {
+    let mut violations = Vec::new();
+
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if let Some(construct) = find_construct(trimmed, lang) {
+            violations.push(TestViolation { line: i + 1, name: construct });
+        }
+    }
+
+    violations
+}
+
+fn find_construct(line: &str, lang: Lang) -> Option<String> {
+    match lang {
+        Lang::Java | Lang::Kotlin => {
+            if is_comment_line(line) {
+                return None;
+            }
+            ["@Disabled", "@Ignore"].iter().find(|a| find_word(line, a).is_some()).map(|a| a.to_string())
+        }
+        Lang::Rust => {
+            let rest = line.strip_prefix("#[ignore")?;
+            if !rest.starts_with([']', ' ', '=']) {
+                return None;
+            }
+            let end = line.find(']').map(|pos| pos + 1).unwrap_or(line.len());
+            Some(line[..end].to_string())
+        }
+        Lang::Javascript | Lang::Typescript => {
+            if is_comment_line(line) {
+                return None;
+            }
+            find_js_construct(line)
+        }
+        Lang::Php => {
+            // @group skip lives in PHPDoc, so check it before skipping comments
+            let annotation = line.trim_start_matches(['/', '*']).trim();
+            if annotation == "@group skip" {
+                return Some(annotation.to_string());
+            }
+            if is_comment_line(line) {
+                return None;
+            }
+            find_word(line, "markTestSkipped").map(|_| "markTestSkipped".to_string())
+        }
+        Lang::Python => None,
+    }
+}
+
+/// Find xit(...), it.only(...) and similar calls
+fn find_js_construct(line: &str) -> Option<String> {
+    for name in JS_PREFIXED {
+        if find_call(line, name) {
+            return Some(name.to_string());
+        }
+    }
+    for base in JS_BASES {
+        for modifier in JS_MODIFIERS {
+            let name = format!("{base}.{modifier}");
+            if find_call(line, &name) {
+                return Some(name);
+            }
+        }
+    }
+    None
+}
+
+/// Returns true if `name` appears as a word followed by `(`
+fn find_call(line: &str, name: &str) -> bool {
+    line.match_indices(name).any(|(pos, _)| {
+        let before = line[..pos].chars().next_back();
+        let after = line[pos + name.len()..].trim_start();
+        !before.is_some_and(is_ident_or_dot) && after.starts_with('(')
+    })
+}
+
+/// Returns the position of `word` if it appears with word boundaries
+fn find_word(line: &str, word: &str) -> Option<usize> {
+    line.match_indices(word).map(|(pos, _)| pos).find(|&pos| {
+        let before = line[..pos].chars().next_back();
+        let after = line[pos + word.len()..].chars().next();
+        !before.is_some_and(is_ident_or_dot) && !after.is_some_and(is_ident)
+    })
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+fn is_ident_or_dot(c: char) -> bool {
+    is_ident(c) || c == '.'
+}
+
+fn is_comment_line(line: &str) -> bool {
+    line.starts_with("//") || line.starts_with("/*") || line.starts_with('*')
+}
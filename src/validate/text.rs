@@ -1,15 +1,58 @@
+use crate::rule::parser::Anchor;
 use crate::rule::TextRule;
 use crate::validate::Violation;
 
+/// Report every occurrence of every keyword, one violation per (line, col)
 pub fn validate(content: &str, rule: &TextRule) -> Vec<Violation> {
     let mut violations = Vec::new();
     for (line_num, line) in content.lines().enumerate() {
-        for keyword in &rule.keywords {
-            if let Some(col) = line.find(keyword) {
-                violations.push(Violation { line: line_num + 1, col: col + 1, found: line.to_string() });
-                break;
-            }
+        let mut cols: Vec<usize> = rule.keywords.iter().flat_map(|keyword| find_keyword(line, keyword, rule)).collect();
+        cols.sort_unstable();
+        cols.dedup();
+        for col in cols {
+            violations.push(Violation { line: line_num + 1, col: col + 1, found: line.to_string() });
         }
     }
     violations
 }
+
+/// Find occurrences of keyword that are anchored and bounded (if required) and whose surrounding token is not allowlisted
+fn find_keyword<'a>(line: &'a str, keyword: &'a str, rule: &'a TextRule) -> impl Iterator<Item = usize> + 'a {
+    line.match_indices(keyword)
+        .map(|(pos, _)| pos)
+        .filter(move |&pos| is_anchored(line, pos, pos + keyword.len(), rule.anchor))
+        .filter(move |&pos| !rule.word_boundary || is_bounded(line, pos, pos + keyword.len()))
+        .filter(move |&pos| {
+            rule.allowlist.is_empty() || !rule.allowlist.allows(token_at(line, pos, pos + keyword.len()))
+        })
+}
+
+/// Returns true if the match is where `anchor` requires, ignoring leading and trailing whitespace of the line
+fn is_anchored(line: &str, start: usize, end: usize, anchor: Anchor) -> bool {
+    match anchor {
+        Anchor::Anywhere => true,
+        Anchor::LineStart => line[..start].trim_start().is_empty(),
+        Anchor::LineEnd => line[end..].trim_end().is_empty(),
+    }
+}
+
+/// Returns true if the match is not adjacent to identifier characters
+pub(crate) fn is_bounded(line: &str, start: usize, end: usize) -> bool {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+    let before = line[..start].chars().next_back();
+    let after = line[end..].chars().next();
+    !before.is_some_and(is_ident_char) && !after.is_some_and(is_ident_char)
+}
+
+/// Expand a match to the surrounding identifier-like token
+fn token_at(line: &str, start: usize, end: usize) -> &str {
+    let is_token_char = |c: char| c.is_alphanumeric() || matches!(c, '_' | '-' | '.');
+    let start = line[..start]
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| !is_token_char(c))
+        .map(|(pos, c)| pos + c.len_utf8())
+        .unwrap_or(0);
+    let end = line[end..].find(|c: char| !is_token_char(c)).map(|pos| end + pos).unwrap_or(line.len());
+    &line[start..end]
+}
@@ -0,0 +1,84 @@
+use crate::validate::text::is_bounded;
+use crate::validate::Violation;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Config for stale TODO checks
+#[derive(Debug, Clone)]
+pub struct TodoConfig {
+    pub markers: Vec<String>,
+    /// `None`: every marker is reported, `Some`: only markers dated more than this many days ago
+    pub max_age_days: Option<u64>,
+}
+
+/// Validate TODO-like markers, one violation per marker with `found` being the marker text (e.g. `TODO(2024-01-01)`)
+/// `today` is the number of days since 1970-01-01
+pub fn validate(content: &str, config: &TodoConfig, today: i64) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for (line_num, line) in content.lines().enumerate() {
+        let mut found: Vec<(usize, &str)> =
+            config.markers.iter().flat_map(|marker| find_marker(line, marker)).collect();
+        found.sort_unstable();
+        found.dedup_by_key(|(col, _)| *col);
+        for (col, text) in found {
+            if is_reported(text, config.max_age_days, today) {
+                violations.push(Violation { line: line_num + 1, col: col + 1, found: text.to_string() });
+            }
+        }
+    }
+    violations
+}
+
+/// Returns the number of days since 1970-01-01 of the current date (UTC)
+pub fn today() -> i64 {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    (secs / 86_400) as i64
+}
+
+/// Find bounded occurrences of the marker, each with its trailing `(...)` note if any
+fn find_marker<'a>(line: &'a str, marker: &'a str) -> impl Iterator<Item = (usize, &'a str)> + 'a {
+    line.match_indices(marker).filter(move |&(pos, _)| is_bounded(line, pos, pos + marker.len())).map(
+        move |(pos, _)| {
+            let end = pos + marker.len();
+            let note_end = line[end..].strip_prefix('(').and_then(|rest| rest.find(')')).map(|close| end + close + 2);
+            (pos, &line[pos..note_end.unwrap_or(end)])
+        },
+    )
+}
+
+fn is_reported(text: &str, max_age_days: Option<u64>, today: i64) -> bool {
+    let Some(max_age_days) = max_age_days else {
+        return true;
+    };
+    // Undated markers are not stale
+    find_date(text).is_some_and(|date| today - date > max_age_days as i64)
+}
+
+/// Find the first `YYYY-MM-DD` date in the text and return it as days since 1970-01-01
+fn find_date(text: &str) -> Option<i64> {
+    let bytes = text.as_bytes();
+    (0..bytes.len().saturating_sub(9)).find_map(|i| parse_date(&bytes[i..i + 10]))
+}
+
+fn parse_date(bytes: &[u8]) -> Option<i64> {
+    let is_date_shape =
+        bytes.iter().enumerate().all(|(i, b)| if i == 4 || i == 7 { *b == b'-' } else { b.is_ascii_digit() });
+    if !is_date_shape {
+        return None;
+    }
+    let number = |range: std::ops::Range<usize>| bytes[range].iter().fold(0i64, |n, b| n * 10 + i64::from(b - b'0'));
+    let (year, month, day) = (number(0..4), number(5..7), number(8..10));
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day))
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
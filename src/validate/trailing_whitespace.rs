@@ -0,0 +1,35 @@
+use crate::validate::Violation;
+
+/// Validate no line ends with spaces or tabs
+/// `col` is the 1-based character position of the first trailing whitespace character
+/// `found` is the line itself (trailing whitespace included)
+pub fn validate(content: &str) -> Vec<Violation> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let trimmed = line.trim_end_matches([' ', '\t']);
+            if trimmed.len() == line.len() {
+                return None;
+            }
+            Some(Violation { line: i + 1, col: trimmed.chars().count() + 1, found: line.to_string() })
+        })
+        .collect()
+}
+
+/// Remove trailing spaces and tabs from every line except the ones `is_disabled` returns true for
+/// Line endings (LF / CRLF) are kept as is
+pub fn fix(content: &str, is_disabled: &dyn Fn(usize) -> bool) -> String {
+    content
+        .split_inclusive('\n')
+        .enumerate()
+        .map(|(i, line)| {
+            if is_disabled(i + 1) {
+                return line.to_string();
+            }
+            let body = line.strip_suffix('\n').unwrap_or(line);
+            let body = body.strip_suffix('\r').unwrap_or(body);
+            format!("{}{}", body.trim_end_matches([' ', '\t']), &line[body.len()..])
+        })
+        .collect()
+}
@@ -0,0 +1,5 @@
+fn added() {}
+
+// TODO: old
+fn a() {}
+// TODO: new
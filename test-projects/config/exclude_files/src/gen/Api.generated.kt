@@ -0,0 +1 @@
+val word = "NGWORD"
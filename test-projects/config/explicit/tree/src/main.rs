@@ -0,0 +1,4 @@
+// TODO: remove
+fn main() {
+    println!("main");
+}
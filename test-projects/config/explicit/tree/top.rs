@@ -0,0 +1,4 @@
+// TODO: remove
+fn top() {
+    println!("top");
+}
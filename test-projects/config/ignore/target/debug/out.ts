@@ -0,0 +1 @@
+const word = "NGWORD";
@@ -0,0 +1 @@
+fun bad() { println("no marker") }
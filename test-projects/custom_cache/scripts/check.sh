@@ -0,0 +1,4 @@
+#!/bin/sh
+# 実行回数を記録してから ALLOWED マーカーの有無を検証する
+echo "$1" >> "$(dirname "$0")/runs.log"
+grep -q ALLOWED "$1"
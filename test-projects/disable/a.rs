@@ -0,0 +1,9 @@
+// TODO: 1 rec_lint-disable-line
+// TODO: 2 rec_lint-disable-line fixme_check
+// rec_lint-disable-next-line todo_check
+// TODO: 4
+/* rec_lint-disable-next-line */
+// TODO FIXME: 6
+// FIXME: 7 rec_lint-disable-line todo_check rec_lint-disable-next-line fixme_check
+// FIXME: 8
+/* TODO: 9 rec_lint-disable-line todo_check, fixme_check */
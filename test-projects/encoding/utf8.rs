@@ -0,0 +1 @@
+let utf8 = "café";
@@ -0,0 +1,3 @@
+// TODO: a
+fn a() {}
+// FIXME: a
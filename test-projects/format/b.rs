@@ -0,0 +1,2 @@
+// TODO: b
+fn b() {}
@@ -0,0 +1,6 @@
+// TODO first line
+// TODO header
+// FIXME header
+// Copyright 2026
+// TODO later
+// FIXME later
@@ -0,0 +1 @@
+// TODO
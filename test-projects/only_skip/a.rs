@@ -0,0 +1,3 @@
+// TODO
+// FIXME
+fn main() { println!("x"); }
@@ -0,0 +1,2 @@
+// TODO: a
+// FIXME: a
@@ -0,0 +1,2 @@
+const name = 'alice';
+const greeting = `hello ${name}`;
@@ -0,0 +1,2 @@
+const name = 'alice';
+const city = "tokyo";
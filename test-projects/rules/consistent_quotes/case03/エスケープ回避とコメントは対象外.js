@@ -0,0 +1,3 @@
+// "comment" is ignored
+const message = "it's fine";
+/* block "comment" */
@@ -0,0 +1,4 @@
+"""docstring with 'quotes'"""
+# 'comment' is ignored
+name = "alice"
+city = 'tokyo'
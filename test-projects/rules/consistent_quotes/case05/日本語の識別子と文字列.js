@@ -0,0 +1,3 @@
+const 名前 = 'アリス';
+const 都市 = "東京";
+const 挨拶 = 'こんにちは\あ';
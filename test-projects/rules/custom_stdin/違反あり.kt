@@ -0,0 +1,3 @@
+fun bad() {
+    // TODO: implement
+}
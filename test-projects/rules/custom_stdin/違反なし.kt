@@ -0,0 +1 @@
+fun ok() { println("safe") }
@@ -0,0 +1,9 @@
+package app;
+
+import java.util.Date;
+import java.util.DateFormat;
+import static java.util.Date.from;
+
+public class Clock {
+    // import java.util.Date in a comment is not an import statement
+}
@@ -0,0 +1,6 @@
+import lodash from 'lodash';
+import { map } from "lodash/fp";
+import {
+  debounce,
+} from 'lodash-es';
+const _ = require('lodash');
@@ -0,0 +1,2 @@
+use std::sync::mpsc::channel;
+use std::sync::Mutex;
@@ -0,0 +1,9 @@
+package main
+
+import "fmt"
+
+import (
+	"net/http"
+	h "net/http/httptest"
+	"net"
+)
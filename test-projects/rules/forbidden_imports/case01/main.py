@@ -0,0 +1,3 @@
+import os.path as p, sys
+from os.path import join
+from os import path
@@ -0,0 +1,2 @@
+db.query("select 1")
+db.query(SAFE_SELECT)
@@ -0,0 +1 @@
+query
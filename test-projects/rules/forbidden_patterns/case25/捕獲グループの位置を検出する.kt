@@ -0,0 +1,2 @@
+val items = mutableListOf<String>()
+    var cache = mutableMapOf<String, Int>()
@@ -0,0 +1 @@
+val items = mutableListOf<String>()
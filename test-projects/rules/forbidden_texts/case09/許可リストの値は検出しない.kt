@@ -0,0 +1,3 @@
+val url = "/password_reset"
+val fixture = "test_password01"
+val secret = "password"
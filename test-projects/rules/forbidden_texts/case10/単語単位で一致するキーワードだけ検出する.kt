@@ -0,0 +1,4 @@
+val variable = 1
+let var = 1
+my_var = 2
+print(var)
@@ -0,0 +1 @@
+foo bar foo
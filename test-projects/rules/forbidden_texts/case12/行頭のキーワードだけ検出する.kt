@@ -0,0 +1,4 @@
+TODO: 行頭
+    TODO: インデントの後
+val todo = "TODO: 文字列"
+// TODO: コメント
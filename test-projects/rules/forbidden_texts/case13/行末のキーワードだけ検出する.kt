@@ -0,0 +1,4 @@
+val a = 1;
+val b = 2;   
+for (;;) {}
+val c = 3
@@ -0,0 +1,2 @@
+val url = "「password_reset」"
+val secret = "「password」"
@@ -0,0 +1,7 @@
+fn a() {}
+
+fn b() {}
+
+fn c() {}
+
+fn d() {}
@@ -0,0 +1,5 @@
+fn a() {}
+
+fn b() {}
+
+fn c() {}
@@ -0,0 +1,4 @@
+fn main() {
+    let a = 12345;
+}
+// 日本語のコメント
@@ -0,0 +1,4 @@
+fn main() {
+    let a = 123456789;
+    // 日本語のコメントです。
+}
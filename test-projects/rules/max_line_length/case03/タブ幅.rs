@@ -0,0 +1,4 @@
+fn main() {
+	let a = 12;
+	let b = 12345;
+}
@@ -0,0 +1,7 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn 足し算() {
+        assert_eq!(1 + 1, 2);
+    }
+}
@@ -0,0 +1,8 @@
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[ignore]
+    fn 足し算() {
+        assert_eq!(1 + 1, 2);
+    }
+}
@@ -0,0 +1,9 @@
+describe('calc', () => {
+  it.only('adds', () => {
+    expect(1 + 1).toBe(2);
+  });
+  // it.skip('commented out', () => {});
+  it('subtracts', () => {
+    expect(1 - 1).toBe(0);
+  });
+});
@@ -0,0 +1,8 @@
+// TODO(2000-01-01) 期限切れ
+fn main() {
+    // FIXME(2000-06-30): 期限切れ
+    // TODO(2999-12-31) 期限内
+    // TODO 日付なし
+    // TODO(alice) 日付なし
+    let todos = 1; // TODOS は対象外
+}
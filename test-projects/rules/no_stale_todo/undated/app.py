@@ -0,0 +1,5 @@
+# TODO 日付なし
+def main():
+    # TODO(2999-12-31) 日付あり
+    # FIXME は markers にない
+    pass
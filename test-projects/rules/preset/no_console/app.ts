@@ -0,0 +1,4 @@
+console.log("debug");
+  console.error ("failed");
+logger.console("ok");
+const consoleLog = 1;
@@ -0,0 +1,5 @@
+function f() {
+  debugger;
+  const debuggerEnabled = false;
+  return "debugger";
+}
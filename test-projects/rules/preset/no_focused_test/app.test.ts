@@ -0,0 +1,7 @@
+describe.only("group", () => {
+  it("runs", () => {});
+  test.only("focused", () => {});
+  fit("focused", () => {});
+  profit("not a test");
+  it.skip("skipped", () => {});
+});
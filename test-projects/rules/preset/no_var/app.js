@@ -0,0 +1,4 @@
+var count = 0;
+let variable = 1;
+const invar = 2;
+for (var i = 0; i < 3; i++) {}
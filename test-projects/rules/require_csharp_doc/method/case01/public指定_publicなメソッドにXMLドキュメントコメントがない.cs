@@ -0,0 +1,44 @@
+/// <summary>Stores users</summary>
+public class UserRepository
+{
+    public UserRepository(string connection)
+    {
+        Connect(connection);
+    }
+
+    /// <summary>Finds a user by id</summary>
+    public User Find(int id)
+    {
+        if (id < 0)
+        {
+            throw new ArgumentException(nameof(id));
+        }
+        var user = Load(id);
+        return user;
+    }
+
+    [HttpPost]
+    public async Task<bool> SaveAsync(User user)
+    {
+        await Task.Delay(1);
+        return true;
+    }
+
+    public static Dictionary<string, int> CountByName(List<User> users)
+    {
+        return new Dictionary<string, int>();
+    }
+
+    public override string ToString() => "repository";
+
+    public string Name => Format("name");
+
+    private void Connect(string connection)
+    {
+    }
+
+    protected virtual User Load(int id)
+    {
+        return null;
+    }
+}
@@ -0,0 +1,43 @@
+/// <summary>注文処理</summary>
+public class OrderService
+{
+    /// <summary>注文を処理する</summary>
+    public int Process(List<Order> orders)
+    {
+        var total = 0;
+        for (int i = 0; i < orders.Count; i++)
+        {
+            if (orders[i].IsValid()) {
+                total += orders[i].Price;
+            } else if (orders[i].IsDraft()) {
+                continue;
+            }
+        }
+        foreach (var order in orders)
+        {
+            Console.WriteLine(order);
+        }
+        while (total > 100) {
+            total -= 1;
+        }
+        switch (total)
+        {
+            case 0:
+                break;
+        }
+        try
+        {
+            Save(orders);
+        } catch (Exception e) {
+            lock (orders) {
+                throw new InvalidOperationException(e.Message);
+            }
+        }
+        return Sum(total);
+    }
+
+    private void Save(List<Order> orders)
+    {
+        orders.Clear();
+    }
+}
@@ -0,0 +1,40 @@
+namespace App.Models
+{
+    /// <summary>
+    /// A user of the app
+    /// </summary>
+    [Serializable]
+    public class User
+    {
+    }
+
+    public sealed partial class Order
+    {
+    }
+
+    /// <summary>Reads users</summary>
+    [Obsolete(
+        "use IUserStore")]
+    public interface IUserReader
+    {
+    }
+
+    [Serializable]
+    public struct Point
+    {
+    }
+
+    public enum Status
+    {
+        Open,
+        Closed,
+    }
+
+    internal class Cache
+    {
+    }
+
+    class Helper
+    {
+    }
+}
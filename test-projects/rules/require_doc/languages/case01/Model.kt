@@ -0,0 +1,4 @@
+/** 利用者 */
+class User
+
+class Missing
@@ -0,0 +1,8 @@
+/// 合計を返す
+pub fn sum(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+pub fn missing() {}
+
+fn private() {}
@@ -0,0 +1 @@
+class Foo; end
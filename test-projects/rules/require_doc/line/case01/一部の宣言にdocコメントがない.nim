@@ -0,0 +1,13 @@
+## Documented class
+class Documented
+
+class Missing
+
+## Documented function
+## with two lines
+def documented
+
+# plain comment is not a doc comment
+def missing
+
+## def inside a comment is not a declaration
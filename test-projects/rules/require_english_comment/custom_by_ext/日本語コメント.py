@@ -0,0 +1,2 @@
+# 日本語コメント
+query = "-- 日本語"
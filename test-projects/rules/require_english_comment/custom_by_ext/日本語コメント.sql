@@ -0,0 +1,2 @@
+-- 日本語コメント
+SELECT '# 日本語' FROM users;
@@ -0,0 +1,2 @@
+String s = "// not a comment 日本語";
+String block = "/* 日本語 */"; // 日本語コメント
@@ -0,0 +1,2 @@
+// Deploy to the 東 region first
+fn main() {}
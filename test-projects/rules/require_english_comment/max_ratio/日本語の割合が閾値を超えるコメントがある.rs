@@ -0,0 +1,2 @@
+// 東京リージョンに deploy する
+fn main() {}
@@ -0,0 +1,2 @@
+// 안녕하세요
+fn main() {}
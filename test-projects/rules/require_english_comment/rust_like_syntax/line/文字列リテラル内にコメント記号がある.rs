@@ -0,0 +1,7 @@
+let s = "// not a comment 日本語";
+let escaped = "\"// 日本語\"";
+let raw = r#"/* 日本語 "quoted" */"#;
+let multi = r"
+// 日本語
+";
+let quote = '"'; // 日本語コメント
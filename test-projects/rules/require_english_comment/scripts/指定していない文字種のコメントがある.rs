@@ -0,0 +1,2 @@
+// ひらがなのみ
+fn main() {}
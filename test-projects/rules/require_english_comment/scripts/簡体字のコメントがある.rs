@@ -0,0 +1,2 @@
+// 这是简体中文注释
+fn main() {}
@@ -0,0 +1,21 @@
+package config
+
+// MaxSize is the max size.
+const MaxSize = 10
+
+const MinSize = 1
+
+const defaultName = "x"
+
+// State values.
+const (
+	Ready State = iota
+	Running
+)
+
+const (
+	// Low priority.
+	Low = 1
+	High = 2
+	medium = 3
+)
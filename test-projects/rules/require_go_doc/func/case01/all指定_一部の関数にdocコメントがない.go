@@ -0,0 +1,23 @@
+package server
+
+// Start starts the server.
+func Start() error {
+	return nil
+}
+
+func Stop() {
+}
+
+// Handle handles a request.
+//go:noinline
+func (s *Server) Handle(req Request) {
+}
+
+func (s *Server) close() {
+}
+
+//go:generate stringer -type=State
+func helper() {
+	inner := func() {}
+	inner()
+}
@@ -0,0 +1,11 @@
+package server
+
+func Stop() {
+}
+
+func (s *Server) close() {
+}
+
+/* Run runs. */
+func Run() {
+}
@@ -0,0 +1,25 @@
+package model
+
+// User is a registered user.
+type User struct {
+	Name string
+}
+
+type Order struct {
+	ID int
+}
+
+// Repository stores users.
+type Repository interface {
+	Find(id int) User
+}
+
+type cache[K comparable, V any] struct {
+	items map[K]V
+}
+
+type reader interface {
+	Read() string
+}
+
+type ID int
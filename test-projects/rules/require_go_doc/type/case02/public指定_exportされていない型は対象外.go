@@ -0,0 +1,12 @@
+package model
+
+type Order struct {
+	ID int
+}
+
+type cache struct {
+	items map[string]string
+}
+
+//
+type Empty interface{}
@@ -0,0 +1,8 @@
+package sample
+
+type  Äpfel struct {}
+
+type  日本 struct {}
+
+// Ωmega は doc コメントあり
+type	Ωmega interface {}
@@ -0,0 +1,5 @@
+package pkg
+
+func Dial(addr string) error {
+	return nil
+}
@@ -0,0 +1,5 @@
+package pkg
+
+import "testing"
+
+func TestDial_invalidAddress(t *testing.T) {}
@@ -0,0 +1,19 @@
+package pkg
+
+type Server struct{}
+
+func NewServer() *Server {
+	return &Server{}
+}
+
+func (s *Server) Start() error {
+	return nil
+}
+
+func (s *Server) Stop() error {
+	return nil
+}
+
+func normalize(s string) string {
+	return s
+}
@@ -0,0 +1,7 @@
+package pkg
+
+import "testing"
+
+func TestNewServer(t *testing.T) {}
+
+func TestServer_Start(t *testing.T) {}
@@ -0,0 +1,5 @@
+package pkg
+
+func FindUser(id int) string {
+	return ""
+}
@@ -0,0 +1,5 @@
+package pkg
+
+import "testing"
+
+func TestFindUser(t *testing.T) {}
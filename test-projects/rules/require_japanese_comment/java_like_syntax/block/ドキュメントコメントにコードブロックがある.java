@@ -0,0 +1,10 @@
+/**
+ * 合計を計算する
+ * ```java
+ * int total = sum(1, 2); // returns 3
+ * ```
+ * 負の値も扱える
+ */
+int sum(int a, int b) {
+    return a + b;
+}
@@ -0,0 +1,10 @@
+/**
+ * 合計を計算する
+ * ~~~
+ * sum(1, 2)
+ * ~~~
+ * returns the total
+ */
+int sum(int a, int b) {
+    return a + b;
+}
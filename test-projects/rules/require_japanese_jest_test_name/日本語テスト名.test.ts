@@ -0,0 +1,13 @@
+describe('ユーザー作成', () => {
+  it("名前が空の場合はエラーになる", () => {
+    expect(() => createUser('')).toThrow();
+  });
+
+  test.each([1, 2])(`${'ID'}を指定して取得できる`, (id) => {
+    expect(findUser(id)).toBeDefined();
+  });
+
+  it.only('重複した名前は登録できない', () => {
+    submit(form);
+  });
+});
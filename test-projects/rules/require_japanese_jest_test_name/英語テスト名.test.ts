@@ -0,0 +1,13 @@
+describe('createUser', () => {
+  it("throws when the name is empty", () => {
+    expect(() => createUser('')).toThrow();
+  });
+
+  test(`returns the user's ${'id'}`, () => {
+    expect(createUser('a').id).toBe(1);
+  });
+
+  it.skip('ユーザーを削除できる', () => {
+    submit(form);
+  });
+});
@@ -0,0 +1,12 @@
+class UserTest {
+    @Test
+    void ユーザーを作成できる() {
+    }
+
+    @Test
+    void createUser() {
+    }
+
+    void helper() {
+    }
+}
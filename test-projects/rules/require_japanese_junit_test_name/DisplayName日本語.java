@@ -0,0 +1,13 @@
+@DisplayName("ユーザーのテスト")
+class UserTest {
+    @Test
+    @DisplayName("ユーザーを作成できる")
+    void createUser() {
+    }
+
+    @ParameterizedTest
+    @ValueSource(strings = {"a", "b"})
+    @DisplayName("メールアドレスを検証できる")
+    void validateEmail(String email) {
+    }
+}
@@ -0,0 +1,12 @@
+@DisplayName("User test")
+class UserTest {
+    @Test
+    @DisplayName("creates user")
+    void createUser() {
+    }
+
+    @Test
+    @DisplayName("メールアドレスを検証できる")
+    void validateEmail() {
+    }
+}
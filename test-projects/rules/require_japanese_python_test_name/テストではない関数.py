@@ -0,0 +1,9 @@
+def helper():
+    return 1
+
+
+def value_for_test():
+    return 2
+
+
+testing = True
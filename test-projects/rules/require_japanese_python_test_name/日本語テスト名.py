@@ -0,0 +1,6 @@
+def test_正常系():
+    assert add(1, 2) == 3
+
+
+async def test_非同期でも日本語():
+    assert await fetch() is not None
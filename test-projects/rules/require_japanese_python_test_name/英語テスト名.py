@@ -0,0 +1,10 @@
+def test_happy_path():
+    assert add(1, 2) == 3
+
+
+class TestUser:
+    def test_ユーザーを作成できる(self):
+        assert create_user("a")
+
+    async def test_delete_user(self):
+        assert await delete_user("a")
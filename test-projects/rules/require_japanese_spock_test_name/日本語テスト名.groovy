@@ -0,0 +1,14 @@
+class UserSpec extends Specification {
+    def setup() {
+    }
+
+    def "ユーザーを作成できる"() {
+        expect:
+        true
+    }
+
+    def 'メールアドレスを検証できる'() {
+        expect:
+        true
+    }
+}
@@ -0,0 +1,16 @@
+class UserSpec extends Specification {
+    def "creates user"() {
+        expect:
+        true
+    }
+
+    def 'validates email'() {
+        expect:
+        true
+    }
+
+    def "ユーザーを削除できる"() {
+        expect:
+        true
+    }
+}
@@ -0,0 +1,12 @@
+/** */
+fun emptyDoc() {}
+
+/**
+ *   
+ */
+fun whitespaceOnlyDoc() {}
+
+/**
+ * documented function
+ */
+fun documented() {}
@@ -0,0 +1,34 @@
+/** 最大件数 */
+const val MAX_COUNT = 10
+
+val missingTopLevel = "top"
+
+/** 利用者 */
+class User(
+    val id: Long,
+    val name: String,
+) {
+    /** 表示名 */
+    val displayName: String
+        get() {
+            val prefix = "user"
+            return "$prefix:$name"
+        }
+
+    var missingMember: Int = 0
+
+    private val hidden = 1
+
+    init {
+        val local = 1
+    }
+
+    fun greet(): String {
+        val message = "hello"
+        return message
+    }
+
+    companion object {
+        val companionValue = 1
+    }
+}
@@ -0,0 +1,14 @@
+/** 設定 */
+class Config {
+    companion object {
+        /** 既定のタイムアウト */
+        const val DEFAULT_TIMEOUT = 30
+
+        val missingCompanionValue = "x"
+
+        fun create(): Config {
+            val config = Config()
+            return config
+        }
+    }
+}
@@ -0,0 +1,14 @@
+<?php
+
+/** */
+function emptyDoc() {}
+
+/**
+ *   
+ */
+function whitespaceOnlyDoc() {}
+
+/**
+ * documented function
+ */
+function documented() {}
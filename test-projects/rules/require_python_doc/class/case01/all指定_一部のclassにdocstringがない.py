@@ -0,0 +1,11 @@
+class Documented:
+    """Documented class."""
+
+
+class MissingDoc:
+    pass
+
+
+class _Private(Base):
+
+    '''Private but documented.'''
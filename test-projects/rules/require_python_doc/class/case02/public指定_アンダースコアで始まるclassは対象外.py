@@ -0,0 +1,6 @@
+class _Internal:
+    pass
+
+
+class Public:
+    value = 1
@@ -0,0 +1,26 @@
+def documented():
+    """Documented."""
+    return 1
+
+
+async def fetch(
+    url,
+    timeout=10,
+):
+    """Multi-line signature."""
+
+
+def missing(a, b):
+    return a + b
+
+
+class Service:
+    """Service."""
+
+    def __init__(self):
+        self.value = 1
+
+    def _helper(self): return 1
+
+
+def one_line(): """Documented."""
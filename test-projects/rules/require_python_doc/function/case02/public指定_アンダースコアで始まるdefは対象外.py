@@ -0,0 +1,7 @@
+def _private():
+    return 1
+
+
+def public():
+    # comment is not a docstring
+    return 1
@@ -0,0 +1,14 @@
+def outer():
+    def inner():
+        pass
+    return inner
+
+
+class Service:
+    def run(self):
+        pass
+
+
+def documented():
+    """docstring あり"""
+    pass
@@ -0,0 +1,4 @@
+#!/usr/bin/env python
+"""Module docstring."""
+
+import os
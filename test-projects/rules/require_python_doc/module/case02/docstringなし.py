@@ -0,0 +1,2 @@
+# comment only
+import os
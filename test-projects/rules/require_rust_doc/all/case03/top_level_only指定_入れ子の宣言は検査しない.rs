@@ -0,0 +1,16 @@
+pub struct MissingStruct;
+
+pub fn missing_fn() {
+    pub struct NestedStruct;
+
+    pub fn nested_fn() {
+        let brace = '{';
+    }
+}
+
+/// ドキュメントあり
+pub fn documented_fn() {
+    let text = "}";
+}
+
+pub fn after_nested() {}
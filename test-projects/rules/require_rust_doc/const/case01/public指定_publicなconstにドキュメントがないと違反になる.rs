@@ -0,0 +1,16 @@
+pub const MISSING: usize = 1;
+
+/// documented constant
+pub const DOCUMENTED: usize = 2;
+
+const PRIVATE: usize = 3;
+
+pub const fn const_function() -> usize {
+    1
+}
+
+pub fn with_const_generics<const N: usize>() -> usize {
+    N
+}
+
+pub(crate) const MISSING_CRATE: &str = "crate";
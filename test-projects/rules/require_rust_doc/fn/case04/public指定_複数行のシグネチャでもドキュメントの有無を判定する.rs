@@ -0,0 +1,27 @@
+pub fn missing_multiline(
+    first: i32,
+    second: i32,
+) -> i32 {
+    first + second
+}
+
+/// documented function
+#[cfg_attr(
+    feature = "serde",
+    allow(clippy::too_many_arguments)
+)]
+pub fn documented_multiline<T>(
+    value: T,
+) -> T
+where
+    T: Clone,
+{
+    value
+}
+
+pub fn missing_where<T>(value: T) -> T
+where
+    T: Clone,
+{
+    value
+}
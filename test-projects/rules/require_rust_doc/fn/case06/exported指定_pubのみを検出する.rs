@@ -0,0 +1,12 @@
+pub fn missing_pub() {}
+
+pub(crate) fn crate_fn() {}
+
+pub(super) fn super_fn() {}
+
+pub(in crate::foo) fn in_path_fn() {}
+
+fn private_fn() {}
+
+/// documented function
+pub fn documented_pub() {}
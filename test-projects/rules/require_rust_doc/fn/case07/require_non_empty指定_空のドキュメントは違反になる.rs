@@ -0,0 +1,18 @@
+///
+pub fn empty_line_doc() {}
+
+///   
+///
+pub fn whitespace_only_doc() {}
+
+/** */
+pub fn empty_block_doc() {}
+
+/**
+ *
+ */
+pub fn decoration_only_doc() {}
+
+///
+/// documented function
+pub fn documented() {}
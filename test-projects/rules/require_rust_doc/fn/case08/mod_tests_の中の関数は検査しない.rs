@@ -0,0 +1,12 @@
+pub fn missing_fn() {}
+
+#[cfg(test)]
+mod tests {
+    pub fn helper() {}
+
+    mod nested {
+        pub fn nested_helper() {}
+    }
+}
+
+pub fn after_tests() {}
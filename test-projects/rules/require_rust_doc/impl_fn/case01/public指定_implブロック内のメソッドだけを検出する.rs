@@ -0,0 +1,29 @@
+pub fn free_function() {}
+
+/// documented struct
+pub struct Counter {
+    count: usize,
+}
+
+impl Counter {
+    /// documented method
+    pub fn documented(&self) -> usize {
+        self.count
+    }
+
+    pub fn missing(&mut self) {
+        if self.count > 0 {
+            self.count -= 1;
+        }
+    }
+
+    fn private_method(&self) {}
+}
+
+impl Default for Counter {
+    fn default() -> Self {
+        Counter { count: 0 }
+    }
+}
+
+pub fn another_free_function() {}
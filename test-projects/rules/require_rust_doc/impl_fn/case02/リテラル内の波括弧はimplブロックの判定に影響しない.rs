@@ -0,0 +1,16 @@
+/// documented struct
+pub struct Bracket;
+
+impl Bracket {
+    /// open
+    pub fn open(&self) -> char { '{' }
+
+    /// escaped
+    pub fn escaped(&self) -> String {
+        format!("{{")
+    }
+
+    fn private_helper(&self) {}
+}
+
+fn missing() {}
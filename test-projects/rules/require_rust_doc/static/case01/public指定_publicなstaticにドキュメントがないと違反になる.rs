@@ -0,0 +1,12 @@
+pub static MISSING: usize = 1;
+
+/// documented static
+pub static DOCUMENTED: usize = 2;
+
+static PRIVATE: usize = 3;
+
+pub static mut MISSING_MUT: usize = 4;
+
+pub fn with_static_lifetime(value: &'static str) -> &'static str {
+    value
+}
@@ -0,0 +1,7 @@
+pub fn create_order() {}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn create_order_works() {}
+}
@@ -0,0 +1,5 @@
+pub fn find_user(id: u32) -> Option<String> {
+    None
+}
+
+pub fn delete_user(id: u32) {}
@@ -0,0 +1,6 @@
+use super::user::find_user;
+
+#[test]
+fn find_user_returns_none() {
+    assert!(find_user(1).is_none());
+}
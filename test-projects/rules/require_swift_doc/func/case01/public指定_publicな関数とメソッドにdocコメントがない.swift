@@ -0,0 +1,35 @@
+/// A repository of users
+public class UserRepository {
+    /// Finds a user by id
+    public func find(id: Int) -> String? {
+        return nil
+    }
+
+    public func save(name: String) {
+    }
+
+    public class func shared() -> UserRepository {
+        return UserRepository()
+    }
+
+    @objc public static func reset() {
+    }
+
+    open override func copy() -> Any {
+        return self
+    }
+
+    func internalHelper() {
+    }
+
+    private func privateHelper() {
+    }
+
+    fileprivate func filePrivateHelper() {
+    }
+}
+
+@discardableResult
+public func format(value: Int) -> String {
+    return String(value)
+}
@@ -0,0 +1,37 @@
+import Foundation
+
+/// A user of the app
+public final class User {
+    let name: String
+
+    init(name: String) {
+        self.name = name
+    }
+}
+
+struct Order {
+    let id: Int
+}
+
+/**
+ * Status of an order
+ */
+enum Status {
+    case open
+    case closed
+}
+
+@MainActor
+/// Renders screens
+protocol Renderer {
+    func render()
+}
+
+@available(iOS 15, *)
+protocol Loader {
+    func load()
+}
+
+private enum Cache {
+    case empty
+}
@@ -0,0 +1,27 @@
+public struct Order {
+    let id: Int
+}
+
+/// A base view controller
+open class BaseViewController {
+}
+
+///
+open class EmptyDocViewController {
+}
+
+class InternalService {
+}
+
+internal struct InternalValue {
+}
+
+fileprivate enum FilePrivateStatus {
+    case ok
+}
+
+private protocol PrivateDelegate {
+}
+
+public protocol Delegate {
+}
@@ -0,0 +1,2 @@
+fn a() {}
+fn b() {}
@@ -0,0 +1,13 @@
+/**
+ * Documented class
+ */
+export class Documented {}
+
+export class MissingDoc {}
+
+/** Decorated class */
+@Component({ selector: 'app' })
+export class Decorated {}
+
+/* not a TSDoc */
+abstract class Internal {}
@@ -0,0 +1,10 @@
+class Internal {}
+
+export default class {
+  run(): void {}
+}
+
+/** Documented class */
+export class Documented {}
+
+export abstract class Base {}
@@ -0,0 +1,15 @@
+/** Documented function */
+export function documented(): void {}
+
+function missing(): void {}
+
+async function load(): Promise<void> {}
+
+const handler = (event: Event): void => {
+  console.log(event);
+};
+
+/** Documented arrow */
+const documentedArrow = () => 1;
+
+const value = 42;
@@ -0,0 +1,13 @@
+function internal(): void {}
+
+const internalArrow = () => 1;
+
+export function exported(): void {}
+
+export const exportedArrow = async (id: string) => {
+  return id;
+};
+
+export const LIMIT = 10;
+
+export default function () {}
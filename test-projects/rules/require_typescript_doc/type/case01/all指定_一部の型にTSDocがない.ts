@@ -0,0 +1,23 @@
+/** Documented interface */
+export interface Documented {
+  name: string;
+}
+
+interface Missing {
+  id: number;
+}
+
+export enum Color {
+  Red,
+  Green,
+}
+
+const enum Direction {
+  Up,
+  Down,
+}
+
+/** Documented alias */
+export type Id = string;
+
+type Pair<T> = [T, T];
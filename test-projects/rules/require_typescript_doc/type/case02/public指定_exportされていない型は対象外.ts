@@ -0,0 +1,19 @@
+interface Internal {
+  id: number;
+}
+
+export interface Exported {
+  id: number;
+}
+
+enum InternalColor {
+  Red,
+}
+
+export declare enum Color {
+  Red,
+}
+
+type InternalId = string;
+
+export type Id = string;
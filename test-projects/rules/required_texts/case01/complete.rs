@@ -0,0 +1,4 @@
+// SPDX-License-Identifier: MIT
+// Copyright 2024 rec_lint
+
+fn main() {}
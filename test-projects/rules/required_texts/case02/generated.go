@@ -0,0 +1,3 @@
+// Code generated by protoc. DO NOT EDIT.
+
+package api
@@ -0,0 +1,3 @@
+// TODO: a
+// FIXME: a
+// NOTE: a
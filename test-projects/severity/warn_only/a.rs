@@ -0,0 +1 @@
+// FIXME: a
@@ -0,0 +1 @@
+// TODO(2000-01-01)
@@ -0,0 +1,77 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use std::fs;
+
+use rec_lint::commands::add::TEMPLATES;
+use rec_lint::rule::parser::RawConfig;
+use rec_lint::rule::Config;
+
+const BUNDLED_SCHEMA: &str = include_str!("../../schema/rec_lint.schema.json");
+
+#[test]
+fn 組み込みテンプレートはすべて読み込めてルールに変換できる() {
+    for template in TEMPLATES {
+        let content = rec_lint::commands::add::content(Some(template.name)).unwrap();
+        let raw = RawConfig::parse(&content).unwrap_or_else(|e| panic!("{}: {e:?}", template.name));
+        Config::try_from(raw).unwrap_or_else(|e| panic!("{}: {e}", template.name));
+    }
+}
+
+#[test]
+fn 組み込みテンプレートはすべてスキーマに適合する() {
+    let schema: serde_json::Value = serde_json::from_str(BUNDLED_SCHEMA).unwrap();
+    let validator = jsonschema::options().build(&schema).unwrap();
+    for template in TEMPLATES {
+        let content = rec_lint::commands::add::content(Some(template.name)).unwrap();
+        let yaml: serde_json::Value = serde_yaml::from_str(&content).unwrap();
+        let errors: Vec<String> = validator.iter_errors(&yaml).map(|e| e.to_string()).collect();
+        assert!(errors.is_empty(), "{}: {errors:?}", template.name);
+    }
+}
+
+#[test]
+fn テンプレートを指定するとテンプレートのルールで作成する() {
+    let dir = tempfile::tempdir().unwrap();
+
+    rec_lint::commands::add::run(dir.path(), Some("rust-lib")).unwrap();
+
+    let content = fs::read_to_string(dir.path().join(".rec_lint.yaml")).unwrap();
+    assert!(content.starts_with("# yaml-language-server: $schema="));
+    assert!(content.contains("  - require_rust_doc:"));
+}
+
+#[test]
+fn テンプレートを指定しなければヘッダーだけのファイルを作成する() {
+    let dir = tempfile::tempdir().unwrap();
+
+    rec_lint::commands::add::run(dir.path(), None).unwrap();
+
+    let content = fs::read_to_string(dir.path().join(".rec_lint.yaml")).unwrap();
+    assert_eq!(content.lines().count(), 1);
+}
+
+#[test]
+fn テンプレートの一覧を表示する() {
+    common::assert_output(
+        &rec_lint::commands::add::list_templates(),
+        "
+        rust-lib        Rust library (rustdoc, unit tests)
+        kotlin-service  Kotlin service (KDoc, Kotest)
+        php-app         PHP application (PHPDoc, PHPUnit)
+        java-app        Java application (JUnit, imports)
+        ",
+    );
+}
+
+#[test]
+fn toml設定ファイルが既に存在する場合はyamlを作成せずにエラーになる() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join(".rec_lint.toml");
+    fs::write(&config, "").unwrap();
+
+    let err = rec_lint::commands::add::run(dir.path(), None).unwrap_err().to_string();
+
+    assert_eq!(err, format!("File already exists: {}", config.display()));
+    assert!(!dir.path().join(".rec_lint.yaml").exists());
+}
@@ -0,0 +1,17 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::check;
+
+#[test]
+#[allow(non_snake_case)]
+fn html_out_は_ブラウザを開かずに指定ファイルへ書き出す() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("check.html");
+    let result = check::write_html(&common::test_project_path("check/list"), &output, None).unwrap();
+    common::assert_output(&result, &format!("Written: {}", output.display()));
+
+    let html = std::fs::read_to_string(&output).unwrap();
+    assert!(html.contains("<title>rec_lint check - "));
+    assert!(html.contains("forbidden_texts"));
+}
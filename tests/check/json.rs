@@ -0,0 +1,60 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::check;
+use rec_lint::commands::CheckMode;
+
+#[test]
+#[allow(non_snake_case)]
+fn json_は_ルールとガイドラインの詳細をツリーで出力し_ルールのないディレクトリは出力しない() {
+    let result = check::run(CheckMode::Json, &common::test_project_path("check/json"), None).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            {
+              "children": [
+                {
+                  "children": [],
+                  "config_file": "src/.rec_lint.yaml",
+                  "guidelines": [],
+                  "name": "src",
+                  "path": "src",
+                  "rule_types": [
+                    "forbidden_texts"
+                  ],
+                  "rules": [
+                    {
+                      "label": "src-rule",
+                      "match": [
+                        "file_ends_with: .rs"
+                      ],
+                      "message": "FIXME found",
+                      "type": "forbidden_texts"
+                    }
+                  ]
+                }
+              ],
+              "config_file": ".rec_lint.yaml",
+              "guidelines": [
+                {
+                  "match": [],
+                  "message": "root-guideline"
+                }
+              ],
+              "name": ".",
+              "path": ".",
+              "rule_types": [
+                "forbidden_texts"
+              ],
+              "rules": [
+                {
+                  "label": "root-rule",
+                  "match": [],
+                  "message": "TODO found",
+                  "type": "forbidden_texts"
+                }
+              ]
+            }
+        "#,
+    );
+}
@@ -0,0 +1,26 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::check;
+use rec_lint::commands::CheckMode;
+
+#[test]
+#[allow(non_snake_case)]
+fn lint_は_messageが空のルールを報告する() {
+    let result = check::run(CheckMode::Lint, &common::test_project_path("check/lint"), None).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            Problems: empty/.rec_lint.yaml
+              - rule 'no-message' (forbidden_texts): message is empty
+              - rule 'blank-message' (forbidden_patterns): message is empty
+        "#,
+    );
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn lint_は_messageが空のpresetを報告しない() {
+    let result = check::run(CheckMode::Lint, &common::test_project_path("check/lint_preset"), None).unwrap();
+    common::assert_output(&result, "No problems found in rule config files.");
+}
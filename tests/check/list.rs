@@ -7,8 +7,7 @@ use rec_lint::commands::CheckMode;
 #[test]
 #[allow(non_snake_case)]
 fn list_は_rec_lint_yaml_があるディレクトリのみ表示する() {
-    std::env::set_current_dir(common::test_project_path("check/list")).unwrap();
-    let result = check::run(CheckMode::List).unwrap();
+    let result = check::run(CheckMode::List, &common::test_project_path("check/list"), None).unwrap();
     common::assert_output(
         &result,
         r#"
@@ -17,3 +16,48 @@ fn list_は_rec_lint_yaml_があるディレクトリのみ表示する() {
         "#,
     );
 }
+
+#[test]
+#[allow(non_snake_case)]
+fn list_は_サブディレクトリを指定してもルートから表示する() {
+    let result = check::run(CheckMode::List, &common::test_project_path("check/list/src"), None).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            ./.rec_lint.yaml: [ forbidden_texts ]
+            src/.rec_lint.yaml: [ custom ]
+        "#,
+    );
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn list_は_depth_より深い_rec_lint_yaml_を表示しない() {
+    let result = check::run(CheckMode::List, &common::test_project_path("check/depth"), Some(1)).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            ./.rec_lint.yaml: [ forbidden_texts ]
+            a/.rec_lint.yaml: [ forbidden_texts ]
+        "#,
+    );
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn list_は_depth_が_0_でもルートの_rec_lint_yaml_を表示する() {
+    let result = check::run(CheckMode::List, &common::test_project_path("check/depth"), Some(0)).unwrap();
+    common::assert_output(&result, "./.rec_lint.yaml: [ forbidden_texts ]");
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn list_は_配下の別のルート設定のディレクトリを表示しない() {
+    let result = check::run(CheckMode::List, &common::test_project_path("config/monorepo/project_a"), None).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            ./.rec_lint.yaml: [ forbidden_texts ]
+        "#,
+    );
+}
@@ -7,8 +7,7 @@ use rec_lint::commands::CheckMode;
 #[test]
 #[allow(non_snake_case)]
 fn schema_は_不正なyamlファイルをエラー報告する() {
-    std::env::set_current_dir(common::test_project_path("check/schema")).unwrap();
-    let result = check::run(CheckMode::Schema).unwrap();
+    let result = check::run(CheckMode::Schema, &common::test_project_path("check/schema"), None).unwrap();
     common::assert_output(
         &result,
         r#"
@@ -7,8 +7,7 @@ use rec_lint::commands::CheckMode;
 #[test]
 #[allow(non_snake_case)]
 fn tree_は_ルールがあるディレクトリのみ表示し_除外ディレクトリは表示しない() {
-    std::env::set_current_dir(common::test_project_path("check/tree")).unwrap();
-    let result = check::run(CheckMode::Tree).unwrap();
+    let result = check::run(CheckMode::Tree, &common::test_project_path("check/tree"), None).unwrap();
     common::assert_output(
         &result,
         r#"
@@ -17,3 +16,30 @@ fn tree_は_ルールがあるディレクトリのみ表示し_除外ディレ
         "#,
     );
 }
+
+#[test]
+#[allow(non_snake_case)]
+fn tree_は_depth_より深いディレクトリを表示しない() {
+    let result = check::run(CheckMode::Tree, &common::test_project_path("check/depth"), Some(1)).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            .        [ forbidden_texts ]
+            `-- a    [ forbidden_texts ]
+        "#,
+    );
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn tree_は_depth_を指定しなければすべての階層を表示する() {
+    let result = check::run(CheckMode::Tree, &common::test_project_path("check/depth"), None).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            .            [ forbidden_texts ]
+            `-- a        [ forbidden_texts ]
+                `-- b    [ forbidden_texts ]
+        "#,
+    );
+}
@@ -0,0 +1,21 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::check;
+use rec_lint::commands::CheckMode;
+
+#[test]
+#[allow(non_snake_case)]
+fn validate_は_スキーマでは検出できないルールのエラーを設定ファイルごとに報告する() {
+    let result = check::run(CheckMode::Validate, &common::test_project_path("check/validate"), None).unwrap();
+    assert_eq!(result[0], "Invalid: invalid/.rec_lint.yaml");
+    assert!(result[1].starts_with("  - Rule 'broken-pattern': invalid regex"), "{}", result[1]);
+    assert!(result[2..].iter().all(|line| line.starts_with("    ")));
+}
+
+#[test]
+#[allow(non_snake_case)]
+fn validate_は_エラーがなければその旨を表示する() {
+    let result = check::run(CheckMode::Validate, &common::test_project_path("check/lint"), None).unwrap();
+    common::assert_output(&result, "All rule config files are valid.");
+}
@@ -0,0 +1,23 @@
+use rec_lint::commands::Lang;
+
+#[test]
+fn lang_enでは英語の説明を出力する() {
+    let result = rec_lint::commands::desc::run(Lang::En).unwrap();
+    assert_eq!(result[0], "rec_lint is a linter that checks code based on config files ( .rec_lint.yaml )");
+    assert_eq!(result.len(), rec_lint::commands::desc::run(Lang::Ja).unwrap().len());
+}
+
+#[test]
+fn langの指定がなければ環境変数の言語になる() {
+    // The only test in this binary that touches the environment
+    std::env::set_var(Lang::ENV, "en");
+    assert_eq!(Lang::resolve(None).unwrap(), Lang::En);
+    assert_eq!(Lang::resolve(Some(Lang::Ja)).unwrap(), Lang::Ja);
+
+    std::env::set_var(Lang::ENV, "fr");
+    let err = Lang::resolve(None).unwrap_err().to_string();
+    assert_eq!(err, "Invalid REC_LINT_LANG: 'fr' (expected ja or en)");
+
+    std::env::remove_var(Lang::ENV);
+    assert_eq!(Lang::resolve(None).unwrap(), Lang::Ja);
+}
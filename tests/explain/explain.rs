@@ -0,0 +1,113 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::Lang;
+use rec_lint::rule::parser::RawConfig;
+use rec_lint::rule::types::{PRESET_INFOS, RULE_TYPE_INFOS};
+use rec_lint::rule::{Config, RULE_TYPES};
+
+#[test]
+fn ルールタイプの説明と設定例を出力する() {
+    let result = rec_lint::commands::explain::run("require_japanese_kotest_test_name", Lang::Ja).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            require_japanese_kotest_test_name: Kotest テスト名が日本語でないファイルを検出
+
+            例:
+            rule:
+              - require_japanese_kotest_test_name:
+                  label: テスト名は日本語
+                  message: テスト名は日本語にすること
+        "#,
+    );
+}
+
+#[test]
+fn lang_enでは英語の説明を出力する() {
+    let result = rec_lint::commands::explain::run("require_japanese_kotest_test_name", Lang::En).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            require_japanese_kotest_test_name: Detects Kotest test names that are not in Japanese
+
+            Example:
+            rule:
+              - require_japanese_kotest_test_name:
+                  label: テスト名は日本語
+                  message: テスト名は日本語にすること
+        "#,
+    );
+}
+
+#[test]
+fn 不明なルールタイプは有効なタイプを列挙したエラーになる() {
+    let err = rec_lint::commands::explain::run("unknown", Lang::Ja).unwrap_err().to_string();
+    assert!(err.starts_with("Unknown rule type: unknown\n\nValid rule types:\n  - forbidden_texts\n"));
+    assert!(err.ends_with("  - no_stale_todo"));
+}
+
+#[test]
+fn すべてのルールタイプに説明がある() {
+    let types: Vec<&str> = RULE_TYPE_INFOS.iter().map(|info| info.type_name).collect();
+    assert_eq!(types, RULE_TYPES);
+}
+
+#[test]
+fn すべての設定例は有効な設定として読み込める() {
+    for info in RULE_TYPE_INFOS {
+        let lines = rec_lint::commands::explain::run(info.type_name, Lang::Ja).unwrap();
+        let yaml = lines.iter().skip_while(|line| *line != "rule:").cloned().collect::<Vec<_>>().join("\n");
+        let config = Config::try_from(RawConfig::parse(&yaml).unwrap());
+        assert!(config.is_ok(), "{}: {:?}", info.type_name, config.err());
+    }
+}
+
+#[test]
+fn presetの説明にはプリセットの一覧を出力する() {
+    let result = rec_lint::commands::explain::run("preset", Lang::Ja).unwrap();
+    let presets: Vec<&str> =
+        result.iter().skip_while(|line| *line != "プリセット:").map(|line| line.as_str()).collect();
+    assert_eq!(
+        presets,
+        vec![
+            "プリセット:",
+            "  - no_console: console.log などの console の呼び出しを検出",
+            "  - no_debugger: debugger 文を検出",
+            "  - no_var: var による変数宣言を検出",
+            "  - no_focused_test: it.only や fdescribe などのフォーカスされたテストを検出",
+        ]
+    );
+}
+
+#[test]
+fn スキーマのプリセット名はプリセットの一覧と一致する() {
+    let schema: serde_json::Value =
+        serde_json::from_str(include_str!("../../schema/parts/rules/preset.schema.json")).unwrap();
+    let names = &schema["definitions"]["presetRule"]["allOf"][1]["properties"]["name"]["enum"];
+    let expected: Vec<&str> = PRESET_INFOS.iter().map(|preset| preset.name).collect();
+    assert_eq!(names, &serde_json::json!(expected));
+}
+
+#[test]
+fn lang_enではプリセットの一覧も英語で出力する() {
+    let result = rec_lint::commands::explain::run("preset", Lang::En).unwrap();
+    let presets: Vec<&str> = result.iter().skip_while(|line| *line != "Presets:").map(|line| line.as_str()).collect();
+    assert_eq!(
+        presets,
+        vec![
+            "Presets:",
+            "  - no_console: Detects console calls such as console.log",
+            "  - no_debugger: Detects debugger statements",
+            "  - no_var: Detects variable declarations with var",
+            "  - no_focused_test: Detects focused tests such as it.only and fdescribe",
+        ]
+    );
+}
+
+#[test]
+fn すべてのルールタイプに英語の説明がある() {
+    for info in RULE_TYPE_INFOS {
+        assert!(!info.description_en.is_empty(), "{}", info.type_name);
+    }
+}
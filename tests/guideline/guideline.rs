@@ -0,0 +1,52 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::GuidelineFormat;
+
+#[test]
+fn ルートから対象ディレクトリまでのガイドラインを表示する() {
+    let dir = common::test_project_path("guideline/src");
+    let result = rec_lint::commands::guideline::run(&dir, GuidelineFormat::Text).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ guideline ] 命名は日本語の仕様書に合わせること
+            [ guideline ] src: N + 1 問題が発生するクエリがないか確認すること
+        "#,
+    );
+}
+
+#[test]
+fn jsonではガイドラインごとにsource_dirとmatchを出力する() {
+    let dir = common::test_project_path("guideline/src");
+    let result = rec_lint::commands::guideline::run(&dir, GuidelineFormat::Json).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            {
+              "match_applied": false,
+              "guidelines": [
+                {
+                  "source_dir": ".",
+                  "message": "命名は日本語の仕様書に合わせること",
+                  "match": []
+                },
+                {
+                  "source_dir": "src",
+                  "message": "N + 1 問題が発生するクエリがないか確認すること",
+                  "match": [
+                    {
+                      "pattern": "file_ends_with",
+                      "keywords": [
+                        "Query.php",
+                        "Command.php"
+                      ],
+                      "cond": "or"
+                    }
+                  ]
+                }
+              ]
+            }
+        "#,
+    );
+}
@@ -0,0 +1,83 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+use std::fs;
+
+const VERSION: &str = include_str!("../../.version");
+
+fn expected_content() -> String {
+    format!(
+        "# yaml-language-server: $schema=https://raw.githubusercontent.com/suzuki-hoge/rec_lint/refs/tags/v{}/schema/rec_lint_config.schema.json",
+        VERSION.trim()
+    )
+}
+
+#[test]
+fn 設定ファイルを作成する() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join(".rec_lint_config.yaml");
+
+    let result = rec_lint::commands::init::run(dir.path(), false, false).unwrap();
+
+    common::assert_output(&result, &format!("Created: {}", config.display()));
+    assert_eq!(fs::read_to_string(&config).unwrap(), format!("{}\n", expected_content()));
+}
+
+#[test]
+fn 設定ファイルが既に存在する場合は上書きせずにエラーになる() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join(".rec_lint_config.yaml");
+    fs::write(&config, "script_dir: scripts\n").unwrap();
+
+    let err = rec_lint::commands::init::run(dir.path(), false, false).unwrap_err().to_string();
+
+    assert_eq!(err, format!("File already exists: {} (use --force to overwrite)", config.display()));
+    assert_eq!(fs::read_to_string(&config).unwrap(), "script_dir: scripts\n");
+}
+
+#[test]
+fn forceを指定すると既存の設定ファイルを上書きする() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join(".rec_lint_config.yaml");
+    fs::write(&config, "script_dir: scripts\n").unwrap();
+
+    rec_lint::commands::init::run(dir.path(), false, true).unwrap();
+
+    assert_eq!(fs::read_to_string(&config).unwrap(), format!("{}\n", expected_content()));
+}
+
+#[test]
+fn stdoutを指定するとファイルを作成せずに内容を出力する() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let result = rec_lint::commands::init::run(dir.path(), true, false).unwrap();
+
+    common::assert_output(&result, &expected_content());
+    assert!(!dir.path().join(".rec_lint_config.yaml").exists());
+}
+
+#[test]
+fn toml設定ファイルが既に存在する場合もyamlを作成せずにエラーになる() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join(".rec_lint_config.toml");
+    fs::write(&config, "script_dir = \"scripts\"\n").unwrap();
+
+    let err = rec_lint::commands::init::run(dir.path(), false, false).unwrap_err().to_string();
+
+    assert_eq!(err, format!("File already exists: {} (use --force to overwrite)", config.display()));
+    assert!(!dir.path().join(".rec_lint_config.yaml").exists());
+}
+
+#[test]
+fn forceを指定すると既存のtoml設定ファイルをyamlで置き換える() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join(".rec_lint_config.toml"), "script_dir = \"scripts\"\n").unwrap();
+
+    rec_lint::commands::init::run(dir.path(), false, true).unwrap();
+
+    assert!(!dir.path().join(".rec_lint_config.toml").exists());
+    assert_eq!(
+        fs::read_to_string(dir.path().join(".rec_lint_config.yaml")).unwrap(),
+        format!("{}\n", expected_content())
+    );
+}
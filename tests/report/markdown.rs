@@ -0,0 +1,36 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+#[test]
+fn dir3指定のときdir1とdir3のルールがタイプ付きで表示される() {
+    let dir = common::test_project_path("rec_tree/dir1/dir2/dir3");
+    let result = rec_lint::commands::report::run(&dir, None).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            # rec_lint ルール一覧
+
+            | ラベル | タイプ | メッセージ | 対象 | 設定ファイル |
+            | --- | --- | --- | --- | --- |
+            | dir1-rule | `forbidden_texts` | dir1 violation | 全ファイル | `dir1/.rec_lint.yaml` |
+            | dir3-rule | `forbidden_texts` | dir3 violation | 全ファイル | `dir1/dir2/dir3/.rec_lint.yaml` |
+        "#,
+    );
+}
+
+#[test]
+fn toml設定のときは設定ファイルにtomlのファイル名を表示する() {
+    let dir = common::test_project_path("config/toml/src");
+    let result = rec_lint::commands::report::run(&dir, None).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            # rec_lint ルール一覧
+
+            | ラベル | タイプ | メッセージ | 対象 | 設定ファイル |
+            | --- | --- | --- | --- | --- |
+            | toml test | `forbidden_texts` | TOML設定のルール | 全ファイル | `.rec_lint.toml` |
+            | toml nested | `max_line_length` | 行が長すぎます | 全ファイル | `src/.rec_lint.toml` |
+        "#,
+    );
+}
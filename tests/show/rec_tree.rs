@@ -1,10 +1,12 @@
 #[path = "../common/mod.rs"]
 mod common;
 
+use rec_lint::commands::ShowFormat;
+
 #[test]
 fn dir1指定のときdir1のルールが表示される() {
     let dir = common::test_project_path("rec_tree/dir1");
-    let result = rec_lint::commands::show::run(&dir).unwrap();
+    let result = rec_lint::commands::show::run(&dir, ShowFormat::Text).unwrap();
     common::assert_output(
         &result,
         r#"
@@ -17,7 +19,7 @@ fn dir1指定のときdir1のルールが表示される() {
 #[test]
 fn dir2指定のときdir1のルールが表示される() {
     let dir = common::test_project_path("rec_tree/dir1/dir2");
-    let result = rec_lint::commands::show::run(&dir).unwrap();
+    let result = rec_lint::commands::show::run(&dir, ShowFormat::Text).unwrap();
     common::assert_output(
         &result,
         r#"
@@ -30,7 +32,7 @@ fn dir2指定のときdir1のルールが表示される() {
 #[test]
 fn dir3指定のときdir1とdir3のルールが表示される() {
     let dir = common::test_project_path("rec_tree/dir1/dir2/dir3");
-    let result = rec_lint::commands::show::run(&dir).unwrap();
+    let result = rec_lint::commands::show::run(&dir, ShowFormat::Text).unwrap();
     common::assert_output(
         &result,
         r#"
@@ -45,7 +47,7 @@ fn dir3指定のときdir1とdir3のルールが表示される() {
 #[test]
 fn file1指定のときdir1のルールが表示される() {
     let file = common::test_project_path("rec_tree/dir1/file1.txt");
-    let result = rec_lint::commands::show::run(&file).unwrap();
+    let result = rec_lint::commands::show::run(&file, ShowFormat::Text).unwrap();
     common::assert_output(
         &result,
         r#"
@@ -58,7 +60,7 @@ fn file1指定のときdir1のルールが表示される() {
 #[test]
 fn file2指定のときdir1のルールが表示される() {
     let file = common::test_project_path("rec_tree/dir1/dir2/file1.txt");
-    let result = rec_lint::commands::show::run(&file).unwrap();
+    let result = rec_lint::commands::show::run(&file, ShowFormat::Text).unwrap();
     common::assert_output(
         &result,
         r#"
@@ -71,7 +73,7 @@ fn file2指定のときdir1のルールが表示される() {
 #[test]
 fn file3指定のときdir1とdir3のルールが表示される() {
     let file = common::test_project_path("rec_tree/dir1/dir2/dir3/file1.txt");
-    let result = rec_lint::commands::show::run(&file).unwrap();
+    let result = rec_lint::commands::show::run(&file, ShowFormat::Text).unwrap();
     common::assert_output(
         &result,
         r#"
@@ -82,3 +84,40 @@ fn file3指定のときdir1とdir3のルールが表示される() {
         "#,
     );
 }
+
+#[test]
+fn jsonではルールとガイドラインを継承元ディレクトリとともに出力する() {
+    let dir = common::test_project_path("rec_tree/dir1/dir2/dir3");
+    let result = rec_lint::commands::show::run(&dir, ShowFormat::Json).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            {
+              "rules": [
+                {
+                  "type": "forbidden_texts",
+                  "label": "dir1-rule",
+                  "message": "dir1 violation",
+                  "source_dir": "dir1"
+                },
+                {
+                  "type": "forbidden_texts",
+                  "label": "dir3-rule",
+                  "message": "dir3 violation",
+                  "source_dir": "dir1/dir2/dir3"
+                }
+              ],
+              "guidelines": [
+                {
+                  "message": "dir1-guideline",
+                  "source_dir": "dir1"
+                },
+                {
+                  "message": "dir3-guideline",
+                  "source_dir": "dir1/dir2/dir3"
+                }
+              ]
+            }
+        "#,
+    );
+}
@@ -0,0 +1,42 @@
+#[path = "../common/mod.rs"]
+mod common;
+
+#[test]
+fn ルールとディレクトリごとの違反数を件数の多い順に出力する() {
+    let dir = common::test_project_path("match/regex");
+    let result = rec_lint::commands::stats::run(&[dir]).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            By rule:
+              domain or infra  3
+              layer            2
+
+            By directory:
+              domain   3
+              infra    1
+              usecase  1
+
+            Total: 5
+        "#,
+    );
+}
+
+#[test]
+fn ルート直下のファイルはドットとして集計する() {
+    let dir = common::test_project_path("format");
+    let result = rec_lint::commands::stats::run(&[dir]).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            By rule:
+              todo_check     2
+              fixme_pattern  1
+
+            By directory:
+              .  3
+
+            Total: 3
+        "#,
+    );
+}
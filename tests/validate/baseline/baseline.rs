@@ -0,0 +1,64 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use std::fs;
+
+use rec_lint::commands::validate::ValidateOptions;
+
+fn run(options: &ValidateOptions) -> rec_lint::commands::validate::ValidateReport {
+    let file = common::test_project_path("baseline/a.rs");
+    rec_lint::commands::validate::run_with_options(&[file], options).unwrap()
+}
+
+#[test]
+fn baselineに記録された違反は行がずれても出力されない() {
+    let options =
+        ValidateOptions { baseline: Some(common::test_project_path("baseline/baseline.json")), ..Default::default() };
+    let report = run(&options);
+    common::assert_output(
+        &report.lines,
+        r#"
+            [ error ] TODO禁止: a.rs:5:4
+        "#,
+    );
+    assert_eq!(report.violation_count, 1);
+}
+
+#[test]
+fn write_baselineは違反を出力せずに記録する() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("baseline.json");
+    let options = ValidateOptions { write_baseline: Some(path.clone()), ..Default::default() };
+    let report = run(&options);
+    assert!(!report.has_failure());
+    assert_eq!(report.lines, vec![format!("Created: {}", path.display())]);
+    common::assert_output(
+        &fs::read_to_string(&path).unwrap().lines().map(String::from).collect::<Vec<_>>(),
+        r#"
+            {
+              "violations": [
+                {
+                  "file": "a.rs",
+                  "label": "todo_check",
+                  "found": "// TODO: new"
+                },
+                {
+                  "file": "a.rs",
+                  "label": "todo_check",
+                  "found": "// TODO: old"
+                }
+              ]
+            }
+        "#,
+    );
+}
+
+#[test]
+fn write_baselineで記録した違反はすべて抑制される() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("baseline.json");
+    run(&ValidateOptions { write_baseline: Some(path.clone()), ..Default::default() });
+    let report = run(&ValidateOptions { baseline: Some(path), ..Default::default() });
+    assert!(report.lines.is_empty());
+    assert!(!report.has_failure());
+}
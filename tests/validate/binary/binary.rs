@@ -0,0 +1,18 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+
+#[test]
+fn nulバイトを含むバイナリファイルはエラーにならずに検証をスキップする() {
+    let dir = common::test_project_path("binary");
+    let report = rec_lint::commands::validate::run_with_options(&[dir], &ValidateOptions::default()).unwrap();
+    common::assert_output(&report.lines, "[ error ] TODO禁止: main.rs:1:4");
+}
+
+#[test]
+fn バイナリファイルを直接指定してもエラーにならない() {
+    let file = common::test_project_path("binary").join("image.png");
+    let report = rec_lint::commands::validate::run_with_options(&[file], &ValidateOptions::default()).unwrap();
+    assert!(report.lines.is_empty());
+}
@@ -0,0 +1,144 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use rec_lint::commands::validate::ValidateOptions;
+
+/// test-projects/changed を一時ディレクトリにコピーして git にコミットする
+fn init_repo(dest: &Path) {
+    let src = common::test_project_path("changed");
+    fs::create_dir_all(dest.join("vendor")).unwrap();
+    for file in [".rec_lint_config.yaml", ".rec_lint.yaml", "a.rs", "b.rs", "deleted.rs", "vendor/v.rs"] {
+        fs::copy(src.join(file), dest.join(file)).unwrap();
+    }
+    git(dest, &["init", "--quiet"]);
+    commit(dest, "init");
+}
+
+fn commit(dir: &Path, message: &str) {
+    git(dir, &["add", "--all"]);
+    git(dir, &["-c", "user.name=test", "-c", "user.email=test@example.com", "commit", "--quiet", "-m", message]);
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git").current_dir(dir).args(args).status().unwrap();
+    assert!(status.success());
+}
+
+/// b.rs を変更、c.rs を追加、deleted.rs を削除、除外ディレクトリの vendor/v.rs を変更する
+fn make_changes(dir: &Path) {
+    fs::write(dir.join("b.rs"), "// TODO\n").unwrap();
+    fs::write(dir.join("c.rs"), "// TODO\n").unwrap();
+    fs::remove_file(dir.join("deleted.rs")).unwrap();
+    fs::write(dir.join("vendor/v.rs"), "// TODO\n").unwrap();
+    git(dir, &["add", "c.rs"]);
+}
+
+fn run(dir: &Path, changed: Option<&str>) -> Vec<String> {
+    let options = ValidateOptions { changed: changed.map(str::to_string), ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[dir.to_path_buf()], &options).unwrap().lines
+}
+
+#[test]
+fn changed指定なしのときすべてのファイルが検証される() {
+    let dir = tempfile::tempdir().unwrap();
+    init_repo(dir.path());
+    make_changes(dir.path());
+
+    common::assert_output(
+        &run(dir.path(), None),
+        r#"
+            [ error ] TODO禁止: a.rs:1:4
+            [ error ] TODO禁止: b.rs:1:4
+            [ error ] TODO禁止: c.rs:1:4
+        "#,
+    );
+}
+
+#[test]
+fn changed指定ありのときheadから変更されたファイルのみ検証される() {
+    let dir = tempfile::tempdir().unwrap();
+    init_repo(dir.path());
+    make_changes(dir.path());
+
+    common::assert_output(
+        &run(dir.path(), Some("HEAD")),
+        r#"
+            [ error ] TODO禁止: b.rs:1:4
+            [ error ] TODO禁止: c.rs:1:4
+        "#,
+    );
+}
+
+#[test]
+fn 日本語のファイル名の変更も検証される() {
+    let dir = tempfile::tempdir().unwrap();
+    init_repo(dir.path());
+    fs::write(dir.path().join("日本.rs"), "").unwrap();
+    commit(dir.path(), "add");
+    fs::write(dir.path().join("日本.rs"), "// TODO\n").unwrap();
+
+    common::assert_output(
+        &run(dir.path(), Some("HEAD")),
+        r#"
+            [ error ] TODO禁止: 日本.rs:1:4
+        "#,
+    );
+}
+
+#[test]
+fn 未追跡のファイルも検証される() {
+    let dir = tempfile::tempdir().unwrap();
+    init_repo(dir.path());
+    fs::write(dir.path().join("untracked.rs"), "// TODO\n").unwrap();
+    fs::write(dir.path().join("ignored.rs"), "// TODO\n").unwrap();
+    fs::write(dir.path().join(".gitignore"), "ignored.rs\n").unwrap();
+
+    common::assert_output(
+        &run(dir.path(), Some("HEAD")),
+        r#"
+            [ error ] TODO禁止: untracked.rs:1:4
+        "#,
+    );
+}
+
+#[test]
+fn changed_sinceに指定したrefから変更されたファイルを検証する() {
+    let dir = tempfile::tempdir().unwrap();
+    init_repo(dir.path());
+    make_changes(dir.path());
+    commit(dir.path(), "change");
+
+    assert!(run(dir.path(), Some("HEAD")).is_empty());
+    common::assert_output(
+        &run(dir.path(), Some("HEAD~1")),
+        r#"
+            [ error ] TODO禁止: b.rs:1:4
+            [ error ] TODO禁止: c.rs:1:4
+        "#,
+    );
+}
+
+#[test]
+fn カレントディレクトリからの相対パスでファイルを指定できる() {
+    let dir = tempfile::tempdir().unwrap();
+    init_repo(dir.path());
+    make_changes(dir.path());
+
+    // このテストバイナリの他のテストは絶対パスを使うため、カレントディレクトリの変更は影響しない
+    let cwd = std::env::current_dir().unwrap();
+    std::env::set_current_dir(dir.path()).unwrap();
+    let options = ValidateOptions { changed: Some("HEAD".to_string()), ..Default::default() };
+    let result = rec_lint::commands::validate::run_with_options(&[Path::new("b.rs").to_path_buf()], &options);
+    std::env::set_current_dir(cwd).unwrap();
+
+    common::assert_output(
+        &result.unwrap().lines,
+        r#"
+            [ error ] TODO禁止: b.rs:1:4
+        "#,
+    );
+}
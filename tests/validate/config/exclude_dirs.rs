@@ -10,7 +10,7 @@ fn vendor除外のときvendorは対象にならない() {
     common::assert_output(
         &result,
         r#"
-            exclude対象テスト: invalid1.php:1:13
+            [ error ] exclude対象テスト: invalid1.php:1:13
         "#,
     );
 }
@@ -24,7 +24,7 @@ fn vendor除外のときruleでvendorを有効しても対象にならない() {
     common::assert_output(
         &result,
         r#"
-            exclude対象テスト: invalid1.php:1:13
+            [ error ] exclude対象テスト: invalid1.php:1:13
         "#,
     );
 }
@@ -0,0 +1,38 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use std::fs;
+
+use rec_lint::commands::SortMode;
+
+#[test]
+fn globにマッチするファイルだけが対象にならない() {
+    // exclude_dirs ではディレクトリ単位でしか除外できないため、gen/Client.kt は対象のまま
+    let dir = common::test_project_path("config/exclude_files");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::File).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] src/Api.kt:1:13: exclude_files対象テスト
+            [ error ] src/gen/Client.kt:1:13: exclude_files対象テスト
+        "#,
+    );
+}
+
+#[test]
+fn globにマッチするファイルは直接指定しても対象にならない() {
+    let file = common::test_project_path("config/exclude_files/src/gen/Api.generated.kt");
+    let result = rec_lint::commands::validate::run(&[file], SortMode::File).unwrap();
+    assert!(result.is_empty());
+}
+
+#[test]
+fn 不正なglobはエラーになる() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join(".rec_lint_config.yaml"), "exclude_files: [ \"src/[a\" ]\n").unwrap();
+    fs::write(dir.path().join("main.rs"), "fn main() {}\n").unwrap();
+
+    let result = rec_lint::commands::validate::run(&[dir.path().to_path_buf()], SortMode::File).unwrap();
+    assert_eq!(result.len(), 1);
+    assert!(result[0].ends_with(": exclude_files: invalid glob 'src/[a': unclosed character class; missing ']'"));
+}
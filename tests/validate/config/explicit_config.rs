@@ -0,0 +1,41 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::{self, ValidateOptions};
+use rec_lint::commands::SortMode;
+
+#[test]
+fn configを指定しないと探索した設定ファイルが適用される() {
+    let dir = common::test_project_path("config/explicit/tree");
+    let result = validate::run(&[dir], SortMode::File).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] tree/src/main.rs:1:4: TODO found
+            [ error ] tree/top.rs:1:4: TODO found
+        "#,
+    );
+}
+
+#[test]
+fn configを指定するとその設定ファイルだけが対象パスを基準に適用される() {
+    let dir = common::test_project_path("config/explicit/tree");
+    let config = common::test_project_path("config/explicit/candidate.yaml");
+    let options = ValidateOptions { sort_mode: SortMode::File, config: Some(config), ..Default::default() };
+    let result = validate::run_with_options(&[dir], &options).unwrap();
+    common::assert_output(
+        &result.lines,
+        r#"
+            [ error ] src/main.rs:3:5: println found
+        "#,
+    );
+}
+
+#[test]
+fn 存在しないconfigを指定するとエラーになる() {
+    let dir = common::test_project_path("config/explicit/tree");
+    let config = common::test_project_path("config/explicit/missing.yaml");
+    let options = ValidateOptions { config: Some(config), ..Default::default() };
+    let err = validate::run_with_options(&[dir], &options).err().unwrap();
+    assert!(err.to_string().contains("missing.yaml"), "{err}");
+}
@@ -0,0 +1,37 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::{CheckMode, SortMode};
+
+#[test]
+fn rec_lintignoreにマッチするディレクトリとファイルは対象にならない() {
+    // keep.generated.ts は否定パターンで対象に戻る
+    let dir = common::test_project_path("config/ignore");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::File).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] src/app.ts:1:15: rec_lintignore対象テスト
+            [ error ] src/keep.generated.ts:1:15: rec_lintignore対象テスト
+        "#,
+    );
+}
+
+#[test]
+fn rec_lintignoreにマッチするファイルは直接指定しても対象にならない() {
+    let file = common::test_project_path("config/ignore/target/debug/out.ts");
+    let result = rec_lint::commands::validate::run(&[file], SortMode::File).unwrap();
+    assert!(result.is_empty());
+}
+
+#[test]
+fn checkでもrec_lintignoreにマッチするディレクトリの設定ファイルは対象にならない() {
+    let dir = common::test_project_path("config/ignore");
+    let result = rec_lint::commands::check::run(CheckMode::List, &dir, None).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            ./.rec_lint.yaml: [ forbidden_texts ]
+        "#,
+    );
+}
@@ -7,7 +7,7 @@ use rec_lint::commands::SortMode;
 fn php対象のときphpは対象になる() {
     let file = common::test_project_path("config/include_extensions/invalid2.php");
     let result = rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap();
-    common::assert_output(&result, "include対象テスト: invalid2.php:1:13");
+    common::assert_output(&result, "[ error ] include対象テスト: invalid2.php:1:13");
 }
 
 #[test]
@@ -0,0 +1,31 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+#[test]
+fn 兄弟のプロジェクトは互いのルールとルート設定を引き継がない() {
+    let project_a = common::test_project_path("config/monorepo/project_a");
+    let project_b = common::test_project_path("config/monorepo/project_b");
+    let result = rec_lint::commands::validate::run(&[project_a, project_b], SortMode::File).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] a.rs:1:4: project_a のルール
+            [ error ] b.kt:2:4: project_b のルール
+            [ error ] n.kt:2:4: nested のルール
+        "#,
+    );
+}
+
+#[test]
+fn 配下のルート設定のディレクトリは独立したプロジェクトとして検証する() {
+    let nested = common::test_project_path("config/monorepo/project_a/nested");
+    let result = rec_lint::commands::validate::run(&[nested], SortMode::File).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] n.kt:2:4: nested のルール
+        "#,
+    );
+}
@@ -0,0 +1,28 @@
+use std::fs;
+
+use rec_lint::commands::validate::{NoRootConfig, ValidateOptions};
+
+#[test]
+fn ルート設定ファイルがなければエラーになる() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("a.rs"), "fn main() {}\n").unwrap();
+
+    let err = rec_lint::commands::validate::run_with_options(&[dir.path().to_path_buf()], &ValidateOptions::default())
+        .err()
+        .unwrap();
+    assert!(err.downcast_ref::<NoRootConfig>().is_some());
+    assert!(err.to_string().contains("rec_lint init"), "{err}");
+}
+
+#[test]
+fn ルート設定ファイルがあれば違反がなくても成功する() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join(".rec_lint_config.yaml"), "").unwrap();
+    fs::write(dir.path().join("a.rs"), "fn main() {}\n").unwrap();
+
+    let report =
+        rec_lint::commands::validate::run_with_options(&[dir.path().to_path_buf()], &ValidateOptions::default())
+            .unwrap();
+    assert!(report.lines.is_empty());
+    assert!(!report.has_failure());
+}
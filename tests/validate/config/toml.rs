@@ -0,0 +1,73 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+use rec_lint::rule::parser::RawConfig;
+
+#[test]
+fn toml設定ファイルのルールとルート設定が適用される() {
+    let dir = common::test_project_path("config/toml");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::File).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] src/main.rs:2:17: TOML設定のルール
+            [ error ] src/main.rs:2:21: 行が長すぎます
+        "#,
+    );
+}
+
+#[test]
+fn 同等のtomlとyamlは同じ設定になる() {
+    let yaml = RawConfig::parse(
+        r#"
+rule:
+  - forbidden_texts:
+      label: debug
+      texts: [ var_dump, dd ]
+      message: デバッグ出力は削除すること
+      match:
+        - pattern: file_ends_with
+          keywords: [ .php ]
+  - max_line_length:
+      label: line
+      message: 行が長すぎます
+      option:
+        limit: 120
+guideline:
+  - message: 例外は握りつぶさないこと
+"#,
+    )
+    .unwrap();
+    let toml = RawConfig::parse_toml(
+        r#"
+[[rule]]
+[rule.forbidden_texts]
+label = "debug"
+texts = ["var_dump", "dd"]
+message = "デバッグ出力は削除すること"
+match = [{ pattern = "file_ends_with", keywords = [".php"] }]
+
+[[rule]]
+[rule.max_line_length]
+label = "line"
+message = "行が長すぎます"
+option = { limit = 120 }
+
+[[guideline]]
+message = "例外は握りつぶさないこと"
+"#,
+    )
+    .unwrap();
+    assert_eq!(format!("{toml:?}"), format!("{yaml:?}"));
+}
+
+#[test]
+fn 同じディレクトリにtomlとyamlの設定ファイルがあるとエラーになる() {
+    let dir = common::test_project_path("config/toml_and_yaml");
+    let result = rec_lint::commands::validate::run(std::slice::from_ref(&dir), SortMode::File).unwrap();
+    common::assert_output(
+        &result,
+        &format!("{0}: Both .rec_lint.yaml and .rec_lint.toml exist in {0} (keep only one)", dir.display()),
+    );
+}
@@ -0,0 +1,26 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::{ValidateOptions, ValidateReport};
+
+fn run(path: &str) -> ValidateReport {
+    let options = ValidateOptions { count: true, ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[common::test_project_path(path)], &options).unwrap()
+}
+
+#[test]
+fn countを指定すると違反の件数だけを出力する() {
+    let report = run("severity/error/a.rs");
+    common::assert_output(&report.lines, "3");
+    assert!(report.has_failure());
+}
+
+#[test]
+fn countを指定しても設定エラーは出力し失敗する() {
+    let report = run("match/glob_invalid");
+    assert_eq!(report.lines.len(), 2);
+    assert!(report.lines[0]
+        .ends_with("Rule 'broken glob': invalid glob 'src/[a.rs': unclosed character class; missing ']'"));
+    assert_eq!(report.lines[1], "0");
+    assert!(report.has_failure());
+}
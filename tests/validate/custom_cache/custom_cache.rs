@@ -0,0 +1,64 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use rec_lint::commands::validate::ValidateOptions;
+
+/// test-projects/custom_cache を一時ディレクトリにコピーする
+fn copy_project(dest: &Path) {
+    let src = common::test_project_path("custom_cache");
+    fs::create_dir_all(dest.join("scripts")).unwrap();
+    for file in [".rec_lint_config.yaml", ".rec_lint.yaml", "scripts/check.sh", "a.kt"] {
+        fs::copy(src.join(file), dest.join(file)).unwrap();
+    }
+}
+
+fn run(dir: &Path, no_cache: bool) -> Vec<String> {
+    let options = ValidateOptions { no_cache, ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[dir.join("a.kt")], &options).unwrap().lines
+}
+
+/// scripts/check.sh が実行された回数
+fn runs(dir: &Path) -> usize {
+    fs::read_to_string(dir.join("scripts/runs.log")).map(|log| log.lines().count()).unwrap_or(0)
+}
+
+#[test]
+fn 内容が変わらなければキャッシュされた結果が使われコマンドは再実行されない() {
+    let dir = tempfile::tempdir().unwrap();
+    copy_project(dir.path());
+
+    common::assert_output(&run(dir.path(), false), "[ error ] ALLOWED マーカーがありません: a.kt");
+    assert_eq!(runs(dir.path()), 1);
+
+    common::assert_output(&run(dir.path(), false), "[ error ] ALLOWED マーカーがありません: a.kt");
+    assert_eq!(runs(dir.path()), 1);
+    assert!(dir.path().join(".rec_lint_cache").is_dir());
+}
+
+#[test]
+fn 内容が変わるとキャッシュは使われずコマンドが再実行される() {
+    let dir = tempfile::tempdir().unwrap();
+    copy_project(dir.path());
+
+    common::assert_output(&run(dir.path(), false), "[ error ] ALLOWED マーカーがありません: a.kt");
+    assert_eq!(runs(dir.path()), 1);
+
+    fs::write(dir.path().join("a.kt"), "// ALLOWED\nfun ok() { println(\"safe\") }\n").unwrap();
+
+    assert!(run(dir.path(), false).is_empty());
+    assert_eq!(runs(dir.path()), 2);
+}
+
+#[test]
+fn no_cacheを指定するとキャッシュを使わず毎回コマンドが実行される() {
+    let dir = tempfile::tempdir().unwrap();
+    copy_project(dir.path());
+
+    run(dir.path(), true);
+    run(dir.path(), true);
+    assert_eq!(runs(dir.path()), 2);
+    assert!(!dir.path().join(".rec_lint_cache").exists());
+}
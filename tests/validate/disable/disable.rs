@@ -0,0 +1,27 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn run() -> Vec<String> {
+    let file = common::test_project_path("disable/a.rs");
+    rec_lint::commands::validate::run(&[file], SortMode::File).unwrap()
+}
+
+#[test]
+fn disableコメントで指定された行とラベルの違反だけが抑制される() {
+    // 1: ラベルなしの disable-line はすべてのルールを抑制
+    // 2: 別ラベルの disable-line では抑制されない
+    // 4: disable-next-line で抑制
+    // 6: ブロックコメントのラベルなし disable-next-line で抑制
+    // 7: 同じ行の disable-next-line は次の行にだけ効く
+    // 8: 同じ行に並んだ disable-next-line で抑制
+    // 9: カンマ区切りの複数ラベルで抑制
+    common::assert_output(
+        &run(),
+        r#"
+            [ error ] a.rs:2:4: TODO禁止
+            [ error ] a.rs:7:4: FIXME禁止
+        "#,
+    );
+}
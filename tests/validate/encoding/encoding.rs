@@ -0,0 +1,25 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::{ValidateOptions, ValidateReport};
+use rec_lint::commands::Encoding;
+
+fn run(encoding: Encoding) -> ValidateReport {
+    let options = ValidateOptions { encoding, ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[common::test_project_path("encoding")], &options).unwrap()
+}
+
+#[test]
+fn utf8ではutf8でないファイルを検証せずに警告する() {
+    let report = run(Encoding::Utf8);
+    assert!(report.lines.is_empty());
+    assert_eq!(report.error_count, 0);
+    assert_eq!(report.skipped, vec!["latin1.rs".to_string()]);
+}
+
+#[test]
+fn lossyではutf8でないファイルも置換して検証する() {
+    let report = run(Encoding::Lossy);
+    common::assert_output(&report.lines, "[ error ] TODO禁止: latin1.rs:1:4");
+    assert!(report.skipped.is_empty());
+}
@@ -0,0 +1,99 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use rec_lint::commands::validate::ValidateOptions;
+
+/// test-projects/fix の設定を一時ディレクトリにコピーし、検証対象のファイルを作る
+fn setup(dest: &Path, files: &[(&str, &str)]) {
+    let src = common::test_project_path("fix");
+    for file in [".rec_lint_config.yaml", ".rec_lint.yaml"] {
+        fs::copy(src.join(file), dest.join(file)).unwrap();
+    }
+    for (name, content) in files {
+        fs::write(dest.join(name), content).unwrap();
+    }
+}
+
+fn options(fix: bool) -> ValidateOptions {
+    ValidateOptions { fix, ..Default::default() }
+}
+
+#[test]
+fn fix指定で行末の空白が削除され修正できない違反だけが報告される() {
+    let dir = tempfile::tempdir().unwrap();
+    setup(dir.path(), &[("a.rs", "let a = 1;  \n// TODO: later\t\n"), ("b.rs", "let b = 2;\n")]);
+
+    let report = rec_lint::commands::validate::run_with_options(&[dir.path().to_path_buf()], &options(true)).unwrap();
+
+    common::assert_output(&report.lines, "[ error ] TODO禁止: a.rs:2:4");
+    assert_eq!(report.fixed, vec!["a.rs".to_string()]);
+    assert_eq!(fs::read_to_string(dir.path().join("a.rs")).unwrap(), "let a = 1;\n// TODO: later\n");
+    assert_eq!(fs::read_to_string(dir.path().join("b.rs")).unwrap(), "let b = 2;\n");
+}
+
+#[test]
+fn fix指定でもcrlfの改行は維持される() {
+    let dir = tempfile::tempdir().unwrap();
+    setup(dir.path(), &[("a.rs", "let a = 1; \r\nlet b = 2;\t\r\n")]);
+
+    let report = rec_lint::commands::validate::run_with_options(&[dir.path().to_path_buf()], &options(true)).unwrap();
+
+    assert!(report.lines.is_empty(), "{:?}", report.lines);
+    assert_eq!(fs::read_to_string(dir.path().join("a.rs")).unwrap(), "let a = 1;\r\nlet b = 2;\r\n");
+}
+
+#[test]
+fn fix指定でも無効化コメントのある行は修正しない() {
+    let dir = tempfile::tempdir().unwrap();
+    let content = "let a = 1; // rec_lint-disable-line trailing_whitespace \nlet b = 2; \n";
+    setup(dir.path(), &[("a.rs", content)]);
+
+    rec_lint::commands::validate::run_with_options(&[dir.path().to_path_buf()], &options(true)).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("a.rs")).unwrap(),
+        "let a = 1; // rec_lint-disable-line trailing_whitespace \nlet b = 2;\n"
+    );
+}
+
+#[test]
+fn fix指定なしではファイルは変更されない() {
+    let dir = tempfile::tempdir().unwrap();
+    setup(dir.path(), &[("a.rs", "let a = 1;  \n")]);
+
+    let report = rec_lint::commands::validate::run_with_options(&[dir.path().to_path_buf()], &options(false)).unwrap();
+
+    common::assert_output(&report.lines, "[ error ] 行末の空白を削除してください: a.rs:1:11");
+    assert!(report.fixed.is_empty());
+    assert_eq!(fs::read_to_string(dir.path().join("a.rs")).unwrap(), "let a = 1;  \n");
+}
+
+#[test]
+fn fix指定で末尾の改行がないファイルに元の改行コードで改行を追加する() {
+    let dir = tempfile::tempdir().unwrap();
+    setup(dir.path(), &[("lf.rs", "let a = 1;\nlet b = 2;"), ("crlf.rs", "let a = 1;\r\nlet b = 2;")]);
+
+    let report = rec_lint::commands::validate::run_with_options(&[dir.path().to_path_buf()], &options(true)).unwrap();
+
+    assert!(report.lines.is_empty(), "{:?}", report.lines);
+    assert_eq!(report.fixed, vec!["crlf.rs".to_string(), "lf.rs".to_string()]);
+    assert_eq!(fs::read_to_string(dir.path().join("lf.rs")).unwrap(), "let a = 1;\nlet b = 2;\n");
+    assert_eq!(fs::read_to_string(dir.path().join("crlf.rs")).unwrap(), "let a = 1;\r\nlet b = 2;\r\n");
+}
+
+#[test]
+fn fix指定でもバイナリファイルは書き換えずエラーにもならない() {
+    let dir = tempfile::tempdir().unwrap();
+    setup(dir.path(), &[("a.rs", "let a = 1;\n")]);
+    let binary = b"\x89PNG\r\n\x1a\n\x00\x00  \n";
+    fs::write(dir.path().join("image.png"), binary).unwrap();
+
+    let report = rec_lint::commands::validate::run_with_options(&[dir.path().to_path_buf()], &options(true)).unwrap();
+
+    assert!(report.lines.is_empty());
+    assert!(report.fixed.is_empty());
+    assert_eq!(fs::read(dir.path().join("image.png")).unwrap(), binary);
+}
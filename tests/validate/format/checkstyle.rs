@@ -0,0 +1,46 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+use rec_lint::commands::OutputFormat;
+
+fn run(project: &str) -> Vec<String> {
+    let dir = common::test_project_path(project);
+    let options = ValidateOptions { format: OutputFormat::Checkstyle, ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[dir], &options).unwrap().lines
+}
+
+#[test]
+fn ファイルごとにerrorを出力する() {
+    common::assert_output(
+        &run("format"),
+        r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <checkstyle version="4.3">
+              <file name="a.rs">
+                <error line="1" column="4" severity="error" message="TODO禁止" source="todo_check"/>
+                <error line="3" column="4" severity="error" message="FIXMEパターン禁止" source="fixme_pattern"/>
+              </file>
+              <file name="b.rs">
+                <error line="1" column="4" severity="error" message="TODO禁止" source="todo_check"/>
+              </file>
+            </checkstyle>
+        "#,
+    );
+}
+
+#[test]
+fn ファイル単位の違反は行と列を省略する() {
+    common::assert_output(
+        &run("format_file_level"),
+        r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <checkstyle version="4.3">
+              <file name="work.tmp.rs">
+                <error severity="error" message="一時ファイル禁止" source="tmp_file"/>
+                <error line="1" column="4" severity="error" message="TODO禁止" source="todo_check"/>
+              </file>
+            </checkstyle>
+        "#,
+    );
+}
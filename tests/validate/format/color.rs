@@ -0,0 +1,32 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+use rec_lint::commands::SortMode;
+
+fn run(path: &str) -> Vec<String> {
+    let options = ValidateOptions { sort_mode: SortMode::File, color: true, ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[common::test_project_path(path)], &options).unwrap().lines
+}
+
+#[test]
+fn colorを有効にするとerrorは赤_warnは黄色で出力する() {
+    let lines = run("severity/error/a.rs");
+    common::assert_output(
+        &lines,
+        "
+            \x1b[31m[ error ] \x1b[0merror/a.rs:1:4: \x1b[31mTODO禁止\x1b[0m
+            \x1b[33m[ warn ] \x1b[0merror/a.rs:2:4: \x1b[33mFIXME注意\x1b[0m
+            [ info ] error/a.rs:3:4: NOTE確認
+        ",
+    );
+}
+
+#[test]
+fn colorを有効にするとfoundは薄く出力する() {
+    let lines = run("sort/b_second.kt");
+    assert_eq!(
+        lines[0],
+        "\x1b[31m[ error ] \x1b[0mb_second.kt:1:1: \x1b[31mコメントを日本語にしてください\x1b[0m\x1b[2m [ found: english comment line 1 ]\x1b[0m"
+    );
+}
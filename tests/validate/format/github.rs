@@ -0,0 +1,35 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+use rec_lint::commands::{OutputFormat, SortMode};
+
+fn run(sort_mode: SortMode) -> Vec<String> {
+    let dir = common::test_project_path("format");
+    let options = ValidateOptions { sort_mode, format: OutputFormat::Github, ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[dir], &options).unwrap().lines
+}
+
+#[test]
+fn githubフォーマットはworkflowコマンドを出力する() {
+    common::assert_output(
+        &run(SortMode::Rule),
+        r#"
+            ::error file=a.rs,line=3,col=4::FIXMEパターン禁止
+            ::error file=a.rs,line=1,col=4::TODO禁止
+            ::error file=b.rs,line=1,col=4::TODO禁止
+        "#,
+    );
+}
+
+#[test]
+fn githubフォーマットはsort指定に従って出力する() {
+    common::assert_output(
+        &run(SortMode::File),
+        r#"
+            ::error file=a.rs,line=1,col=4::TODO禁止
+            ::error file=a.rs,line=3,col=4::FIXMEパターン禁止
+            ::error file=b.rs,line=1,col=4::TODO禁止
+        "#,
+    );
+}
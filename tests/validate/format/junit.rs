@@ -0,0 +1,109 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+use rec_lint::commands::{JunitGroupBy, OutputFormat};
+
+fn run(junit_group_by: JunitGroupBy) -> Vec<String> {
+    let dir = common::test_project_path("format");
+    let options = ValidateOptions { format: OutputFormat::Junit, junit_group_by, ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[dir], &options).unwrap().lines
+}
+
+#[test]
+fn junit_group_by_ruleはルールごとにtestsuiteを出力する() {
+    common::assert_output(
+        &run(JunitGroupBy::Rule),
+        r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <testsuites name="rec_lint" tests="3" failures="3">
+              <testsuite name="fixme_pattern" tests="1" failures="1">
+                <testcase name="a.rs:3:4" classname="fixme_pattern">
+                  <failure message="FIXMEパターン禁止">a.rs:3:4</failure>
+                </testcase>
+              </testsuite>
+              <testsuite name="todo_check" tests="2" failures="2">
+                <testcase name="a.rs:1:4" classname="todo_check">
+                  <failure message="TODO禁止">a.rs:1:4</failure>
+                </testcase>
+                <testcase name="b.rs:1:4" classname="todo_check">
+                  <failure message="TODO禁止">b.rs:1:4</failure>
+                </testcase>
+              </testsuite>
+            </testsuites>
+        "#,
+    );
+}
+
+#[test]
+fn junit_group_by_fileはファイルごとにtestsuiteを出力する() {
+    common::assert_output(
+        &run(JunitGroupBy::File),
+        r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <testsuites name="rec_lint" tests="3" failures="3">
+              <testsuite name="a.rs" tests="2" failures="2">
+                <testcase name="1:4: TODO禁止" classname="a.rs">
+                  <failure message="TODO禁止">a.rs:1:4</failure>
+                </testcase>
+                <testcase name="3:4: FIXMEパターン禁止" classname="a.rs">
+                  <failure message="FIXMEパターン禁止">a.rs:3:4</failure>
+                </testcase>
+              </testsuite>
+              <testsuite name="b.rs" tests="1" failures="1">
+                <testcase name="1:4: TODO禁止" classname="b.rs">
+                  <failure message="TODO禁止">b.rs:1:4</failure>
+                </testcase>
+              </testsuite>
+            </testsuites>
+        "#,
+    );
+}
+
+#[test]
+fn ファイル単位の違反は行なしのtestcaseになる() {
+    let dir = common::test_project_path("format_file_level");
+    let options = ValidateOptions { format: OutputFormat::Junit, ..Default::default() };
+    common::assert_output(
+        &rec_lint::commands::validate::run_with_options(&[dir], &options).unwrap().lines,
+        r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <testsuites name="rec_lint" tests="2" failures="2">
+              <testsuite name="tmp_file" tests="1" failures="1">
+                <testcase name="work.tmp.rs" classname="tmp_file">
+                  <failure message="一時ファイル禁止">work.tmp.rs</failure>
+                </testcase>
+              </testsuite>
+              <testsuite name="todo_check" tests="1" failures="1">
+                <testcase name="work.tmp.rs:1:4" classname="todo_check">
+                  <failure message="TODO禁止">work.tmp.rs:1:4</failure>
+                </testcase>
+              </testsuite>
+            </testsuites>
+        "#,
+    );
+}
+
+#[test]
+fn junit_group_by_ruleはメッセージが同じルールを別のtestsuiteにする() {
+    let dir = common::test_project_path("format_shared_message");
+    let options = ValidateOptions { format: OutputFormat::Junit, ..Default::default() };
+    common::assert_output(
+        &rec_lint::commands::validate::run_with_options(&[dir], &options).unwrap().lines,
+        r#"
+            <?xml version="1.0" encoding="UTF-8"?>
+            <testsuites name="rec_lint" tests="2" failures="2">
+              <testsuite name="fixme_check" tests="1" failures="1">
+                <testcase name="a.rs:2:4" classname="fixme_check">
+                  <failure message="作業メモ禁止">a.rs:2:4</failure>
+                </testcase>
+              </testsuite>
+              <testsuite name="todo_check" tests="1" failures="1">
+                <testcase name="a.rs:1:4" classname="todo_check">
+                  <failure message="作業メモ禁止">a.rs:1:4</failure>
+                </testcase>
+              </testsuite>
+            </testsuites>
+        "#,
+    );
+}
@@ -0,0 +1,56 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+use rec_lint::commands::OutputFormat;
+
+fn run(project: &str) -> Vec<String> {
+    let dir = common::test_project_path(project);
+    let options = ValidateOptions { format: OutputFormat::LspJson, ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[dir], &options).unwrap().lines
+}
+
+#[test]
+fn lsp_jsonフォーマットはファイルごとに0始まりの範囲で出力する() {
+    let lines = run("format");
+    let json: serde_json::Value = serde_json::from_str(&lines.join("\n")).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "a.rs": [
+                {
+                    "range": { "start": { "line": 0, "character": 3 }, "end": { "line": 1, "character": 0 } },
+                    "message": "TODO禁止",
+                    "severity": 1,
+                    "code": "todo_check",
+                },
+                {
+                    "range": { "start": { "line": 2, "character": 3 }, "end": { "line": 3, "character": 0 } },
+                    "message": "FIXMEパターン禁止",
+                    "severity": 1,
+                    "code": "fixme_pattern",
+                },
+            ],
+            "b.rs": [
+                {
+                    "range": { "start": { "line": 0, "character": 3 }, "end": { "line": 1, "character": 0 } },
+                    "message": "TODO禁止",
+                    "severity": 1,
+                    "code": "todo_check",
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn lsp_jsonフォーマットは重要度を数値で出力する() {
+    let lines = run("severity/warn_only");
+    let json: serde_json::Value = serde_json::from_str(&lines.join("\n")).unwrap();
+    assert_eq!(json["warn_only/a.rs"][0]["severity"], 2);
+}
+
+#[test]
+fn lsp_jsonフォーマットは違反がなければ空のオブジェクトを出力する() {
+    common::assert_output(&run("require_rules"), "{}");
+}
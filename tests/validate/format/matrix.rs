@@ -0,0 +1,37 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+use rec_lint::commands::OutputFormat;
+
+fn run(format: OutputFormat) -> Vec<String> {
+    let dir = common::test_project_path("matrix");
+    let options = ValidateOptions { format, ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[dir], &options).unwrap().lines
+}
+
+#[test]
+fn matrixフォーマットはファイルとルールの適合表を出力する() {
+    common::assert_output(
+        &run(OutputFormat::Matrix),
+        r#"
+            file  todo_check  kotlin_println
+            a.rs  fail        -
+            b.rs  pass        -
+            c.kt  pass        fail
+        "#,
+    );
+}
+
+#[test]
+fn matrix_csvフォーマットは適合表をcsvで出力する() {
+    common::assert_output(
+        &run(OutputFormat::MatrixCsv),
+        r#"
+            file,todo_check,kotlin_println
+            a.rs,fail,-
+            b.rs,pass,-
+            c.kt,pass,fail
+        "#,
+    );
+}
@@ -0,0 +1,38 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn validate(relative: &str) -> Vec<String> {
+    let dir = common::test_project_path(format!("line_range/{relative}"));
+    rec_lint::commands::validate::run(&[dir], SortMode::File).unwrap()
+}
+
+#[test]
+fn line_rangeの範囲外の違反は検出しない() {
+    common::assert_output(
+        &validate("window"),
+        r#"
+            [ error ] window/a.rs: 1行目に著作権表記が必要
+            [ error ] window/a.rs:2:4: ヘッダーにTODO禁止
+            [ error ] window/a.rs:3:4: 3行目以降にFIXME禁止
+            [ error ] window/a.rs:6:4: 3行目以降にFIXME禁止
+        "#,
+    );
+}
+
+#[test]
+fn line_rangeに対応していないルールはエラーになる() {
+    let result = validate("invalid_type");
+    assert_eq!(result.len(), 1);
+    assert!(result[0].ends_with(
+        ": Rule '行の長さ': 'line_range' is supported only by forbidden_texts, forbidden_patterns, required_texts, preset"
+    ));
+}
+
+#[test]
+fn line_rangeのendがstartより前だとエラーになる() {
+    let result = validate("invalid_end");
+    assert_eq!(result.len(), 1);
+    assert!(result[0].ends_with(": Rule '逆順の範囲': line_range end 2 is before start 5"));
+}
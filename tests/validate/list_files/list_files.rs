@@ -0,0 +1,53 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+
+fn run(path: &str, options: &ValidateOptions) -> Vec<String> {
+    rec_lint::commands::validate::list_files(&[common::test_project_path(path)], options).unwrap()
+}
+
+#[test]
+fn ルールごとに対象ファイルをソートして列挙する() {
+    common::assert_output(
+        &run("unused", &ValidateOptions::default()),
+        r#"
+            rs-todo (forbidden_texts) in .rec_lint.yaml
+              - a.rs
+              - src/b.rs
+            kt-todo (forbidden_texts) in .rec_lint.yaml
+              (no files)
+            md-fixme (forbidden_texts) in src/.rec_lint.yaml
+              (no files)
+        "#,
+    );
+}
+
+#[test]
+fn globのmatch条件で対象になるファイルを確認できる() {
+    common::assert_output(
+        &run("match/glob", &ValidateOptions::default()),
+        r#"
+            fixtures json (forbidden_texts) in .rec_lint.yaml
+              - fixtures/a.json
+            not fixtures (forbidden_texts) in .rec_lint.yaml
+              - docs/d.md
+              - src/c.json
+            docs (forbidden_texts) in .rec_lint.yaml
+              - docs/d.md
+        "#,
+    );
+}
+
+#[test]
+fn onlyで選択したルールだけを列挙する() {
+    let options = ValidateOptions { only: vec!["rs-todo".to_string()], ..Default::default() };
+    common::assert_output(
+        &run("unused", &options),
+        r#"
+            rs-todo (forbidden_texts) in .rec_lint.yaml
+              - a.rs
+              - src/b.rs
+        "#,
+    );
+}
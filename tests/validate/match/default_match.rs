@@ -0,0 +1,34 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+#[test]
+fn default_matchはmatchを持たないルールにも適用される() {
+    let dir = common::test_project_path("match/default_match_extend");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::Rule).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] TODO禁止: app.js:1:4
+            [ error ] TODO禁止: note.txt:1:1
+            [ error ] jsでTODO禁止: app.js:1:4
+        "#,
+    );
+}
+
+#[test]
+fn overrideの場合はmatchを持つルールにdefault_matchを適用しない() {
+    let dir = common::test_project_path("match/default_match_override");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::Rule).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] TODO禁止: app.js:1:4
+            [ error ] TODO禁止: note.txt:1:1
+            [ error ] jsでTODO禁止: app.js:1:4
+            [ error ] jsでTODO禁止: app.min.js:1:4
+            [ error ] jsでTODO禁止: vendor/lib.js:1:4
+        "#,
+    );
+}
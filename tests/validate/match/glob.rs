@@ -0,0 +1,34 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+#[test]
+fn path_globはパス全体をglobで判定する() {
+    let dir = common::test_project_path("match/glob");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::Rule).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] fixtures以外でTODO禁止: docs/d.md:1:1
+            [ error ] fixtures以外でTODO禁止: src/c.json:1:1
+            [ error ] fixtures直下のjsonでTODO禁止: fixtures/a.json:1:1
+            [ error ] mdとtxtでTODO禁止: docs/d.md:1:1
+        "#,
+    );
+}
+
+#[test]
+fn path_globの単一のアスタリスクはディレクトリをまたがない() {
+    let file = common::test_project_path("match/glob/fixtures/nested/b.json");
+    let result = rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap();
+    assert!(result.is_empty());
+}
+
+#[test]
+fn 不正なglobは設定エラーになる() {
+    let dir = common::test_project_path("match/glob_invalid");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::Rule).unwrap();
+    assert_eq!(result.len(), 1);
+    assert!(result[0].ends_with("Rule 'broken glob': invalid glob 'src/[a.rs': unclosed character class; missing ']'"));
+}
@@ -0,0 +1,28 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+#[test]
+fn path_matches_regexはパス全体を正規表現で判定する() {
+    let dir = common::test_project_path("match/regex");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::Rule).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] domainとinfraでTODO禁止: domain/User.kt:1:4
+            [ error ] domainとinfraでTODO禁止: domain/User_test.kt:1:4
+            [ error ] domainとinfraでTODO禁止: infra/Repo.kt:1:4
+            [ error ] domainとusecaseの本体でTODO禁止: domain/User.kt:1:4
+            [ error ] domainとusecaseの本体でTODO禁止: usecase/Create.kt:1:4
+        "#,
+    );
+}
+
+#[test]
+fn 不正な正規表現は設定エラーになる() {
+    let dir = common::test_project_path("match/regex_invalid");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::Rule).unwrap();
+    assert_eq!(result.len(), 1);
+    assert!(result[0].contains("Rule 'broken regex': invalid regex 'src/(a'"));
+}
@@ -0,0 +1,29 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+#[test]
+fn relative_depthは設定ファイルのディレクトリからの階層で判定する() {
+    let dir = common::test_project_path("match/relative_depth/src");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::Rule).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] FIXME found: src/a.rs:2:4
+            [ error ] FIXME found: src/sub/b.rs:2:4
+            [ error ] TODO found: src/a.rs:1:4
+            [ error ] XXX found: src/a.rs:3:4
+            [ error ] XXX found: src/sub/b.rs:3:4
+            [ error ] XXX found: src/sub/deep/c.rs:3:4
+        "#,
+    );
+}
+
+#[test]
+fn relative_depthに数値以外を指定するとエラーになる() {
+    let dir = common::test_project_path("match/relative_depth/invalid");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::Rule).unwrap();
+    assert_eq!(result.len(), 1);
+    assert!(result[0].ends_with(": Rule '不正な階層': invalid depth 'one': expected 0 or more"));
+}
@@ -0,0 +1,78 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+
+fn run(only: &[&str], skip: &[&str]) -> Vec<String> {
+    let file = common::test_project_path("only_skip/a.rs");
+    let options = ValidateOptions {
+        only: only.iter().map(|s| s.to_string()).collect(),
+        skip: skip.iter().map(|s| s.to_string()).collect(),
+        ..Default::default()
+    };
+    rec_lint::commands::validate::run_with_options(&[file], &options).unwrap().lines
+}
+
+#[test]
+fn 指定なしのときすべてのルールが検証される() {
+    common::assert_output(
+        &run(&[], &[]),
+        r#"
+            [ error ] FIXME禁止: a.rs:2:4
+            [ error ] TODO禁止: a.rs:1:4
+            [ error ] デバッグ出力禁止: a.rs:3:13
+        "#,
+    );
+}
+
+#[test]
+fn onlyにラベルを指定するとそのルールのみ検証される() {
+    common::assert_output(
+        &run(&["todo_check"], &[]),
+        r#"
+            [ error ] TODO禁止: a.rs:1:4
+        "#,
+    );
+}
+
+#[test]
+fn onlyにタイプを指定するとそのタイプのルールのみ検証される() {
+    common::assert_output(
+        &run(&["forbidden_texts"], &[]),
+        r#"
+            [ error ] FIXME禁止: a.rs:2:4
+            [ error ] TODO禁止: a.rs:1:4
+        "#,
+    );
+}
+
+#[test]
+fn skipにラベルを指定するとそのルールは検証されない() {
+    common::assert_output(
+        &run(&[], &["debug_pattern"]),
+        r#"
+            [ error ] FIXME禁止: a.rs:2:4
+            [ error ] TODO禁止: a.rs:1:4
+        "#,
+    );
+}
+
+#[test]
+fn skipにタイプを指定するとそのタイプのルールは検証されない() {
+    common::assert_output(
+        &run(&[], &["forbidden_texts"]),
+        r#"
+            [ error ] デバッグ出力禁止: a.rs:3:13
+        "#,
+    );
+}
+
+#[test]
+fn onlyで絞り込んだルールからskipで除外できる() {
+    common::assert_output(
+        &run(&["forbidden_texts"], &["fixme_check"]),
+        r#"
+            [ error ] TODO禁止: a.rs:1:4
+        "#,
+    );
+}
@@ -0,0 +1,45 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+
+fn run(profile: bool) -> Vec<String> {
+    let file = common::test_project_path("skip_type/a.rs");
+    let options = ValidateOptions { profile, no_cache: true, ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[file], &options).unwrap().profile
+}
+
+/// Timing lines without the elapsed time, sorted (the order by time is not stable)
+fn without_time(lines: &[String]) -> Vec<String> {
+    let mut lines: Vec<String> = lines
+        .iter()
+        .map(|line| line.split_once("ms").map_or(line.clone(), |(_, rest)| rest.trim().to_string()))
+        .collect();
+    lines.sort();
+    lines
+}
+
+#[test]
+fn profile指定なしのとき計測結果は空になる() {
+    assert!(run(false).is_empty());
+}
+
+#[test]
+fn profile指定ありのときルールごとの実行回数を出力する() {
+    let profile = run(true);
+    assert_eq!(profile[0], "Rule timings (total, runs, rule):");
+    common::assert_output(
+        &without_time(&profile[1..]),
+        r#"
+            1 runs  custom_check (custom)
+            1 runs  todo_check (forbidden_texts)
+        "#,
+    );
+}
+
+#[test]
+fn profileは時間のかかったルールから順に出力する() {
+    let millis: Vec<f64> =
+        run(true)[1..].iter().map(|line| line.trim().split_once("ms").unwrap().0.parse().unwrap()).collect();
+    assert!(millis.windows(2).all(|w| w[0] >= w[1]), "{millis:?}");
+}
@@ -0,0 +1,36 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::{NoEffectiveRules, ValidateOptions};
+
+fn options(require_rules: bool) -> ValidateOptions {
+    ValidateOptions { require_rules, ..Default::default() }
+}
+
+#[test]
+fn require_rules指定なしのときルールがなくても成功する() {
+    let dir = common::test_project_path("require_rules");
+    let report = rec_lint::commands::validate::run_with_options(&[dir], &options(false)).unwrap();
+    assert!(report.lines.is_empty());
+    assert!(!report.has_failure());
+}
+
+#[test]
+fn require_rules指定ありのときルールがなければエラーになる() {
+    let dir = common::test_project_path("require_rules");
+    let err = rec_lint::commands::validate::run_with_options(&[dir], &options(true)).err().unwrap();
+    assert!(err.downcast_ref::<NoEffectiveRules>().is_some());
+}
+
+#[test]
+fn require_rules指定ありのときルールがあれば通常どおり検証する() {
+    let file = common::test_project_path("skip_type/a.rs");
+    let report = rec_lint::commands::validate::run_with_options(&[file], &options(true)).unwrap();
+    common::assert_output(
+        &report.lines,
+        r#"
+            [ error ] TODO禁止: a.rs:1:4
+            [ error ] カスタムルール違反: a.rs
+        "#,
+    );
+}
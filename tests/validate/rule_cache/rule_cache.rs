@@ -0,0 +1,69 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use rec_lint::commands::validate::{RuleCache, ValidateOptions};
+
+const FIXME_RULE: &str = r#"
+  - forbidden_texts:
+      label: fixme_check
+      texts: [FIXME]
+      message: FIXME禁止
+"#;
+
+/// test-projects/rule_cache を一時ディレクトリにコピーする
+fn copy_project(dest: &Path) {
+    let src = common::test_project_path("rule_cache");
+    fs::create_dir_all(dest.join("child")).unwrap();
+    for file in [".rec_lint_config.yaml", ".rec_lint.yaml", "root.rs", "child/a.rs"] {
+        fs::copy(src.join(file), dest.join(file)).unwrap();
+    }
+}
+
+#[test]
+fn 親ディレクトリの設定を変更すると子ディレクトリのファイルが新しいルールで再検証される() {
+    let dir = tempfile::tempdir().unwrap();
+    copy_project(dir.path());
+    let paths = [dir.path().join("child")];
+    let options = ValidateOptions::default();
+    let mut cache = RuleCache::new();
+
+    let report = rec_lint::commands::validate::run_with_cache(&paths, &options, &mut cache).unwrap();
+    common::assert_output(&report.lines, "[ error ] TODO禁止: child/a.rs:1:4");
+
+    let config = dir.path().join(".rec_lint.yaml");
+    let mut content = fs::read_to_string(&config).unwrap();
+    content.push_str(FIXME_RULE);
+    fs::write(&config, content).unwrap();
+
+    // 無効化しなければキャッシュされたルールで検証される
+    let report = rec_lint::commands::validate::run_with_cache(&paths, &options, &mut cache).unwrap();
+    common::assert_output(&report.lines, "[ error ] TODO禁止: child/a.rs:1:4");
+
+    let invalidated = cache.invalidate(&config);
+    assert_eq!(invalidated, vec![paths[0].clone()]);
+
+    let report = rec_lint::commands::validate::run_with_cache(&paths, &options, &mut cache).unwrap();
+    common::assert_output(
+        &report.lines,
+        r#"
+            [ error ] FIXME禁止: child/a.rs:2:4
+            [ error ] TODO禁止: child/a.rs:1:4
+        "#,
+    );
+}
+
+#[test]
+fn 子ディレクトリの設定変更は親ディレクトリのキャッシュを無効化しない() {
+    let dir = tempfile::tempdir().unwrap();
+    copy_project(dir.path());
+    let paths = [dir.path().to_path_buf()];
+    let mut cache = RuleCache::new();
+
+    rec_lint::commands::validate::run_with_cache(&paths, &ValidateOptions::default(), &mut cache).unwrap();
+
+    let invalidated = cache.invalidate(&dir.path().join("child/.rec_lint.yaml"));
+    assert_eq!(invalidated, vec![dir.path().join("child")]);
+}
@@ -0,0 +1,67 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn run(case: &str, file: &str) -> Vec<String> {
+    let file = common::project_file("consistent_quotes", format!("{case}/{file}"));
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+fn assert_ok(case: &str, file: &str) {
+    assert!(run(case, file).is_empty());
+}
+
+fn assert_violation(case: &str, file: &str, expected: &str) {
+    let result = run(case, file);
+    common::assert_output(&result, expected);
+}
+
+#[test]
+fn 指定したクォートのみの場合は空の結果を返す() {
+    assert_ok("case01", "シングルクォートのみ.js");
+}
+
+#[test]
+fn 指定と異なるクォートの文字列を検出する() {
+    assert_violation(
+        "case02",
+        "ダブルクォートあり.js",
+        "[ error ] クォート違反: consistent_quotes/case02/ダブルクォートあり.js:2:14",
+    );
+}
+
+#[test]
+fn 指定したクォートを含む文字列とコメントは対象外() {
+    assert_ok("case03", "エスケープ回避とコメントは対象外.js");
+}
+
+#[test]
+fn pythonでは三重引用符とシャープコメントを対象外にする() {
+    assert_violation(
+        "case04",
+        "シングルクォートあり.py",
+        "[ error ] クォート違反: consistent_quotes/case04/シングルクォートあり.py:4:8",
+    );
+}
+
+#[test]
+fn 日本語の識別子と文字列があっても文字単位の列で検出する() {
+    assert_violation(
+        "case05",
+        "日本語の識別子と文字列.js",
+        "[ error ] クォート違反: consistent_quotes/case05/日本語の識別子と文字列.js:2:12",
+    );
+}
+
+#[test]
+fn エスケープした改行を含む文字列の後も行番号がずれない() {
+    assert_violation(
+        "case06",
+        "エスケープした改行を含む文字列.js",
+        r#"
+            [ error ] クォート違反: consistent_quotes/case06/エスケープした改行を含む文字列.js:1:11
+            [ error ] クォート違反: consistent_quotes/case06/エスケープした改行を含む文字列.js:3:11
+        "#,
+    );
+}
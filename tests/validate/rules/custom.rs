@@ -18,6 +18,17 @@ fn run_with_missing_script_dir(file: &str) -> Vec<String> {
     rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
 }
 
+fn run_with_stdin(file: &str) -> Vec<String> {
+    let file = common::project_file("custom_stdin", file);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+fn run_with_env(dir: &str, file: &str) -> Vec<String> {
+    std::env::set_var("REC_LINT_TEST_LICENSE_MARKER", "SPDX-License-Identifier");
+    let file = common::project_file(dir, file);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
 fn assert_ok(file: &str) {
     assert!(run(file).is_empty());
 }
@@ -34,7 +45,7 @@ fn 任意コマンドが違反を返さない場合はエラーにならない()
 
 #[test]
 fn 任意コマンドが違反を返した場合はそのままエラーメッセージになる() {
-    assert_violation("違反あり.kt", "カスタムルール違反: custom/違反あり.kt");
+    assert_violation("違反あり.kt", "[ error ] カスタムルール違反: custom/違反あり.kt");
 }
 
 #[test]
@@ -49,7 +60,7 @@ fn script_dir経由でstoriesファイルがなければ違反になる() {
     common::assert_output(
         &result,
         "
-        .tsx に対応する .stories.tsx を作成してください: src/components/TextInput.tsx [ not found: TextInput.stories.tsx ]
+        [ error ] .tsx に対応する .stories.tsx を作成してください: src/components/TextInput.tsx [ not found: TextInput.stories.tsx ]
         ",
     );
 }
@@ -64,3 +75,40 @@ fn script_dirを設定せずにプレースホルダーを使うと設定エラ
         ",
     );
 }
+
+#[test]
+fn stdinを有効にするとファイル内容が標準入力で渡され違反がなければエラーにならない() {
+    assert!(run_with_stdin("違反なし.kt").is_empty());
+}
+
+#[test]
+fn stdinを有効にするとファイル内容が標準入力で渡されコマンドの出力がエラーメッセージになる() {
+    let result = run_with_stdin("違反あり.kt");
+    common::assert_output(
+        &result,
+        "
+        [ error ] TODO を残さないでください: custom_stdin/違反あり.kt [ // TODO: implement ]
+        ",
+    );
+}
+
+#[test]
+fn execの環境変数は展開される() {
+    assert!(run_with_env("custom_env", "表記あり.kt").is_empty());
+    common::assert_output(
+        &run_with_env("custom_env", "表記なし.kt"),
+        "
+        [ error ] ライセンス表記がありません: custom_env/表記なし.kt
+        ",
+    );
+}
+
+#[test]
+fn execで未設定の環境変数を使うとエラーになる() {
+    common::assert_output(
+        &run_with_env("custom_env_unset", "Main.kt"),
+        "
+        custom_env_unset/Main.kt: Environment variable 'REC_LINT_TEST_UNSET_TOOL' used in exec is not set
+        ",
+    );
+}
@@ -0,0 +1,45 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn run(file: &str) -> Vec<String> {
+    let file = common::project_file("forbidden_file_names", file);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+#[test]
+fn パターンに一致しないファイルは空の結果を返す() {
+    assert!(run("config.php").is_empty());
+}
+
+#[test]
+fn globパターンに一致するファイルを検出する() {
+    common::assert_output(
+        &run("config.local.php"),
+        "[ error ] コミットしてはいけないファイルです: forbidden_file_names/config.local.php",
+    );
+}
+
+#[test]
+fn テキストとして読めないファイルも検出する() {
+    common::assert_output(
+        &run("Thumbs.db"),
+        "[ error ] コミットしてはいけないファイルです: forbidden_file_names/Thumbs.db",
+    );
+}
+
+#[test]
+fn サブディレクトリのファイルもファイル名で検出する() {
+    common::assert_output(
+        &run("sub/.DS_Store"),
+        "[ error ] コミットしてはいけないファイルです: forbidden_file_names/sub/.DS_Store",
+    );
+}
+
+#[test]
+fn patternsが空の場合はエラーになる() {
+    let result = run("invalid/パターンが空.txt");
+    assert_eq!(result.len(), 1);
+    assert!(result[0].ends_with("Rule 'empty_patterns': 'forbidden_file_names' requires 'patterns'"));
+}
@@ -0,0 +1,26 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+#[test]
+fn 拡張子ごとのimport文で禁止された対象とその配下を検出する() {
+    let dir = common::project_dir("forbidden_imports/case01");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::File).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] forbidden_imports/case01/Clock.java:3:8: 禁止されたimportです
+            [ error ] forbidden_imports/case01/Clock.java:5:15: 禁止されたimportです
+            [ error ] forbidden_imports/case01/app.ts:1:21: 禁止されたimportです
+            [ error ] forbidden_imports/case01/app.ts:2:22: 禁止されたimportです
+            [ error ] forbidden_imports/case01/app.ts:6:20: 禁止されたimportです
+            [ error ] forbidden_imports/case01/lib.rs:1:5: 禁止されたimportです
+            [ error ] forbidden_imports/case01/main.c:1:11: 禁止されたimportです
+            [ error ] forbidden_imports/case01/main.go:6:3: 禁止されたimportです
+            [ error ] forbidden_imports/case01/main.go:7:5: 禁止されたimportです
+            [ error ] forbidden_imports/case01/main.py:1:8: 禁止されたimportです
+            [ error ] forbidden_imports/case01/main.py:2:6: 禁止されたimportです
+        "#,
+    );
+}
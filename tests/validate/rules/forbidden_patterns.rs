@@ -27,7 +27,7 @@ fn リテラルパターンがマッチする() {
     assert_violation(
         "case02",
         "違反リテラルパターンあり.kt",
-        "パターン違反: forbidden_patterns/case02/違反リテラルパターンあり.kt:1:1",
+        "[ error ] パターン違反: forbidden_patterns/case02/違反リテラルパターンあり.kt:1:1",
     );
 }
 
@@ -36,7 +36,7 @@ fn ワイルドカードパターンがマッチする() {
     assert_violation(
         "case03",
         "違反ワイルドカードパターンあり.kt",
-        "パターン違反: forbidden_patterns/case03/違反ワイルドカードパターンあり.kt:1:1",
+        "[ error ] パターン違反: forbidden_patterns/case03/違反ワイルドカードパターンあり.kt:1:1",
     );
 }
 
@@ -45,7 +45,10 @@ fn 文字クラスがマッチする() {
     assert_violation(
         "case04",
         "違反文字クラスパターンあり.kt",
-        "パターン違反: forbidden_patterns/case04/違反文字クラスパターンあり.kt:1:2",
+        r#"
+            [ error ] パターン違反: forbidden_patterns/case04/違反文字クラスパターンあり.kt:1:2
+            [ error ] パターン違反: forbidden_patterns/case04/違反文字クラスパターンあり.kt:1:9
+        "#,
     );
 }
 
@@ -54,7 +57,7 @@ fn 単語境界で完全一致する() {
     assert_violation(
         "case05",
         "違反単語境界パターンあり.kt",
-        "パターン違反: forbidden_patterns/case05/違反単語境界パターンあり.kt:1:5",
+        "[ error ] パターン違反: forbidden_patterns/case05/違反単語境界パターンあり.kt:1:5",
     );
 }
 
@@ -68,7 +71,7 @@ fn 行頭アンカーがマッチする() {
     assert_violation(
         "case07",
         "違反行頭アンカーパターンあり.kt",
-        "パターン違反: forbidden_patterns/case07/違反行頭アンカーパターンあり.kt:1:1",
+        "[ error ] パターン違反: forbidden_patterns/case07/違反行頭アンカーパターンあり.kt:1:1",
     );
 }
 
@@ -83,8 +86,8 @@ fn オプショナルパターンが両方にマッチする() {
         "case09",
         "違反オプショナルパターンあり.kt",
         r#"
-            パターン違反: forbidden_patterns/case09/違反オプショナルパターンあり.kt:1:1
-            パターン違反: forbidden_patterns/case09/違反オプショナルパターンあり.kt:2:1
+            [ error ] パターン違反: forbidden_patterns/case09/違反オプショナルパターンあり.kt:1:1
+            [ error ] パターン違反: forbidden_patterns/case09/違反オプショナルパターンあり.kt:2:1
         "#,
     );
 }
@@ -94,7 +97,7 @@ fn 選択パターンがいずれかにマッチする() {
     assert_violation(
         "case10",
         "違反選択パターンあり.kt",
-        "パターン違反: forbidden_patterns/case10/違反選択パターンあり.kt:1:1",
+        "[ error ] パターン違反: forbidden_patterns/case10/違反選択パターンあり.kt:1:1",
     );
 }
 
@@ -104,8 +107,8 @@ fn 複数行の違反を検出できる() {
         "case11",
         "複数行に複数種類の違反パターンあり.kt",
         r#"
-            パターン違反: forbidden_patterns/case11/複数行に複数種類の違反パターンあり.kt:1:1
-            パターン違反: forbidden_patterns/case11/複数行に複数種類の違反パターンあり.kt:2:1
+            [ error ] パターン違反: forbidden_patterns/case11/複数行に複数種類の違反パターンあり.kt:1:1
+            [ error ] パターン違反: forbidden_patterns/case11/複数行に複数種類の違反パターンあり.kt:2:1
         "#,
     );
 }
@@ -115,7 +118,7 @@ fn 貪欲マッチでもカラム位置は正しい() {
     assert_violation(
         "case12",
         "違反貪欲マッチパターンあり.kt",
-        "パターン違反: forbidden_patterns/case12/違反貪欲マッチパターンあり.kt:1:2",
+        "[ error ] パターン違反: forbidden_patterns/case12/違反貪欲マッチパターンあり.kt:1:2",
     );
 }
 
@@ -124,7 +127,7 @@ fn ドットは任意の1文字にマッチする() {
     assert_violation(
         "case13",
         "違反任意の1文字マッチパターンあり.kt",
-        "パターン違反: forbidden_patterns/case13/違反任意の1文字マッチパターンあり.kt:1:1",
+        "[ error ] パターン違反: forbidden_patterns/case13/違反任意の1文字マッチパターンあり.kt:1:1",
     );
 }
 
@@ -133,7 +136,7 @@ fn デフォルトでは大文字小文字を区別する() {
     assert_violation(
         "case14",
         "大文字と小文字あり.kt",
-        "パターン違反: forbidden_patterns/case14/大文字と小文字あり.kt:1:1",
+        "[ error ] パターン違反: forbidden_patterns/case14/大文字と小文字あり.kt:1:1",
     );
 }
 
@@ -142,7 +145,7 @@ fn 複数パターンでは先に定義されたパターンが優先される()
     assert_violation(
         "case15",
         "複数パターンで先に定義されたパターンが優先.kt",
-        "パターン違反: forbidden_patterns/case15/複数パターンで先に定義されたパターンが優先.kt:1:1",
+        "[ error ] パターン違反: forbidden_patterns/case15/複数パターンで先に定義されたパターンが優先.kt:1:1",
     );
 }
 
@@ -151,7 +154,7 @@ fn 最初のパターンが無ければ次のパターンでマッチする() {
     assert_violation(
         "case16",
         "最初のパターンが無ければ次のパターンでマッチ.kt",
-        "パターン違反2: forbidden_patterns/case16/最初のパターンが無ければ次のパターンでマッチ.kt:1:1",
+        "[ error ] パターン違反2: forbidden_patterns/case16/最初のパターンが無ければ次のパターンでマッチ.kt:1:1",
     );
 }
 
@@ -160,7 +163,7 @@ fn 行番号は1から始まる() {
     assert_violation(
         "case17",
         "行番号は1から始まる.kt",
-        "パターン違反: forbidden_patterns/case17/行番号は1から始まる.kt:1:1",
+        "[ error ] パターン違反: forbidden_patterns/case17/行番号は1から始まる.kt:1:1",
     );
 }
 
@@ -169,7 +172,7 @@ fn カラム位置は正規表現マッチの開始位置() {
     assert_violation(
         "case18",
         "カラム位置は正規表現マッチの開始位置.kt",
-        "パターン違反: forbidden_patterns/case18/カラム位置は正規表現マッチの開始位置.kt:1:5",
+        "[ error ] パターン違反: forbidden_patterns/case18/カラム位置は正規表現マッチの開始位置.kt:1:5",
     );
 }
 
@@ -178,7 +181,7 @@ fn 検出結果には行全体が含まれる() {
     assert_violation(
         "case19",
         "検出結果には行全体が含まれる.kt",
-        "パターン違反: forbidden_patterns/case19/検出結果には行全体が含まれる.kt:1:8",
+        "[ error ] パターン違反: forbidden_patterns/case19/検出結果には行全体が含まれる.kt:1:8",
     );
 }
 
@@ -187,6 +190,62 @@ fn 大文字小文字を無視するフラグが使える() {
     assert_violation(
         "case20",
         "大文字小文字を無視するフラグ.kt",
-        "パターン違反: forbidden_patterns/case20/大文字小文字を無視するフラグ.kt:1:1",
+        "[ error ] パターン違反: forbidden_patterns/case20/大文字小文字を無視するフラグ.kt:1:1",
+    );
+}
+
+#[test]
+fn 許可リストに一致するマッチは検出せず同じ行の他のマッチは検出する() {
+    assert_violation(
+        "case21",
+        "許可リストの値は検出しない.kt",
+        "[ error ] パターン違反: forbidden_patterns/case21/許可リストの値は検出しない.kt:2:38",
+    );
+}
+
+#[test]
+fn 同一行に複数マッチすればそれぞれ検出される() {
+    assert_violation(
+        "case22",
+        "同一行に複数の違反パターンあり.kt",
+        r#"
+            [ error ] パターン違反: forbidden_patterns/case22/同一行に複数の違反パターンあり.kt:1:1
+            [ error ] パターン違反: forbidden_patterns/case22/同一行に複数の違反パターンあり.kt:1:9
+        "#,
+    );
+}
+
+#[test]
+fn allowの正規表現に一部でも一致するマッチは検出しない() {
+    assert_violation(
+        "case23",
+        "allowに一致するマッチは検出しない.kt",
+        "[ error ] パターン違反: forbidden_patterns/case23/allowに一致するマッチは検出しない.kt:1:4",
+    );
+}
+
+#[test]
+fn allowの正規表現が不正なら設定エラーになる() {
+    let result = run("case24", "allowの正規表現が不正.kt");
+    assert_eq!(result.len(), 1);
+    assert!(result[0].contains("Rule 'forbidden_patterns_check': invalid allow regex 'query('"));
+}
+
+#[test]
+fn report_group指定時は捕獲グループの位置と値を検出する() {
+    assert_violation(
+        "case25",
+        "捕獲グループの位置を検出する.kt",
+        r#"
+            [ error ] パターン違反: forbidden_patterns/case25/捕獲グループの位置を検出する.kt:1:5 [ found: items ]
+            [ error ] パターン違反: forbidden_patterns/case25/捕獲グループの位置を検出する.kt:2:9 [ found: cache ]
+        "#,
     );
 }
+
+#[test]
+fn report_groupがグループ数より大きければ設定エラーになる() {
+    let result = run("case26", "捕獲グループが足りない.kt");
+    assert_eq!(result.len(), 1);
+    assert!(result[0].contains("Rule 'forbidden_patterns_check': report_group 2 exceeds the capture groups"));
+}
@@ -27,16 +27,19 @@ fn キーワードがマッチすると違反が検出される() {
     assert_violation(
         "case02",
         "行中に違反キーワードあり.kt",
-        "テキスト違反: forbidden_texts/case02/行中に違反キーワードあり.kt:1:1",
+        "[ error ] テキスト違反: forbidden_texts/case02/行中に違反キーワードあり.kt:1:1",
     );
 }
 
 #[test]
-fn 行中のキーワードも検出される() {
+fn 同一行の複数キーワードはそれぞれ検出される() {
     assert_violation(
         "case03",
         "同一行に複数の違反キーワードあり.kt",
-        "テキスト違反: forbidden_texts/case03/同一行に複数の違反キーワードあり.kt:1:1",
+        r#"
+            [ error ] テキスト違反: forbidden_texts/case03/同一行に複数の違反キーワードあり.kt:1:1
+            [ error ] テキスト違反: forbidden_texts/case03/同一行に複数の違反キーワードあり.kt:1:7
+        "#,
     );
 }
 
@@ -45,7 +48,7 @@ fn 複数キーワードでは先に定義されたものが優先される() {
     assert_violation(
         "case04",
         "複数キーワード指定で２つめの違反キーワードあり.kt",
-        "テキスト違反: forbidden_texts/case04/複数キーワード指定で２つめの違反キーワードあり.kt:1:1",
+        "[ error ] テキスト違反: forbidden_texts/case04/複数キーワード指定で２つめの違反キーワードあり.kt:1:1",
     );
 }
 
@@ -55,8 +58,8 @@ fn 複数行の違反を検出できる() {
         "case05",
         "複数行に複数種類の違反キーワードあり.kt",
         r#"
-            テキスト違反: forbidden_texts/case05/複数行に複数種類の違反キーワードあり.kt:1:1
-            テキスト違反: forbidden_texts/case05/複数行に複数種類の違反キーワードあり.kt:2:1
+            [ error ] テキスト違反: forbidden_texts/case05/複数行に複数種類の違反キーワードあり.kt:1:1
+            [ error ] テキスト違反: forbidden_texts/case05/複数行に複数種類の違反キーワードあり.kt:2:1
         "#,
     );
 }
@@ -66,7 +69,7 @@ fn 行末のキーワードも検出される() {
     assert_violation(
         "case06",
         "行末に違反キーワードあり.kt",
-        "テキスト違反: forbidden_texts/case06/行末に違反キーワードあり.kt:1:6",
+        "[ error ] テキスト違反: forbidden_texts/case06/行末に違反キーワードあり.kt:1:6",
     );
 }
 
@@ -75,7 +78,7 @@ fn 大文字小文字を区別する() {
     assert_violation(
         "case07",
         "大文字キーワードと小文字キーワードあり.kt",
-        "テキスト違反: forbidden_texts/case07/大文字キーワードと小文字キーワードあり.kt:2:1",
+        "[ error ] テキスト違反: forbidden_texts/case07/大文字キーワードと小文字キーワードあり.kt:2:1",
     );
 }
 
@@ -84,6 +87,72 @@ fn 部分文字列もマッチする() {
     assert_violation(
         "case08",
         "キーワードを含む長い文字列あり.kt",
-        "テキスト違反: forbidden_texts/case08/キーワードを含む長い文字列あり.kt:1:8",
+        "[ error ] テキスト違反: forbidden_texts/case08/キーワードを含む長い文字列あり.kt:1:8",
+    );
+}
+
+#[test]
+fn 許可リストに一致する単語は検出しない() {
+    assert_violation(
+        "case09",
+        "許可リストの値は検出しない.kt",
+        "[ error ] テキスト違反: forbidden_texts/case09/許可リストの値は検出しない.kt:3:15",
+    );
+}
+
+#[test]
+fn word_boundary指定時は単語単位で一致するキーワードだけ検出する() {
+    assert_violation(
+        "case10",
+        "単語単位で一致するキーワードだけ検出する.kt",
+        r#"
+            [ error ] テキスト違反: forbidden_texts/case10/単語単位で一致するキーワードだけ検出する.kt:2:5
+            [ error ] テキスト違反: forbidden_texts/case10/単語単位で一致するキーワードだけ検出する.kt:4:7
+        "#,
+    );
+}
+
+#[test]
+fn 同一行に同じキーワードが複数あればそれぞれ検出される() {
+    assert_violation(
+        "case11",
+        "同一行に同じ違反キーワードが複数あり.kt",
+        r#"
+            [ error ] テキスト違反: forbidden_texts/case11/同一行に同じ違反キーワードが複数あり.kt:1:1
+            [ error ] テキスト違反: forbidden_texts/case11/同一行に同じ違反キーワードが複数あり.kt:1:9
+        "#,
+    );
+}
+
+#[test]
+fn anchor_line_start指定時はインデントの後の行頭のキーワードだけ検出する() {
+    assert_violation(
+        "case12",
+        "行頭のキーワードだけ検出する.kt",
+        r#"
+            [ error ] テキスト違反: forbidden_texts/case12/行頭のキーワードだけ検出する.kt:1:1
+            [ error ] テキスト違反: forbidden_texts/case12/行頭のキーワードだけ検出する.kt:2:5
+        "#,
+    );
+}
+
+#[test]
+fn anchor_line_end指定時は末尾の空白の前の行末のキーワードだけ検出する() {
+    assert_violation(
+        "case13",
+        "行末のキーワードだけ検出する.kt",
+        r#"
+            [ error ] テキスト違反: forbidden_texts/case13/行末のキーワードだけ検出する.kt:1:10
+            [ error ] テキスト違反: forbidden_texts/case13/行末のキーワードだけ検出する.kt:2:10
+        "#,
+    );
+}
+
+#[test]
+fn 全角括弧で囲まれた許可リストの値は検出しない() {
+    assert_violation(
+        "case14",
+        "全角括弧で囲まれた許可リストの値は検出しない.kt",
+        "[ error ] テキスト違反: forbidden_texts/case14/全角括弧で囲まれた許可リストの値は検出しない.kt:2:18",
     );
 }
@@ -0,0 +1,36 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn run(case: &str, file: &str) -> Vec<String> {
+    let file = common::project_file("max_file_lines", format!("{case}/{file}"));
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+#[test]
+fn 制限以内の行数の場合は空の結果を返す() {
+    assert!(run("case01", "制限以内.rs").is_empty());
+}
+
+#[test]
+fn 制限を超える場合は超過した最初の行を1件だけ検出する() {
+    common::assert_output(
+        &run("case02", "制限超過.rs"),
+        r#"
+            [ error ] ファイルが長すぎます: max_file_lines/case02/制限超過.rs:6:1
+        "#,
+    );
+}
+
+#[test]
+fn matchでテストとプロダクションコードに異なる制限を適用できる() {
+    let dir = common::project_file("max_file_lines", "case03");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::File).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] max_file_lines/case03/src/a.rs:4:1: プロダクションコードが長すぎます
+        "#,
+    );
+}
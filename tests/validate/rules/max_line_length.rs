@@ -0,0 +1,48 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn run(case: &str, file: &str) -> Vec<String> {
+    let file = common::project_file("max_line_length", format!("{case}/{file}"));
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+#[test]
+fn 制限以内の行のみの場合は空の結果を返す() {
+    assert!(run("case01", "制限以内.rs").is_empty());
+}
+
+#[test]
+fn 制限を超える行を表示幅で検出する() {
+    common::assert_output(
+        &run("case02", "制限超過.rs"),
+        r#"
+            [ error ] 行が長すぎます: max_line_length/case02/制限超過.rs:2:21
+            [ error ] 行が長すぎます: max_line_length/case02/制限超過.rs:3:21
+        "#,
+    );
+}
+
+#[test]
+fn タブはtab_widthで指定した幅に展開する() {
+    common::assert_output(
+        &run("case03", "タブ幅.rs"),
+        r#"
+            [ error ] 行が長すぎます: max_line_length/case03/タブ幅.rs:3:21
+        "#,
+    );
+}
+
+#[test]
+fn matchでファイルごとに異なる制限を適用できる() {
+    let dir = common::project_file("max_line_length", "case04");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::File).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] max_line_length/case04/a.rs:2:21: rsの行が長すぎます
+            [ error ] max_line_length/case04/b.md:4:41: mdの行が長すぎます
+        "#,
+    );
+}
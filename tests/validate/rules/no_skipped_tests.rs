@@ -0,0 +1,41 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn run(case: &str, file: &str) -> Vec<String> {
+    let file = common::project_file("no_skipped_tests", format!("{case}/{file}"));
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+fn assert_ok(case: &str, file: &str) {
+    assert!(run(case, file).is_empty());
+}
+
+fn assert_violation(case: &str, file: &str, expected: &str) {
+    let result = run(case, file);
+    common::assert_output(&result, expected);
+}
+
+#[test]
+fn スキップされたテストがない場合は空の結果を返す() {
+    assert_ok("case01", "スキップなし.rs");
+}
+
+#[test]
+fn rustのignoreアトリビュートを検出する() {
+    assert_violation(
+        "case02",
+        "ignoreあり.rs",
+        "[ error ] スキップテスト禁止: no_skipped_tests/case02/ignoreあり.rs:4:1 [ found: #[ignore] ]",
+    );
+}
+
+#[test]
+fn jsのit_onlyを検出しコメントは対象外にする() {
+    assert_violation(
+        "case03",
+        "onlyあり.test.js",
+        "[ error ] スキップテスト禁止: no_skipped_tests/case03/onlyあり.test.js:2:1 [ found: it.only ]",
+    );
+}
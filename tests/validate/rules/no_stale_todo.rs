@@ -0,0 +1,38 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn run(case: &str) -> Vec<String> {
+    let dir = common::project_file("no_stale_todo", case);
+    rec_lint::commands::validate::run(&[dir], SortMode::File).unwrap()
+}
+
+#[test]
+fn max_age_daysより古い日付のマーカーを検出する() {
+    common::assert_output(
+        &run("dated"),
+        r#"
+            [ error ] no_stale_todo/dated/app.rs:1:4: 期限切れの TODO を解消すること [ found: TODO(2000-01-01) ]
+            [ error ] no_stale_todo/dated/app.rs:3:8: 期限切れの TODO を解消すること [ found: FIXME(2000-06-30) ]
+        "#,
+    );
+}
+
+#[test]
+fn max_age_daysがない場合は日付に関係なくすべてのマーカーを検出する() {
+    common::assert_output(
+        &run("undated"),
+        r#"
+            [ error ] no_stale_todo/undated/app.py:1:3: TODO を残さないこと [ found: TODO ]
+            [ error ] no_stale_todo/undated/app.py:3:7: TODO を残さないこと [ found: TODO(2999-12-31) ]
+        "#,
+    );
+}
+
+#[test]
+fn markersが空の場合はエラーになる() {
+    let result = run("empty_markers");
+    assert_eq!(result.len(), 1);
+    assert!(result[0].contains("Rule 'TODO の禁止': 'markers' must not be empty"));
+}
@@ -0,0 +1,27 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn run(file: &str) -> Vec<String> {
+    let file = common::project_file("no_trailing_whitespace", file);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+#[test]
+fn 行末に空白がない場合は空の結果を返す() {
+    assert!(run("空白なし.rs").is_empty());
+}
+
+#[test]
+fn 行末の空白の先頭の文字位置を検出する() {
+    common::assert_output(
+        &run("空白あり.rs"),
+        r#"
+            [ error ] 行末の空白を削除してください: no_trailing_whitespace/空白あり.rs:1:12
+            [ error ] 行末の空白を削除してください: no_trailing_whitespace/空白あり.rs:2:15
+            [ error ] 行末の空白を削除してください: no_trailing_whitespace/空白あり.rs:3:1
+            [ error ] 行末の空白を削除してください: no_trailing_whitespace/空白あり.rs:4:11
+        "#,
+    );
+}
@@ -0,0 +1,73 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn run(case: &str) -> Vec<String> {
+    let dir = common::project_file("preset", case);
+    rec_lint::commands::validate::run(&[dir], SortMode::File).unwrap()
+}
+
+#[test]
+fn no_consoleはconsoleの呼び出しを検出する() {
+    common::assert_output(
+        &run("no_console"),
+        r#"
+            [ error ] preset/no_console/app.ts:1:1: console の呼び出しを削除すること
+            [ error ] preset/no_console/app.ts:2:3: console の呼び出しを削除すること
+        "#,
+    );
+}
+
+#[test]
+fn no_debuggerはdebugger文を検出する() {
+    common::assert_output(
+        &run("no_debugger"),
+        r#"
+            [ error ] preset/no_debugger/app.ts:2:3: debugger 文を削除すること
+        "#,
+    );
+}
+
+#[test]
+fn no_varはvarによる変数宣言を検出する() {
+    common::assert_output(
+        &run("no_var"),
+        r#"
+            [ error ] preset/no_var/app.js:1:1: var ではなく let か const を使うこと
+            [ error ] preset/no_var/app.js:4:6: var ではなく let か const を使うこと
+        "#,
+    );
+}
+
+#[test]
+fn no_focused_testはフォーカスされたテストを検出する() {
+    common::assert_output(
+        &run("no_focused_test"),
+        r#"
+            [ error ] preset/no_focused_test/app.test.ts:1:1: フォーカスされたテストを元に戻すこと
+            [ error ] preset/no_focused_test/app.test.ts:3:3: フォーカスされたテストを元に戻すこと
+            [ error ] preset/no_focused_test/app.test.ts:4:3: フォーカスされたテストを元に戻すこと
+        "#,
+    );
+}
+
+#[test]
+fn messageを指定するとプリセットのメッセージを上書きする() {
+    common::assert_output(
+        &run("message"),
+        r#"
+            [ error ] preset/message/app.ts:1:1: logger を使うこと
+            [ error ] preset/message/app.ts:2:3: logger を使うこと
+        "#,
+    );
+}
+
+#[test]
+fn 不明なプリセットは有効なプリセットを列挙した設定エラーになる() {
+    let result = run("unknown");
+    assert_eq!(result.len(), 1);
+    assert!(result[0].contains(
+        "Rule 'alert禁止': unknown preset 'no_alert' (valid presets: no_console, no_debugger, no_var, no_focused_test)"
+    ));
+}
@@ -0,0 +1,49 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn validate_case(relative: &str) -> Vec<String> {
+    let file = common::project_file("require_csharp_doc", relative);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+fn expect_violation(relative: &str, expected: &str) {
+    let result = validate_case(relative);
+    common::assert_output(&result, expected);
+}
+
+#[test]
+fn type_public指定でxmlドキュメントコメントがないpublicな型は違反になり属性は読み飛ばす() {
+    expect_violation(
+        "type/case01/public指定_一部の型にXMLドキュメントコメントがない.cs",
+        r#"
+            [ error ] XMLドキュメントコメントを書いてください: require_csharp_doc/type/case01/public指定_一部の型にXMLドキュメントコメントがない.cs:11:1 [ found: class Order ]
+            [ error ] XMLドキュメントコメントを書いてください: require_csharp_doc/type/case01/public指定_一部の型にXMLドキュメントコメントがない.cs:23:1 [ found: struct Point ]
+            [ error ] XMLドキュメントコメントを書いてください: require_csharp_doc/type/case01/public指定_一部の型にXMLドキュメントコメントがない.cs:27:1 [ found: enum Status ]
+        "#,
+    );
+}
+
+#[test]
+fn method_public指定でxmlドキュメントコメントがないpublicなメソッドとコンストラクタは違反になる() {
+    expect_violation(
+        "method/case01/public指定_publicなメソッドにXMLドキュメントコメントがない.cs",
+        r#"
+            [ error ] XMLドキュメントコメントを書いてください: require_csharp_doc/method/case01/public指定_publicなメソッドにXMLドキュメントコメントがない.cs:4:1 [ found: method UserRepository ]
+            [ error ] XMLドキュメントコメントを書いてください: require_csharp_doc/method/case01/public指定_publicなメソッドにXMLドキュメントコメントがない.cs:21:1 [ found: method SaveAsync ]
+            [ error ] XMLドキュメントコメントを書いてください: require_csharp_doc/method/case01/public指定_publicなメソッドにXMLドキュメントコメントがない.cs:27:1 [ found: method CountByName ]
+            [ error ] XMLドキュメントコメントを書いてください: require_csharp_doc/method/case01/public指定_publicなメソッドにXMLドキュメントコメントがない.cs:32:1 [ found: method ToString ]
+        "#,
+    );
+}
+
+#[test]
+fn method_all指定で制御構文や呼び出しはメソッドとして検出しない() {
+    expect_violation(
+        "method/case02/all指定_制御構文はメソッドとして検出しない.cs",
+        r#"
+            [ error ] XMLドキュメントコメントを書いてください: require_csharp_doc/method/case02/all指定_制御構文はメソッドとして検出しない.cs:39:1 [ found: method Save ]
+        "#,
+    );
+}
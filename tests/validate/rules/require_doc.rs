@@ -0,0 +1,66 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn validate_case(relative: &str) -> Vec<String> {
+    let file = common::project_file("require_doc", relative);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+fn expect_violation(relative: &str, expected: &str) {
+    let result = validate_case(relative);
+    common::assert_output(&result, expected);
+}
+
+#[test]
+fn 行コメントの構文でdocコメントがない宣言は違反になる() {
+    expect_violation(
+        "line/case01/一部の宣言にdocコメントがない.nim",
+        r#"
+            [ error ] docコメントを書いてください: require_doc/line/case01/一部の宣言にdocコメントがない.nim:4:1 [ found: declaration Missing ]
+            [ error ] docコメントを書いてください: require_doc/line/case01/一部の宣言にdocコメントがない.nim:11:1 [ found: declaration missing ]
+        "#,
+    );
+}
+
+#[test]
+fn ブロックコメントの構文でdocコメントがない宣言は違反になる() {
+    expect_violation(
+        "block/case01/ブロックのdocコメント.txt",
+        r#"
+            [ error ] docコメントを書いてください: require_doc/block/case01/ブロックのdocコメント.txt:9:1 [ found: declaration fn ]
+        "#,
+    );
+}
+
+#[test]
+fn custom以外の構文はエラーになる() {
+    let file = common::project_file("require_doc", "invalid/custom以外の構文.txt");
+    let result = rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap();
+    assert_eq!(result.len(), 1);
+    assert!(result[0].ends_with("Rule 'doc_rule': 'require_doc' supports only 'custom' format"));
+}
+
+#[test]
+fn languagesは拡張子ごとに各言語のdocルールで検証する() {
+    let dir = common::project_file("require_doc", "languages/case01");
+    let result = rec_lint::commands::validate::run(&[dir], SortMode::Rule).unwrap();
+    common::assert_output(
+        &result,
+        r#"
+            [ error ] docコメントを書いてください: require_doc/languages/case01/Model.kt:4:1 [ found: class Missing ]
+            [ error ] docコメントを書いてください: require_doc/languages/case01/lib.rs:6:1 [ found: fn missing ]
+        "#,
+    );
+}
+
+#[test]
+fn languagesに未対応の拡張子があるとエラーになる() {
+    let file = common::project_file("require_doc", "languages/invalid/未対応の拡張子.rb");
+    let result = rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap();
+    assert_eq!(result.len(), 1);
+    assert!(result[0].ends_with(
+        "Rule 'doc_rule': unsupported extension 'rb' in 'languages' (supported: php, kt, kts, rs, py, ts, tsx, go, swift, cs)"
+    ));
+}
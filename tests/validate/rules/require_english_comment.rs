@@ -37,7 +37,7 @@ fn java_line_コメント行に日本語コメントがある() {
     expect_comment_violation(
         "java_like_syntax/line/コメント行に日本語コメントがある.java",
         r#"
-            コメントを英語にしてください: require_english_comment/java_like_syntax/line/コメント行に日本語コメントがある.java:1:1 [ found: 日本語コメント ]
+            [ error ] コメントを英語にしてください: require_english_comment/java_like_syntax/line/コメント行に日本語コメントがある.java:1:1 [ found: 日本語コメント ]
         "#,
     );
 }
@@ -47,7 +47,7 @@ fn java_line_コード行末に日本語コメントがある() {
     expect_comment_violation(
         "java_like_syntax/line/コード行末に日本語コメントがある.java",
         r#"
-            コメントを英語にしてください: require_english_comment/java_like_syntax/line/コード行末に日本語コメントがある.java:1:1 [ found: 説明 ]
+            [ error ] コメントを英語にしてください: require_english_comment/java_like_syntax/line/コード行末に日本語コメントがある.java:1:1 [ found: 説明 ]
         "#,
     );
 }
@@ -57,8 +57,8 @@ fn java_line_複数行に日本語コメントがある() {
     expect_comment_violation(
         "java_like_syntax/line/複数行に日本語コメントがある.java",
         r#"
-            コメントを英語にしてください: require_english_comment/java_like_syntax/line/複数行に日本語コメントがある.java:1:1 [ found: 一行目 ]
-            コメントを英語にしてください: require_english_comment/java_like_syntax/line/複数行に日本語コメントがある.java:2:1 [ found: 二行目 ]
+            [ error ] コメントを英語にしてください: require_english_comment/java_like_syntax/line/複数行に日本語コメントがある.java:1:1 [ found: 一行目 ]
+            [ error ] コメントを英語にしてください: require_english_comment/java_like_syntax/line/複数行に日本語コメントがある.java:2:1 [ found: 二行目 ]
         "#,
     );
 }
@@ -73,7 +73,7 @@ fn java_line_URL文字列と同じ行末にコメントがある() {
     expect_comment_violation(
         "java_like_syntax/line/URL文字列と同じ行末にコメントがある.java",
         r#"
-            コメントを英語にしてください: require_english_comment/java_like_syntax/line/URL文字列と同じ行末にコメントがある.java:1:1 [ found: 説明 ]
+            [ error ] コメントを英語にしてください: require_english_comment/java_like_syntax/line/URL文字列と同じ行末にコメントがある.java:1:1 [ found: 説明 ]
         "#,
     );
 }
@@ -95,7 +95,7 @@ fn java_block_単一行ブロックコメントに日本語がある() {
     expect_comment_violation(
         "java_like_syntax/block/単一行ブロックコメントに日本語がある.java",
         r#"
-            コメントを英語にしてください: require_english_comment/java_like_syntax/block/単一行ブロックコメントに日本語がある.java:1:1 [ found: 日本語コメント ]
+            [ error ] コメントを英語にしてください: require_english_comment/java_like_syntax/block/単一行ブロックコメントに日本語がある.java:1:1 [ found: 日本語コメント ]
         "#,
     );
 }
@@ -105,8 +105,8 @@ fn java_block_複数行ブロックコメントに日本語がある() {
     expect_comment_violation(
         "java_like_syntax/block/複数行ブロックコメントに日本語がある.java",
         r#"
-            コメントを英語にしてください: require_english_comment/java_like_syntax/block/複数行ブロックコメントに日本語がある.java:2:1 [ found: * 一行目 ]
-            コメントを英語にしてください: require_english_comment/java_like_syntax/block/複数行ブロックコメントに日本語がある.java:3:1 [ found: * 二行目 ]
+            [ error ] コメントを英語にしてください: require_english_comment/java_like_syntax/block/複数行ブロックコメントに日本語がある.java:2:1 [ found: * 一行目 ]
+            [ error ] コメントを英語にしてください: require_english_comment/java_like_syntax/block/複数行ブロックコメントに日本語がある.java:3:1 [ found: * 二行目 ]
         "#,
     );
 }
@@ -116,7 +116,7 @@ fn java_block_コード行中のブロックコメントに日本語がある()
     expect_comment_violation(
         "java_like_syntax/block/コード行の中の単一行ブロックコメントに日本語がある.java",
         r#"
-            コメントを英語にしてください: require_english_comment/java_like_syntax/block/コード行の中の単一行ブロックコメントに日本語がある.java:1:1 [ found: 日本語 ]
+            [ error ] コメントを英語にしてください: require_english_comment/java_like_syntax/block/コード行の中の単一行ブロックコメントに日本語がある.java:1:1 [ found: 日本語 ]
         "#,
     );
 }
@@ -138,7 +138,7 @@ fn python_line_コメント行に日本語コメントがある() {
     expect_comment_violation(
         "python_like_syntax/line/コメント行に日本語コメントがある.py",
         r#"
-            コメントを英語にしてください: require_english_comment/python_like_syntax/line/コメント行に日本語コメントがある.py:1:1 [ found: 日本語コメント ]
+            [ error ] コメントを英語にしてください: require_english_comment/python_like_syntax/line/コメント行に日本語コメントがある.py:1:1 [ found: 日本語コメント ]
         "#,
     );
 }
@@ -148,7 +148,7 @@ fn python_line_コード行末に日本語コメントがある() {
     expect_comment_violation(
         "python_like_syntax/line/コード行末に日本語コメントがある.py",
         r#"
-            コメントを英語にしてください: require_english_comment/python_like_syntax/line/コード行末に日本語コメントがある.py:1:1 [ found: 説明 ]
+            [ error ] コメントを英語にしてください: require_english_comment/python_like_syntax/line/コード行末に日本語コメントがある.py:1:1 [ found: 説明 ]
         "#,
     );
 }
@@ -158,8 +158,8 @@ fn python_line_複数行に日本語コメントがある() {
     expect_comment_violation(
         "python_like_syntax/line/複数行に日本語コメントがある.py",
         r#"
-            コメントを英語にしてください: require_english_comment/python_like_syntax/line/複数行に日本語コメントがある.py:1:1 [ found: 一行目 ]
-            コメントを英語にしてください: require_english_comment/python_like_syntax/line/複数行に日本語コメントがある.py:2:1 [ found: 二行目 ]
+            [ error ] コメントを英語にしてください: require_english_comment/python_like_syntax/line/複数行に日本語コメントがある.py:1:1 [ found: 一行目 ]
+            [ error ] コメントを英語にしてください: require_english_comment/python_like_syntax/line/複数行に日本語コメントがある.py:2:1 [ found: 二行目 ]
         "#,
     );
 }
@@ -174,7 +174,7 @@ fn python_line_URL文字列と同じ行末にコメントがある() {
     expect_comment_violation(
         "python_like_syntax/line/URL文字列と同じ行末にコメントがある.py",
         r#"
-            コメントを英語にしてください: require_english_comment/python_like_syntax/line/URL文字列と同じ行末にコメントがある.py:1:1 [ found: 説明 ]
+            [ error ] コメントを英語にしてください: require_english_comment/python_like_syntax/line/URL文字列と同じ行末にコメントがある.py:1:1 [ found: 説明 ]
         "#,
     );
 }
@@ -194,7 +194,7 @@ fn python_block_単一行ブロックコメントに日本語がある() {
     expect_comment_violation(
         "python_like_syntax/block/単一行ブロックコメントに日本語がある.py",
         r#"
-            コメントを英語にしてください: require_english_comment/python_like_syntax/block/単一行ブロックコメントに日本語がある.py:1:1 [ found: 日本語 ]
+            [ error ] コメントを英語にしてください: require_english_comment/python_like_syntax/block/単一行ブロックコメントに日本語がある.py:1:1 [ found: 日本語 ]
         "#,
     );
 }
@@ -204,8 +204,8 @@ fn python_block_複数行ブロックコメントに日本語がある() {
     expect_comment_violation(
         "python_like_syntax/block/複数行ブロックコメントに日本語がある.py",
         r#"
-            コメントを英語にしてください: require_english_comment/python_like_syntax/block/複数行ブロックコメントに日本語がある.py:2:1 [ found: 一行目 ]
-            コメントを英語にしてください: require_english_comment/python_like_syntax/block/複数行ブロックコメントに日本語がある.py:3:1 [ found: 二行目 ]
+            [ error ] コメントを英語にしてください: require_english_comment/python_like_syntax/block/複数行ブロックコメントに日本語がある.py:2:1 [ found: 一行目 ]
+            [ error ] コメントを英語にしてください: require_english_comment/python_like_syntax/block/複数行ブロックコメントに日本語がある.py:3:1 [ found: 二行目 ]
         "#,
     );
 }
@@ -215,7 +215,7 @@ fn python_block_コード行中のブロックコメントに日本語がある(
     expect_comment_violation(
         "python_like_syntax/block/コード行の中の単一行ブロックコメントに日本語がある.py",
         r#"
-            コメントを英語にしてください: require_english_comment/python_like_syntax/block/コード行の中の単一行ブロックコメントに日本語がある.py:2:1 [ found: 日本語 ]
+            [ error ] コメントを英語にしてください: require_english_comment/python_like_syntax/block/コード行の中の単一行ブロックコメントに日本語がある.py:2:1 [ found: 日本語 ]
         "#,
     );
 }
@@ -237,7 +237,7 @@ fn rust_line_コメント行に日本語コメントがある() {
     expect_comment_violation(
         "rust_like_syntax/line/コメント行に日本語コメントがある.rs",
         r#"
-            コメントを英語にしてください: require_english_comment/rust_like_syntax/line/コメント行に日本語コメントがある.rs:1:1 [ found: 日本語コメント ]
+            [ error ] コメントを英語にしてください: require_english_comment/rust_like_syntax/line/コメント行に日本語コメントがある.rs:1:1 [ found: 日本語コメント ]
         "#,
     );
 }
@@ -247,7 +247,7 @@ fn rust_line_コード行末に日本語コメントがある() {
     expect_comment_violation(
         "rust_like_syntax/line/コード行末に日本語コメントがある.rs",
         r#"
-            コメントを英語にしてください: require_english_comment/rust_like_syntax/line/コード行末に日本語コメントがある.rs:1:1 [ found: 説明 ]
+            [ error ] コメントを英語にしてください: require_english_comment/rust_like_syntax/line/コード行末に日本語コメントがある.rs:1:1 [ found: 説明 ]
         "#,
     );
 }
@@ -257,8 +257,8 @@ fn rust_line_複数行に日本語コメントがある() {
     expect_comment_violation(
         "rust_like_syntax/line/複数行に日本語コメントがある.rs",
         r#"
-            コメントを英語にしてください: require_english_comment/rust_like_syntax/line/複数行に日本語コメントがある.rs:1:1 [ found: 一行目 ]
-            コメントを英語にしてください: require_english_comment/rust_like_syntax/line/複数行に日本語コメントがある.rs:2:1 [ found: 二行目 ]
+            [ error ] コメントを英語にしてください: require_english_comment/rust_like_syntax/line/複数行に日本語コメントがある.rs:1:1 [ found: 一行目 ]
+            [ error ] コメントを英語にしてください: require_english_comment/rust_like_syntax/line/複数行に日本語コメントがある.rs:2:1 [ found: 二行目 ]
         "#,
     );
 }
@@ -273,7 +273,7 @@ fn rust_line_URL文字列と同じ行末にコメントがある() {
     expect_comment_violation(
         "rust_like_syntax/line/URL文字列と同じ行末にコメントがある.rs",
         r#"
-            コメントを英語にしてください: require_english_comment/rust_like_syntax/line/URL文字列と同じ行末にコメントがある.rs:1:1 [ found: 説明 ]
+            [ error ] コメントを英語にしてください: require_english_comment/rust_like_syntax/line/URL文字列と同じ行末にコメントがある.rs:1:1 [ found: 説明 ]
         "#,
     );
 }
@@ -293,7 +293,7 @@ fn rust_block_単一行ブロックコメントに日本語がある() {
     expect_comment_violation(
         "rust_like_syntax/block/単一行ブロックコメントに日本語がある.rs",
         r#"
-            コメントを英語にしてください: require_english_comment/rust_like_syntax/block/単一行ブロックコメントに日本語がある.rs:1:1 [ found: 日本語コメント ]
+            [ error ] コメントを英語にしてください: require_english_comment/rust_like_syntax/block/単一行ブロックコメントに日本語がある.rs:1:1 [ found: 日本語コメント ]
         "#,
     );
 }
@@ -303,8 +303,8 @@ fn rust_block_複数行ブロックコメントに日本語がある() {
     expect_comment_violation(
         "rust_like_syntax/block/複数行ブロックコメントに日本語がある.rs",
         r#"
-            コメントを英語にしてください: require_english_comment/rust_like_syntax/block/複数行ブロックコメントに日本語がある.rs:2:1 [ found: * 一行目 ]
-            コメントを英語にしてください: require_english_comment/rust_like_syntax/block/複数行ブロックコメントに日本語がある.rs:3:1 [ found: * 二行目 ]
+            [ error ] コメントを英語にしてください: require_english_comment/rust_like_syntax/block/複数行ブロックコメントに日本語がある.rs:2:1 [ found: * 一行目 ]
+            [ error ] コメントを英語にしてください: require_english_comment/rust_like_syntax/block/複数行ブロックコメントに日本語がある.rs:3:1 [ found: * 二行目 ]
         "#,
     );
 }
@@ -314,7 +314,103 @@ fn rust_block_コード行中のブロックコメントに日本語がある()
     expect_comment_violation(
         "rust_like_syntax/block/コード行の中の単一行ブロックコメントに日本語がある.rs",
         r#"
-            コメントを英語にしてください: require_english_comment/rust_like_syntax/block/コード行の中の単一行ブロックコメントに日本語がある.rs:1:1 [ found: 日本語 ]
+            [ error ] コメントを英語にしてください: require_english_comment/rust_like_syntax/block/コード行の中の単一行ブロックコメントに日本語がある.rs:1:1 [ found: 日本語 ]
+        "#,
+    );
+}
+
+#[test]
+fn custom_by_ext_pyファイルはpyの構文で解析する() {
+    expect_comment_violation(
+        "custom_by_ext/日本語コメント.py",
+        r#"
+            [ error ] コメントを英語にしてください: require_english_comment/custom_by_ext/日本語コメント.py:1:1 [ found: 日本語コメント ]
+        "#,
+    );
+}
+
+#[test]
+fn custom_by_ext_sqlファイルはsqlの構文で解析する() {
+    expect_comment_violation(
+        "custom_by_ext/日本語コメント.sql",
+        r#"
+            [ error ] コメントを英語にしてください: require_english_comment/custom_by_ext/日本語コメント.sql:1:1 [ found: 日本語コメント ]
+        "#,
+    );
+}
+
+#[test]
+fn custom_by_ext_どの拡張子にも一致しないファイルはコメントなしとみなす() {
+    expect_comment_ok("custom_by_ext/対象外の拡張子.txt");
+}
+
+#[test]
+fn rust_line_scripts未指定ならハングルのコメントは違反にならない() {
+    expect_comment_ok("rust_like_syntax/line/ハングルのコメントがある.rs");
+}
+
+// Scripts
+
+#[test]
+fn scripts_koreanを指定するとハングルのコメントは違反になる() {
+    expect_comment_violation(
+        "scripts/ハングルのコメントがある.rs",
+        r#"
+            [ error ] コメントを英語にしてください: require_english_comment/scripts/ハングルのコメントがある.rs:1:1 [ found: 안녕하세요 ]
+        "#,
+    );
+}
+
+#[test]
+fn scripts_chineseを指定すると簡体字のコメントは違反になる() {
+    expect_comment_violation(
+        "scripts/簡体字のコメントがある.rs",
+        r#"
+            [ error ] コメントを英語にしてください: require_english_comment/scripts/簡体字のコメントがある.rs:1:1 [ found: 这是简体中文注释 ]
+        "#,
+    );
+}
+
+#[test]
+fn scripts_指定していない文字種のコメントは違反にならない() {
+    expect_comment_ok("scripts/指定していない文字種のコメントがある.rs");
+}
+
+// Max ratio
+
+#[test]
+fn max_ratio_ほぼ英語のコメントに漢字が1文字あるだけなら違反にならない() {
+    expect_comment_ok("max_ratio/ほぼ英語のコメントに漢字が1文字ある.rs");
+}
+
+#[test]
+fn max_ratio_日本語の割合が閾値を超えるコメントは違反になる() {
+    expect_comment_violation(
+        "max_ratio/日本語の割合が閾値を超えるコメントがある.rs",
+        r#"
+            [ error ] コメントを英語にしてください: require_english_comment/max_ratio/日本語の割合が閾値を超えるコメントがある.rs:1:1 [ found: 東京リージョンに deploy する ]
+        "#,
+    );
+}
+
+// String literals
+
+#[test]
+fn java_line_文字列リテラル内のコメント記号はコメントとみなさない() {
+    expect_comment_violation(
+        "java_like_syntax/line/文字列リテラル内にコメント記号がある.java",
+        r#"
+            [ error ] コメントを英語にしてください: require_english_comment/java_like_syntax/line/文字列リテラル内にコメント記号がある.java:2:1 [ found: 日本語コメント ]
+        "#,
+    );
+}
+
+#[test]
+fn rust_line_文字列リテラルと生文字列リテラル内のコメント記号はコメントとみなさない() {
+    expect_comment_violation(
+        "rust_like_syntax/line/文字列リテラル内にコメント記号がある.rs",
+        r#"
+            [ error ] コメントを英語にしてください: require_english_comment/rust_like_syntax/line/文字列リテラル内にコメント記号がある.rs:7:1 [ found: 日本語コメント ]
         "#,
     );
 }
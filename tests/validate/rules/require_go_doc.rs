@@ -0,0 +1,81 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn validate_case(relative: &str) -> Vec<String> {
+    let file = common::project_file("require_go_doc", relative);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+fn expect_violation(relative: &str, expected: &str) {
+    let result = validate_case(relative);
+    common::assert_output(&result, expected);
+}
+
+#[test]
+fn type_all指定でdocコメントがないstructとinterfaceは違反になる() {
+    expect_violation(
+        "type/case01/all指定_一部の型にdocコメントがない.go",
+        r#"
+            [ error ] docコメントを書いてください: require_go_doc/type/case01/all指定_一部の型にdocコメントがない.go:8:1 [ found: struct Order ]
+            [ error ] docコメントを書いてください: require_go_doc/type/case01/all指定_一部の型にdocコメントがない.go:17:1 [ found: struct cache ]
+            [ error ] docコメントを書いてください: require_go_doc/type/case01/all指定_一部の型にdocコメントがない.go:21:1 [ found: interface reader ]
+        "#,
+    );
+}
+
+#[test]
+fn type_public指定で小文字で始まる型は違反にならず空のコメントはdocコメントとみなさない() {
+    expect_violation(
+        "type/case02/public指定_exportされていない型は対象外.go",
+        r#"
+            [ error ] docコメントを書いてください: require_go_doc/type/case02/public指定_exportされていない型は対象外.go:3:1 [ found: struct Order ]
+            [ error ] docコメントを書いてください: require_go_doc/type/case02/public指定_exportされていない型は対象外.go:12:1 [ found: interface Empty ]
+        "#,
+    );
+}
+
+#[test]
+fn 型名の前に空白がある非asciiの型名も検出する() {
+    expect_violation(
+        "type/case03/型名の前に空白がある非ASCIIの型.go",
+        r#"
+            [ error ] docコメントを書いてください: require_go_doc/type/case03/型名の前に空白がある非ASCIIの型.go:3:1 [ found: struct Äpfel ]
+        "#,
+    );
+}
+
+#[test]
+fn func_all指定でdocコメントがない関数とメソッドは違反になる() {
+    expect_violation(
+        "func/case01/all指定_一部の関数にdocコメントがない.go",
+        r#"
+            [ error ] docコメントを書いてください: require_go_doc/func/case01/all指定_一部の関数にdocコメントがない.go:8:1 [ found: func Stop ]
+            [ error ] docコメントを書いてください: require_go_doc/func/case01/all指定_一部の関数にdocコメントがない.go:16:1 [ found: func close ]
+            [ error ] docコメントを書いてください: require_go_doc/func/case01/all指定_一部の関数にdocコメントがない.go:20:1 [ found: func helper ]
+        "#,
+    );
+}
+
+#[test]
+fn func_public指定でexportされていない関数は違反にならずブロックコメントはdocコメントとみなさない() {
+    expect_violation(
+        "func/case02/public指定_exportされていない関数は対象外.go",
+        r#"
+            [ error ] docコメントを書いてください: require_go_doc/func/case02/public指定_exportされていない関数は対象外.go:3:1 [ found: func Stop ]
+            [ error ] docコメントを書いてください: require_go_doc/func/case02/public指定_exportされていない関数は対象外.go:10:1 [ found: func Run ]
+        "#,
+    );
+}
+
+#[test]
+fn const_public指定でグループかエントリにdocコメントがない定数は違反になる() {
+    expect_violation(
+        "const/case01/public指定_docコメントがない定数は違反.go",
+        r#"
+            [ error ] docコメントを書いてください: require_go_doc/const/case01/public指定_docコメントがない定数は違反.go:6:1 [ found: const MinSize ]
+            [ error ] docコメントを書いてください: require_go_doc/const/case01/public指定_docコメントがない定数は違反.go:19:1 [ found: const High ]
+        "#,
+    );
+}
@@ -0,0 +1,58 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn validate_file(mode: &str, relative: &str) -> Vec<String> {
+    let file = common::project_dir("require_go_test").join(mode).join("pkg").join(relative);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+fn expect_ok(mode: &str, relative: &str) {
+    let result = validate_file(mode, relative);
+    assert!(result.is_empty(), "expected no violations, got {result:?}");
+}
+
+// exists mode tests
+
+#[test]
+fn exists指定のとき同じディレクトリにテストファイルがあればエラーにならない() {
+    expect_ok("file_exists", "user.go");
+}
+
+#[test]
+fn exists指定のときテストファイルがなければエラーになる() {
+    common::assert_output(
+        &validate_file("file_exists", "order.go"),
+        r#"
+            [ error ] テストファイルが必要です: pkg/order.go [ found: テストファイルが存在しません: pkg/order_test.go ]
+        "#,
+    );
+}
+
+#[test]
+fn exists指定でもエクスポートされた関数がないファイルはエラーにならない() {
+    expect_ok("file_exists", "internal.go");
+}
+
+#[test]
+fn テストファイル自体は検証しない() {
+    expect_ok("file_exists", "user_test.go");
+}
+
+// all_public mode tests
+
+#[test]
+fn all_public指定のときサフィックス付きのテスト関数でもテスト済みになる() {
+    expect_ok("all_public", "client.go");
+}
+
+#[test]
+fn all_public指定のときテスト関数がないエクスポート関数とメソッドはエラーになる() {
+    common::assert_output(
+        &validate_file("all_public", "server.go"),
+        r#"
+            [ error ] 全てのエクスポート関数にテストが必要です: pkg/server.go:13:1 [ found: L13: pub 関数 `Stop` がテストされていません (期待するテスト: `TestServer_Stop`) ]
+        "#,
+    );
+}
@@ -37,7 +37,7 @@ fn java_line_コメント行に英語コメントがある() {
     expect_comment_violation(
         "java_like_syntax/line/コメント行に英語コメントがある.java",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/java_like_syntax/line/コメント行に英語コメントがある.java:1:1 [ found: english comment ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/line/コメント行に英語コメントがある.java:1:1 [ found: english comment ]
         "#,
     );
 }
@@ -47,7 +47,7 @@ fn java_line_コード行末に英語コメントがある() {
     expect_comment_violation(
         "java_like_syntax/line/コード行末に英語コメントがある.java",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/java_like_syntax/line/コード行末に英語コメントがある.java:1:1 [ found: description ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/line/コード行末に英語コメントがある.java:1:1 [ found: description ]
         "#,
     );
 }
@@ -57,8 +57,8 @@ fn java_line_複数行に英語コメントがある() {
     expect_comment_violation(
         "java_like_syntax/line/複数行に英語コメントがある.java",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/java_like_syntax/line/複数行に英語コメントがある.java:1:1 [ found: first line ]
-            コメントを日本語にしてください: require_japanese_comment/java_like_syntax/line/複数行に英語コメントがある.java:2:1 [ found: second line ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/line/複数行に英語コメントがある.java:1:1 [ found: first line ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/line/複数行に英語コメントがある.java:2:1 [ found: second line ]
         "#,
     );
 }
@@ -73,7 +73,7 @@ fn java_line_URL文字列と同じ行末にコメントがある() {
     expect_comment_violation(
         "java_like_syntax/line/URL文字列と同じ行末にコメントがある.java",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/java_like_syntax/line/URL文字列と同じ行末にコメントがある.java:1:1 [ found: description ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/line/URL文字列と同じ行末にコメントがある.java:1:1 [ found: description ]
         "#,
     );
 }
@@ -95,7 +95,7 @@ fn java_block_単一行ブロックコメントに英語がある() {
     expect_comment_violation(
         "java_like_syntax/block/単一行ブロックコメントに英語がある.java",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/java_like_syntax/block/単一行ブロックコメントに英語がある.java:1:1 [ found: english comment ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/block/単一行ブロックコメントに英語がある.java:1:1 [ found: english comment ]
         "#,
     );
 }
@@ -105,8 +105,8 @@ fn java_block_複数行ブロックコメントに英語がある() {
     expect_comment_violation(
         "java_like_syntax/block/複数行ブロックコメントに英語がある.java",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/java_like_syntax/block/複数行ブロックコメントに英語がある.java:2:1 [ found: * first line ]
-            コメントを日本語にしてください: require_japanese_comment/java_like_syntax/block/複数行ブロックコメントに英語がある.java:3:1 [ found: * second line ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/block/複数行ブロックコメントに英語がある.java:2:1 [ found: * first line ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/block/複数行ブロックコメントに英語がある.java:3:1 [ found: * second line ]
         "#,
     );
 }
@@ -116,7 +116,7 @@ fn java_block_コード行中のブロックコメントに英語がある() {
     expect_comment_violation(
         "java_like_syntax/block/コード行の中の単一行ブロックコメントに英語がある.java",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/java_like_syntax/block/コード行の中の単一行ブロックコメントに英語がある.java:1:1 [ found: english ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/block/コード行の中の単一行ブロックコメントに英語がある.java:1:1 [ found: english ]
         "#,
     );
 }
@@ -138,7 +138,7 @@ fn python_line_コメント行に英語コメントがある() {
     expect_comment_violation(
         "python_like_syntax/line/コメント行に英語コメントがある.py",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/python_like_syntax/line/コメント行に英語コメントがある.py:1:1 [ found: english comment ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/python_like_syntax/line/コメント行に英語コメントがある.py:1:1 [ found: english comment ]
         "#,
     );
 }
@@ -148,7 +148,7 @@ fn python_line_コード行末に英語コメントがある() {
     expect_comment_violation(
         "python_like_syntax/line/コード行末に英語コメントがある.py",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/python_like_syntax/line/コード行末に英語コメントがある.py:1:1 [ found: description ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/python_like_syntax/line/コード行末に英語コメントがある.py:1:1 [ found: description ]
         "#,
     );
 }
@@ -158,8 +158,8 @@ fn python_line_複数行に英語コメントがある() {
     expect_comment_violation(
         "python_like_syntax/line/複数行に英語コメントがある.py",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/python_like_syntax/line/複数行に英語コメントがある.py:1:1 [ found: first line ]
-            コメントを日本語にしてください: require_japanese_comment/python_like_syntax/line/複数行に英語コメントがある.py:2:1 [ found: second line ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/python_like_syntax/line/複数行に英語コメントがある.py:1:1 [ found: first line ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/python_like_syntax/line/複数行に英語コメントがある.py:2:1 [ found: second line ]
         "#,
     );
 }
@@ -174,7 +174,7 @@ fn python_line_URL文字列と同じ行末にコメントがある() {
     expect_comment_violation(
         "python_like_syntax/line/URL文字列と同じ行末にコメントがある.py",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/python_like_syntax/line/URL文字列と同じ行末にコメントがある.py:1:1 [ found: description ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/python_like_syntax/line/URL文字列と同じ行末にコメントがある.py:1:1 [ found: description ]
         "#,
     );
 }
@@ -196,7 +196,7 @@ fn python_block_単一行ブロックコメントに英語がある() {
     expect_comment_violation(
         "python_like_syntax/block/単一行ブロックコメントに英語がある.py",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/python_like_syntax/block/単一行ブロックコメントに英語がある.py:1:1 [ found: english ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/python_like_syntax/block/単一行ブロックコメントに英語がある.py:1:1 [ found: english ]
         "#,
     );
 }
@@ -206,8 +206,8 @@ fn python_block_複数行ブロックコメントに英語がある() {
     expect_comment_violation(
         "python_like_syntax/block/複数行ブロックコメントに英語がある.py",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/python_like_syntax/block/複数行ブロックコメントに英語がある.py:2:1 [ found: first line ]
-            コメントを日本語にしてください: require_japanese_comment/python_like_syntax/block/複数行ブロックコメントに英語がある.py:3:1 [ found: second line ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/python_like_syntax/block/複数行ブロックコメントに英語がある.py:2:1 [ found: first line ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/python_like_syntax/block/複数行ブロックコメントに英語がある.py:3:1 [ found: second line ]
         "#,
     );
 }
@@ -217,7 +217,7 @@ fn python_block_コード行中のブロックコメントに英語がある() {
     expect_comment_violation(
         "python_like_syntax/block/コード行の中の単一行ブロックコメントに英語がある.py",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/python_like_syntax/block/コード行の中の単一行ブロックコメントに英語がある.py:2:1 [ found: english ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/python_like_syntax/block/コード行の中の単一行ブロックコメントに英語がある.py:2:1 [ found: english ]
         "#,
     );
 }
@@ -239,7 +239,7 @@ fn rust_line_コメント行に英語コメントがある() {
     expect_comment_violation(
         "rust_like_syntax/line/コメント行に英語コメントがある.rs",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/line/コメント行に英語コメントがある.rs:1:1 [ found: english comment ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/line/コメント行に英語コメントがある.rs:1:1 [ found: english comment ]
         "#,
     );
 }
@@ -249,7 +249,7 @@ fn rust_line_コード行末に英語コメントがある() {
     expect_comment_violation(
         "rust_like_syntax/line/コード行末に英語コメントがある.rs",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/line/コード行末に英語コメントがある.rs:1:1 [ found: description ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/line/コード行末に英語コメントがある.rs:1:1 [ found: description ]
         "#,
     );
 }
@@ -259,8 +259,8 @@ fn rust_line_複数行に英語コメントがある() {
     expect_comment_violation(
         "rust_like_syntax/line/複数行に英語コメントがある.rs",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/line/複数行に英語コメントがある.rs:1:1 [ found: first line ]
-            コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/line/複数行に英語コメントがある.rs:2:1 [ found: second line ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/line/複数行に英語コメントがある.rs:1:1 [ found: first line ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/line/複数行に英語コメントがある.rs:2:1 [ found: second line ]
         "#,
     );
 }
@@ -275,7 +275,7 @@ fn rust_line_URL文字列と同じ行末にコメントがある() {
     expect_comment_violation(
         "rust_like_syntax/line/URL文字列と同じ行末にコメントがある.rs",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/line/URL文字列と同じ行末にコメントがある.rs:1:1 [ found: description ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/line/URL文字列と同じ行末にコメントがある.rs:1:1 [ found: description ]
         "#,
     );
 }
@@ -297,7 +297,7 @@ fn rust_block_単一行ブロックコメントに英語がある() {
     expect_comment_violation(
         "rust_like_syntax/block/単一行ブロックコメントに英語がある.rs",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/block/単一行ブロックコメントに英語がある.rs:1:1 [ found: english comment ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/block/単一行ブロックコメントに英語がある.rs:1:1 [ found: english comment ]
         "#,
     );
 }
@@ -307,8 +307,8 @@ fn rust_block_複数行ブロックコメントに英語がある() {
     expect_comment_violation(
         "rust_like_syntax/block/複数行ブロックコメントに英語がある.rs",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/block/複数行ブロックコメントに英語がある.rs:2:1 [ found: * first line ]
-            コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/block/複数行ブロックコメントに英語がある.rs:3:1 [ found: * second line ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/block/複数行ブロックコメントに英語がある.rs:2:1 [ found: * first line ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/block/複数行ブロックコメントに英語がある.rs:3:1 [ found: * second line ]
         "#,
     );
 }
@@ -318,7 +318,34 @@ fn rust_block_コード行中のブロックコメントに英語がある() {
     expect_comment_violation(
         "rust_like_syntax/block/コード行の中の単一行ブロックコメントに英語がある.rs",
         r#"
-            コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/block/コード行の中の単一行ブロックコメントに英語がある.rs:1:1 [ found: english ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/rust_like_syntax/block/コード行の中の単一行ブロックコメントに英語がある.rs:1:1 [ found: english ]
+        "#,
+    );
+}
+
+#[test]
+fn java_block_ドキュメントコメントのコードブロックも検査する() {
+    expect_comment_violation(
+        "java_like_syntax/block/ドキュメントコメントにコードブロックがある.java",
+        r#"
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/block/ドキュメントコメントにコードブロックがある.java:3:1 [ found: * ```java ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/block/ドキュメントコメントにコードブロックがある.java:4:1 [ found: * int total = sum(1, 2); // returns 3 ]
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/block/ドキュメントコメントにコードブロックがある.java:5:1 [ found: * ``` ]
+        "#,
+    );
+}
+
+#[test]
+fn java_skip_code_blocks_コードブロックを検査しない() {
+    expect_comment_ok("java_like_syntax/doc_code_block/コードブロックがある.java");
+}
+
+#[test]
+fn java_skip_code_blocks_コードブロックの外は検査する() {
+    expect_comment_violation(
+        "java_like_syntax/doc_code_block/コードブロックの外に英語がある.java",
+        r#"
+            [ error ] コメントを日本語にしてください: require_japanese_comment/java_like_syntax/doc_code_block/コードブロックの外に英語がある.java:6:1 [ found: * returns the total ]
         "#,
     );
 }
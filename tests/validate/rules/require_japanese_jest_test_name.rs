@@ -0,0 +1,26 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn validate_case(relative: &str) -> Vec<String> {
+    let file = common::project_file("require_japanese_jest_test_name", relative);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+#[test]
+fn 日本語テスト名は違反にならない() {
+    assert!(validate_case("日本語テスト名.test.ts").is_empty());
+}
+
+#[test]
+fn 英語テスト名はit_test_describeの行で違反になる() {
+    common::assert_output(
+        &validate_case("英語テスト名.test.ts"),
+        r#"
+            [ error ] テスト名を日本語にしてください: require_japanese_jest_test_name/英語テスト名.test.ts:1:1 [ found: createUser ]
+            [ error ] テスト名を日本語にしてください: require_japanese_jest_test_name/英語テスト名.test.ts:2:1 [ found: throws when the name is empty ]
+            [ error ] テスト名を日本語にしてください: require_japanese_jest_test_name/英語テスト名.test.ts:6:1 [ found: returns the user's ${'id'} ]
+        "#,
+    );
+}
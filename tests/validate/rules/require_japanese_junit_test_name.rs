@@ -0,0 +1,47 @@
+#![allow(non_snake_case)]
+
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn validate_case(relative: &str) -> Vec<String> {
+    let file = common::project_file("require_japanese_junit_test_name", relative);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+fn expect_ok(relative: &str) {
+    let result = validate_case(relative);
+    assert!(result.is_empty(), "expected no violations for {relative}, got {result:?}");
+}
+
+fn expect_violation(relative: &str, expected: &str) {
+    let result = validate_case(relative);
+    common::assert_output(&result, expected);
+}
+
+#[test]
+fn DisplayNameが日本語の場合は違反にならない() {
+    expect_ok("DisplayName日本語.java");
+}
+
+#[test]
+fn DisplayNameが英語の場合は違反になる() {
+    expect_violation(
+        "DisplayName英語.java",
+        r#"
+            [ error ] テスト名を日本語にしてください: require_japanese_junit_test_name/DisplayName英語.java:1:1 [ found: User test ]
+            [ error ] テスト名を日本語にしてください: require_japanese_junit_test_name/DisplayName英語.java:4:1 [ found: creates user ]
+        "#,
+    );
+}
+
+#[test]
+fn DisplayNameがない場合はメソッド名を検証する() {
+    expect_violation(
+        "DisplayNameなし.java",
+        r#"
+            [ error ] テスト名を日本語にしてください: require_japanese_junit_test_name/DisplayNameなし.java:6:1 [ found: createUser ]
+        "#,
+    );
+}
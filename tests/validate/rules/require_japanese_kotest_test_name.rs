@@ -30,8 +30,8 @@ fn 英語テスト名は違反になる() {
     expect_violation(
         "英語テスト名.kt",
         r#"
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/英語テスト名.kt:2:1 [ found: should create user ]
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/英語テスト名.kt:5:1 [ found: should validate email ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/英語テスト名.kt:2:1 [ found: should create user ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/英語テスト名.kt:5:1 [ found: should validate email ]
         "#,
     );
 }
@@ -46,8 +46,8 @@ fn describeパターンで英語は違反になる() {
     expect_violation(
         "describeパターン英語.kt",
         r#"
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/describeパターン英語.kt:2:1 [ found: UserService ]
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/describeパターン英語.kt:3:1 [ found: should create user ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/describeパターン英語.kt:2:1 [ found: UserService ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/describeパターン英語.kt:3:1 [ found: should create user ]
         "#,
     );
 }
@@ -62,9 +62,9 @@ fn behaviorパターンで英語は違反になる() {
     expect_violation(
         "behaviorパターン英語.kt",
         r#"
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/behaviorパターン英語.kt:2:1 [ found: a logged in user ]
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/behaviorパターン英語.kt:3:1 [ found: updating profile ]
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/behaviorパターン英語.kt:4:1 [ found: should succeed ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/behaviorパターン英語.kt:2:1 [ found: a logged in user ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/behaviorパターン英語.kt:3:1 [ found: updating profile ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/behaviorパターン英語.kt:4:1 [ found: should succeed ]
         "#,
     );
 }
@@ -74,7 +74,7 @@ fn 日本語と英語が混在する場合は英語のみ違反になる() {
     expect_violation(
         "混在.kt",
         r#"
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/混在.kt:5:1 [ found: should validate email ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/混在.kt:5:1 [ found: should validate email ]
         "#,
     );
 }
@@ -89,8 +89,8 @@ fn contextパターンで英語は違反になる() {
     expect_violation(
         "contextパターン英語.kt",
         r#"
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/contextパターン英語.kt:1:1 [ found: User registration ]
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/contextパターン英語.kt:2:1 [ found: should create user ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/contextパターン英語.kt:1:1 [ found: User registration ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/contextパターン英語.kt:2:1 [ found: should create user ]
         "#,
     );
 }
@@ -116,8 +116,8 @@ fn given_when_thenパターンで英語は違反になる() {
     expect_violation(
         "given_when_thenパターン英語.kt",
         r#"
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/given_when_thenパターン英語.kt:1:1 [ found: a valid user ]
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/given_when_thenパターン英語.kt:3:1 [ found: user should be created ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/given_when_thenパターン英語.kt:1:1 [ found: a valid user ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/given_when_thenパターン英語.kt:3:1 [ found: user should be created ]
         "#,
     );
 }
@@ -127,9 +127,9 @@ fn 複数のDSLパターンを同時に検出できる() {
     expect_violation(
         "複数のDSLパターン.kt",
         r#"
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/複数のDSLパターン.kt:1:1 [ found: User service ]
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/複数のDSLパターン.kt:2:1 [ found: when creating user ]
-            テスト名を日本語にしてください: require_japanese_kotest_test_name/複数のDSLパターン.kt:3:1 [ found: should succeed ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/複数のDSLパターン.kt:1:1 [ found: User service ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/複数のDSLパターン.kt:2:1 [ found: when creating user ]
+            [ error ] テスト名を日本語にしてください: require_japanese_kotest_test_name/複数のDSLパターン.kt:3:1 [ found: should succeed ]
         "#,
     );
 }
@@ -30,8 +30,8 @@ fn 英語テスト名は違反になる() {
     expect_violation(
         "英語テスト名.php",
         r#"
-            テスト名を日本語にしてください: require_japanese_phpunit_test_name/英語テスト名.php:5:1 [ found: testCreateUser ]
-            テスト名を日本語にしてください: require_japanese_phpunit_test_name/英語テスト名.php:9:1 [ found: testValidateEmail ]
+            [ error ] テスト名を日本語にしてください: require_japanese_phpunit_test_name/英語テスト名.php:5:1 [ found: testCreateUser ]
+            [ error ] テスト名を日本語にしてください: require_japanese_phpunit_test_name/英語テスト名.php:9:1 [ found: testValidateEmail ]
         "#,
     );
 }
@@ -46,7 +46,7 @@ fn アノテーションで英語テスト名は違反になる() {
     expect_violation(
         "アノテーションで英語.php",
         r#"
-            テスト名を日本語にしてください: require_japanese_phpunit_test_name/アノテーションで英語.php:5:1 [ found: shouldCreateUser ]
+            [ error ] テスト名を日本語にしてください: require_japanese_phpunit_test_name/アノテーションで英語.php:5:1 [ found: shouldCreateUser ]
         "#,
     );
 }
@@ -61,7 +61,7 @@ fn アトリビュートで英語テスト名は違反になる() {
     expect_violation(
         "アトリビュートで英語.php",
         r#"
-            テスト名を日本語にしてください: require_japanese_phpunit_test_name/アトリビュートで英語.php:5:1 [ found: shouldCreateUser ]
+            [ error ] テスト名を日本語にしてください: require_japanese_phpunit_test_name/アトリビュートで英語.php:5:1 [ found: shouldCreateUser ]
         "#,
     );
 }
@@ -76,7 +76,7 @@ fn プライベートメソッドでもtestプレフィックスがあれば検
     expect_violation(
         "プライベートメソッドでtestプレフィックス.php",
         r#"
-            テスト名を日本語にしてください: require_japanese_phpunit_test_name/プライベートメソッドでtestプレフィックス.php:3:1 [ found: testPrivateMethod ]
+            [ error ] テスト名を日本語にしてください: require_japanese_phpunit_test_name/プライベートメソッドでtestプレフィックス.php:3:1 [ found: testPrivateMethod ]
         "#,
     );
 }
@@ -86,7 +86,7 @@ fn 単一行PHPDocのtestアノテーションを検出できる() {
     expect_violation(
         "単一行PHPDocアノテーション.php",
         r#"
-            テスト名を日本語にしてください: require_japanese_phpunit_test_name/単一行PHPDocアノテーション.php:3:1 [ found: shouldCreateUser ]
+            [ error ] テスト名を日本語にしてください: require_japanese_phpunit_test_name/単一行PHPDocアノテーション.php:3:1 [ found: shouldCreateUser ]
         "#,
     );
 }
@@ -96,7 +96,7 @@ fn 複数のアトリビュートがある場合も検出できる() {
     expect_violation(
         "複数のアトリビュート.php",
         r#"
-            テスト名を日本語にしてください: require_japanese_phpunit_test_name/複数のアトリビュート.php:3:1 [ found: shouldCreateUser ]
+            [ error ] テスト名を日本語にしてください: require_japanese_phpunit_test_name/複数のアトリビュート.php:3:1 [ found: shouldCreateUser ]
         "#,
     );
 }
@@ -0,0 +1,30 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn validate_case(relative: &str) -> Vec<String> {
+    let file = common::project_file("require_japanese_python_test_name", relative);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+#[test]
+fn 日本語テスト名は違反にならない() {
+    assert!(validate_case("日本語テスト名.py").is_empty());
+}
+
+#[test]
+fn 英語テスト名は違反になる() {
+    common::assert_output(
+        &validate_case("英語テスト名.py"),
+        r#"
+            [ error ] テスト名を日本語にしてください: require_japanese_python_test_name/英語テスト名.py:1:1 [ found: test_happy_path ]
+            [ error ] テスト名を日本語にしてください: require_japanese_python_test_name/英語テスト名.py:9:1 [ found: test_delete_user ]
+        "#,
+    );
+}
+
+#[test]
+fn テストではない関数は検出されない() {
+    assert!(validate_case("テストではない関数.py").is_empty());
+}
@@ -28,8 +28,8 @@ fn 英語テスト名は違反になる() {
     expect_violation(
         "英語テスト名.rs",
         r#"
-            テスト名を日本語にしてください: require_japanese_rust_test_name/英語テスト名.rs:1:1 [ found: test_create_user ]
-            テスト名を日本語にしてください: require_japanese_rust_test_name/英語テスト名.rs:5:1 [ found: test_validate_email ]
+            [ error ] テスト名を日本語にしてください: require_japanese_rust_test_name/英語テスト名.rs:1:1 [ found: test_create_user ]
+            [ error ] テスト名を日本語にしてください: require_japanese_rust_test_name/英語テスト名.rs:5:1 [ found: test_validate_email ]
         "#,
     );
 }
@@ -44,7 +44,7 @@ fn tokio_testで英語は違反になる() {
     expect_violation(
         "tokio_test英語.rs",
         r#"
-            テスト名を日本語にしてください: require_japanese_rust_test_name/tokio_test英語.rs:1:1 [ found: test_async_create_user ]
+            [ error ] テスト名を日本語にしてください: require_japanese_rust_test_name/tokio_test英語.rs:1:1 [ found: test_async_create_user ]
         "#,
     );
 }
@@ -59,7 +59,7 @@ fn 複数属性で英語は違反になる() {
     expect_violation(
         "複数属性英語.rs",
         r#"
-            テスト名を日本語にしてください: require_japanese_rust_test_name/複数属性英語.rs:1:1 [ found: test_should_panic ]
+            [ error ] テスト名を日本語にしてください: require_japanese_rust_test_name/複数属性英語.rs:1:1 [ found: test_should_panic ]
         "#,
     );
 }
@@ -74,7 +74,7 @@ fn 日本語と英語が混在する場合は英語のみ違反になる() {
     expect_violation(
         "混在.rs",
         r#"
-            テスト名を日本語にしてください: require_japanese_rust_test_name/混在.rs:5:1 [ found: test_validate_email ]
+            [ error ] テスト名を日本語にしてください: require_japanese_rust_test_name/混在.rs:5:1 [ found: test_validate_email ]
         "#,
     );
 }
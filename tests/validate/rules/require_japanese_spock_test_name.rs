@@ -0,0 +1,37 @@
+#![allow(non_snake_case)]
+
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn validate_case(relative: &str) -> Vec<String> {
+    let file = common::project_file("require_japanese_spock_test_name", relative);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+fn expect_ok(relative: &str) {
+    let result = validate_case(relative);
+    assert!(result.is_empty(), "expected no violations for {relative}, got {result:?}");
+}
+
+fn expect_violation(relative: &str, expected: &str) {
+    let result = validate_case(relative);
+    common::assert_output(&result, expected);
+}
+
+#[test]
+fn 日本語のフィーチャーメソッド名は違反にならない() {
+    expect_ok("日本語テスト名.groovy");
+}
+
+#[test]
+fn 英語のフィーチャーメソッド名は違反になる() {
+    expect_violation(
+        "英語テスト名.groovy",
+        r#"
+            [ error ] テスト名を日本語にしてください: require_japanese_spock_test_name/英語テスト名.groovy:2:1 [ found: creates user ]
+            [ error ] テスト名を日本語にしてください: require_japanese_spock_test_name/英語テスト名.groovy:7:1 [ found: validates email ]
+        "#,
+    );
+}
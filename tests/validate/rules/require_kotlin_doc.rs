@@ -29,7 +29,7 @@ fn class_all指定ですべてのclassにドキュメントがあると違反に
 fn class_all指定で一部のclassにドキュメントがないと違反になる() {
     expect_violation(
         "class/case02/all指定_一部のclassにしかドキュメントがない.kt",
-        "KDocを書いてください: require_kotlin_doc/class/case02/all指定_一部のclassにしかドキュメントがない.kt:6:1 [ found: class MissingDocClass ]",
+        "[ error ] KDocを書いてください: require_kotlin_doc/class/case02/all指定_一部のclassにしかドキュメントがない.kt:6:1 [ found: class MissingDocClass ]",
     );
 }
 
@@ -44,7 +44,7 @@ fn interface_all指定ですべてのinterfaceにドキュメントがあると
 fn interface_all指定で一部のinterfaceにドキュメントがないと違反になる() {
     expect_violation(
         "interface/case02/all指定_一部のinterfaceにしかドキュメントがない.kt",
-        "KDocを書いてください: require_kotlin_doc/interface/case02/all指定_一部のinterfaceにしかドキュメントがない.kt:6:1 [ found: interface MissingDocInterface ]",
+        "[ error ] KDocを書いてください: require_kotlin_doc/interface/case02/all指定_一部のinterfaceにしかドキュメントがない.kt:6:1 [ found: interface MissingDocInterface ]",
     );
 }
 
@@ -59,7 +59,7 @@ fn function_all指定ですべてのfunctionにドキュメントがあると違
 fn function_all指定で一部のfunctionにドキュメントがないと違反になる() {
     expect_violation(
         "function/case02/all指定_一部のfunctionにしかドキュメントがない.kt",
-        "KDocを書いてください: require_kotlin_doc/function/case02/all指定_一部のfunctionにしかドキュメントがない.kt:6:1 [ found: function missingDocFunction ]",
+        "[ error ] KDocを書いてください: require_kotlin_doc/function/case02/all指定_一部のfunctionにしかドキュメントがない.kt:6:1 [ found: function missingDocFunction ]",
     );
 }
 
@@ -75,9 +75,41 @@ fn all指定で複数種類にドキュメントがないと違反になる() {
     expect_violation(
         "all/case02/all指定_複数種類にドキュメントがない.kt",
         r#"
-            KDocを書いてください: require_kotlin_doc/all/case02/all指定_複数種類にドキュメントがない.kt:1:1 [ found: class MissingClass ]
-            KDocを書いてください: require_kotlin_doc/all/case02/all指定_複数種類にドキュメントがない.kt:3:1 [ found: interface MissingInterface ]
-            KDocを書いてください: require_kotlin_doc/all/case02/all指定_複数種類にドキュメントがない.kt:5:1 [ found: function missingFunction ]
+            [ error ] KDocを書いてください: require_kotlin_doc/all/case02/all指定_複数種類にドキュメントがない.kt:1:1 [ found: class MissingClass ]
+            [ error ] KDocを書いてください: require_kotlin_doc/all/case02/all指定_複数種類にドキュメントがない.kt:3:1 [ found: interface MissingInterface ]
+            [ error ] KDocを書いてください: require_kotlin_doc/all/case02/all指定_複数種類にドキュメントがない.kt:5:1 [ found: function missingFunction ]
+        "#,
+    );
+}
+
+#[test]
+fn require_non_empty指定で空や空白だけのドキュメントは違反になる() {
+    expect_violation(
+        "function/case03/require_non_empty指定_空のドキュメントは違反になる.kt",
+        r#"
+            [ error ] KDocを書いてください: require_kotlin_doc/function/case03/require_non_empty指定_空のドキュメントは違反になる.kt:2:1 [ found: function emptyDoc ]
+            [ error ] KDocを書いてください: require_kotlin_doc/function/case03/require_non_empty指定_空のドキュメントは違反になる.kt:7:1 [ found: function whitespaceOnlyDoc ]
+        "#,
+    );
+}
+
+#[test]
+fn property指定でドキュメントのないメンバーのプロパティだけ違反になる() {
+    expect_violation(
+        "property/case01/public指定_ドキュメントのないプロパティは違反になる.kt",
+        r#"
+            [ error ] KDocを書いてください: require_kotlin_doc/property/case01/public指定_ドキュメントのないプロパティは違反になる.kt:4:1 [ found: property missingTopLevel ]
+            [ error ] KDocを書いてください: require_kotlin_doc/property/case01/public指定_ドキュメントのないプロパティは違反になる.kt:18:1 [ found: property missingMember ]
+        "#,
+    );
+}
+
+#[test]
+fn include_companion指定でcompanion_objectのプロパティも違反になる() {
+    expect_violation(
+        "property/case02/include_companion指定_companion_objectのプロパティも検証する.kt",
+        r#"
+            [ error ] KDocを書いてください: require_kotlin_doc/property/case02/include_companion指定_companion_objectのプロパティも検証する.kt:7:1 [ found: property missingCompanionValue ]
         "#,
     );
 }
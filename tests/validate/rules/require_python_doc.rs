@@ -0,0 +1,85 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn validate_case(relative: &str) -> Vec<String> {
+    let file = common::project_file("require_python_doc", relative);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+fn expect_ok(relative: &str) {
+    let result = validate_case(relative);
+    assert!(result.is_empty(), "expected no violations for {relative}, got {result:?}");
+}
+
+fn expect_violation(relative: &str, expected: &str) {
+    let result = validate_case(relative);
+    common::assert_output(&result, expected);
+}
+
+#[test]
+fn class_all指定でdocstringがないclassは違反になる() {
+    expect_violation(
+        "class/case01/all指定_一部のclassにdocstringがない.py",
+        r#"
+            [ error ] docstringを書いてください: require_python_doc/class/case01/all指定_一部のclassにdocstringがない.py:5:1 [ found: class MissingDoc ]
+        "#,
+    );
+}
+
+#[test]
+fn class_public指定でアンダースコアで始まるclassは違反にならない() {
+    expect_violation(
+        "class/case02/public指定_アンダースコアで始まるclassは対象外.py",
+        r#"
+            [ error ] docstringを書いてください: require_python_doc/class/case02/public指定_アンダースコアで始まるclassは対象外.py:5:1 [ found: class Public ]
+        "#,
+    );
+}
+
+#[test]
+fn function_all指定でdocstringがないdefは違反になる() {
+    expect_violation(
+        "function/case01/all指定_一部のdefにdocstringがない.py",
+        r#"
+            [ error ] docstringを書いてください: require_python_doc/function/case01/all指定_一部のdefにdocstringがない.py:13:1 [ found: function missing ]
+            [ error ] docstringを書いてください: require_python_doc/function/case01/all指定_一部のdefにdocstringがない.py:23:1 [ found: function _helper ]
+        "#,
+    );
+}
+
+#[test]
+fn function_public指定でアンダースコアで始まるdefは違反にならない() {
+    expect_violation(
+        "function/case02/public指定_アンダースコアで始まるdefは対象外.py",
+        r#"
+            [ error ] docstringを書いてください: require_python_doc/function/case02/public指定_アンダースコアで始まるdefは対象外.py:5:1 [ found: function public ]
+        "#,
+    );
+}
+
+#[test]
+fn module指定でモジュールdocstringがあると違反にならない() {
+    expect_ok("module/case01/docstringあり.py");
+}
+
+#[test]
+fn module指定でモジュールdocstringがないと違反になる() {
+    expect_violation(
+        "module/case02/docstringなし.py",
+        r#"
+            [ error ] docstringを書いてください: require_python_doc/module/case02/docstringなし.py:1:1 [ found: module docstringなし ]
+        "#,
+    );
+}
+
+#[test]
+fn top_level_only指定で入れ子のdefは違反にならない() {
+    expect_violation(
+        "function/case03/top_level_only指定_入れ子のdefは検査しない.py",
+        r#"
+            [ error ] docstringを書いてください: require_python_doc/function/case03/top_level_only指定_入れ子のdefは検査しない.py:1:1 [ found: function outer ]
+        "#,
+    );
+}
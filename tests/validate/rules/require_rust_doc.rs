@@ -28,8 +28,8 @@ fn struct_all指定で一部のstructにドキュメントがないと違反に
     expect_violation(
         "struct/case02/all指定_一部のstructにしかドキュメントがない.rs",
         r#"
-            RustDocを書いてください: require_rust_doc/struct/case02/all指定_一部のstructにしかドキュメントがない.rs:4:1 [ found: struct MissingDoc ]
-            RustDocを書いてください: require_rust_doc/struct/case02/all指定_一部のstructにしかドキュメントがない.rs:6:1 [ found: struct PrivateStruct ]
+            [ error ] RustDocを書いてください: require_rust_doc/struct/case02/all指定_一部のstructにしかドキュメントがない.rs:4:1 [ found: struct MissingDoc ]
+            [ error ] RustDocを書いてください: require_rust_doc/struct/case02/all指定_一部のstructにしかドキュメントがない.rs:6:1 [ found: struct PrivateStruct ]
         "#,
     );
 }
@@ -44,7 +44,7 @@ fn struct_public指定ですべてのpublicなstructにドキュメントがな
     expect_violation(
         "struct/case04/public指定_すべてのpublicなstructにはドキュメントがない.rs",
         r#"
-            RustDocを書いてください: require_rust_doc/struct/case04/public指定_すべてのpublicなstructにはドキュメントがない.rs:1:1 [ found: struct MissingPublicDoc ]
+            [ error ] RustDocを書いてください: require_rust_doc/struct/case04/public指定_すべてのpublicなstructにはドキュメントがない.rs:1:1 [ found: struct MissingPublicDoc ]
         "#,
     );
 }
@@ -59,7 +59,7 @@ fn enum_public指定ですべてのpublicなenumにドキュメントがない
     expect_violation(
         "enum/case02/public指定_すべてのpublicなenumにはドキュメントがない.rs",
         r#"
-            RustDocを書いてください: require_rust_doc/enum/case02/public指定_すべてのpublicなenumにはドキュメントがない.rs:1:1 [ found: enum Missing ]
+            [ error ] RustDocを書いてください: require_rust_doc/enum/case02/public指定_すべてのpublicなenumにはドキュメントがない.rs:1:1 [ found: enum Missing ]
         "#,
     );
 }
@@ -74,7 +74,7 @@ fn trait_public指定ですべてのpublicなtraitにドキュメントがない
     expect_violation(
         "trait/case02/public指定_すべてのpublicなtraitにはドキュメントがない.rs",
         r#"
-            RustDocを書いてください: require_rust_doc/trait/case02/public指定_すべてのpublicなtraitにはドキュメントがない.rs:1:1 [ found: trait MissingTrait ]
+            [ error ] RustDocを書いてください: require_rust_doc/trait/case02/public指定_すべてのpublicなtraitにはドキュメントがない.rs:1:1 [ found: trait MissingTrait ]
         "#,
     );
 }
@@ -89,7 +89,7 @@ fn typealias_public指定ですべてのpublicなtypealiasにドキュメント
     expect_violation(
         "type_alias/case02/public指定_すべてのpublicなtypealiasにはドキュメントがない.rs",
         r#"
-            RustDocを書いてください: require_rust_doc/type_alias/case02/public指定_すべてのpublicなtypealiasにはドキュメントがない.rs:1:1 [ found: type MissingAlias ]
+            [ error ] RustDocを書いてください: require_rust_doc/type_alias/case02/public指定_すべてのpublicなtypealiasにはドキュメントがない.rs:1:1 [ found: type MissingAlias ]
         "#,
     );
 }
@@ -104,7 +104,7 @@ fn union_public指定ですべてのpublicなunionにドキュメントがない
     expect_violation(
         "union/case02/public指定_すべてのpublicなunionにはドキュメントがない.rs",
         r#"
-            RustDocを書いてください: require_rust_doc/union/case02/public指定_すべてのpublicなunionにはドキュメントがない.rs:1:1 [ found: union MissingUnion ]
+            [ error ] RustDocを書いてください: require_rust_doc/union/case02/public指定_すべてのpublicなunionにはドキュメントがない.rs:1:1 [ found: union MissingUnion ]
         "#,
     );
 }
@@ -119,7 +119,7 @@ fn fn_public指定ですべてのpublicな関数にドキュメントがない
     expect_violation(
         "fn/case02/public指定_すべてのpublicなfnにはドキュメントがない.rs",
         r#"
-            RustDocを書いてください: require_rust_doc/fn/case02/public指定_すべてのpublicなfnにはドキュメントがない.rs:1:1 [ found: fn missing ]
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case02/public指定_すべてのpublicなfnにはドキュメントがない.rs:1:1 [ found: fn missing ]
         "#,
     );
 }
@@ -129,9 +129,84 @@ fn fn_public指定でpub_crate_super_in_pathにドキュメントがないと違
     expect_violation(
         "fn/case03/public指定_pub_crate_super_in_pathにドキュメントがないと違反になる.rs",
         r#"
-            RustDocを書いてください: require_rust_doc/fn/case03/public指定_pub_crate_super_in_pathにドキュメントがないと違反になる.rs:1:1 [ found: fn missing_crate ]
-            RustDocを書いてください: require_rust_doc/fn/case03/public指定_pub_crate_super_in_pathにドキュメントがないと違反になる.rs:3:1 [ found: fn missing_super ]
-            RustDocを書いてください: require_rust_doc/fn/case03/public指定_pub_crate_super_in_pathにドキュメントがないと違反になる.rs:5:1 [ found: fn missing_in_path ]
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case03/public指定_pub_crate_super_in_pathにドキュメントがないと違反になる.rs:1:1 [ found: fn missing_crate ]
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case03/public指定_pub_crate_super_in_pathにドキュメントがないと違反になる.rs:3:1 [ found: fn missing_super ]
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case03/public指定_pub_crate_super_in_pathにドキュメントがないと違反になる.rs:5:1 [ found: fn missing_in_path ]
+        "#,
+    );
+}
+
+#[test]
+fn fn_public指定で複数行のシグネチャでもドキュメントがないものだけ違反になる() {
+    expect_violation(
+        "fn/case04/public指定_複数行のシグネチャでもドキュメントの有無を判定する.rs",
+        r#"
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case04/public指定_複数行のシグネチャでもドキュメントの有無を判定する.rs:1:1 [ found: fn missing_multiline ]
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case04/public指定_複数行のシグネチャでもドキュメントの有無を判定する.rs:22:1 [ found: fn missing_where ]
+        "#,
+    );
+}
+
+#[test]
+fn fn_public指定でimpl_fn省略時はimplブロック内のpublicなメソッドも違反になる() {
+    expect_violation(
+        "fn/case05/public指定_impl_fn省略時はimplブロック内のメソッドも検出する.rs",
+        r#"
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case05/public指定_impl_fn省略時はimplブロック内のメソッドも検出する.rs:1:1 [ found: fn free_function ]
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case05/public指定_impl_fn省略時はimplブロック内のメソッドも検出する.rs:14:1 [ found: fn missing ]
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case05/public指定_impl_fn省略時はimplブロック内のメソッドも検出する.rs:29:1 [ found: fn another_free_function ]
+        "#,
+    );
+}
+
+#[test]
+fn fn_exported指定でpub_crate_super_in_pathとprivateは違反にならない() {
+    expect_violation(
+        "fn/case06/exported指定_pubのみを検出する.rs",
+        r#"
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case06/exported指定_pubのみを検出する.rs:1:1 [ found: fn missing_pub ]
+        "#,
+    );
+}
+
+#[test]
+fn impl_fn_public指定でimplブロック内のpublicなメソッドにドキュメントがないと違反になる() {
+    expect_violation(
+        "impl_fn/case01/public指定_implブロック内のメソッドだけを検出する.rs",
+        r#"
+            [ error ] RustDocを書いてください: require_rust_doc/impl_fn/case01/public指定_implブロック内のメソッドだけを検出する.rs:14:1 [ found: fn missing ]
+        "#,
+    );
+}
+
+#[test]
+fn impl_fn指定時は文字列や文字リテラル内の波括弧でimplブロックの判定がずれない() {
+    expect_violation(
+        "impl_fn/case02/リテラル内の波括弧はimplブロックの判定に影響しない.rs",
+        r#"
+            [ error ] RustDocを書いてください: require_rust_doc/impl_fn/case02/リテラル内の波括弧はimplブロックの判定に影響しない.rs:16:1 [ found: fn missing ]
+        "#,
+    );
+}
+
+#[test]
+fn const_public指定でpublicなconstにドキュメントがないと違反になる() {
+    expect_violation(
+        "const/case01/public指定_publicなconstにドキュメントがないと違反になる.rs",
+        r#"
+            [ error ] RustDocを書いてください: require_rust_doc/const/case01/public指定_publicなconstにドキュメントがないと違反になる.rs:1:1 [ found: const MISSING ]
+            [ error ] RustDocを書いてください: require_rust_doc/const/case01/public指定_publicなconstにドキュメントがないと違反になる.rs:16:1 [ found: const MISSING_CRATE ]
+        "#,
+    );
+}
+
+#[test]
+fn static_public指定でpublicなstaticにドキュメントがないと違反になる() {
+    expect_violation(
+        "static/case01/public指定_publicなstaticにドキュメントがないと違反になる.rs",
+        r#"
+            [ error ] RustDocを書いてください: require_rust_doc/static/case01/public指定_publicなstaticにドキュメントがないと違反になる.rs:1:1 [ found: static MISSING ]
+            [ error ] RustDocを書いてください: require_rust_doc/static/case01/public指定_publicなstaticにドキュメントがないと違反になる.rs:8:1 [ found: static MISSING_MUT ]
         "#,
     );
 }
@@ -146,7 +221,7 @@ fn macro_all指定ですべてのmacroにドキュメントがないと違反に
     expect_violation(
         "macro_rules/case02/all指定_すべてのmacroにはドキュメントがない.rs",
         r#"
-            RustDocを書いてください: require_rust_doc/macro_rules/case02/all指定_すべてのmacroにはドキュメントがない.rs:1:1 [ found: macro_rules missing ]
+            [ error ] RustDocを書いてください: require_rust_doc/macro_rules/case02/all指定_すべてのmacroにはドキュメントがない.rs:1:1 [ found: macro_rules missing ]
         "#,
     );
 }
@@ -161,7 +236,7 @@ fn mod_public指定ですべてのpublicなmodにドキュメントがないと
     expect_violation(
         "mod/case02/public指定_すべてのpublicなmodにはドキュメントがない.rs",
         r#"
-            RustDocを書いてください: require_rust_doc/mod/case02/public指定_すべてのpublicなmodにはドキュメントがない.rs:1:1 [ found: mod missing ]
+            [ error ] RustDocを書いてください: require_rust_doc/mod/case02/public指定_すべてのpublicなmodにはドキュメントがない.rs:1:1 [ found: mod missing ]
         "#,
     );
 }
@@ -176,9 +251,45 @@ fn all指定で複数種類にドキュメントがないと違反になる() {
     expect_violation(
         "all/case02/all指定_複数種類にドキュメントがない.rs",
         r#"
-            RustDocを書いてください: require_rust_doc/all/case02/all指定_複数種類にドキュメントがない.rs:1:1 [ found: struct MissingStruct ]
-            RustDocを書いてください: require_rust_doc/all/case02/all指定_複数種類にドキュメントがない.rs:3:1 [ found: enum MissingEnum ]
-            RustDocを書いてください: require_rust_doc/all/case02/all指定_複数種類にドキュメントがない.rs:5:1 [ found: fn missing_fn ]
+            [ error ] RustDocを書いてください: require_rust_doc/all/case02/all指定_複数種類にドキュメントがない.rs:1:1 [ found: struct MissingStruct ]
+            [ error ] RustDocを書いてください: require_rust_doc/all/case02/all指定_複数種類にドキュメントがない.rs:3:1 [ found: enum MissingEnum ]
+            [ error ] RustDocを書いてください: require_rust_doc/all/case02/all指定_複数種類にドキュメントがない.rs:5:1 [ found: fn missing_fn ]
+        "#,
+    );
+}
+
+#[test]
+fn require_non_empty指定で空や空白だけのドキュメントは違反になる() {
+    expect_violation(
+        "fn/case07/require_non_empty指定_空のドキュメントは違反になる.rs",
+        r#"
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case07/require_non_empty指定_空のドキュメントは違反になる.rs:2:1 [ found: fn empty_line_doc ]
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case07/require_non_empty指定_空のドキュメントは違反になる.rs:6:1 [ found: fn whitespace_only_doc ]
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case07/require_non_empty指定_空のドキュメントは違反になる.rs:9:1 [ found: fn empty_block_doc ]
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case07/require_non_empty指定_空のドキュメントは違反になる.rs:14:1 [ found: fn decoration_only_doc ]
+        "#,
+    );
+}
+
+#[test]
+fn top_level_only指定で入れ子の宣言は違反にならない() {
+    expect_violation(
+        "all/case03/top_level_only指定_入れ子の宣言は検査しない.rs",
+        r#"
+            [ error ] RustDocを書いてください: require_rust_doc/all/case03/top_level_only指定_入れ子の宣言は検査しない.rs:1:1 [ found: struct MissingStruct ]
+            [ error ] RustDocを書いてください: require_rust_doc/all/case03/top_level_only指定_入れ子の宣言は検査しない.rs:3:1 [ found: fn missing_fn ]
+            [ error ] RustDocを書いてください: require_rust_doc/all/case03/top_level_only指定_入れ子の宣言は検査しない.rs:16:1 [ found: fn after_nested ]
+        "#,
+    );
+}
+
+#[test]
+fn mod_testsの中の関数は違反にならない() {
+    expect_violation(
+        "fn/case08/mod_tests_の中の関数は検査しない.rs",
+        r#"
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case08/mod_tests_の中の関数は検査しない.rs:1:1 [ found: fn missing_fn ]
+            [ error ] RustDocを書いてください: require_rust_doc/fn/case08/mod_tests_の中の関数は検査しない.rs:12:1 [ found: fn after_tests ]
         "#,
     );
 }
@@ -36,7 +36,7 @@ fn unit_exists指定のときテストモジュールがなければエラーに
         "unit_file_exists",
         "テストがない.rs",
         r#"
-            ユニットテストが必要です: src/テストがない.rs [ found: ユニットテストが存在しません ]
+            [ error ] ユニットテストが必要です: src/テストがない.rs [ found: ユニットテストが存在しません ]
         "#,
     );
 }
@@ -47,7 +47,7 @@ fn unit_exists指定のときテストモジュールもアトリビュートも
         "unit_file_exists",
         "テストがない.rs",
         r#"
-            ユニットテストが必要です: src/テストがない.rs [ found: ユニットテストが存在しません ]
+            [ error ] ユニットテストが必要です: src/テストがない.rs [ found: ユニットテストが存在しません ]
         "#,
     );
 }
@@ -95,7 +95,7 @@ fn unit_all_public指定のとき一部のテストがなければエラーに
         "unit_all_public",
         "一部メソッド未テスト.rs",
         r#"
-            全てのpublic関数にテストが必要です: src/一部メソッド未テスト.rs:4:1 [ found: L4: pub 関数 `delete_user` がテストされていません ]
+            [ error ] 全てのpublic関数にテストが必要です: src/一部メソッド未テスト.rs:4:1 [ found: L4: pub 関数 `delete_user` がテストされていません (期待するテスト: `test_delete_user`) ]
         "#,
     );
 }
@@ -111,3 +111,53 @@ fn exists指定でもモジュール再エクスポートのみのファイル
 fn exists指定でもpub関数がないファイルはエラーにならない() {
     expect_ok("no_public", "pub関数なし.rs");
 }
+
+// style tests
+
+#[test]
+fn style_inline_module指定のときテストモジュールがあればエラーにならない() {
+    expect_ok("inline_module", "order.rs");
+}
+
+#[test]
+fn style_inline_module指定のとき別ファイルのテストだけではエラーになる() {
+    expect_violation(
+        "inline_module",
+        "user.rs",
+        r#"
+            [ error ] ユニットテストが必要です: src/user.rs [ found: ユニットテストが存在しません ]
+        "#,
+    );
+}
+
+#[test]
+fn style_sibling_file指定のとき隣にテストファイルがあればエラーにならない() {
+    expect_ok("sibling_file", "user.rs");
+}
+
+#[test]
+fn style_sibling_file指定のとき隣にテストファイルがなければエラーになる() {
+    expect_violation(
+        "sibling_file",
+        "order.rs",
+        r#"
+            [ error ] テストファイルが必要です: src/order.rs [ found: テストファイルが存在しません: src/order_test.rs ]
+        "#,
+    );
+}
+
+#[test]
+fn style_sibling_file指定のときテストファイル自体は検証しない() {
+    expect_ok("sibling_file", "user_test.rs");
+}
+
+#[test]
+fn style_sibling_file指定かつall_public指定のときテストファイルにない関数はエラーになる() {
+    expect_violation(
+        "sibling_all_public",
+        "user.rs",
+        r#"
+            [ error ] テストファイルが必要です: src/user.rs:5:1 [ found: L5: pub 関数 `delete_user` がテストされていません (期待するテスト: `test_delete_user`) ]
+        "#,
+    );
+}
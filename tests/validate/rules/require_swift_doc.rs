@@ -0,0 +1,52 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn validate_case(relative: &str) -> Vec<String> {
+    let file = common::project_file("require_swift_doc", relative);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+fn expect_violation(relative: &str, expected: &str) {
+    let result = validate_case(relative);
+    common::assert_output(&result, expected);
+}
+
+#[test]
+fn type_all指定でdocコメントがない型は違反になり属性は読み飛ばす() {
+    expect_violation(
+        "type/case01/all指定_一部の型にdocコメントがない.swift",
+        r#"
+            [ error ] docコメントを書いてください: require_swift_doc/type/case01/all指定_一部の型にdocコメントがない.swift:12:1 [ found: struct Order ]
+            [ error ] docコメントを書いてください: require_swift_doc/type/case01/all指定_一部の型にdocコメントがない.swift:31:1 [ found: protocol Loader ]
+            [ error ] docコメントを書いてください: require_swift_doc/type/case01/all指定_一部の型にdocコメントがない.swift:35:1 [ found: enum Cache ]
+        "#,
+    );
+}
+
+#[test]
+fn type_public指定でpublicとopen以外は違反にならず空のdocコメントは認めない() {
+    expect_violation(
+        "type/case02/public指定_publicとopen以外は対象外.swift",
+        r#"
+            [ error ] docコメントを書いてください: require_swift_doc/type/case02/public指定_publicとopen以外は対象外.swift:1:1 [ found: struct Order ]
+            [ error ] docコメントを書いてください: require_swift_doc/type/case02/public指定_publicとopen以外は対象外.swift:10:1 [ found: class EmptyDocViewController ]
+            [ error ] docコメントを書いてください: require_swift_doc/type/case02/public指定_publicとopen以外は対象外.swift:26:1 [ found: protocol Delegate ]
+        "#,
+    );
+}
+
+#[test]
+fn func_public指定でdocコメントがないpublicな関数とメソッドは違反になる() {
+    expect_violation(
+        "func/case01/public指定_publicな関数とメソッドにdocコメントがない.swift",
+        r#"
+            [ error ] docコメントを書いてください: require_swift_doc/func/case01/public指定_publicな関数とメソッドにdocコメントがない.swift:8:1 [ found: func save ]
+            [ error ] docコメントを書いてください: require_swift_doc/func/case01/public指定_publicな関数とメソッドにdocコメントがない.swift:11:1 [ found: func shared ]
+            [ error ] docコメントを書いてください: require_swift_doc/func/case01/public指定_publicな関数とメソッドにdocコメントがない.swift:15:1 [ found: func reset ]
+            [ error ] docコメントを書いてください: require_swift_doc/func/case01/public指定_publicな関数とメソッドにdocコメントがない.swift:18:1 [ found: func copy ]
+            [ error ] docコメントを書いてください: require_swift_doc/func/case01/public指定_publicな関数とメソッドにdocコメントがない.swift:33:1 [ found: func format ]
+        "#,
+    );
+}
@@ -0,0 +1,32 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn run(file: &str) -> Vec<String> {
+    let file = common::project_file("require_trailing_newline", file);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+#[test]
+fn 末尾が改行で終わる場合は空の結果を返す() {
+    assert!(run("改行あり.rs").is_empty());
+}
+
+#[test]
+fn 末尾がcrlfで終わる場合は空の結果を返す() {
+    assert!(run("CRLFの改行あり.rs").is_empty());
+}
+
+#[test]
+fn 空のファイルは違反にならない() {
+    assert!(run("空ファイル.rs").is_empty());
+}
+
+#[test]
+fn 末尾が改行で終わらない場合は最終行を検出する() {
+    common::assert_output(
+        &run("改行なし.rs"),
+        "[ error ] ファイルの末尾に改行を入れてください: require_trailing_newline/改行なし.rs:2:1",
+    );
+}
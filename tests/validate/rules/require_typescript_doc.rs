@@ -0,0 +1,85 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn validate_case(relative: &str) -> Vec<String> {
+    let file = common::project_file("require_typescript_doc", relative);
+    rec_lint::commands::validate::run(&[file], SortMode::Rule).unwrap()
+}
+
+fn expect_violation(relative: &str, expected: &str) {
+    let result = validate_case(relative);
+    common::assert_output(&result, expected);
+}
+
+#[test]
+fn class_all指定でtsdocがないclassは違反になる() {
+    expect_violation(
+        "class/case01/all指定_一部のclassにTSDocがない.ts",
+        r#"
+            [ error ] TSDocを書いてください: require_typescript_doc/class/case01/all指定_一部のclassにTSDocがない.ts:6:1 [ found: class MissingDoc ]
+            [ error ] TSDocを書いてください: require_typescript_doc/class/case01/all指定_一部のclassにTSDocがない.ts:13:1 [ found: class Internal ]
+        "#,
+    );
+}
+
+#[test]
+fn class_public指定でexportされていないclassは違反にならない() {
+    expect_violation(
+        "class/case02/public指定_exportされていないclassは対象外.ts",
+        r#"
+            [ error ] TSDocを書いてください: require_typescript_doc/class/case02/public指定_exportされていないclassは対象外.ts:3:1 [ found: class default ]
+            [ error ] TSDocを書いてください: require_typescript_doc/class/case02/public指定_exportされていないclassは対象外.ts:10:1 [ found: class Base ]
+        "#,
+    );
+}
+
+#[test]
+fn function_all指定でtsdocがない関数とアロー関数は違反になる() {
+    expect_violation(
+        "function/case01/all指定_一部の関数にTSDocがない.ts",
+        r#"
+            [ error ] TSDocを書いてください: require_typescript_doc/function/case01/all指定_一部の関数にTSDocがない.ts:4:1 [ found: function missing ]
+            [ error ] TSDocを書いてください: require_typescript_doc/function/case01/all指定_一部の関数にTSDocがない.ts:6:1 [ found: function load ]
+            [ error ] TSDocを書いてください: require_typescript_doc/function/case01/all指定_一部の関数にTSDocがない.ts:8:1 [ found: function handler ]
+        "#,
+    );
+}
+
+#[test]
+fn function_public指定でexportされていない関数は違反にならない() {
+    expect_violation(
+        "function/case02/public指定_exportされていない関数は対象外.ts",
+        r#"
+            [ error ] TSDocを書いてください: require_typescript_doc/function/case02/public指定_exportされていない関数は対象外.ts:5:1 [ found: function exported ]
+            [ error ] TSDocを書いてください: require_typescript_doc/function/case02/public指定_exportされていない関数は対象外.ts:7:1 [ found: function exportedArrow ]
+            [ error ] TSDocを書いてください: require_typescript_doc/function/case02/public指定_exportされていない関数は対象外.ts:13:1 [ found: function default ]
+        "#,
+    );
+}
+
+#[test]
+fn type_all指定でtsdocがないinterfaceとenumとtypeは違反になる() {
+    expect_violation(
+        "type/case01/all指定_一部の型にTSDocがない.ts",
+        r#"
+            [ error ] TSDocを書いてください: require_typescript_doc/type/case01/all指定_一部の型にTSDocがない.ts:6:1 [ found: interface Missing ]
+            [ error ] TSDocを書いてください: require_typescript_doc/type/case01/all指定_一部の型にTSDocがない.ts:10:1 [ found: enum Color ]
+            [ error ] TSDocを書いてください: require_typescript_doc/type/case01/all指定_一部の型にTSDocがない.ts:15:1 [ found: enum Direction ]
+            [ error ] TSDocを書いてください: require_typescript_doc/type/case01/all指定_一部の型にTSDocがない.ts:23:1 [ found: type Pair ]
+        "#,
+    );
+}
+
+#[test]
+fn type_public指定でexportされていない型は違反にならない() {
+    expect_violation(
+        "type/case02/public指定_exportされていない型は対象外.ts",
+        r#"
+            [ error ] TSDocを書いてください: require_typescript_doc/type/case02/public指定_exportされていない型は対象外.ts:5:1 [ found: interface Exported ]
+            [ error ] TSDocを書いてください: require_typescript_doc/type/case02/public指定_exportされていない型は対象外.ts:13:1 [ found: enum Color ]
+            [ error ] TSDocを書いてください: require_typescript_doc/type/case02/public指定_exportされていない型は対象外.ts:19:1 [ found: type Id ]
+        "#,
+    );
+}
@@ -0,0 +1,30 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::SortMode;
+
+fn run(case: &str) -> Vec<String> {
+    let dir = common::project_file("required_texts", case);
+    rec_lint::commands::validate::run(&[dir], SortMode::File).unwrap()
+}
+
+#[test]
+fn andの場合はすべてのキーワードを含まないファイルを検出する() {
+    common::assert_output(
+        &run("case01"),
+        r#"
+            [ error ] required_texts/case01/none.rs: ライセンス表記がありません
+            [ error ] required_texts/case01/partial.rs: ライセンス表記がありません
+        "#,
+    );
+}
+
+#[test]
+fn orの場合はどのキーワードも含まないファイルだけを検出する() {
+    common::assert_output(
+        &run("case02"),
+        r#"
+            [ error ] required_texts/case02/handwritten.go: 生成ファイルの表記がありません
+        "#,
+    );
+}
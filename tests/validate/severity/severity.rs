@@ -0,0 +1,44 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::{ValidateOptions, ValidateReport};
+use rec_lint::commands::SortMode;
+
+fn run(case: &str) -> ValidateReport {
+    let file = common::test_project_path(format!("severity/{case}/a.rs"));
+    let options = ValidateOptions { sort_mode: SortMode::File, ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[file], &options).unwrap()
+}
+
+#[test]
+fn error以外の重要度は行頭に重要度を付けて出力する() {
+    let report = run("error");
+    common::assert_output(
+        &report.lines,
+        r#"
+            [ error ] error/a.rs:1:4: TODO禁止
+            [ warn ] error/a.rs:2:4: FIXME注意
+            [ info ] error/a.rs:3:4: NOTE確認
+        "#,
+    );
+    assert!(report.has_failure());
+}
+
+#[test]
+fn warnの違反だけの場合は失敗にならない() {
+    let report = run("warn_only");
+    common::assert_output(
+        &report.lines,
+        r#"
+            [ warn ] warn_only/a.rs:1:4: FIXME注意
+        "#,
+    );
+    assert!(!report.has_failure());
+}
+
+#[test]
+fn fail_onはerrorの違反数がしきい値を超えた場合のみ失敗にする() {
+    let report = run("error");
+    assert!(report.exceeds(0));
+    assert!(!report.exceeds(1));
+}
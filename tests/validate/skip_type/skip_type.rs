@@ -0,0 +1,61 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+
+fn run(skip_types: &[&str]) -> Vec<String> {
+    run_file("skip_type/a.rs", skip_types)
+}
+
+fn run_file(path: &str, skip_types: &[&str]) -> Vec<String> {
+    let file = common::test_project_path(path);
+    let options =
+        ValidateOptions { skip_types: skip_types.iter().map(|t| t.to_string()).collect(), ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[file], &options).unwrap().lines
+}
+
+#[test]
+fn skip_type指定なしのときすべてのルールが検証される() {
+    common::assert_output(
+        &run(&[]),
+        r#"
+            [ error ] TODO禁止: a.rs:1:4
+            [ error ] カスタムルール違反: a.rs
+        "#,
+    );
+}
+
+#[test]
+fn skip_type_customのときcustomルールの違反だけが出力されない() {
+    common::assert_output(
+        &run(&["custom"]),
+        r#"
+            [ error ] TODO禁止: a.rs:1:4
+        "#,
+    );
+}
+
+#[test]
+fn skip_typeを複数指定すると指定したすべてのタイプが無効になる() {
+    assert!(run(&["custom", "forbidden_texts"]).is_empty());
+}
+
+#[test]
+fn skip_type_presetのときpresetルールの違反だけが出力されない() {
+    common::assert_output(
+        &run_file("skip_type_preset/app.js", &["preset"]),
+        r#"
+            [ error ] console.log禁止: app.js:2:1
+        "#,
+    );
+}
+
+#[test]
+fn skip_type_forbidden_patternsのときpresetルールは検証される() {
+    common::assert_output(
+        &run_file("skip_type_preset/app.js", &["forbidden_patterns"]),
+        r#"
+            [ error ] var ではなく let か const を使うこと: app.js:1:1
+        "#,
+    );
+}
@@ -1,7 +1,8 @@
 #[path = "../../common/mod.rs"]
 mod common;
 
-use rec_lint::commands::SortMode;
+use rec_lint::commands::validate::ValidateOptions;
+use rec_lint::commands::{OutputFormat, SortMode};
 
 fn sort_dir() -> std::path::PathBuf {
     common::test_project_path("sort")
@@ -17,14 +18,14 @@ fn sort_fileはファイルパス順でソートされる() {
     common::assert_output(
         &result,
         r#"
-            a_first.rs:1:4: TODO禁止
-            a_first.rs:3:4: FIXMEパターン禁止
-            b_second.kt:1:1: コメントを日本語にしてください [ found: english comment line 1 ]
-            b_second.kt:3:1: コメントを日本語にしてください [ found: FIXME: line 3 ]
-            b_second.kt:3:8: FIXMEパターン禁止
-            c_third.java:1:1: コメントを日本語にしてください [ found: TODO: line 1 ]
-            c_third.java:1:4: TODO禁止
-            c_third.java:3:1: コメントを日本語にしてください [ found: another english line 3 ]
+            [ error ] a_first.rs:1:4: TODO禁止
+            [ error ] a_first.rs:3:4: FIXMEパターン禁止
+            [ error ] b_second.kt:1:1: コメントを日本語にしてください [ found: english comment line 1 ]
+            [ error ] b_second.kt:3:1: コメントを日本語にしてください [ found: FIXME: line 3 ]
+            [ error ] b_second.kt:3:8: FIXMEパターン禁止
+            [ error ] c_third.java:1:1: コメントを日本語にしてください [ found: TODO: line 1 ]
+            [ error ] c_third.java:1:4: TODO禁止
+            [ error ] c_third.java:3:1: コメントを日本語にしてください [ found: another english line 3 ]
         "#,
     );
 }
@@ -39,14 +40,54 @@ fn sort_ruleはルール名順でソートされる() {
     common::assert_output(
         &result,
         r#"
-            FIXMEパターン禁止: a_first.rs:3:4
-            FIXMEパターン禁止: b_second.kt:3:8
-            TODO禁止: a_first.rs:1:4
-            TODO禁止: c_third.java:1:4
-            コメントを日本語にしてください: b_second.kt:1:1 [ found: english comment line 1 ]
-            コメントを日本語にしてください: b_second.kt:3:1 [ found: FIXME: line 3 ]
-            コメントを日本語にしてください: c_third.java:1:1 [ found: TODO: line 1 ]
-            コメントを日本語にしてください: c_third.java:3:1 [ found: another english line 3 ]
+            [ error ] FIXMEパターン禁止: a_first.rs:3:4
+            [ error ] FIXMEパターン禁止: b_second.kt:3:8
+            [ error ] TODO禁止: a_first.rs:1:4
+            [ error ] TODO禁止: c_third.java:1:4
+            [ error ] コメントを日本語にしてください: b_second.kt:1:1 [ found: english comment line 1 ]
+            [ error ] コメントを日本語にしてください: b_second.kt:3:1 [ found: FIXME: line 3 ]
+            [ error ] コメントを日本語にしてください: c_third.java:1:1 [ found: TODO: line 1 ]
+            [ error ] コメントを日本語にしてください: c_third.java:3:1 [ found: another english line 3 ]
         "#,
     );
 }
+
+#[test]
+fn ソートキーが同じ違反は何度実行しても同じ順で出力される() {
+    let dirs = [common::test_project_path("sort_tiebreak")];
+    let expected = rec_lint::commands::validate::run(&dirs, SortMode::File).unwrap();
+
+    // 同じファイル・行・列・メッセージの違反は検出した文字列 → ラベルの順で並ぶ
+    common::assert_output(
+        &expected,
+        r#"
+            [ error ] a.rs:1:4: TODO禁止
+            [ error ] a.rs:1:4: TODO禁止
+            [ error ] a.rs:1:4: TODO禁止 [ found: TODO(2000 ]
+            [ error ] a.rs:1:4: TODO禁止 [ found: TODO(2000-01-01) ]
+            [ error ] b.rs:1:4: TODO禁止
+            [ error ] b.rs:1:4: TODO禁止
+            [ error ] b.rs:1:4: TODO禁止 [ found: TODO(2000 ]
+            [ error ] b.rs:1:4: TODO禁止 [ found: TODO(2000-01-01) ]
+        "#,
+    );
+    let expected_rule = rec_lint::commands::validate::run(&dirs, SortMode::Rule).unwrap();
+    for _ in 0..20 {
+        assert_eq!(rec_lint::commands::validate::run(&dirs, SortMode::File).unwrap(), expected);
+        assert_eq!(rec_lint::commands::validate::run(&dirs, SortMode::Rule).unwrap(), expected_rule);
+    }
+}
+
+#[test]
+fn ソートキーと検出した文字列が同じ違反はラベル順で出力される() {
+    let dirs = [common::test_project_path("sort_tiebreak")];
+    let options = ValidateOptions { format: OutputFormat::Checkstyle, ..Default::default() };
+    let expected = rec_lint::commands::validate::run_with_options(&dirs, &options).unwrap().lines;
+
+    let sources: Vec<&str> =
+        expected.iter().filter_map(|line| line.split("source=\"").nth(1)).map(|s| s.trim_end_matches("\"/>")).collect();
+    assert_eq!(sources[..4], ["a_text", "b_text", "todo_short", "todo_full"]);
+    for _ in 0..20 {
+        assert_eq!(rec_lint::commands::validate::run_with_options(&dirs, &options).unwrap().lines, expected);
+    }
+}
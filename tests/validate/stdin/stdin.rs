@@ -0,0 +1,39 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+
+fn run(path: &str, content: &str) -> Vec<String> {
+    let path = common::test_project_path(path);
+    rec_lint::commands::validate::run_with_content(&path, content, &ValidateOptions::default()).unwrap().lines
+}
+
+#[test]
+fn 存在しないファイルでもディレクトリのルールで検証する() {
+    let result = run("format/new.rs", "ok\n// TODO: new\n");
+    common::assert_output(&result, "[ error ] TODO禁止: new.rs:2:4");
+}
+
+#[test]
+fn ディスク上の内容ではなく渡した内容を検証する() {
+    let result = run("format/a.rs", "fn a() {}\n");
+    assert!(result.is_empty());
+}
+
+#[test]
+fn 指定したパスでマッチ条件を判定する() {
+    let result = run("match/regex/infra/Repo_test.kt", "// TODO\n");
+    common::assert_output(&result, "[ error ] domainとinfraでTODO禁止: infra/Repo_test.kt:1:4");
+}
+
+#[test]
+fn カスタムルールは実行しない() {
+    let result = run("skip_type/a.rs", "// TODO: a\n");
+    common::assert_output(&result, "[ error ] TODO禁止: a.rs:1:4");
+}
+
+#[test]
+fn ラベルがcustomのルールは実行する() {
+    let result = run("stdin/a.rs", "// TODO: a\n");
+    common::assert_output(&result, "[ error ] TODO禁止: a.rs:1:4");
+}
@@ -0,0 +1,75 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+use rec_lint::commands::{OutputFormat, SortMode};
+
+fn run_streaming(options: &ValidateOptions) -> (Vec<String>, rec_lint::commands::validate::ValidateReport) {
+    let dir = common::test_project_path("format");
+    let mut lines = Vec::new();
+    let report = rec_lint::commands::validate::run_streaming(&[dir], options, &mut |line| lines.push(line)).unwrap();
+    (lines, report)
+}
+
+/// ファイルの出力順は不定なので、ファイルごとのまとまりを保ったまま並べ替える
+fn sort_by_file_chunks(lines: &[String], file_of: impl Fn(&str) -> &str) -> Vec<String> {
+    let mut chunks: Vec<Vec<String>> = Vec::new();
+    for line in lines {
+        match chunks.last_mut() {
+            Some(chunk) if file_of(&chunk[0]) == file_of(line) => chunk.push(line.clone()),
+            _ => chunks.push(vec![line.clone()]),
+        }
+    }
+    chunks.sort();
+    chunks.concat()
+}
+
+#[test]
+fn ファイルごとにルール順で出力する() {
+    let options = ValidateOptions { sort_mode: SortMode::Rule, ..Default::default() };
+    let (lines, report) = run_streaming(&options);
+    let lines = sort_by_file_chunks(&lines, |line| line.split(": ").nth(1).unwrap().split(':').next().unwrap());
+    common::assert_output(
+        &lines,
+        r#"
+            [ error ] FIXMEパターン禁止: a.rs:3:4
+            [ error ] TODO禁止: a.rs:1:4
+            [ error ] TODO禁止: b.rs:1:4
+        "#,
+    );
+    assert_eq!(report.violation_count, 3);
+    assert!(report.has_failure());
+    assert!(report.lines.is_empty());
+}
+
+#[test]
+fn ファイルごとに行順で出力する() {
+    let options = ValidateOptions { sort_mode: SortMode::File, ..Default::default() };
+    let (lines, _) = run_streaming(&options);
+    let lines = sort_by_file_chunks(&lines, |line| line.split(':').next().unwrap());
+    common::assert_output(
+        &lines,
+        r#"
+            [ error ] a.rs:1:4: TODO禁止
+            [ error ] a.rs:3:4: FIXMEパターン禁止
+            [ error ] b.rs:1:4: TODO禁止
+        "#,
+    );
+}
+
+#[test]
+fn 違反がなければ失敗しない() {
+    let dir = common::test_project_path("rules/no_trailing_whitespace");
+    let options = ValidateOptions { only: vec!["unknown".to_string()], ..Default::default() };
+    let mut lines = Vec::new();
+    let report = rec_lint::commands::validate::run_streaming(&[dir], &options, &mut |line| lines.push(line)).unwrap();
+    assert!(lines.is_empty());
+    assert!(!report.has_failure());
+}
+
+#[test]
+fn text_github以外の形式はエラーになる() {
+    let dir = common::test_project_path("format");
+    let options = ValidateOptions { format: OutputFormat::Junit, ..Default::default() };
+    assert!(rec_lint::commands::validate::run_streaming(&[dir], &options, &mut |_| {}).is_err());
+}
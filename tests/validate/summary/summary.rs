@@ -0,0 +1,54 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::ValidateOptions;
+use rec_lint::commands::SortMode;
+
+fn options() -> ValidateOptions {
+    ValidateOptions { sort_mode: SortMode::File, summary: true, ..Default::default() }
+}
+
+#[test]
+fn summary指定時は違反の後に集計行を出力する() {
+    let dir = common::test_project_path("format");
+    let report = rec_lint::commands::validate::run_with_options(&[dir], &options()).unwrap();
+    common::assert_output(
+        &report.lines,
+        r#"
+            [ error ] a.rs:1:4: TODO禁止
+            [ error ] a.rs:3:4: FIXMEパターン禁止
+            [ error ] b.rs:1:4: TODO禁止
+            3 violations in 2 files (2 rules triggered)
+        "#,
+    );
+    assert_eq!(report.violation_count, 3);
+}
+
+#[test]
+fn 違反がなくても集計行を出力し失敗にはしない() {
+    let file = common::project_file("forbidden_texts", "case01/違反キーワードなし.kt");
+    let report = rec_lint::commands::validate::run_with_options(&[file], &options()).unwrap();
+    common::assert_output(
+        &report.lines,
+        r#"
+            0 violations in 0 files (0 rules triggered)
+        "#,
+    );
+    assert!(!report.has_failure());
+}
+
+#[test]
+fn streamでも最後に集計行を出力する() {
+    let dir = common::test_project_path("format_file_level");
+    let mut lines = Vec::new();
+    let report = rec_lint::commands::validate::run_streaming(&[dir], &options(), &mut |line| lines.push(line)).unwrap();
+    common::assert_output(
+        &lines,
+        r#"
+            [ error ] work.tmp.rs: 一時ファイル禁止
+            [ error ] work.tmp.rs:1:4: TODO禁止
+            2 violations in 1 file (2 rules triggered)
+        "#,
+    );
+    assert_eq!(report.violation_count, 2);
+}
@@ -0,0 +1,41 @@
+#[path = "../../common/mod.rs"]
+mod common;
+
+use rec_lint::commands::validate::{ValidateOptions, ValidateReport};
+
+fn run(path: &str, warn_unused: bool) -> ValidateReport {
+    let options = ValidateOptions { warn_unused, ..Default::default() };
+    rec_lint::commands::validate::run_with_options(&[common::test_project_path(path)], &options).unwrap()
+}
+
+#[test]
+fn どのファイルにもマッチしないルールを設定ファイルごとに報告する() {
+    let report = run("unused", true);
+    common::assert_output(
+        &report.unused,
+        r#"
+            Unused rules: .rec_lint.yaml
+              - rule 'kt-todo' (forbidden_texts) matched no files
+            Unused rules: src/.rec_lint.yaml
+              - rule 'md-fixme' (forbidden_texts) matched no files
+        "#,
+    );
+    assert!(report.lines.is_empty());
+}
+
+#[test]
+fn 検証したファイルに継承されないルールは報告しない() {
+    let report = run("unused/a.rs", true);
+    common::assert_output(
+        &report.unused,
+        r#"
+            Unused rules: .rec_lint.yaml
+              - rule 'kt-todo' (forbidden_texts) matched no files
+        "#,
+    );
+}
+
+#[test]
+fn warn_unusedを指定しない場合は報告しない() {
+    assert!(run("unused", false).unused.is_empty());
+}
@@ -0,0 +1,63 @@
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+
+use rec_lint::commands::validate::ValidateOptions;
+use rec_lint::commands::SortMode;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+fn write_rule(dir: &Path, text: &str) {
+    let config = format!(
+        "rule:\n  - forbidden_texts:\n      label: {text}禁止\n      texts: [{text}]\n      message: {text}禁止\n"
+    );
+    fs::write(dir.join(".rec_lint.yaml"), config).unwrap();
+}
+
+/// Wait for a report whose lines equal `expected` (intermediate reports of partial changes are skipped)
+fn wait_for(receiver: &Receiver<Vec<String>>, expected: &[&str]) {
+    let deadline = Instant::now() + TIMEOUT;
+    let mut last = None;
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        match receiver.recv_timeout(remaining) {
+            Ok(lines) if lines == expected => return,
+            Ok(lines) => last = Some(lines),
+            Err(_) => break,
+        }
+    }
+    panic!("expected {expected:?}, last report: {last:?}");
+}
+
+#[test]
+fn ファイルや設定ファイルが変更されるたびに検証し直す() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().canonicalize().unwrap();
+    fs::write(root.join(".rec_lint_config.yaml"), "").unwrap();
+    write_rule(&root, "TODO");
+    fs::write(root.join("a.rs"), "fn main() {}\n").unwrap();
+
+    let paths = vec![root.clone()];
+    let stop = AtomicBool::new(false);
+    let (sender, receiver) = mpsc::channel();
+    let options = ValidateOptions { sort_mode: SortMode::File, ..Default::default() };
+    std::thread::scope(|scope| {
+        let watching = scope.spawn(|| {
+            rec_lint::commands::validate::watch::run(&paths, &options, &stop, &mut |report| {
+                sender.send(report.lines).unwrap();
+            })
+        });
+
+        wait_for(&receiver, &[]);
+
+        fs::write(root.join("a.rs"), "// TODO\nfn main() {}\n").unwrap();
+        wait_for(&receiver, &["[ error ] a.rs:1:4: TODO禁止"]);
+
+        write_rule(&root, "FIXME");
+        wait_for(&receiver, &[]);
+
+        stop.store(true, Ordering::SeqCst);
+        watching.join().unwrap().unwrap();
+    });
+}
@@ -7,6 +7,13 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+// Shared with the rec_lint crate (`rec_lint explain` uses the examples)
+#[path = "../../../src/rule/types.rs"]
+#[allow(dead_code)]
+mod types;
+
+use types::{PRESET_INFOS, RULE_TYPE_INFOS};
+
 const OUTPUT_DIR: &str = "docs/schema";
 const RULES_OUTPUT_DIR: &str = "docs/schema/rules";
 
@@ -67,6 +74,36 @@ const SCHEMAS: &[SchemaConfig] = &[
         output: "rules/require-rust-doc.md",
         is_index: false,
     },
+    SchemaConfig {
+        input: "schema/parts/rules/require-python-doc.schema.json",
+        output: "rules/require-python-doc.md",
+        is_index: false,
+    },
+    SchemaConfig {
+        input: "schema/parts/rules/require-typescript-doc.schema.json",
+        output: "rules/require-typescript-doc.md",
+        is_index: false,
+    },
+    SchemaConfig {
+        input: "schema/parts/rules/require-go-doc.schema.json",
+        output: "rules/require-go-doc.md",
+        is_index: false,
+    },
+    SchemaConfig {
+        input: "schema/parts/rules/require-swift-doc.schema.json",
+        output: "rules/require-swift-doc.md",
+        is_index: false,
+    },
+    SchemaConfig {
+        input: "schema/parts/rules/require-csharp-doc.schema.json",
+        output: "rules/require-csharp-doc.md",
+        is_index: false,
+    },
+    SchemaConfig {
+        input: "schema/parts/rules/require-doc.schema.json",
+        output: "rules/require-doc.md",
+        is_index: false,
+    },
     SchemaConfig {
         input: "schema/parts/rules/require-english-comment.schema.json",
         output: "rules/require-english-comment.md",
@@ -92,6 +129,16 @@ const SCHEMAS: &[SchemaConfig] = &[
         output: "rules/require-japanese-rust-test-name.md",
         is_index: false,
     },
+    SchemaConfig {
+        input: "schema/parts/rules/require-japanese-junit-test-name.schema.json",
+        output: "rules/require-japanese-junit-test-name.md",
+        is_index: false,
+    },
+    SchemaConfig {
+        input: "schema/parts/rules/require-japanese-spock-test-name.schema.json",
+        output: "rules/require-japanese-spock-test-name.md",
+        is_index: false,
+    },
     SchemaConfig {
         input: "schema/parts/rules/require-phpunit-test.schema.json",
         output: "rules/require-phpunit-test.md",
@@ -107,84 +154,75 @@ const SCHEMAS: &[SchemaConfig] = &[
         output: "rules/require-rust-unit-test.md",
         is_index: false,
     },
-];
-
-struct RuleTypeInfo {
-    type_name: &'static str,
-    description: &'static str,
-    doc_path: &'static str,
-}
-
-const RULE_TYPES: &[RuleTypeInfo] = &[
-    RuleTypeInfo {
-        type_name: "forbidden_texts",
-        description: "禁止キーワードを完全一致で検出",
-        doc_path: "./rules/forbidden-texts.md",
+    SchemaConfig {
+        input: "schema/parts/rules/consistent-quotes.schema.json",
+        output: "rules/consistent-quotes.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "forbidden_patterns",
-        description: "禁止パターンを正規表現で検出",
-        doc_path: "./rules/forbidden-patterns.md",
+    SchemaConfig {
+        input: "schema/parts/rules/no-skipped-tests.schema.json",
+        output: "rules/no-skipped-tests.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "custom",
-        description: "任意のコマンドを実行して検証",
-        doc_path: "./rules/custom.md",
+    SchemaConfig {
+        input: "schema/parts/rules/max-line-length.schema.json",
+        output: "rules/max-line-length.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "require_php_doc",
-        description: "PHPDoc がないファイルを検出",
-        doc_path: "./rules/require-php-doc.md",
+    SchemaConfig {
+        input: "schema/parts/rules/no-trailing-whitespace.schema.json",
+        output: "rules/no-trailing-whitespace.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "require_kotlin_doc",
-        description: "KDoc がないファイルを検出",
-        doc_path: "./rules/require-kotlin-doc.md",
+    SchemaConfig {
+        input: "schema/parts/rules/require-trailing-newline.schema.json",
+        output: "rules/require-trailing-newline.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "require_rust_doc",
-        description: "rustdoc がないファイルを検出",
-        doc_path: "./rules/require-rust-doc.md",
+    SchemaConfig {
+        input: "schema/parts/rules/forbidden-file-names.schema.json",
+        output: "rules/forbidden-file-names.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "require_english_comment",
-        description: "コメントが日本語のファイルを検出",
-        doc_path: "./rules/require-english-comment.md",
+    SchemaConfig {
+        input: "schema/parts/rules/max-file-lines.schema.json",
+        output: "rules/max-file-lines.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "require_japanese_comment",
-        description: "コメントが英語のファイルを検出",
-        doc_path: "./rules/require-japanese-comment.md",
+    SchemaConfig {
+        input: "schema/parts/rules/require-go-test.schema.json",
+        output: "rules/require-go-test.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "require_japanese_phpunit_test_name",
-        description: "PHPUnit テスト名が日本語でないファイルを検出",
-        doc_path: "./rules/require-japanese-phpunit-test-name.md",
+    SchemaConfig {
+        input: "schema/parts/rules/forbidden-imports.schema.json",
+        output: "rules/forbidden-imports.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "require_japanese_kotest_test_name",
-        description: "Kotest テスト名が日本語でないファイルを検出",
-        doc_path: "./rules/require-japanese-kotest-test-name.md",
+    SchemaConfig {
+        input: "schema/parts/rules/required-texts.schema.json",
+        output: "rules/required-texts.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "require_japanese_rust_test_name",
-        description: "Rust テスト名が日本語でないファイルを検出",
-        doc_path: "./rules/require-japanese-rust-test-name.md",
+    SchemaConfig {
+        input: "schema/parts/rules/require-japanese-python-test-name.schema.json",
+        output: "rules/require-japanese-python-test-name.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "require_phpunit_test",
-        description: "PHPUnit テストファイルの存在を検証",
-        doc_path: "./rules/require-phpunit-test.md",
+    SchemaConfig {
+        input: "schema/parts/rules/require-japanese-jest-test-name.schema.json",
+        output: "rules/require-japanese-jest-test-name.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "require_kotest_test",
-        description: "Kotest テストファイルの存在を検証",
-        doc_path: "./rules/require-kotest-test.md",
+    SchemaConfig {
+        input: "schema/parts/rules/preset.schema.json",
+        output: "rules/preset.md",
+        is_index: false,
     },
-    RuleTypeInfo {
-        type_name: "require_rust_unit_test",
-        description: "Rust ユニットテストの存在を検証",
-        doc_path: "./rules/require-rust-unit-test.md",
+    SchemaConfig {
+        input: "schema/parts/rules/no-stale-todo.schema.json",
+        output: "rules/no-stale-todo.md",
+        is_index: false,
     },
 ];
 
@@ -561,11 +599,13 @@ fn render_index_schema(schema: &Value, schema_set: &SchemaSet, current_path: &st
     writeln!(out, "## Rule Types\n").unwrap();
     writeln!(out, "| type | 説明 | ドキュメント |").unwrap();
     writeln!(out, "|------|------|--------------|").unwrap();
-    for rule_type in RULE_TYPES {
+    for rule_type in RULE_TYPE_INFOS {
         writeln!(
             out,
-            "| `{}` | {} | [詳細]({}) |",
-            rule_type.type_name, rule_type.description, rule_type.doc_path
+            "| `{}` | {} | [詳細](./rules/{}.md) |",
+            rule_type.type_name,
+            rule_type.description,
+            rule_type.type_name.replace('_', "-")
         )
         .unwrap();
     }
@@ -665,9 +705,36 @@ fn render_rule_schema(schema: &Value, schema_set: &SchemaSet, current_path: &str
         }
     }
 
+    if current_path.ends_with("preset.schema.json") {
+        render_presets(&mut out);
+    }
+
     out
 }
 
+fn render_presets(out: &mut String) {
+    writeln!(out, "## プリセット\n").unwrap();
+    writeln!(out, "| name | 説明 | patterns | デフォルトの message |").unwrap();
+    writeln!(out, "|------|------|----------|----------------------|").unwrap();
+    for preset in PRESET_INFOS {
+        let patterns: Vec<String> = preset
+            .patterns
+            .iter()
+            .map(|p| format!("`{}`", p.replace('|', "\\|")))
+            .collect();
+        writeln!(
+            out,
+            "| `{}` | {} | {} | {} |",
+            preset.name,
+            preset.description,
+            patterns.join("<br>"),
+            preset.message
+        )
+        .unwrap();
+    }
+    writeln!(out).unwrap();
+}
+
 fn extract_type_const(def: &Value, schema_set: &SchemaSet, current_path: &str) -> Option<String> {
     if let Some(all_of) = def.get("allOf").and_then(|v| v.as_array()) {
         for item in all_of {